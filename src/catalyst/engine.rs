@@ -13,6 +13,7 @@ use crate::catalyst::generator::{DocumentType, GenerationContext, VisionData};
 use crate::catalyst::prompts::PromptTemplate;
 use crate::catalyst::validation::validate_generated_document;
 use crate::llm::LlmClient;
+use crate::schema::planning::PlanningSchema;
 
 /// Report of document generation results
 #[derive(Debug, Clone)]
@@ -187,16 +188,24 @@ pub struct CatalystEngine {
     obsidian_path: PathBuf,
     /// LLM client for generation
     llm_client: LlmClient,
+    /// Planning schema generated documents are validated against
+    schema: PlanningSchema,
 }
 
 impl CatalystEngine {
     /// Create a new catalyst engine
     #[allow(clippy::unnecessary_wraps)] // Consistent API, may add validation later
-    pub fn new(project_id: String, obsidian_path: PathBuf, llm_client: LlmClient) -> Result<Self> {
+    pub fn new(
+        project_id: String,
+        obsidian_path: PathBuf,
+        llm_client: LlmClient,
+        schema: PlanningSchema,
+    ) -> Result<Self> {
         Ok(Self {
             project_id,
             obsidian_path,
             llm_client,
+            schema,
         })
     }
 
@@ -233,6 +242,7 @@ impl CatalystEngine {
             .context("Failed to write scope document")?;
 
         let is_valid = validate_generated_document(
+            &self.schema,
             DocumentType::Scope,
             &content,
             &output_path,
@@ -292,6 +302,7 @@ impl CatalystEngine {
             .context("Failed to write tech stack document")?;
 
         let is_valid = validate_generated_document(
+            &self.schema,
             DocumentType::TechStack,
             &content,
             &output_path,
@@ -354,6 +365,7 @@ impl CatalystEngine {
             .context("Failed to write architecture document")?;
 
         let is_valid = validate_generated_document(
+            &self.schema,
             DocumentType::Architecture,
             &content,
             &output_path,
@@ -418,6 +430,7 @@ impl CatalystEngine {
             .context("Failed to write MVP breakdown document")?;
 
         let is_valid = validate_generated_document(
+            &self.schema,
             DocumentType::MvpBreakdown,
             &content,
             &output_path,
@@ -618,7 +631,7 @@ Please regenerate the document incorporating the user's feedback while maintaini
         fs::write(&doc_path, &cleaned)
             .context("Failed to write refined document")?;
 
-        let is_valid = validate_generated_document(doc_type, &cleaned, &doc_path)?;
+        let is_valid = validate_generated_document(&self.schema, doc_type, &cleaned, &doc_path)?;
 
         if !is_valid {
             println!(
@@ -668,7 +681,7 @@ Please regenerate the document incorporating the user's feedback while maintaini
             if doc_path.exists() {
                 // Check if it passes validation
                 if let Ok(content) = fs::read_to_string(&doc_path) {
-                    match validate_generated_document(*doc_type, &content, &doc_path) {
+                    match validate_generated_document(&self.schema, *doc_type, &content, &doc_path) {
                         Ok(true) => status.mark_complete(*doc_type),
                         Ok(false) => status.mark_needs_refinement(*doc_type),
                         Err(_) => status.mark_needs_refinement(*doc_type),
@@ -929,15 +942,11 @@ fn extract_sections(content: &str) -> HashMap<String, String> {
                     current_content.push(text.to_string());
                 }
             }
-            Event::Code(code) => {
-                if !in_header && current_header.is_some() {
-                    current_content.push(format!("`{code}`"));
-                }
+            Event::Code(code) if !in_header && current_header.is_some() => {
+                current_content.push(format!("`{code}`"));
             }
-            Event::SoftBreak | Event::HardBreak => {
-                if !in_header && current_header.is_some() {
-                    current_content.push("\n".to_string());
-                }
+            Event::SoftBreak | Event::HardBreak if !in_header && current_header.is_some() => {
+                current_content.push("\n".to_string());
             }
             _ => {}
         }
@@ -5,14 +5,16 @@ use std::path::Path;
 
 use crate::catalyst::generator::DocumentType;
 use crate::planning::validate_planning_document_with_headers;
+use crate::schema::planning::PlanningSchema;
 
 /// Validate a generated document against its expected structure
 pub fn validate_generated_document(
+    schema: &PlanningSchema,
     doc_type: DocumentType,
     _content: &str,
     path: &Path,
 ) -> Result<bool> {
-    let (required_headers, min_word_count) = get_validation_requirements(doc_type);
+    let (required_headers, min_word_count) = get_validation_requirements(schema, doc_type)?;
 
     let illegal_strings: Vec<String> = vec![
         "TODO".to_string(),
@@ -42,46 +44,15 @@ pub fn validate_generated_document(
     Ok(validation.passed)
 }
 
-/// Get validation requirements for each document type
-fn get_validation_requirements(doc_type: DocumentType) -> (Vec<String>, usize) {
-    match doc_type {
-        DocumentType::Scope => (
-            vec![
-                "MVP (Minimum Viable Product):".to_string(),
-                "Version 2 (NOT NOW - just document):".to_string(),
-                "Never (things I will NOT build):".to_string(),
-                "Tech constraints:".to_string(),
-            ],
-            15, // minimum word count per section
-        ),
-        DocumentType::TechStack => (
-            vec![
-                "Stack (force yourself to choose NOW):".to_string(),
-                "Why these choices?".to_string(),
-                "What I will NOT use:".to_string(),
-                "Dependencies (important ones):".to_string(),
-                "Development environment:".to_string(),
-            ],
-            15,
-        ),
-        DocumentType::Architecture => (
-            vec![
-                "Folder structure:".to_string(),
-                "Data model (main entities):".to_string(),
-                "Flow (user journey):".to_string(),
-                "Critical technical decisions:".to_string(),
-            ],
-            15,
-        ),
-        DocumentType::MvpBreakdown => (
-            vec![
-                "Sprint 1:".to_string(),
-                "Sprint 2:".to_string(),
-                "Definition of Done (each sprint):".to_string(),
-            ],
-            15,
-        ),
-    }
+/// Get validation requirements for a document type from the planning schema
+fn get_validation_requirements(
+    schema: &PlanningSchema,
+    doc_type: DocumentType,
+) -> Result<(Vec<String>, usize)> {
+    let document = schema.document(doc_type.filename()).with_context(|| {
+        format!("Planning schema has no document '{}'", doc_type.filename())
+    })?;
+    Ok((document.required_headers.clone(), document.min_word_count))
 }
 
 /// Check if a document contains placeholder text that should be replaced
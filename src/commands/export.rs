@@ -0,0 +1,89 @@
+//! Export Command Implementation
+//!
+//! Writes the sprint roadmap as Mermaid, JSON, CSV or iCalendar for people
+//! outside the vault.
+
+use crate::config::NexusConfig;
+use crate::export::{ExportFormat, RoadmapExport};
+use crate::mvp::{parse_mvp_sprints, parse_task_list};
+use crate::roadmap::Roadmap;
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Execute `nexus export roadmap`
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory (where nexus.toml lives)
+/// * `format` - Output format
+/// * `output` - File to write; standard output when `None`
+///
+/// # Returns
+/// * `Ok(())` - Roadmap exported
+/// * `Err` - If the config or MVP breakdown cannot be read, the dependency
+///   graph is invalid, or the output cannot be written
+pub fn execute_roadmap(project_path: &Path, format: ExportFormat, output: Option<&Path>) -> Result<()> {
+    let config_path = project_path.join("nexus.toml");
+    let config_content = fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read nexus.toml from: {}", project_path.display()))?;
+    let config = NexusConfig::from_toml(&config_content)
+        .with_context(|| format!("Failed to parse config from: {}", config_path.display()))?;
+
+    let layout = config.layout();
+    let mvp_path = layout.mvp_breakdown();
+    if !mvp_path.exists() {
+        bail!("MVP breakdown file not found: {}", mvp_path.display());
+    }
+    let sprints =
+        parse_mvp_sprints(&mvp_path).context("Failed to parse sprints from MVP breakdown")?;
+    let roadmap = Roadmap::new(&sprints);
+
+    let problems = roadmap.problems();
+    if !problems.is_empty() {
+        let problems: Vec<String> = problems
+            .iter()
+            .map(|problem| format!("  [{}] {}", problem.rule, problem.message))
+            .collect();
+        bail!(
+            "Cannot export the roadmap, fix the sprint dependencies in {} first:\n{}",
+            mvp_path.display(),
+            problems.join("\n")
+        );
+    }
+
+    // Task completion comes from the sprint workspaces that have been scaffolded
+    let mut progress = BTreeMap::new();
+    for sprint in &sprints {
+        let tasks_path = layout.sprint_folder(sprint).join("Tasks.md");
+        if tasks_path.exists() {
+            let content = fs::read_to_string(&tasks_path)
+                .with_context(|| format!("Failed to read {}", tasks_path.display()))?;
+            progress.insert(sprint.number, parse_task_list(&content, &sprint.id_prefix()));
+        }
+    }
+
+    let export = RoadmapExport::new(
+        &config.project.name,
+        &roadmap,
+        config.state.as_ref(),
+        &progress,
+        chrono::Local::now().date_naive(),
+    );
+    let rendered = export.render(format);
+
+    match output {
+        Some(output) => {
+            fs::write(output, rendered)
+                .with_context(|| format!("Failed to write {}", output.display()))?;
+            println!(
+                "{}",
+                format!("✓ Roadmap exported to {}", output.display()).green()
+            );
+        }
+        None => print!("{rendered}"),
+    }
+
+    Ok(())
+}
@@ -7,22 +7,94 @@
 #![allow(clippy::if_not_else)] // Validation logic is clearer with existence checks first
 
 use crate::config::NexusConfig;
+use crate::gate_cache::{CACHE_FILE, CachedDocument, GateCache, content_hash};
+use crate::gate_report::{
+    DocumentReport, GateReport, ReportFormat, ReportGroup, ReportedIssue, WaiverStatus,
+};
+use crate::heuristics::{BUILT_IN_LAYER, CompiledRule, HeuristicsProfiles, Severity};
+use crate::layout::DASHBOARD_FILE;
+use crate::links::VaultIndex;
+use crate::schema::planning::{DocumentSchema, PlanningSchema};
+use crate::transclusion::{SourcedIssue, expand_embeds};
+use crate::waivers::{WAIVERS_FILE, Waiver};
 use crate::planning::{
-    ValidationIssue, validate_dashboard_checkboxes, validate_planning_document_with_headers,
+    HeaderMatcher, ValidationIssue, validate_dashboard_checkboxes, validate_planning_content,
+    validate_rules_content,
 };
 use anyhow::{Context, Result};
 use colored::Colorize;
-use std::path::Path;
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Exit code when the gate ran to completion but validation failed
+pub const EXIT_GATE_CLOSED: i32 = 1;
+/// Exit code when the gate could not run (missing config, unreadable vault, ...)
+pub const EXIT_GATE_ERROR: i32 = 2;
 
 /// Execute the gate command
 /// Returns Ok(()) if gate passes, Err if validation fails or error occurs
 pub fn execute(project_path: &Path) -> Result<()> {
+    let report = execute_with_format(project_path, ReportFormat::Text, true)?;
+    if report.passed() {
+        Ok(())
+    } else {
+        anyhow::bail!("Validation failed")
+    }
+}
+
+/// Execute the gate command and print the report in the requested format
+/// Returns the report so callers can derive an exit code
+pub fn execute_with_format(
+    project_path: &Path,
+    format: ReportFormat,
+    use_cache: bool,
+) -> Result<GateReport> {
+    let report = run(project_path, use_cache)?;
+
+    match format {
+        ReportFormat::Text => print_text_report(&report),
+        ReportFormat::Json => println!("{}", report.to_json()?),
+        ReportFormat::Sarif => println!("{}", report.to_sarif()?),
+    }
+
+    Ok(report)
+}
+
+/// Record a waiver in `.nexus/gate-waivers.toml` (`nexus gate waive`)
+///
+/// When no author is given, the git `user.name` is used, then `$USER`.
+pub fn execute_waive(project_path: &Path, mut waiver: Waiver) -> Result<()> {
+    if waiver.author.trim().is_empty() {
+        waiver.author = crate::git_ops::default_author().context(
+            "Could not determine waiver author; pass --author or set git user.name",
+        )?;
+    }
+    if waiver.is_expired(chrono::Local::now().date_naive()) {
+        anyhow::bail!("Waiver expiry {} is in the past", waiver.expires);
+    }
+
+    let path = project_path.join(WAIVERS_FILE);
+    crate::waivers::add_waiver(&path, waiver.clone())?;
+
+    println!("{} Waiver recorded: {}", "✓".green().bold(), waiver.describe());
+    println!("   Reason: {}", waiver.reason);
+    println!("   Author: {}", waiver.author);
+    println!("   Expires: {}", waiver.expires);
+    println!("   File: {}", path.display().to_string().dimmed());
+
+    Ok(())
+}
+
+/// Run every gate check and collect the results without printing
+///
+/// Planning documents whose contents are unchanged since the last run reuse the
+/// results in `.nexus/gate-cache.json`; with `use_cache = false` every document
+/// is validated again and the cache is rebuilt.
+/// Returns Err only if the gate itself cannot run (config or vault problems)
+pub fn run(project_path: &Path, use_cache: bool) -> Result<GateReport> {
     // Load project configuration
-    let config_path = project_path.join("nexus.toml");
-    let config_content = std::fs::read_to_string(&config_path)
-        .with_context(|| format!("Failed to read config from: {}", config_path.display()))?;
-    let config: NexusConfig = toml::from_str(&config_content)
-        .with_context(|| format!("Failed to parse config from: {}", config_path.display()))?;
+    let config = load_config(project_path)?;
 
     // Resolve obsidian vault path
     let vault_path = config.get_repo_path();
@@ -62,9 +134,6 @@ pub fn execute(project_path: &Path) -> Result<()> {
         }
     }
 
-    println!("{}", "🚪 INITIATING GATE SEQUENCE...".bold().cyan());
-    println!();
-
     // Check lifecycle state and mode
     let is_unlocked = config
         .state
@@ -77,13 +146,13 @@ pub fn execute(project_path: &Path) -> Result<()> {
     } else {
         "PHASE 1: PLANNING"
     };
-    println!("{} {}", "📍 Lifecycle Phase:".bold(), phase_label.yellow());
-    println!();
+
+    let mut report = GateReport::new(&config.project.name, phase_label, project_path);
 
     // Mode and phase-specific validation
-    let all_passed = if config.is_adhoc_mode() {
+    if config.is_adhoc_mode() {
         // ADHOC MODE: Validate adhoc planning documents
-        validate_adhoc_planning(&vault_path, &config)?
+        validate_adhoc_planning(&vault_path, &config, &mut report)?;
     } else {
         // Sprint mode - load heuristics file with smart fallback
         let stable_path = project_path.join(".nexus/gate-heuristics.json");
@@ -92,431 +161,827 @@ pub fn execute(project_path: &Path) -> Result<()> {
         // Check if we need to bootstrap
         let needs_bootstrap = !stable_path.exists() && !legacy_path.exists();
 
-        // Use smart fallback: stable → legacy → bootstrap
+        // Use smart fallback: stable → legacy → bootstrap, merged over inherited profiles
         let heuristics = crate::heuristics::load_heuristics_with_fallback(
             &stable_path,
             Some(&legacy_path),
-        ).context("Failed to load or create gate heuristics")?;
+            &heuristics_profiles(project_path, &config),
+        )
+        .context("Failed to load or create gate heuristics")?
+        .heuristics;
 
         // Inform user if bootstrap was created
         if needs_bootstrap {
-            println!(
-                "  {} Created bootstrap heuristics at: {}",
-                "ℹ".cyan(),
-                stable_path.display().to_string().dimmed()
-            );
+            report.notices.push(format!(
+                "Created bootstrap heuristics at: {}",
+                stable_path.display()
+            ));
         }
 
+        // Severities from heuristics; strict mode promotes warnings to errors
+        report.policy = heuristics.severity_policy(config.gate.strict_mode);
+
         if is_unlocked {
             // PHASE 2: Active Sprint Validation
-            validate_active_sprint(&vault_path, &config)?
+            validate_active_sprint(&config, &mut report)?;
         } else {
             // PHASE 1: Planning Document Validation
-            validate_planning_phase(&vault_path, &config, &heuristics)?
+            let schema = PlanningSchema::for_project(&config)?;
+            let cache_path = project_path.join(CACHE_FILE);
+            let fingerprint = crate::gate_cache::fingerprint(&heuristics, &schema)?;
+            let mut cache = if use_cache {
+                GateCache::load(&cache_path, &fingerprint)
+            } else {
+                GateCache::empty(fingerprint)
+            };
+
+            validate_planning_phase(
+                &config,
+                &heuristics,
+                &schema,
+                &mut cache,
+                &mut report,
+            )?;
+
+            // A cache that cannot be written only costs speed on the next run
+            if let Err(e) = cache.save(&cache_path) {
+                report.notices.push(format!("Gate cache not saved: {e:#}"));
+            }
         }
-    };
+    }
+
+    // Waivers apply in every phase; expired ones suppress nothing
+    let waivers = crate::waivers::load_waivers(&project_path.join(WAIVERS_FILE))?;
+    report.apply_waivers(waivers.waivers, chrono::Local::now().date_naive());
+
+    Ok(report)
+}
+
+/// Load and parse nexus.toml from the project root
+pub(crate) fn load_config(project_path: &Path) -> Result<NexusConfig> {
+    let config_path = project_path.join("nexus.toml");
+    let config_content = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read config from: {}", config_path.display()))?;
+    toml::from_str(&config_content)
+        .with_context(|| format!("Failed to parse config from: {}", config_path.display()))
+}
+
+/// Inherited heuristics layers for a project: shared profiles and `gate.extends`
+fn heuristics_profiles(project_path: &Path, config: &NexusConfig) -> HeuristicsProfiles {
+    HeuristicsProfiles {
+        profile_dir: crate::heuristics::default_profile_dir(),
+        extends: config.gate.extends.clone(),
+        project_root: project_path.to_path_buf(),
+    }
+}
+
+/// Print the effective gate heuristics and the layer each value came from
+/// (`nexus gate --explain-config`). Does not create bootstrap files.
+pub fn execute_explain_config(project_path: &Path) -> Result<()> {
+    let config = load_config(project_path)?;
+    if config.is_adhoc_mode() {
+        anyhow::bail!(
+            "--explain-config applies to sprint mode; ad-hoc mode uses the built-in task heuristics"
+        );
+    }
+
+    let stable_path = project_path.join(".nexus/gate-heuristics.json");
+    let legacy_path = config.get_repo_path().join(&config.gate.heuristics_file);
+    let project_file = [stable_path, legacy_path].into_iter().find(|path| path.exists());
+    let resolved = crate::heuristics::resolve_heuristics(
+        project_file.as_deref(),
+        &heuristics_profiles(project_path, &config),
+    )
+    .context("Failed to resolve gate heuristics")?;
+
+    println!("{}", "⚙  EFFECTIVE GATE HEURISTICS".bold().cyan());
+    println!();
+    println!("{}", "Layers (lowest to highest precedence):".bold());
+    println!("  {:<12} {}", BUILT_IN_LAYER.yellow(), "hard-coded defaults".dimmed());
+    for layer in &resolved.layers {
+        println!(
+            "  {:<12} {}",
+            layer.name.yellow(),
+            layer.path.display().to_string().dimmed()
+        );
+    }
+    if project_file.is_none() {
+        println!(
+            "  {}",
+            "(no project heuristics file yet; `nexus gate` bootstraps one)".dimmed()
+        );
+    }
+    println!();
+
+    let mut current_list = None;
+    for setting in resolved.settings() {
+        let line = if setting.entry {
+            if current_list.as_deref() != Some(setting.key.as_str()) {
+                println!("  {}:", setting.key);
+                current_list = Some(setting.key.clone());
+            }
+            format!("    - {}", setting.value)
+        } else {
+            current_list = None;
+            format!("  {} = {}", setting.key, setting.value)
+        };
+        println!("{line:<56} {}", format!("← {}", setting.layer).dimmed());
+    }
+
+    Ok(())
+}
+
+/// Print the gate report as ADHD-friendly terminal output
+fn print_text_report(report: &GateReport) {
+    println!("{}", "🚪 INITIATING GATE SEQUENCE...".bold().cyan());
+    println!();
+    println!("{} {}", "📍 Lifecycle Phase:".bold(), report.phase.yellow());
+    println!();
+
+    for notice in &report.notices {
+        println!("  {} {}", "ℹ".cyan(), notice.dimmed());
+    }
+
+    for (index, section) in report.sections.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        println!("{}", section.group.title().bold());
+        for document in &section.documents {
+            print_document_report(document, &report.waivers);
+        }
+    }
+
+    if !report.waivers.is_empty() {
+        println!();
+        print_waivers(&report.waivers);
+    }
 
     println!();
     println!("{}", "━".repeat(60).dimmed());
 
+    // Non-blocking findings summary
+    let warnings = report.count(Severity::Warning);
+    let infos = report.count(Severity::Info);
+    if warnings + infos > 0 {
+        println!();
+        println!(
+            "{} {} warning(s), {} info (non-blocking)",
+            "⚠".yellow().bold(),
+            warnings.to_string().yellow(),
+            infos
+        );
+    }
+    let waived = report.waived_count();
+    if waived > 0 {
+        println!(
+            "{} {} issue(s) waived (see WAIVERS above)",
+            "🛡".green(),
+            waived.to_string().green()
+        );
+    }
+
     // Final verdict
-    if all_passed {
+    if report.passed() && warnings > 0 {
         println!();
         println!("{}", "✅ MISSION READY".green().bold());
         println!(
             "{}",
-            "   Gate is open. All validation checks passed.".green()
+            "   Gate is open with warnings. Review them before they become errors in strict mode."
+                .green()
         );
+    } else if report.passed() {
         println!();
-        Ok(())
+        println!("{}", "✅ MISSION READY".green().bold());
+        println!(
+            "{}",
+            "   Gate is open. All validation checks passed.".green()
+        );
     } else {
         println!();
         println!("{}", "🚫 GATE CLOSED".red().bold());
         println!("{}", "   Fix the issues above before proceeding.".red());
-        println!();
-        anyhow::bail!("Validation failed")
+    }
+    println!();
+}
+
+/// Print a single document result with its issues
+fn print_document_report(document: &DocumentReport, waivers: &[WaiverStatus]) {
+    for warning in &document.warnings {
+        println!(
+            "  {} {} - {}",
+            "⚠".yellow().bold(),
+            document.name,
+            warning
+        );
+    }
+
+    let marker = if document.passed && document.count(Severity::Warning) > 0 {
+        "⚠".yellow().bold()
+    } else if document.passed {
+        "✓".green().bold()
+    } else {
+        "✗".red().bold()
+    };
+    println!("  {} {}", marker, document.headline);
+
+    if !document.issues.is_empty() {
+        print_validation_issues(&document.issues, &document.path, waivers);
+    }
+
+    for failure in &document.failures {
+        println!("  {} {} {}", "✗".red().bold(), document.name, failure);
+    }
+
+    for detail in &document.details {
+        println!("     {detail}");
+    }
+}
+
+/// Print active and expired waivers with their justification
+fn print_waivers(waivers: &[WaiverStatus]) {
+    println!("{}", "🛡  WAIVERS".bold());
+    for status in waivers {
+        let waiver = &status.waiver;
+        if status.expired {
+            println!(
+                "  {} {} - {} on {}, issues block again",
+                "✗".red().bold(),
+                waiver.describe(),
+                "expired".red(),
+                waiver.expires
+            );
+        } else {
+            println!(
+                "  {} {} - until {} by {} ({} issue(s) waived)",
+                "✓".green().bold(),
+                waiver.describe(),
+                waiver.expires,
+                waiver.author,
+                status.matched
+            );
+        }
+        println!("     Reason: {}", waiver.reason.dimmed());
+    }
+}
+
+/// Turn a document read error into a short, user-facing message
+fn describe_read_error(error: &anyhow::Error) -> String {
+    let message = format!("{error:#}");
+    if message.contains("invalid utf-8") || message.contains("stream did not contain valid UTF-8")
+    {
+        "File contains invalid UTF-8 or binary data".to_string()
+    } else if message.contains("Permission denied") {
+        "Permission denied".to_string()
+    } else {
+        error.to_string()
+    }
+}
+
+/// Check file metadata before reading it
+/// Returns size warnings on success, or a user-facing message if the file is inaccessible
+fn check_file_access(file_path: &Path) -> std::result::Result<Vec<String>, String> {
+    match file_path.metadata() {
+        Ok(metadata) => {
+            // Check file size to warn about very large files
+            if metadata.len() > 100_000_000 {
+                // 100MB
+                Ok(vec![format!(
+                    "File very large ({}MB), may take time to process",
+                    metadata.len() / 1_000_000
+                )])
+            } else {
+                Ok(Vec::new())
+            }
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+            Err("Permission denied".to_string())
+        }
+        Err(e) => Err(format!("Cannot access file: {e}")),
+    }
+}
+
+/// A planning document to validate and the headers it must contain
+struct DocumentCheck<'a> {
+    file_name: String,
+    file_path: PathBuf,
+    required_headers: &'a [String],
+    /// Aliases and matching mode for the required headers
+    headers: &'a HeaderMatcher,
+    min_word_count: usize,
+    /// Whether the document holds the MVP breakdown, so sprint dependencies are checked
+    sprint_graph: bool,
+}
+
+/// Outcome of one document check, with the cache entry for its contents
+struct CheckedDocument {
+    report: DocumentReport,
+    /// `None` when the file could not be read
+    cached: Option<CachedDocument>,
+}
+
+/// Validate planning documents in parallel, reusing cached results for unchanged files
+///
+/// Reports come back in the order of `checks`; the cache is updated to hold
+/// exactly the documents that were checked.
+fn check_planning_documents(
+    checks: &[DocumentCheck],
+    illegal_strings: &[String],
+    rules: &[CompiledRule],
+    vault: Option<&VaultIndex>,
+    cache: &mut GateCache,
+) -> Vec<CheckedDocument> {
+    let previous: &GateCache = cache;
+    let checked: Vec<CheckedDocument> = checks
+        .par_iter()
+        .map(|check| check_planning_document(check, illegal_strings, rules, vault, Some(previous)))
+        .collect();
+
+    let entries = checks
+        .iter()
+        .zip(&checked)
+        .filter_map(|(check, checked)| {
+            checked
+                .cached
+                .clone()
+                .map(|cached| (check.file_name.clone(), cached))
+        })
+        .collect::<BTreeMap<_, _>>();
+    cache.set_documents(entries);
+    checked
+}
+
+/// Validate one planning document and turn the outcome into a document report
+///
+/// With a vault index, embeds are expanded before validation and wikilinks
+/// are resolved; broken links depend on the whole vault, so they are never
+/// cached. With a cache, an entry matching the hash of the expanded document
+/// replaces validation.
+fn check_planning_document(
+    check: &DocumentCheck,
+    illegal_strings: &[String],
+    rules: &[CompiledRule],
+    vault: Option<&VaultIndex>,
+    cache: Option<&GateCache>,
+) -> CheckedDocument {
+    let file_name = check.file_name.as_str();
+    let file_path = check.file_path.as_path();
+
+    // DEFENSIVE: Check for symlink loops and permissions
+    let warnings = match check_file_access(file_path) {
+        Ok(warnings) => warnings,
+        Err(message) => {
+            return CheckedDocument {
+                report: DocumentReport::failed(file_name, file_path, format!("{file_name} - {message}")),
+                cached: None,
+            };
+        }
+    };
+
+    let content = match std::fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read file: {}", file_path.display()))
+    {
+        Ok(content) => content,
+        Err(e) => {
+            let mut report = DocumentReport::failed(
+                file_name,
+                file_path,
+                format!("{} - {}", file_name, describe_read_error(&e)),
+            );
+            report.warnings = warnings;
+            return CheckedDocument { report, cached: None };
+        }
+    };
+
+    // Embedded notes are validated as part of the document, so the hash covers them too
+    let expanded = vault.map(|vault| expand_embeds(file_path, &content, vault));
+    let (text, hash) = match &expanded {
+        Some(expanded) => {
+            let sources: Vec<String> = expanded
+                .sources()
+                .map(|source| source.display().to_string())
+                .collect();
+            let hashed = format!("{}\0{}", sources.join("\n"), expanded.text);
+            (expanded.text.as_str(), content_hash(hashed.as_bytes()))
+        }
+        None => (content.as_str(), content_hash(content.as_bytes())),
+    };
+
+    let issues = match cache.and_then(|cache| cache.document(file_name, &hash)) {
+        Some(issues) => issues.to_vec(),
+        None => {
+            let mut result = validate_planning_content(
+                text,
+                check.required_headers,
+                check.headers,
+                check.min_word_count,
+                illegal_strings,
+            );
+            // Scoped heuristics rules (version 2) add to the built-in checks
+            let today = chrono::Local::now().date_naive();
+            for issue in validate_rules_content(text, file_name, rules, check.headers, today) {
+                result.add_issue(issue);
+            }
+            if check.sprint_graph {
+                for issue in crate::roadmap::check_dependencies(text) {
+                    result.add_issue(issue);
+                }
+            }
+            result
+                .issues
+                .into_iter()
+                .map(|issue| match &expanded {
+                    Some(expanded) => expanded.attribute(issue),
+                    None => issue.into(),
+                })
+                .collect()
+        }
+    };
+    let cached = Some(CachedDocument {
+        hash,
+        issues: issues.clone(),
+    });
+
+    let mut issues: Vec<SourcedIssue> = issues;
+    if let Some(vault) = vault {
+        issues.extend(
+            crate::links::find_broken_links(file_path, &content, vault)
+                .into_iter()
+                .map(SourcedIssue::from),
+        );
+    }
+
+    let mut report = if issues.is_empty() {
+        DocumentReport::passed(file_name, file_path, file_name)
+    } else {
+        DocumentReport::failed(file_name, file_path, file_name).with_sourced_issues(issues)
+    };
+    report.warnings = warnings;
+    CheckedDocument { report, cached }
+}
+
+/// Report terms that one planning document rules out and another one uses
+///
+/// `set_hash` identifies the contents of the whole document set; findings for
+/// an unchanged set come from the cache.
+fn check_cross_document_consistency(
+    planning_dir: &Path,
+    schema: &PlanningSchema,
+    set_hash: Option<String>,
+    cache: &mut GateCache,
+    report: &mut GateReport,
+) {
+    let cached = set_hash
+        .as_deref()
+        .and_then(|hash| cache.consistency(hash))
+        .map(<[_]>::to_vec);
+    let checked = match cached {
+        Some(findings) => Ok(findings),
+        None => crate::consistency::check_consistency(planning_dir, schema),
+    };
+    let findings = match checked {
+        Ok(findings) => {
+            if let Some(hash) = set_hash {
+                cache.set_consistency(hash, findings.clone());
+            }
+            findings
+        }
+        Err(e) => {
+            report.push(DocumentReport::failed(
+                "Planning documents",
+                planning_dir,
+                format!("Consistency check failed: {}", describe_read_error(&e)),
+            ));
+            return;
+        }
+    };
+
+    if findings.is_empty() {
+        report.push(DocumentReport::passed(
+            "Planning documents",
+            planning_dir,
+            "No conflicts between planning documents",
+        ));
+        return;
+    }
+
+    // One entry per document that uses a ruled-out term, in first-seen order
+    let mut by_file: Vec<(String, Vec<ValidationIssue>)> = Vec::new();
+    for finding in findings {
+        match by_file.iter_mut().find(|(file, _)| *file == finding.file) {
+            Some((_, issues)) => issues.push(finding.issue),
+            None => by_file.push((finding.file, vec![finding.issue])),
+        }
+    }
+    for (file, issues) in by_file {
+        report.push(
+            DocumentReport::failed(
+                &file,
+                &planning_dir.join(&file),
+                format!("{file} contradicts other planning documents:"),
+            )
+            .with_issues(issues),
+        );
     }
 }
 
 /// Validates planning documents in Phase 1 (Locked) with per-file specific headers
 fn validate_planning_phase(
-    vault_path: &Path,
     config: &NexusConfig,
     heuristics: &crate::heuristics::GateHeuristics,
-) -> Result<bool> {
-    let mut all_passed = true;
-
+    schema: &PlanningSchema,
+    cache: &mut GateCache,
+    report: &mut GateReport,
+) -> Result<()> {
     // Validate Dashboard (00-START-HERE.md)
-    println!("{}", "📋 SCANNING DASHBOARD...".bold());
-    let dashboard_path = vault_path
-        .join(&config.structure.management_dir)
-        .join("00-START-HERE.md");
+    report.begin(ReportGroup::Dashboard);
+    let layout = config.layout();
+    let dashboard_path = layout.dashboard();
+    let dashboard_name = DASHBOARD_FILE;
 
     // DEFENSIVE: Check if dashboard exists
     if dashboard_path.exists() {
         // DEFENSIVE: Check dashboard file permissions
-        match dashboard_path.metadata() {
+        let document = match dashboard_path.metadata() {
             Ok(_) => match validate_dashboard_checkboxes(&dashboard_path) {
-                Ok(result) => {
-                    if result.passed {
-                        println!(
-                            "  {} Dashboard clean - all tasks completed",
-                            "✓".green().bold()
-                        );
-                    } else {
-                        all_passed = false;
-                        println!("  {} Dashboard has unchecked items:", "✗".red().bold());
-                        print_validation_issues(&result.issues, &dashboard_path);
-                    }
-                }
-                Err(e) => {
-                    all_passed = false;
-                    let error_msg = if e.to_string().contains("invalid utf-8")
-                        || e.to_string().contains("stream did not contain valid UTF-8")
-                    {
-                        "File contains invalid UTF-8 or binary data".to_string()
-                    } else {
-                        e.to_string()
-                    };
-                    println!(
-                        "  {} Failed to read dashboard: {}",
-                        "✗".red().bold(),
-                        error_msg
-                    );
-                }
+                Ok(result) if result.passed => DocumentReport::passed(
+                    dashboard_name,
+                    &dashboard_path,
+                    "Dashboard clean - all tasks completed",
+                ),
+                Ok(result) => DocumentReport::failed(
+                    dashboard_name,
+                    &dashboard_path,
+                    "Dashboard has unchecked items:",
+                )
+                .with_issues(result.issues),
+                Err(e) => DocumentReport::failed(
+                    dashboard_name,
+                    &dashboard_path,
+                    format!("Failed to read dashboard: {}", describe_read_error(&e)),
+                ),
             },
-            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
-                all_passed = false;
-                println!("  {} Dashboard file permission denied", "✗".red().bold());
-            }
-            Err(e) => {
-                all_passed = false;
-                println!("  {} Cannot access dashboard: {}", "✗".red().bold(), e);
-            }
-        }
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => DocumentReport::failed(
+                dashboard_name,
+                &dashboard_path,
+                "Dashboard file permission denied",
+            ),
+            Err(e) => DocumentReport::failed(
+                dashboard_name,
+                &dashboard_path,
+                format!("Cannot access dashboard: {e}"),
+            ),
+        };
+        report.push(document);
     } else {
-        all_passed = false;
-        println!(
-            "  {} Dashboard file not found: {}",
-            "✗".red().bold(),
-            dashboard_path.display()
+        let mut document = DocumentReport::failed(
+            dashboard_name,
+            &dashboard_path,
+            format!("Dashboard file not found: {}", dashboard_path.display()),
         );
-        println!(
-            "     Expected file: 00-START-HERE.md in {}",
+        document.details.push(format!(
+            "Expected file: 00-START-HERE.md in {}",
             config.structure.management_dir
-        );
+        ));
+        report.push(document);
     }
 
-    println!();
-
     // Validate Planning Documents (01-PLANNING/*.md) with per-file headers
-    println!("{}", "📝 SCANNING PLANNING DOCUMENTS...".bold());
-    let planning_dir = vault_path.join(&config.structure.planning_dir);
-
-    if planning_dir.exists() {
-        // Use heuristics for validation parameters
-        let min_word_count = heuristics.min_section_length as usize;
-        let illegal_strings: Vec<String> = heuristics.illegal_strings.clone();
-
-        // File-specific validation rules for structured projects
-        // Only use specific file rules if heuristics has required headers
-        let use_specific_file_rules = !heuristics.required_headers.is_empty();
-
-        let file_rules: Vec<(&str, Vec<String>, usize)> = if use_specific_file_rules {
-            crate::schema::planning::PLANNING_DOCUMENTS
-                .iter()
-                .map(|doc| {
-                    (
-                        doc.filename,
-                        doc.required_headers.iter().map(|s| s.to_string()).collect(),
-                        doc.min_word_count,
-                    )
-                })
-                .collect()
-        } else {
-            vec![] // No specific file rules if heuristics doesn't require headers
-        };
+    report.begin(ReportGroup::PlanningDocuments);
+    let planning_dir = layout.planning_dir.clone();
 
-        // Check if structured files exist - if any exist, require all
-        let structured_files_exist = use_specific_file_rules && file_rules.iter().any(|(name, _, _)| {
-            planning_dir.join(name).exists()
-        });
-
-        if structured_files_exist {
-            // Validate structured planning files
-            for (file_name, required_headers, doc_min_word_count) in file_rules {
-                let file_path = planning_dir.join(file_name);
-
-                if !file_path.exists() {
-                    all_passed = false;
-                    println!(
-                        "  {} {} - File not found",
-                        "✗".red().bold(),
-                        file_name
-                    );
-                    continue;
-                }
+    if !planning_dir.exists() {
+        report.push(DocumentReport::failed(
+            &config.structure.planning_dir,
+            &planning_dir,
+            format!("Planning directory not found: {}", planning_dir.display()),
+        ));
+        return Ok(());
+    }
 
-                // DEFENSIVE: Check for symlink loops and permissions
-                match file_path.metadata() {
-                    Ok(metadata) => {
-                        // Check file size to warn about very large files
-                        if metadata.len() > 100_000_000 {
-                            // 100MB
-                            println!(
-                                "  {} {} - File very large ({}MB), may take time to process",
-                                "⚠".yellow().bold(),
-                                file_name,
-                                metadata.len() / 1_000_000
-                            );
-                        }
-                    }
-                    Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
-                        all_passed = false;
-                        println!("  {} {} - Permission denied", "✗".red().bold(), file_name);
-                        continue;
-                    }
-                    Err(e) => {
-                        all_passed = false;
-                        println!(
-                            "  {} {} - Cannot access file: {}",
-                            "✗".red().bold(),
-                            file_name,
-                            e
-                        );
-                        continue;
-                    }
-                }
+    // Use heuristics for validation parameters
+    let min_word_count = heuristics.min_section_length as usize;
+    let illegal_strings: Vec<String> = heuristics.illegal_strings.clone();
+    let rules = heuristics
+        .compile_rules()
+        .context("Invalid gate heuristics rules")?;
 
-                // Validate with specific headers for this file
-                match validate_planning_document_with_headers(
-                    &file_path,
-                    &required_headers,
-                    doc_min_word_count,
-                    &illegal_strings,
-                ) {
-                    Ok(result) => {
-                        if result.passed {
-                            println!("  {} {}", "✓".green().bold(), file_name);
-                        } else {
-                            all_passed = false;
-                            println!("  {} {}", "✗".red().bold(), file_name);
-                            print_validation_issues(&result.issues, &file_path);
-                        }
-                    }
-                    Err(e) => {
-                        all_passed = false;
-                        let error_msg = if e.to_string().contains("invalid utf-8")
-                            || e.to_string().contains("stream did not contain valid UTF-8")
-                        {
-                            "File contains invalid UTF-8 or binary data".to_string()
-                        } else if e.to_string().contains("Permission denied") {
-                            "Permission denied".to_string()
-                        } else {
-                            e.to_string()
-                        };
-                        println!("  {} {} - {}", "✗".red().bold(), file_name, error_msg);
-                    }
-                }
+    // Wikilinks and embeds resolve against the vault rooted at the planning path
+    let vault = match VaultIndex::build(&config.get_planning_path()) {
+        Ok(vault) => Some(vault),
+        Err(e) => {
+            report
+                .notices
+                .push(format!("Link check skipped: {e:#}"));
+            None
+        }
+    };
+
+    // Header aliases from the schema apply to every document, including fallback ones
+    let headers = schema.header_matcher();
+
+    // File-specific validation rules for structured projects, from the planning schema
+    // Only use specific file rules if heuristics has required headers
+    let use_specific_file_rules = !heuristics.required_headers.is_empty();
+
+    let file_rules: Vec<&DocumentSchema> = if use_specific_file_rules {
+        schema.documents.iter().collect()
+    } else {
+        vec![] // No specific file rules if heuristics doesn't require headers
+    };
+
+    // Check if structured files exist - if any exist, require all
+    let structured_files_exist = use_specific_file_rules && file_rules.iter().any(|doc| {
+        planning_dir.join(&doc.filename).exists()
+    });
+
+    if structured_files_exist {
+        // Validate structured planning files, each with its specific headers
+        let present: Vec<bool> = file_rules
+            .iter()
+            .map(|doc| planning_dir.join(&doc.filename).exists())
+            .collect();
+        let checks: Vec<DocumentCheck> = file_rules
+            .iter()
+            .zip(&present)
+            .filter(|(_, present)| **present)
+            .map(|(doc, _)| DocumentCheck {
+                file_name: doc.filename.clone(),
+                file_path: planning_dir.join(&doc.filename),
+                required_headers: &doc.required_headers,
+                headers: &headers,
+                min_word_count: doc.min_word_count,
+                sprint_graph: doc.context.contains_key("mvp_breakdown"),
+            })
+            .collect();
+        let mut checked = check_planning_documents(&checks, &illegal_strings, &rules, vault.as_ref(), cache).into_iter();
+
+        // The consistency pass reads the whole set, so it is cached against every document's hash
+        let mut set_hashes = String::new();
+        let mut set_readable = true;
+        for (doc, present) in file_rules.iter().zip(present) {
+            let file_name = doc.filename.as_str();
+            if !present {
+                report.push(DocumentReport::failed(
+                    file_name,
+                    &planning_dir.join(file_name),
+                    format!("{file_name} - File not found"),
+                ));
+                set_hashes.push_str(&format!("{file_name}:missing\n"));
+                continue;
             }
-        } else {
-            // Fallback: validate any .md files found (backward compatibility)
-            let planning_files = std::fs::read_dir(&planning_dir)
-                .with_context(|| {
-                    format!(
-                        "Failed to read planning directory: {}",
-                        planning_dir.display()
-                    )
-                })?
-                .filter_map(std::result::Result::ok)
-                .filter(|entry| entry.path().extension().and_then(|s| s.to_str()) == Some("md"))
-                .collect::<Vec<_>>();
 
-            if planning_files.is_empty() {
-                all_passed = false;
-                println!(
-                    "  {} No planning documents found in {}",
-                    "✗".red().bold(),
+            let Some(CheckedDocument { report: document, cached }) = checked.next() else {
+                break;
+            };
+            match cached {
+                Some(cached) => set_hashes.push_str(&format!("{file_name}:{}\n", cached.hash)),
+                None => set_readable = false,
+            }
+            report.push(document);
+        }
+        let set_hash = set_readable.then(|| crate::gate_cache::content_hash(set_hashes.as_bytes()));
+
+        // Cross-document consistency across the structured set
+        report.begin(ReportGroup::CrossDocument);
+        check_cross_document_consistency(&planning_dir, schema, set_hash, cache, report);
+    } else {
+        // Fallback: validate any .md files found (backward compatibility)
+        let planning_files = std::fs::read_dir(&planning_dir)
+            .with_context(|| {
+                format!(
+                    "Failed to read planning directory: {}",
                     planning_dir.display()
-                );
-            } else {
-                // Use generic validation with all required headers from heuristics
-                for entry in planning_files {
-                    let file_path = entry.path();
-                    let file_name = if let Some(name) = file_path.file_name() { name.to_string_lossy().to_string() } else {
-                        all_passed = false;
-                        println!(
-                            "  {} Skipping file with invalid path: {}",
-                            "⚠".yellow().bold(),
-                            file_path.display()
-                        );
-                        continue;
-                    };
-
-                    // DEFENSIVE: Check for symlink loops before reading
-                    match file_path.metadata() {
-                        Ok(metadata) => {
-                            if metadata.len() > 100_000_000 {
-                                println!(
-                                    "  {} {} - File very large ({}MB), may take time to process",
-                                    "⚠".yellow().bold(),
-                                    file_name,
-                                    metadata.len() / 1_000_000
-                                );
-                            }
-                        }
-                        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
-                            all_passed = false;
-                            println!("  {} {} - Permission denied", "✗".red().bold(), file_name);
-                            continue;
-                        }
-                        Err(e) => {
-                            all_passed = false;
-                            println!(
-                                "  {} {} - Cannot access file: {}",
-                                "✗".red().bold(),
-                                file_name,
-                                e
-                            );
-                            continue;
-                        }
-                    }
-
-                    // Use generic validation with heuristics
-                    match validate_planning_document_with_headers(
+                )
+            })?
+            .filter_map(std::result::Result::ok)
+            .filter(|entry| entry.path().extension().and_then(|s| s.to_str()) == Some("md"))
+            .collect::<Vec<_>>();
+
+        if planning_files.is_empty() {
+            report.push(DocumentReport::failed(
+                &config.structure.planning_dir,
+                &planning_dir,
+                format!("No planning documents found in {}", planning_dir.display()),
+            ));
+        } else {
+            // Use generic validation with all required headers from heuristics
+            let mut checks = Vec::new();
+            for entry in planning_files {
+                let file_path = entry.path();
+                let Some(file_name) = file_path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                else {
+                    report.push(DocumentReport::failed(
+                        file_path.display().to_string(),
                         &file_path,
-                        &heuristics.required_headers,
-                        min_word_count,
-                        &illegal_strings,
-                    ) {
-                        Ok(result) => {
-                            if result.passed {
-                                println!("  {} {}", "✓".green().bold(), file_name);
-                            } else {
-                                all_passed = false;
-                                println!("  {} {}", "✗".red().bold(), file_name);
-                                print_validation_issues(&result.issues, &file_path);
-                            }
-                        }
-                        Err(e) => {
-                            all_passed = false;
-                            let error_msg = if e.to_string().contains("invalid utf-8")
-                                || e.to_string().contains("stream did not contain valid UTF-8")
-                            {
-                                "File contains invalid UTF-8 or binary data".to_string()
-                            } else if e.to_string().contains("Permission denied") {
-                                "Permission denied".to_string()
-                            } else {
-                                e.to_string()
-                            };
-                            println!("  {} {} - {}", "✗".red().bold(), file_name, error_msg);
-                        }
-                    }
-                }
+                        format!("Skipping file with invalid path: {}", file_path.display()),
+                    ));
+                    continue;
+                };
+
+                checks.push(DocumentCheck {
+                    file_name,
+                    file_path,
+                    required_headers: &heuristics.required_headers,
+                    headers: &headers,
+                    min_word_count,
+                    sprint_graph: false,
+                });
+            }
+
+            for checked in check_planning_documents(&checks, &illegal_strings, &rules, vault.as_ref(), cache) {
+                report.push(checked.report);
             }
         }
-    } else {
-        all_passed = false;
-        println!(
-            "  {} Planning directory not found: {}",
-            "✗".red().bold(),
-            planning_dir.display()
-        );
     }
 
-    Ok(all_passed)
+    Ok(())
 }
 
 /// Validates adhoc planning documents for ad-hoc task mode
-fn validate_adhoc_planning(_vault_path: &Path, config: &NexusConfig) -> Result<bool> {
-    let mut all_passed = true;
-
+fn validate_adhoc_planning(
+    _vault_path: &Path,
+    config: &NexusConfig,
+    report: &mut GateReport,
+) -> Result<()> {
     // Load adhoc heuristics - embedded in binary for portability
     const ADHOC_HEURISTICS_JSON: &str = include_str!("../../templates/adhoc/adhoc-gate-heuristics.json");
     let heuristics: crate::heuristics::GateHeuristics = serde_json::from_str(ADHOC_HEURISTICS_JSON)
         .context("Failed to parse embedded adhoc heuristics")?;
+    report.policy = heuristics.severity_policy(config.gate.strict_mode);
+    let headers = HeaderMatcher::default();
 
     // Validate Dashboard (00-ADHOC-TASK.md) - Planning Phase only
-    println!("{}", "📋 SCANNING ADHOC DASHBOARD...".bold());
+    report.begin(ReportGroup::AdhocDashboard);
     let dashboard_path = config.get_adhoc_dashboard_path();
+    let dashboard_name = dashboard_path
+        .file_name()
+        .map_or_else(|| "00-ADHOC-TASK.md".to_string(), |n| n.to_string_lossy().to_string());
 
     if dashboard_path.exists() {
-        // Read dashboard content and filter for Planning Phase checkboxes only
-        match std::fs::read_to_string(&dashboard_path) {
-            Ok(content) => {
-                let mut planning_phase_section = false;
-                let mut unchecked_planning_items = Vec::new();
-                let mut line_num = 0;
-
-                for line in content.lines() {
-                    line_num += 1;
-                    let trimmed = line.trim();
-
-                    // Track which section we're in
-                    if trimmed.starts_with("## Planning Phase") {
-                        planning_phase_section = true;
-                    } else if trimmed.starts_with("## Execution Phase") || trimmed.starts_with("## ") {
-                        planning_phase_section = false;
-                    }
-
-                    // Check for unchecked checkboxes in Planning Phase
-                    if planning_phase_section && trimmed.starts_with("- [ ]") {
-                        let task_text = trimmed.strip_prefix("- [ ]").unwrap_or("").trim();
-                        unchecked_planning_items.push((line_num, task_text.to_string()));
-                    }
-                }
+        // Parse dashboard checkboxes and keep only those in the Planning Phase section
+        let document = match validate_dashboard_checkboxes(&dashboard_path) {
+            Ok(result) => {
+                let unchecked_planning_items: Vec<ValidationIssue> = result
+                    .issues
+                    .into_iter()
+                    .filter(|issue| {
+                        issue
+                            .section()
+                            .is_some_and(|section| section.starts_with("Planning Phase"))
+                    })
+                    .collect();
 
                 if unchecked_planning_items.is_empty() {
-                    println!(
-                        "  {} Dashboard planning phase complete",
-                        "✓".green().bold()
-                    );
+                    DocumentReport::passed(
+                        &dashboard_name,
+                        &dashboard_path,
+                        "Dashboard planning phase complete",
+                    )
                 } else {
-                    all_passed = false;
-                    println!("  {} Dashboard planning phase incomplete:", "✗".red().bold());
-                    for (line, task) in unchecked_planning_items {
-                        println!("      ▸ Unchecked task at line {line}");
-                        println!("         Task: {task}");
-                    }
-                    println!("      📍 File: {}", dashboard_path.display());
+                    DocumentReport::failed(
+                        &dashboard_name,
+                        &dashboard_path,
+                        "Dashboard planning phase incomplete:",
+                    )
+                    .with_issues(unchecked_planning_items)
                 }
             }
-            Err(e) => {
-                all_passed = false;
-                println!(
-                    "  {} Failed to read dashboard: {}",
-                    "✗".red().bold(),
-                    e
-                );
-            }
-        }
+            Err(e) => DocumentReport::failed(
+                &dashboard_name,
+                &dashboard_path,
+                format!("Failed to read dashboard: {}", describe_read_error(&e)),
+            ),
+        };
+        report.push(document);
     } else {
-        all_passed = false;
-        println!(
-            "  {} Dashboard not found: {}",
-            "✗".red().bold(),
-            dashboard_path.display()
-        );
+        report.push(DocumentReport::failed(
+            &dashboard_name,
+            &dashboard_path,
+            format!("Dashboard not found: {}", dashboard_path.display()),
+        ));
     }
 
-    println!();
-
     // Validate Planning Documents
-    println!("{}", "📝 SCANNING ADHOC PLANNING DOCUMENTS...".bold());
+    report.begin(ReportGroup::AdhocPlanningDocuments);
     let planning_dir = config.get_adhoc_planning_path();
 
     if !planning_dir.exists() {
-        all_passed = false;
-        println!(
-            "  {} Planning directory not found: {}",
-            "✗".red().bold(),
-            planning_dir.display()
-        );
-        return Ok(all_passed);
+        report.push(DocumentReport::failed(
+            planning_dir.display().to_string(),
+            &planning_dir,
+            format!("Planning directory not found: {}", planning_dir.display()),
+        ));
+        return Ok(());
     }
 
     let min_word_count = heuristics.min_section_length as usize;
     let illegal_strings: Vec<String> = heuristics.illegal_strings.clone();
+    let rules = heuristics
+        .compile_rules()
+        .context("Invalid embedded adhoc heuristics rules")?;
 
     // Validate Task-Capture.md
     let capture_path = planning_dir.join("Task-Capture.md");
@@ -528,32 +993,21 @@ fn validate_adhoc_planning(_vault_path: &Path, config: &NexusConfig) -> Result<b
             "Definition of Done".to_string(),
         ];
 
-        match validate_planning_document_with_headers(
-            &capture_path,
-            &required_headers,
-            100, // Min 100 words total
-            &illegal_strings,
-        ) {
-            Ok(result) => {
-                if result.passed {
-                    println!("  {} Task-Capture.md", "✓".green().bold());
-                } else {
-                    all_passed = false;
-                    println!("  {} Task-Capture.md", "✗".red().bold());
-                    print_validation_issues(&result.issues, &capture_path);
-                }
-            }
-            Err(e) => {
-                all_passed = false;
-                println!("  {} Task-Capture.md - {}", "✗".red().bold(), e);
-            }
-        }
+        let check = DocumentCheck {
+            file_name: "Task-Capture.md".to_string(),
+            file_path: capture_path,
+            required_headers: &required_headers,
+            headers: &headers,
+            min_word_count: 100, // Min 100 words total
+            sprint_graph: false,
+        };
+        report.push(check_planning_document(&check, &illegal_strings, &rules, None, None).report);
     } else {
-        all_passed = false;
-        println!(
-            "  {} Task-Capture.md not found",
-            "✗".red().bold()
-        );
+        report.push(DocumentReport::failed(
+            "Task-Capture.md",
+            &capture_path,
+            "Task-Capture.md not found",
+        ));
     }
 
     // Validate Task-Approach.md
@@ -566,42 +1020,28 @@ fn validate_adhoc_planning(_vault_path: &Path, config: &NexusConfig) -> Result<b
             "Files to Modify".to_string(),
         ];
 
-        match validate_planning_document_with_headers(
-            &approach_path,
-            &required_headers,
-            150, // Min 150 words total
-            &illegal_strings,
-        ) {
-            Ok(result) => {
-                if result.passed {
-                    println!("  {} Task-Approach.md", "✓".green().bold());
-                } else {
-                    all_passed = false;
-                    println!("  {} Task-Approach.md", "✗".red().bold());
-                    print_validation_issues(&result.issues, &approach_path);
-                }
-            }
-            Err(e) => {
-                all_passed = false;
-                println!("  {} Task-Approach.md - {}", "✗".red().bold(), e);
-            }
-        }
+        let check = DocumentCheck {
+            file_name: "Task-Approach.md".to_string(),
+            file_path: approach_path.clone(),
+            required_headers: &required_headers,
+            headers: &headers,
+            min_word_count: 150, // Min 150 words total
+            sprint_graph: false,
+        };
+        let mut document = check_planning_document(&check, &illegal_strings, &rules, None, None).report;
 
         // Additional risk mitigation check
         let content = std::fs::read_to_string(&approach_path).unwrap_or_default();
         if !content.contains("## Risks") || (!content.contains("- [ ]") && !content.contains("- [x]")) {
-            all_passed = false;
-            println!(
-                "  {} Task-Approach.md missing risk mitigation checkboxes",
-                "✗".red().bold()
-            );
+            document.add_failure("missing risk mitigation checkboxes");
         }
+        report.push(document);
     } else {
-        all_passed = false;
-        println!(
-            "  {} Task-Approach.md not found",
-            "✗".red().bold()
-        );
+        report.push(DocumentReport::failed(
+            "Task-Approach.md",
+            &approach_path,
+            "Task-Approach.md not found",
+        ));
     }
 
     // Validate Task-Validation.md (structure only, don't require checkboxes checked)
@@ -613,56 +1053,42 @@ fn validate_adhoc_planning(_vault_path: &Path, config: &NexusConfig) -> Result<b
             "Verification".to_string(),
         ];
 
-        match validate_planning_document_with_headers(
-            &validation_path,
-            &required_headers,
+        let check = DocumentCheck {
+            file_name: "Task-Validation.md".to_string(),
+            file_path: validation_path.clone(),
+            required_headers: &required_headers,
+            headers: &headers,
             min_word_count, // Use default min word count
-            &illegal_strings,
-        ) {
-            Ok(result) => {
-                if result.passed {
-                    println!("  {} Task-Validation.md", "✓".green().bold());
-                } else {
-                    all_passed = false;
-                    println!("  {} Task-Validation.md", "✗".red().bold());
-                    print_validation_issues(&result.issues, &validation_path);
-                }
-            }
-            Err(e) => {
-                all_passed = false;
-                println!("  {} Task-Validation.md - {}", "✗".red().bold(), e);
-            }
-        }
+            sprint_graph: false,
+        };
+        let mut document = check_planning_document(&check, &illegal_strings, &rules, None, None).report;
 
         // Verify checkbox structure
         let content = std::fs::read_to_string(&validation_path).unwrap_or_default();
         let sections = ["Pre-Work", "Implementation", "Verification"];
         for section in sections {
             if !content.contains(&format!("## {section}")) {
-                all_passed = false;
-                println!(
-                    "  {} Task-Validation.md missing {} section",
-                    "✗".red().bold(),
-                    section
-                );
+                document.add_failure(format!("missing {section} section"));
             }
         }
+        report.push(document);
     } else {
-        all_passed = false;
-        println!(
-            "  {} Task-Validation.md not found",
-            "✗".red().bold()
-        );
+        report.push(DocumentReport::failed(
+            "Task-Validation.md",
+            &validation_path,
+            "Task-Validation.md not found",
+        ));
     }
 
-    Ok(all_passed)
+    Ok(())
 }
 
 /// Validates active sprint folder in Phase 2 (Active Sprint)
-fn validate_active_sprint(vault_path: &Path, config: &NexusConfig) -> Result<bool> {
-    let mut all_passed = true;
-
-    println!("{}", "🎯 SCANNING ACTIVE SPRINT...".bold());
+fn validate_active_sprint(
+    config: &NexusConfig,
+    report: &mut GateReport,
+) -> Result<()> {
+    report.begin(ReportGroup::ActiveSprint);
 
     // Get active sprint from config
     let active_sprint = config
@@ -673,147 +1099,227 @@ fn validate_active_sprint(vault_path: &Path, config: &NexusConfig) -> Result<boo
         .ok_or_else(|| {
             anyhow::anyhow!("Active sprint not configured in nexus.toml. Expected state.active_sprint.current")
         })?;
-    println!("  {} Sprint: {}", "📌".dimmed(), active_sprint.cyan());
+    report.notices.push(format!("Sprint: {active_sprint}"));
 
-    // Build sprint folder path
-    let sprint_folder = vault_path
-        .join(&config.structure.sprint_dir)
-        .join(active_sprint);
+    // Sprint folders are named sprint-{number}-{name}
+    let sprint_folder = config.layout().find_sprint_folder(active_sprint);
 
     if !sprint_folder.exists() {
-        all_passed = false;
-        println!(
-            "  {} Sprint folder not found: {}",
-            "✗".red().bold(),
-            sprint_folder.display()
-        );
-        return Ok(all_passed);
+        report.push(DocumentReport::failed(
+            active_sprint,
+            &sprint_folder,
+            format!("Sprint folder not found: {}", sprint_folder.display()),
+        ));
+        return Ok(());
     }
 
     // Validate Tasks.md (must have zero unchecked boxes)
     let tasks_path = sprint_folder.join("Tasks.md");
-    if tasks_path.exists() {
+    let tasks_document = if tasks_path.exists() {
         match validate_dashboard_checkboxes(&tasks_path) {
-            Ok(result) => {
-                if result.passed {
-                    println!(
-                        "  {} Tasks.md - All tasks completed",
-                        "✓".green().bold()
-                    );
-                } else {
-                    all_passed = false;
-                    println!(
-                        "  {} Tasks.md - Has unchecked items:",
-                        "✗".red().bold()
-                    );
-                    print_validation_issues(&result.issues, &tasks_path);
-                }
-            }
-            Err(e) => {
-                all_passed = false;
-                println!(
-                    "  {} Failed to read Tasks.md: {}",
-                    "✗".red().bold(),
-                    e
-                );
-            }
+            Ok(result) if result.passed => DocumentReport::passed(
+                "Tasks.md",
+                &tasks_path,
+                "Tasks.md - All tasks completed",
+            ),
+            Ok(result) => DocumentReport::failed(
+                "Tasks.md",
+                &tasks_path,
+                "Tasks.md - Has unchecked items:",
+            )
+            .with_issues(result.issues),
+            Err(e) => DocumentReport::failed(
+                "Tasks.md",
+                &tasks_path,
+                format!("Failed to read Tasks.md: {e}"),
+            ),
         }
     } else {
-        all_passed = false;
-        println!(
-            "  {} Tasks.md not found in sprint folder",
-            "✗".red().bold()
-        );
-    }
+        DocumentReport::failed(
+            "Tasks.md",
+            &tasks_path,
+            "Tasks.md not found in sprint folder",
+        )
+    };
+    report.push(tasks_document);
 
     // Validate Sprint-Context.md (must exist and contain content)
     let context_path = sprint_folder.join("Sprint-Context.md");
-    if context_path.exists() {
+    let context_document = if context_path.exists() {
         match std::fs::read_to_string(&context_path) {
             Ok(content) => {
                 let trimmed = content.trim();
                 if trimmed.is_empty() {
-                    all_passed = false;
-                    println!(
-                        "  {} Sprint-Context.md is empty",
-                        "✗".red().bold()
-                    );
+                    DocumentReport::failed(
+                        "Sprint-Context.md",
+                        &context_path,
+                        "Sprint-Context.md is empty",
+                    )
                 } else {
-                    println!(
-                        "  {} Sprint-Context.md - Contains content ({} bytes)",
-                        "✓".green().bold(),
-                        trimmed.len()
-                    );
+                    DocumentReport::passed(
+                        "Sprint-Context.md",
+                        &context_path,
+                        format!(
+                            "Sprint-Context.md - Contains content ({} bytes)",
+                            trimmed.len()
+                        ),
+                    )
                 }
             }
-            Err(e) => {
-                all_passed = false;
-                println!(
-                    "  {} Failed to read Sprint-Context.md: {}",
-                    "✗".red().bold(),
-                    e
-                );
-            }
+            Err(e) => DocumentReport::failed(
+                "Sprint-Context.md",
+                &context_path,
+                format!("Failed to read Sprint-Context.md: {e}"),
+            ),
         }
     } else {
-        all_passed = false;
-        println!(
-            "  {} Sprint-Context.md not found in sprint folder",
-            "✗".red().bold()
-        );
-    }
+        DocumentReport::failed(
+            "Sprint-Context.md",
+            &context_path,
+            "Sprint-Context.md not found in sprint folder",
+        )
+    };
+    report.push(context_document);
 
-    Ok(all_passed)
+    Ok(())
 }
 
 /// Print validation issues with ADHD-friendly context
-fn print_validation_issues(issues: &[ValidationIssue], file_path: &Path) {
-    for issue in issues {
+///
+/// Positions are printed as `file:line:col` so terminals and editors can jump to them.
+/// Non-blocking issues are tagged with their severity, waived issues with the waiver.
+fn print_validation_issues(issues: &[ReportedIssue], file_path: &Path, waivers: &[WaiverStatus]) {
+    for ReportedIssue {
+        issue,
+        severity,
+        waiver,
+        source,
+    } in issues
+    {
+        // Issues in embedded content point at the embedded file
+        let issue_path = source.as_deref().unwrap_or(file_path);
+        let location = issue
+            .location(issue_path)
+            .unwrap_or_else(|| issue_path.display().to_string());
+        let waiver = waiver.map(|index| &waivers[index].waiver);
+        let marker = match severity {
+            _ if waiver.is_some() => format!("{} {}", "▸".green(), "[waived]".green()),
+            Severity::Error => "▸".yellow().to_string(),
+            Severity::Warning => format!("{} {}", "▸".yellow(), "[warning]".yellow()),
+            Severity::Info => format!("{} {}", "▸".cyan(), "[info]".cyan()),
+        };
         match issue {
             ValidationIssue::SectionTooShort {
                 header,
                 word_count,
                 required,
+                ..
             } => {
                 println!(
                     "      {} Section '{}' too short: {} words (need {})",
-                    "▸".yellow(),
+                    marker,
                     header.bold(),
                     word_count.to_string().yellow(),
                     required.to_string().green()
                 );
+                println!("         At: {}", location.cyan());
             }
             ValidationIssue::MissingHeader { header } => {
                 println!(
                     "      {} Missing required header: {}",
-                    "▸".yellow(),
+                    marker,
                     header.bold()
                 );
             }
             ValidationIssue::IllegalString {
-                string,
-                context,
-                line_estimate,
+                string, context, ..
             } => {
                 println!(
-                    "      {} Illegal string '{}' at line ~{}",
-                    "▸".yellow(),
+                    "      {} Illegal string '{}' at {}",
+                    marker,
                     string.bold().red(),
-                    line_estimate.to_string().cyan()
+                    location.cyan()
                 );
                 println!("         Context: {}", context.dimmed());
             }
-            ValidationIssue::UncheckedCheckbox {
-                context,
-                line_estimate,
+            ValidationIssue::UncheckedCheckbox { context, .. } => {
+                println!("      {} Unchecked task at {}", marker, location.cyan());
+                println!("         Task: {}", context.dimmed());
+            }
+            ValidationIssue::CrossDocumentConflict {
+                term,
+                relation,
+                other_file,
+                other_section,
+                other_span,
+                ..
             } => {
                 println!(
-                    "      {} Unchecked task at line ~{}",
-                    "▸".yellow(),
-                    line_estimate.to_string().cyan()
+                    "      {} '{}' used at {}",
+                    marker,
+                    term.bold().red(),
+                    location.cyan()
+                );
+                println!(
+                    "         but {} in {}:{}:{} ({})",
+                    relation,
+                    file_path.with_file_name(other_file).display(),
+                    other_span.start.line,
+                    other_span.start.column,
+                    other_section.dimmed()
                 );
-                println!("         Task: {}", context.dimmed());
             }
+            ValidationIssue::BrokenLink {
+                target,
+                embed,
+                reason,
+                ..
+            } => {
+                let (kind, link) = if *embed {
+                    ("embed", format!("![[{target}]]"))
+                } else {
+                    ("link", format!("[[{target}]]"))
+                };
+                println!(
+                    "      {} Broken {} {} at {}",
+                    marker,
+                    kind,
+                    link.bold().red(),
+                    location.cyan()
+                );
+                println!("         {}", reason.dimmed());
+            }
+            ValidationIssue::RuleViolation {
+                rule,
+                message,
+                section,
+                ..
+            }
+            | ValidationIssue::InvalidDependency {
+                rule,
+                message,
+                section,
+                ..
+            } => {
+                println!(
+                    "      {} [{}] {}",
+                    marker,
+                    rule.bold(),
+                    message
+                );
+                match section {
+                    Some(section) => println!("         At: {} ({})", location.cyan(), section.dimmed()),
+                    None => println!("         At: {}", location.cyan()),
+                }
+            }
+        }
+        if let Some(waiver) = waiver {
+            println!(
+                "         Waived until {} by {}: {}",
+                waiver.expires,
+                waiver.author,
+                waiver.reason.dimmed()
+            );
         }
     }
     println!(
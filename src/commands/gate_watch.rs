@@ -0,0 +1,334 @@
+//! Gate Watch - Continuous Validation
+//!
+//! `nexus gate <path> --watch` keeps the gate running while planning documents
+//! are edited. Each save triggers a new gate run; the gate cache means only the
+//! changed document is parsed again. A compact status is redrawn after every
+//! run, and the watch ends with the MISSION READY banner once the gate opens.
+
+use crate::commands::gate;
+use crate::gate_report::{DocumentReport, GateReport};
+use crate::heuristics::Severity;
+use crate::waivers::WAIVERS_FILE;
+use anyhow::{Context, Result};
+use colored::Colorize;
+use crossbeam_channel::{Receiver, RecvTimeoutError, unbounded};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Quiet period after a change before the gate runs (editors save in bursts)
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Issue messages listed under a failing document before the rest are summarized
+const ISSUES_PER_DOCUMENT: usize = 3;
+
+/// Files and directories whose changes trigger a new gate run
+struct WatchTargets {
+    /// Markdown files anywhere below these roots (hidden directories excluded)
+    roots: Vec<PathBuf>,
+    /// Configuration files: nexus.toml, heuristics, waivers
+    files: Vec<PathBuf>,
+}
+
+impl WatchTargets {
+    fn new(project_path: &Path) -> Result<Self> {
+        let config = gate::load_config(project_path)?;
+        let vault_path = config.get_repo_path();
+        let vault_path = vault_path.canonicalize().unwrap_or(vault_path);
+
+        let mut roots = vec![project_path.to_path_buf()];
+        if !vault_path.starts_with(project_path) {
+            roots.push(vault_path.clone());
+        }
+
+        Ok(Self {
+            roots,
+            files: vec![
+                project_path.join("nexus.toml"),
+                project_path.join(".nexus/gate-heuristics.json"),
+                project_path.join(WAIVERS_FILE),
+                vault_path.join(&config.gate.heuristics_file),
+            ],
+        })
+    }
+
+    /// Whether a change to this path can affect the gate result
+    fn is_relevant(&self, path: &Path) -> bool {
+        if self.files.iter().any(|file| file == path) {
+            return true;
+        }
+        if path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+            return false;
+        }
+        self.roots.iter().any(|root| {
+            path.strip_prefix(root).is_ok_and(|relative| {
+                !relative
+                    .components()
+                    .any(|component| component.as_os_str().to_string_lossy().starts_with('.'))
+            })
+        })
+    }
+}
+
+/// Watch the project and re-run the gate on every relevant save
+///
+/// Returns once the gate opens; errors only if the project cannot be watched.
+pub fn execute(project_path: &Path) -> Result<()> {
+    let project_path = project_path
+        .canonicalize()
+        .with_context(|| format!("Project directory not found: {}", project_path.display()))?;
+    let targets = WatchTargets::new(&project_path)?;
+
+    let (tx, rx) = unbounded();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if let Ok(event) = res {
+            let _ = tx.send(event);
+        }
+    })
+    .context("Failed to create file watcher")?;
+    for root in &targets.roots {
+        watcher
+            .watch(root, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {}", root.display()))?;
+    }
+
+    let interactive = std::io::stdout().is_terminal();
+    let mut changed: Vec<String> = Vec::new();
+    loop {
+        let status = match gate::run(&project_path, true) {
+            Ok(report) if report.passed() => {
+                redraw(&render_status(&report, &changed), interactive);
+                print_mission_ready();
+                return Ok(());
+            }
+            Ok(report) => render_status(&report, &changed),
+            Err(e) => format!(
+                "{} Gate could not run: {e:#}\n",
+                "✗".red().bold()
+            ),
+        };
+        redraw(&status, interactive);
+        println!(
+            "{}",
+            "👁  Watching for changes... (Ctrl+C to stop)".dimmed()
+        );
+
+        changed = wait_for_changes(&rx, &targets)?
+            .iter()
+            .map(|path| display_name(path, &targets.roots))
+            .collect();
+    }
+}
+
+/// Blocks until a relevant file changes, then collects the rest of the burst
+fn wait_for_changes(rx: &Receiver<Event>, targets: &WatchTargets) -> Result<BTreeSet<PathBuf>> {
+    let mut changed = BTreeSet::new();
+    while changed.is_empty() {
+        let event = rx.recv().context("File watcher stopped")?;
+        collect_relevant(&event, targets, &mut changed);
+    }
+    loop {
+        match rx.recv_timeout(DEBOUNCE) {
+            Ok(event) => collect_relevant(&event, targets, &mut changed),
+            Err(RecvTimeoutError::Timeout) => return Ok(changed),
+            Err(RecvTimeoutError::Disconnected) => anyhow::bail!("File watcher stopped"),
+        }
+    }
+}
+
+fn collect_relevant(event: &Event, targets: &WatchTargets, changed: &mut BTreeSet<PathBuf>) {
+    if !matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) {
+        return;
+    }
+    changed.extend(
+        event
+            .paths
+            .iter()
+            .filter(|path| targets.is_relevant(path))
+            .cloned(),
+    );
+}
+
+/// Path shown in the "changed" line, relative to the watched root
+fn display_name(path: &Path, roots: &[PathBuf]) -> String {
+    roots
+        .iter()
+        .find_map(|root| path.strip_prefix(root).ok())
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// Clears the terminal (or separates runs when output is not a terminal) and prints
+fn redraw(status: &str, interactive: bool) {
+    if interactive {
+        print!("\x1B[2J\x1B[H");
+    } else {
+        println!("{}", "━".repeat(60).dimmed());
+    }
+    print!("{status}");
+}
+
+/// Blocking issues and failed checks of a document
+fn remaining_issues(document: &DocumentReport) -> Vec<String> {
+    let mut remaining: Vec<String> = document
+        .issues
+        .iter()
+        .filter(|reported| reported.blocks())
+        .map(|reported| match reported.issue.span() {
+            Some(span) => format!("L{}: {}", span.start.line, reported.issue.message()),
+            None => reported.issue.message(),
+        })
+        .collect();
+    remaining.extend(document.failures.iter().cloned());
+    if remaining.is_empty() && !document.is_passing() {
+        remaining.push(document.headline.clone());
+    }
+    remaining
+}
+
+/// Compact status: one line per document, blocking issues under failing ones
+fn render_status(report: &GateReport, changed: &[String]) -> String {
+    let mut out = format!(
+        "{} {} {}\n",
+        "🚪 GATE WATCH".bold().cyan(),
+        report.project.bold(),
+        format!("({})", report.phase).dimmed()
+    );
+    if !changed.is_empty() {
+        out.push_str(&format!(
+            "{}\n",
+            format!(
+                "   ↻ {} at {}",
+                changed.join(", "),
+                chrono::Local::now().format("%H:%M:%S")
+            )
+            .dimmed()
+        ));
+    }
+    out.push('\n');
+
+    let (mut passing, mut total, mut blocking) = (0, 0, 0);
+    for (_, document) in report.documents() {
+        total += 1;
+        if document.is_passing() {
+            passing += 1;
+            let marker = if document.count(Severity::Warning) > 0 {
+                "⚠".yellow().bold()
+            } else {
+                "✓".green().bold()
+            };
+            out.push_str(&format!("  {marker} {}\n", document.name));
+            continue;
+        }
+
+        let remaining = remaining_issues(document);
+        blocking += remaining.len();
+        out.push_str(&format!(
+            "  {} {} {}\n",
+            "✗".red().bold(),
+            document.name,
+            format!("({} issue(s))", remaining.len()).red()
+        ));
+        for message in remaining.iter().take(ISSUES_PER_DOCUMENT) {
+            out.push_str(&format!("      {message}\n"));
+        }
+        if remaining.len() > ISSUES_PER_DOCUMENT {
+            out.push_str(&format!(
+                "      {}\n",
+                format!("... and {} more", remaining.len() - ISSUES_PER_DOCUMENT).dimmed()
+            ));
+        }
+    }
+
+    out.push_str(&format!(
+        "\n  {passing}/{total} passing, {blocking} issue(s) remaining\n\n"
+    ));
+    out
+}
+
+fn print_mission_ready() {
+    println!();
+    println!("{}", "━".repeat(60).green());
+    println!("{}", "✅ MISSION READY".green().bold());
+    println!(
+        "{}",
+        "   Gate is open. All validation checks passed.".green()
+    );
+    println!("{}", "━".repeat(60).green());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gate_report::ReportGroup;
+    use crate::planning::ValidationIssue;
+
+    #[test]
+    fn test_relevant_changes() {
+        let targets = WatchTargets {
+            roots: vec![PathBuf::from("/project")],
+            files: vec![
+                PathBuf::from("/project/nexus.toml"),
+                PathBuf::from("/project/.nexus/gate-heuristics.json"),
+            ],
+        };
+
+        assert!(targets.is_relevant(Path::new("/project/01-PLANNING/03-Tech-Stack.md")));
+        assert!(targets.is_relevant(Path::new("/project/00-MANAGEMENT/00-START-HERE.md")));
+        assert!(targets.is_relevant(Path::new("/project/nexus.toml")));
+        assert!(targets.is_relevant(Path::new("/project/.nexus/gate-heuristics.json")));
+
+        // The gate's own cache, editor state and files outside the project are ignored
+        assert!(!targets.is_relevant(Path::new("/project/.nexus/gate-cache.json")));
+        assert!(!targets.is_relevant(Path::new("/project/.obsidian/workspace.md")));
+        assert!(!targets.is_relevant(Path::new("/project/01-PLANNING/notes.txt")));
+        assert!(!targets.is_relevant(Path::new("/elsewhere/01-PLANNING/03-Tech-Stack.md")));
+    }
+
+    #[test]
+    fn test_render_status_lists_remaining_issues() {
+        let mut report = GateReport::new("demo", "PHASE 1: PLANNING", Path::new("/project"));
+        report.begin(ReportGroup::PlanningDocuments);
+        report.push(DocumentReport::passed(
+            "01-Problem-and-Vision.md",
+            Path::new("/project/01-PLANNING/01-Problem-and-Vision.md"),
+            "01-Problem-and-Vision.md",
+        ));
+        report.push(
+            DocumentReport::failed(
+                "03-Tech-Stack.md",
+                Path::new("/project/01-PLANNING/03-Tech-Stack.md"),
+                "03-Tech-Stack.md",
+            )
+            .with_issues(
+                ["Stack:", "Why:", "Avoid:", "Dependencies:"]
+                    .into_iter()
+                    .map(|header| ValidationIssue::MissingHeader {
+                        header: header.to_string(),
+                    })
+                    .collect(),
+            ),
+        );
+        report.push(DocumentReport::failed(
+            "04-Architecture.md",
+            Path::new("/project/01-PLANNING/04-Architecture.md"),
+            "04-Architecture.md - File not found",
+        ));
+
+        let status = render_status(&report, &["01-PLANNING/03-Tech-Stack.md".to_string()]);
+        assert!(status.contains("01-Problem-and-Vision.md"));
+        assert!(status.contains("↻ 01-PLANNING/03-Tech-Stack.md"));
+        assert!(status.contains("(4 issue(s))"));
+        assert!(status.contains("Missing required header: Avoid:"));
+        assert!(!status.contains("Missing required header: Dependencies:"));
+        assert!(status.contains("... and 1 more"));
+        assert!(status.contains("04-Architecture.md - File not found"));
+        assert!(status.contains("1/3 passing, 5 issue(s) remaining"));
+    }
+}
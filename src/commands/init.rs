@@ -1,4 +1,5 @@
 use crate::config::NexusConfig;
+use crate::layout::VaultLayout;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
@@ -9,12 +10,14 @@ use std::path::{Path, PathBuf};
 /// # Arguments
 /// * `project_name` - Name of the project to create
 /// * `mode` - "sprint" or "adhoc"
+/// * `locale` - Language of the planning templates ("en", or a directory under templates/locales)
 /// * `is_full_project` - If true, creates full project structure (God Move)
 /// * `base_dir` - Optional base directory to create project in (defaults to current dir)
 /// * `obsidian_root` - Optional Obsidian vault root directory (defaults to ~/obsidian/work)
 pub fn execute(
     project_name: &str,
     mode: &str,
+    locale: &str,
     is_full_project: bool,
     base_dir: Option<&Path>,
     obsidian_root: Option<&Path>,
@@ -26,9 +29,17 @@ pub fn execute(
         ));
     }
 
+    // Validate locale before anything is created
+    let locale_dir = locale_template_dir(locale)?;
+    if locale_dir.is_some() && mode == "adhoc" && !is_full_project {
+        return Err(format!(
+            "Locale '{locale}' is only available for sprint-mode planning templates."
+        ));
+    }
+
     // If --project flag is set, use the God Move initialization
     if is_full_project {
-        return init_full_project(project_name, mode, base_dir, obsidian_root);
+        return init_full_project(project_name, mode, locale_dir.as_deref(), base_dir, obsidian_root);
     }
 
     // Construct project path: base_dir/project_name or just project_name
@@ -67,17 +78,20 @@ pub fn execute(
     if mode == "adhoc" {
         init_adhoc_project(&project_path, &folder_name, &absolute_path)?;
     } else {
-        init_sprint_project(&project_path, &folder_name, &absolute_path)?;
+        init_sprint_project(&project_path, &folder_name, &absolute_path, locale_dir.as_deref())?;
     }
 
     Ok(())
 }
 
 /// Initialize a sprint-mode project (original behavior)
+///
+/// With a locale, its translated planning documents replace the English ones.
 fn init_sprint_project(
     project_path: &Path,
     folder_name: &str,
     absolute_path: &Path,
+    locale_dir: Option<&Path>,
 ) -> Result<(), String> {
     // Copy template files from templates/project
     let template_source = Path::new("templates/project");
@@ -96,6 +110,13 @@ fn init_sprint_project(
         format!("Failed to copy template files: {e}")
     })?;
 
+    if let Some(locale_dir) = locale_dir {
+        copy_dir_recursive(locale_dir, project_path).map_err(|e| {
+            let _ = fs::remove_dir_all(project_path);
+            format!("Failed to copy translated templates: {e}")
+        })?;
+    }
+
     println!("✓ Copied template files");
 
     // Create nexus.toml configuration (sprint mode - no tasks config)
@@ -230,6 +251,39 @@ fn init_adhoc_project(
     Ok(())
 }
 
+/// Template directory of a locale
+///
+/// # Returns
+/// * `Ok(None)` - English, the templates in templates/project
+/// * `Ok(Some(dir))` - templates/locales/<locale>, with translated planning documents
+/// * `Err` - No templates exist for the locale
+fn locale_template_dir(locale: &str) -> Result<Option<PathBuf>, String> {
+    if locale == "en" {
+        return Ok(None);
+    }
+
+    let locales_dir = Path::new("templates/locales");
+    let locale_dir = locales_dir.join(locale);
+    if !locale.is_empty() && !locale.contains(['/', '\\', '.']) && locale_dir.is_dir() {
+        return Ok(Some(locale_dir));
+    }
+
+    let mut available = vec!["en".to_string()];
+    if let Ok(entries) = fs::read_dir(locales_dir) {
+        let mut translated: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect();
+        translated.sort();
+        available.extend(translated);
+    }
+    Err(format!(
+        "Unknown locale '{locale}'. Available: {}",
+        available.join(", ")
+    ))
+}
+
 /// Recursively copy a directory and its contents
 fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
     // Create destination directory if it doesn't exist
@@ -262,6 +316,7 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
 fn init_full_project(
     project_name: &str,
     _mode: &str,
+    locale_dir: Option<&Path>,
     base_dir: Option<&Path>,
     obsidian_root: Option<&Path>,
 ) -> Result<(), String> {
@@ -360,17 +415,18 @@ fn init_full_project(
 
     println!("    {} Vault directory: {}", "✓".green(), vault_path.display());
 
+    let config = NexusConfig::new(
+        project_name.to_string(),
+        vault_path.to_string_lossy().to_string(),
+    );
+
     // Create planning documents (01-05)
-    copy_project_templates(&vault_path)?;
+    copy_project_templates(&config.layout(), locale_dir)?;
 
     println!();
     println!("{} Creating nexus.toml...", "4/5".cyan().bold());
 
     // Create nexus.toml
-    let config = NexusConfig::new(
-        project_name.to_string(),
-        vault_path.to_string_lossy().to_string(),
-    );
 
     let mut config_toml = config
         .to_toml()
@@ -414,15 +470,15 @@ fn init_full_project(
     let mut existing_entries = std::collections::HashSet::new();
 
     // If .gitignore exists, read and preserve its content
-    if gitignore_path.exists() {
-        if let Ok(existing_content) = fs::read_to_string(&gitignore_path) {
-            final_content = existing_content.clone();
-            // Track what's already in the file (normalized, trimmed lines)
-            for line in existing_content.lines() {
-                let trimmed = line.trim();
-                if !trimmed.is_empty() {
-                    existing_entries.insert(trimmed.to_string());
-                }
+    if gitignore_path.exists()
+        && let Ok(existing_content) = fs::read_to_string(&gitignore_path)
+    {
+        final_content = existing_content.clone();
+        // Track what's already in the file (normalized, trimmed lines)
+        for line in existing_content.lines() {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() {
+                existing_entries.insert(trimmed.to_string());
             }
         }
     }
@@ -496,7 +552,9 @@ fn init_full_project(
 }
 
 /// Copy project templates to the Obsidian vault
-fn copy_project_templates(vault_path: &Path) -> Result<(), String> {
+///
+/// Planning documents come from `locale_dir` when given (translated templates).
+fn copy_project_templates(layout: &VaultLayout, locale_dir: Option<&Path>) -> Result<(), String> {
     use colored::Colorize;
 
     let template_source = Path::new("templates/project");
@@ -507,29 +565,24 @@ fn copy_project_templates(vault_path: &Path) -> Result<(), String> {
         ));
     }
 
-    // 1. Setup 01-PLANNING
-    let planning_dir = vault_path.join("01-PLANNING");
-    fs::create_dir_all(&planning_dir)
-        .map_err(|e| format!("Failed to create planning directory: {e}"))?;
-
-    let planning_files = vec![
-        "01-Problem-and-Vision.md",
-        "02-Scope-and-Boundaries.md",
-        "03-Tech-Stack.md",
-        "04-Architecture.md",
-        "05-MVP-Breakdown.md",
-    ];
+    let vault_path = layout.root.as_path();
+
+    // 1. Setup the planning directory from the planning schema
+    let planning_dir = &layout.planning_dir;
+    let schema = crate::schema::planning::PlanningSchema::builtin().map_err(|e| format!("{e:#}"))?;
+    let planning_templates = locale_dir.unwrap_or(template_source);
+    let planning_files =
+        crate::schema::planning::scaffold_documents(&schema, planning_dir, vault_path, planning_templates)
+            .map_err(|e| format!("Failed to scaffold planning documents: {e:#}"))?;
 
     for file in planning_files {
-        fs::copy(template_source.join(file), planning_dir.join(file))
-            .map_err(|e| format!("Failed to copy {file}: {e}"))?;
         println!("    {} {}", "✓".green(), file);
     }
 
-    // 2. Setup 00-MANAGEMENT
-    let management_dir = vault_path.join("00-MANAGEMENT");
-    fs::create_dir_all(&management_dir)
-        .map_err(|e| format!("Failed to create 00-MANAGEMENT directory: {e}"))?;
+    // 2. Setup the management directory
+    let management_dir = &layout.management_dir;
+    fs::create_dir_all(management_dir)
+        .map_err(|e| format!("Failed to create {} directory: {e}", layout.relative(management_dir).display()))?;
 
     let management_files = vec![
         "00-START-HERE.md",
@@ -542,7 +595,7 @@ fn copy_project_templates(vault_path: &Path) -> Result<(), String> {
         println!("    {} {}", "✓".green(), file);
     }
 
-    println!("    {} 00-MANAGEMENT/ directory", "✓".green());
+    println!("    {} {}/ directory", "✓".green(), layout.relative(management_dir).display());
 
     // 3. Other directories (decisions, dev-sessions)
     let other_dirs = vec!["decisions", "dev-sessions"];
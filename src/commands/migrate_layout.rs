@@ -0,0 +1,405 @@
+//! Layout Migration (`nexus migrate-layout`)
+//!
+//! Moves the planning, management and sprint folders of an existing vault to
+//! new places, rewrites wikilinks that spell out a moved folder
+//! (`[[01-PLANNING/03-Tech-Stack]]`) and saves the new `[structure]` in
+//! nexus.toml. Links by note name alone resolve anywhere in the vault and are
+//! left as they are.
+
+use crate::config::{NexusConfig, StructureConfig};
+use crate::layout::VaultLayout;
+use crate::links::{VaultIndex, find_links};
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use std::fs;
+use std::path::{Component, Path};
+
+/// Folder in the vault root that holds moved folders until they are in place
+const STAGING_DIR: &str = ".nexus-migrate";
+
+/// Options of `nexus migrate-layout`; folders not given stay where they are
+#[derive(Debug, Clone, Default)]
+pub struct MigrateArgs {
+    pub planning_dir: Option<String>,
+    pub management_dir: Option<String>,
+    pub sprint_dir: Option<String>,
+    /// Print what would change without changing it
+    pub dry_run: bool,
+}
+
+/// A folder move, relative to the vault root with forward slashes
+///
+/// Folders that keep their place but sit inside a moved folder are moves to
+/// themselves, so they stay behind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FolderMove {
+    from: String,
+    to: String,
+}
+
+/// Execute `nexus migrate-layout`
+///
+/// # Flow:
+/// 1. Work out the new `[structure]` and the folders to move
+/// 2. Find wikilinks with paths into moved folders
+/// 3. Move the folders (via a staging folder, so folders can swap or nest)
+/// 4. Rewrite the links and save the new `[structure]` in nexus.toml
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory (where nexus.toml lives)
+/// * `args` - New folders, and whether to only print the plan
+///
+/// # Returns
+/// * `Ok(())` - Vault migrated (or plan printed)
+/// * `Err` - If a folder is invalid, a target already exists or a move fails
+pub fn execute(project_path: &Path, args: &MigrateArgs) -> Result<()> {
+    println!("{}", "🚚 Vault Layout Migration".bright_cyan().bold());
+    println!();
+
+    let config_path = project_path.join("nexus.toml");
+    let config_content = fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read nexus.toml from: {}", project_path.display()))?;
+    let mut config = NexusConfig::from_toml(&config_content)
+        .with_context(|| format!("Failed to parse config from: {}", config_path.display()))?;
+
+    let layout = config.layout();
+    let current = current_structure(&layout);
+    let target = target_structure(&current, args)?;
+    let moves = plan_moves(&current, &target);
+    if moves.iter().all(|folder| folder.from == folder.to) {
+        println!("{}", "✓ The vault already uses this layout".green());
+        return Ok(());
+    }
+
+    println!("{}", "📁 Folders:".bright_blue());
+    for folder in moves.iter().filter(|folder| folder.from != folder.to) {
+        let missing = if layout.root.join(&folder.from).exists() { "" } else { " (not found, nothing to move)" };
+        println!("  {}/ → {}/{missing}", folder.from, folder.to);
+        let to = layout.root.join(&folder.to);
+        if to.exists() && !moves.iter().any(|other| within(&folder.to, &other.from)) {
+            bail!("Cannot move {}/: {} already exists", folder.from, to.display());
+        }
+    }
+    println!();
+
+    // Links are rewritten in place; only the files they live in move
+    let vault = VaultIndex::build(&layout.root)?;
+    let mut rewrites = Vec::new();
+    for file in vault.files().iter().filter(|file| file.ends_with(".md")) {
+        let path = layout.root.join(file);
+        let content =
+            fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        let (rewritten, count) = rewrite_links(&content, &moves);
+        if count > 0 {
+            rewrites.push((relocate(file, &moves).unwrap_or_else(|| file.clone()), rewritten, count));
+        }
+    }
+    println!("{}", "🔗 Wikilinks:".bright_blue());
+    if rewrites.is_empty() {
+        println!("  No links spell out a moved folder");
+    }
+    for (file, _, count) in &rewrites {
+        println!("  {file}: {count} link(s)");
+    }
+    println!();
+
+    if args.dry_run {
+        println!("{}", "Dry run: nothing was changed".bright_yellow());
+        return Ok(());
+    }
+
+    move_folders(&layout.root, &moves)?;
+    for (file, content, _) in &rewrites {
+        let path = layout.root.join(file);
+        fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    config.structure = target;
+    let updated_toml = config.to_toml().context("Failed to serialize config")?;
+    fs::write(&config_path, updated_toml)
+        .with_context(|| format!("Failed to write config to: {}", config_path.display()))?;
+    println!("{}", "  ✓ Updated [structure] in nexus.toml".green());
+    println!();
+
+    println!("{}", "✅ LAYOUT MIGRATED".bright_green().bold());
+    Ok(())
+}
+
+/// `[structure]` as the folders actually are, relative to the vault root
+fn current_structure(layout: &VaultLayout) -> StructureConfig {
+    let relative = |dir: &Path| layout.relative(dir).to_string_lossy().replace('\\', "/");
+    StructureConfig {
+        planning_dir: relative(&layout.planning_dir),
+        management_dir: relative(&layout.management_dir),
+        sprint_dir: relative(&layout.sprint_dir),
+    }
+}
+
+/// `[structure]` after the migration
+///
+/// A sprint directory inside the management directory moves along with it
+/// unless `--sprint-dir` says otherwise.
+fn target_structure(current: &StructureConfig, args: &MigrateArgs) -> Result<StructureConfig> {
+    if args.planning_dir.is_none() && args.management_dir.is_none() && args.sprint_dir.is_none() {
+        bail!("Nothing to migrate: pass --planning-dir, --management-dir or --sprint-dir");
+    }
+    let planning_dir = folder_arg(args.planning_dir.as_deref(), &current.planning_dir)?;
+    let management_dir = folder_arg(args.management_dir.as_deref(), &current.management_dir)?;
+    let sprint_dir = match args.sprint_dir.as_deref() {
+        Some(dir) => folder_arg(Some(dir), &current.sprint_dir)?,
+        None => relocate(
+            &current.sprint_dir,
+            &[FolderMove {
+                from: current.management_dir.clone(),
+                to: management_dir.clone(),
+            }],
+        )
+        .unwrap_or_else(|| current.sprint_dir.clone()),
+    };
+    Ok(StructureConfig {
+        planning_dir,
+        management_dir,
+        sprint_dir,
+    })
+}
+
+/// A folder given on the command line, checked to stay inside the vault
+fn folder_arg(dir: Option<&str>, current: &str) -> Result<String> {
+    let Some(dir) = dir else {
+        return Ok(current.to_string());
+    };
+    let dir = dir.replace('\\', "/").trim_matches('/').to_string();
+    let inside_vault = Path::new(&dir)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    if dir.is_empty() || !inside_vault {
+        bail!("Invalid folder '{dir}': use a path relative to the vault root");
+    }
+    Ok(dir)
+}
+
+/// Folders that change place, plus unchanged folders inside them
+fn plan_moves(current: &StructureConfig, target: &StructureConfig) -> Vec<FolderMove> {
+    let pairs = [
+        (&current.planning_dir, &target.planning_dir),
+        (&current.management_dir, &target.management_dir),
+        (&current.sprint_dir, &target.sprint_dir),
+    ];
+    let moved: Vec<&String> = pairs
+        .iter()
+        .filter(|(from, to)| from != to)
+        .map(|(from, _)| *from)
+        .collect();
+    let mut moves: Vec<FolderMove> = Vec::new();
+    for (from, to) in pairs {
+        let stays_inside_moved = from == to && moved.iter().any(|dir| *dir != from && within(from, dir));
+        if (from != to || stays_inside_moved) && !moves.iter().any(|folder| &folder.from == from) {
+            moves.push(FolderMove {
+                from: from.clone(),
+                to: to.clone(),
+            });
+        }
+    }
+    moves
+}
+
+/// Whether `path` is `dir` or below it, ignoring ASCII case like Obsidian links do
+fn within(path: &str, dir: &str) -> bool {
+    path.len() >= dir.len()
+        && path.is_char_boundary(dir.len())
+        && path[..dir.len()].eq_ignore_ascii_case(dir)
+        && (path.len() == dir.len() || path[dir.len()..].starts_with('/'))
+}
+
+/// Where a vault path ends up, or `None` if it does not move
+///
+/// The deepest folder containing the path decides.
+fn relocate(path: &str, moves: &[FolderMove]) -> Option<String> {
+    let trimmed = path.trim_start_matches('/');
+    let leading = &path[..path.len() - trimmed.len()];
+    let folder = moves
+        .iter()
+        .filter(|folder| within(trimmed, &folder.from))
+        .max_by_key(|folder| folder.from.len())?;
+    (folder.from != folder.to)
+        .then(|| format!("{leading}{}{}", folder.to, &trimmed[folder.from.len()..]))
+}
+
+/// Rewrites wikilinks and embeds whose path starts with a moved folder
+///
+/// Returns the new content and the number of links changed.
+fn rewrite_links(content: &str, moves: &[FolderMove]) -> (String, usize) {
+    let mut rewritten = String::with_capacity(content.len());
+    let mut copied = 0;
+    let mut count = 0;
+    for link in find_links(content) {
+        let Some(note) = relocate(&link.note, moves) else {
+            continue;
+        };
+        let Some(offset) = content[link.range.clone()].find(&link.note) else {
+            continue;
+        };
+        let start = link.range.start + offset;
+        rewritten.push_str(&content[copied..start]);
+        rewritten.push_str(&note);
+        copied = start + link.note.len();
+        count += 1;
+    }
+    rewritten.push_str(&content[copied..]);
+    (rewritten, count)
+}
+
+/// Moves the folders that exist, deepest first into a staging folder, then
+/// shallowest first to their new place
+fn move_folders(root: &Path, moves: &[FolderMove]) -> Result<()> {
+    let staging = root.join(STAGING_DIR);
+    if staging.exists() {
+        bail!(
+            "{} is left over from an interrupted migration; move its folders back first",
+            staging.display()
+        );
+    }
+    let depth = |dir: &str| dir.matches('/').count();
+
+    let mut staged: Vec<(usize, &FolderMove)> = moves
+        .iter()
+        .enumerate()
+        .filter(|(_, folder)| root.join(&folder.from).is_dir())
+        .collect();
+    staged.sort_by_key(|(_, folder)| std::cmp::Reverse(depth(&folder.from)));
+    fs::create_dir_all(&staging)
+        .with_context(|| format!("Failed to create {}", staging.display()))?;
+    for (index, folder) in &staged {
+        fs::rename(root.join(&folder.from), staging.join(index.to_string()))
+            .with_context(|| format!("Failed to move {}/", folder.from))?;
+    }
+
+    staged.sort_by_key(|(_, folder)| depth(&folder.to));
+    for (index, folder) in &staged {
+        let to = root.join(&folder.to);
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::rename(staging.join(index.to_string()), &to)
+            .with_context(|| format!("Failed to move {}/ to {}/", folder.from, folder.to))?;
+        if folder.from != folder.to {
+            println!("{}", format!("  ✓ Moved {}/ to {}/", folder.from, folder.to).green());
+        }
+    }
+    fs::remove_dir(&staging).with_context(|| format!("Failed to remove {}", staging.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn structure(planning_dir: &str, management_dir: &str, sprint_dir: &str) -> StructureConfig {
+        StructureConfig {
+            planning_dir: planning_dir.to_string(),
+            management_dir: management_dir.to_string(),
+            sprint_dir: sprint_dir.to_string(),
+        }
+    }
+
+    fn folder_move(from: &str, to: &str) -> FolderMove {
+        FolderMove {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_sprint_dir_follows_management_dir() {
+        let current = structure("01-PLANNING", "00-MANAGEMENT", "00-MANAGEMENT/sprints");
+        let args = MigrateArgs {
+            management_dir: Some("docs/mgmt/".to_string()),
+            ..MigrateArgs::default()
+        };
+        let target = target_structure(&current, &args).unwrap();
+        assert_eq!(target.planning_dir, "01-PLANNING");
+        assert_eq!(target.management_dir, "docs/mgmt");
+        assert_eq!(target.sprint_dir, "docs/mgmt/sprints");
+        assert_eq!(
+            plan_moves(&current, &target),
+            vec![
+                folder_move("00-MANAGEMENT", "docs/mgmt"),
+                folder_move("00-MANAGEMENT/sprints", "docs/mgmt/sprints"),
+            ]
+        );
+
+        let args = MigrateArgs {
+            planning_dir: Some("../outside".to_string()),
+            ..MigrateArgs::default()
+        };
+        assert!(target_structure(&current, &args).is_err());
+        assert!(target_structure(&current, &MigrateArgs::default()).is_err());
+    }
+
+    #[test]
+    fn test_unchanged_folder_inside_moved_folder_stays() {
+        let current = structure("01-PLANNING", "00-MANAGEMENT", "00-MANAGEMENT/sprints");
+        let target = structure("01-PLANNING", "mgmt", "00-MANAGEMENT/sprints");
+        let moves = plan_moves(&current, &target);
+        assert_eq!(
+            moves,
+            vec![
+                folder_move("00-MANAGEMENT", "mgmt"),
+                folder_move("00-MANAGEMENT/sprints", "00-MANAGEMENT/sprints"),
+            ]
+        );
+        assert_eq!(relocate("00-MANAGEMENT/sprints/sprint-1-core/Tasks.md", &moves), None);
+        assert_eq!(relocate("00-MANAGEMENT/Backlog.md", &moves).as_deref(), Some("mgmt/Backlog.md"));
+    }
+
+    #[test]
+    fn test_rewrite_links() {
+        let moves = vec![
+            folder_move("01-PLANNING", "docs/plan"),
+            folder_move("00-MANAGEMENT/sprints", "work/sprints"),
+        ];
+        let content = "See [[01-planning/03-Tech-Stack#Database|stack]] and \
+                       ![[00-MANAGEMENT/sprints/sprint-1-core/Tasks]].\n\
+                       [[03-Tech-Stack]] and [[01-PLANNING-old/x]] stay.\n\
+                       `[[01-PLANNING/in-code]]`\n";
+        let (rewritten, count) = rewrite_links(content, &moves);
+        assert_eq!(count, 2);
+        assert_eq!(
+            rewritten,
+            "See [[docs/plan/03-Tech-Stack#Database|stack]] and \
+             ![[work/sprints/sprint-1-core/Tasks]].\n\
+             [[03-Tech-Stack]] and [[01-PLANNING-old/x]] stay.\n\
+             `[[01-PLANNING/in-code]]`\n"
+        );
+    }
+
+    #[test]
+    fn test_move_folders_nested_and_swapped() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join("00-MANAGEMENT/sprints/sprint-1-core")).unwrap();
+        fs::create_dir_all(root.join("01-PLANNING")).unwrap();
+        fs::write(root.join("00-MANAGEMENT/Backlog.md"), "backlog").unwrap();
+        fs::write(root.join("00-MANAGEMENT/sprints/sprint-1-core/Tasks.md"), "tasks").unwrap();
+        fs::write(root.join("01-PLANNING/01-Problem.md"), "problem").unwrap();
+
+        // Planning takes management's old place; sprints move under planning
+        let moves = vec![
+            folder_move("01-PLANNING", "00-MANAGEMENT"),
+            folder_move("00-MANAGEMENT", "mgmt"),
+            folder_move("00-MANAGEMENT/sprints", "00-MANAGEMENT/sprints"),
+        ];
+        move_folders(root, &moves).unwrap();
+
+        assert_eq!(fs::read_to_string(root.join("00-MANAGEMENT/01-Problem.md")).unwrap(), "problem");
+        assert_eq!(fs::read_to_string(root.join("mgmt/Backlog.md")).unwrap(), "backlog");
+        assert_eq!(
+            fs::read_to_string(root.join("00-MANAGEMENT/sprints/sprint-1-core/Tasks.md")).unwrap(),
+            "tasks"
+        );
+        assert!(!root.join("01-PLANNING").exists());
+        assert!(!root.join(STAGING_DIR).exists());
+    }
+}
@@ -1,8 +1,17 @@
 pub mod diagnose;
+pub mod export;
 pub mod gate;
+pub mod gate_watch;
 pub mod init;
+pub mod migrate_layout;
 pub mod plan;
+pub mod roadmap;
+pub mod session;
 pub mod shell;
 pub mod sprint;
+pub mod sprint_abort;
+pub mod sprint_approve;
+pub mod sprint_close;
+pub mod sprint_finish;
 pub mod task;
 pub mod unlock;
@@ -13,6 +13,7 @@
 use crate::config::NexusConfig;
 use crate::genesis::GenesisEngine;
 use crate::llm::{LlmClient, LlmProvider};
+use crate::schema::planning::PlanningSchema;
 use anyhow::{Context, Result};
 use colored::Colorize;
 use std::path::Path;
@@ -100,6 +101,11 @@ pub fn execute_init(project_path: &Path) -> Result<()> {
         _ => anyhow::bail!("Unknown LLM provider: {}", llm_config.provider),
     };
 
+    let schema = PlanningSchema::for_project(&config)?;
+    if schema.documents.len() < 2 {
+        anyhow::bail!("Project Genesis needs a vision document and at least one document to generate");
+    }
+
     // Create runtime for async operations
     let runtime = tokio::runtime::Runtime::new()?;
 
@@ -112,7 +118,7 @@ pub fn execute_init(project_path: &Path) -> Result<()> {
 
         // Create Genesis engine
         let planning_path = config.get_planning_path();
-        let engine = GenesisEngine::new(planning_path, llm_client)?;
+        let engine = GenesisEngine::new(planning_path, llm_client, schema)?;
 
         // Execute Genesis
         engine.execute_genesis().await
@@ -0,0 +1,144 @@
+//! Roadmap Command Implementation
+//!
+//! Prints the sprint and task dependency graph of the MVP breakdown, the order
+//! sprints can be worked in, and the critical path through the tasks.
+
+use crate::config::NexusConfig;
+use crate::mvp::{MvpTask, parse_mvp_sprints};
+use crate::roadmap::{Roadmap, SprintStatus};
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use std::fs;
+use std::path::Path;
+
+/// Execute the roadmap command
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory (where nexus.toml lives)
+///
+/// # Returns
+/// * `Ok(())` - Roadmap printed
+/// * `Err` - If the config or MVP breakdown cannot be read, or the graph is invalid
+pub fn execute(project_path: &Path) -> Result<()> {
+    println!("{}", "🗺️  Sprint Roadmap".bright_cyan().bold());
+    println!();
+
+    let config_path = project_path.join("nexus.toml");
+    let config_content = fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read nexus.toml from: {}", project_path.display()))?;
+    let config = NexusConfig::from_toml(&config_content)
+        .with_context(|| format!("Failed to parse config from: {}", config_path.display()))?;
+
+    let mvp_path = config.layout().mvp_breakdown();
+    if !mvp_path.exists() {
+        bail!("MVP breakdown file not found: {}", mvp_path.display());
+    }
+    let sprints =
+        parse_mvp_sprints(&mvp_path).context("Failed to parse sprints from MVP breakdown")?;
+    let roadmap = Roadmap::new(&sprints);
+
+    let problems = roadmap.problems();
+    if !problems.is_empty() {
+        println!("{}", "❌ Invalid sprint dependencies".bright_red().bold());
+        println!();
+        for problem in &problems {
+            println!(
+                "  {} [{}] {}",
+                "▸".yellow(),
+                problem.rule.bold(),
+                problem.message
+            );
+            println!("    {}", problem.sprint.dimmed());
+        }
+        println!();
+        bail!(
+            "{} dependency problem(s) in {}",
+            problems.len(),
+            mvp_path.display()
+        );
+    }
+
+    let state = config.state.as_ref();
+    for sprint in roadmap.sprints() {
+        let status = roadmap.status(sprint.number, state);
+        let status = match status {
+            SprintStatus::Approved | SprintStatus::Closed => status.to_string().green(),
+            SprintStatus::InProgress => status.to_string().bright_yellow(),
+            SprintStatus::Ready => status.to_string().bright_cyan(),
+            SprintStatus::Blocked(_) => status.to_string().bright_red(),
+        };
+        let estimate = sprint
+            .total_estimate()
+            .map(|estimate| format!(" ~{estimate}"))
+            .unwrap_or_default();
+
+        println!(
+            "{} {}{} [{}]",
+            format!("Sprint {}:", sprint.number).bold(),
+            sprint.title,
+            estimate.bright_black(),
+            status
+        );
+        if !sprint.depends_on.is_empty() {
+            let dependencies: Vec<String> =
+                sprint.depends_on.iter().map(ToString::to_string).collect();
+            println!("  {} {}", "depends on".bright_black(), dependencies.join(", "));
+        }
+        for task in &sprint.tasks {
+            print_task(task, 1);
+        }
+        println!();
+    }
+
+    let order: Vec<String> = roadmap
+        .sprint_order()
+        .into_iter()
+        .map(|number| number.to_string())
+        .collect();
+    println!("{} {}", "Suggested order:".bright_blue().bold(), order.join(" → "));
+
+    if let Some(path) = roadmap.critical_path() {
+        println!(
+            "{} {} task(s), ~{}",
+            "Critical path:".bright_blue().bold(),
+            path.tasks.len(),
+            path.estimate
+        );
+        for task in &path.tasks {
+            let estimate = task
+                .total_estimate()
+                .map(|estimate| format!(" ~{estimate}"))
+                .unwrap_or_default();
+            println!("  {} {}{}", task.id.bright_yellow(), task.text, estimate.bright_black());
+        }
+    }
+
+    Ok(())
+}
+
+/// Print a task and its sub-tasks with their estimates and dependencies
+fn print_task(task: &MvpTask, depth: usize) {
+    let checkbox = if task.done { "[x]" } else { "[ ]" };
+    let estimate = task
+        .estimate
+        .map(|estimate| format!(" ~{estimate}"))
+        .unwrap_or_default();
+    let dependencies = if task.depends_on.is_empty() {
+        String::new()
+    } else {
+        let dependencies: Vec<String> = task.depends_on.iter().map(ToString::to_string).collect();
+        format!(" ← {}", dependencies.join(", "))
+    };
+    println!(
+        "{}{} {} {}{}{}",
+        "  ".repeat(depth),
+        checkbox,
+        task.id.bright_black(),
+        task.text,
+        estimate.bright_black(),
+        dependencies.cyan()
+    );
+    for subtask in &task.subtasks {
+        print_task(subtask, depth + 1);
+    }
+}
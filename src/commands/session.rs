@@ -0,0 +1,270 @@
+//! Dev Sessions (`nexus session start|note|end`)
+//!
+//! Logs dev sessions of the active sprint as notes in its `sessions/` folder:
+//! `start` renders the session template and links the note from `Tasks.md`,
+//! `note` adds timestamped Comms lines, and `end` fills in the Debrief with the
+//! session's duration and the commits made since it started.
+
+use crate::config::NexusConfig;
+use crate::git_ops::{commits_since, head_commit};
+use crate::layout::VaultLayout;
+use crate::mvp::{SprintData, parse_mvp_sprints};
+use crate::scaffolding::{TASKS_FILE, link_session};
+use crate::session_log::{
+    Debrief, SESSION_TEMPLATE, SessionStart, add_comms, close_session, field, find_open_session,
+    new_session_path, render_session,
+};
+use anyhow::{Context, Result, bail};
+use chrono::Local;
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Options of `nexus session end`
+#[derive(Debug, Clone, Default)]
+pub struct EndArgs {
+    /// Whether the build and tests pass (the Debrief checklist), if known
+    pub passed: Option<bool>,
+    /// Where to pick up next time
+    pub next_task: Option<String>,
+}
+
+/// The active sprint and where its sessions live
+struct SprintWorkspace {
+    config: NexusConfig,
+    layout: VaultLayout,
+    sprint: SprintData,
+    /// Git checkout the sprint's commits are made in (its worktree, if any)
+    repo_path: PathBuf,
+}
+
+impl SprintWorkspace {
+    fn load(project_path: &Path) -> Result<Self> {
+        let config_path = project_path.join("nexus.toml");
+        let config_content = fs::read_to_string(&config_path)
+            .with_context(|| format!("Failed to read nexus.toml from: {}", project_path.display()))?;
+        let config = NexusConfig::from_toml(&config_content)
+            .with_context(|| format!("Failed to parse config from: {}", config_path.display()))?;
+
+        let Some(active) = config
+            .state
+            .as_ref()
+            .and_then(|state| state.active_sprint.clone())
+        else {
+            bail!("No active sprint. Start one with: nexus sprint <project_path> <N>");
+        };
+        if active.status == "closed" {
+            bail!("{} is closed; start the next sprint first", active.current);
+        }
+        let sprint_number = active
+            .number()
+            .with_context(|| format!("Invalid active sprint identifier: {}", active.current))?;
+
+        let layout = config.layout();
+        let sprints = parse_mvp_sprints(&layout.mvp_breakdown())
+            .context("Failed to parse sprints from MVP breakdown")?;
+        let sprint = sprints
+            .into_iter()
+            .find(|sprint| sprint.number == sprint_number)
+            .with_context(|| format!("Sprint {sprint_number} not found in MVP breakdown"))?;
+        let repo_path = active.checkout_path(project_path);
+
+        Ok(Self {
+            config,
+            layout,
+            sprint,
+            repo_path,
+        })
+    }
+
+    fn sessions_dir(&self) -> PathBuf {
+        self.layout.sprint_folder(&self.sprint).join("sessions")
+    }
+
+    /// The session in progress, or an error saying how to start one
+    fn open_session(&self) -> Result<PathBuf> {
+        find_open_session(&self.sessions_dir()).with_context(|| {
+            format!(
+                "No session in progress for Sprint {}. Start one with: nexus session start <project_path>",
+                self.sprint.number
+            )
+        })
+    }
+
+    /// Wikilink target of a vault file (vault-relative, without `.md`)
+    fn link(&self, path: &Path) -> String {
+        self.layout
+            .relative(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+            .trim_end_matches(".md")
+            .to_string()
+    }
+}
+
+/// Execute `nexus session start`
+///
+/// # Flow:
+/// 1. Verify no session of the active sprint is in progress
+/// 2. Render the session template (the vault's `dev-sessions/_session-template.md`,
+///    else the built-in one) into the sprint's `sessions/` folder
+/// 3. Link the note from the sprint's `Tasks.md`
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory (where nexus.toml lives)
+/// * `goal` - Objective for the Mission Briefing
+///
+/// # Returns
+/// * `Ok(PathBuf)` - Path of the session note
+/// * `Err` - If there is no active sprint, a session is already in progress,
+///   or the note cannot be written
+pub fn start(project_path: &Path, goal: Option<&str>) -> Result<PathBuf> {
+    println!("{}", "🎬 Session Start".bright_cyan().bold());
+    println!();
+
+    let workspace = SprintWorkspace::load(project_path)?;
+    let sessions_dir = workspace.sessions_dir();
+    if let Some(open) = find_open_session(&sessions_dir) {
+        bail!(
+            "Session {} is still in progress. End it with: nexus session end <project_path>",
+            workspace.layout.relative(&open).display()
+        );
+    }
+
+    let template_path = workspace.layout.session_template();
+    let template = if template_path.exists() {
+        fs::read_to_string(&template_path)
+            .with_context(|| format!("Failed to read template from: {}", template_path.display()))?
+    } else {
+        SESSION_TEMPLATE.to_string()
+    };
+    let sprint = &workspace.sprint;
+    let started = Local::now().naive_local();
+    let note = render_session(
+        &template,
+        &SessionStart {
+            started,
+            project: workspace.config.project.name.clone(),
+            project_link: workspace.link(&workspace.layout.dashboard()),
+            sprint_link: workspace.link(&workspace.layout.sprint_folder(sprint).join(TASKS_FILE)),
+            sprint_title: sprint.heading_text(),
+            start_commit: head_commit(&workspace.repo_path).ok().map(|head| head.sha),
+            goal: goal.map(str::to_string),
+        },
+    )?;
+
+    fs::create_dir_all(&sessions_dir)
+        .with_context(|| format!("Failed to create folder: {}", sessions_dir.display()))?;
+    let session_path = new_session_path(&sessions_dir, started);
+    fs::write(&session_path, note)
+        .with_context(|| format!("Failed to write session note: {}", session_path.display()))?;
+    println!(
+        "{}",
+        format!("  ✓ {}", workspace.layout.relative(&session_path).display()).green()
+    );
+
+    let link = format!(
+        "[[{}|Session {}]]",
+        workspace.link(&session_path),
+        started.format("%Y-%m-%d %H:%M")
+    );
+    let tasks_path = link_session(&workspace.layout, sprint, &link)?;
+    println!(
+        "{}",
+        format!("  ✓ Linked from {}", workspace.layout.relative(&tasks_path).display()).green()
+    );
+    println!();
+    println!(
+        "{}",
+        format!("Session started for {}", sprint.heading_text()).bright_white()
+    );
+
+    Ok(session_path)
+}
+
+/// Execute `nexus session note`: add a timestamped line to the session's Comms
+///
+/// # Returns
+/// * `Ok(())` - Line added
+/// * `Err` - If there is no session in progress or the note cannot be written
+pub fn note(project_path: &Path, text: &str) -> Result<()> {
+    if text.trim().is_empty() {
+        bail!("Nothing to note: pass the text of the note");
+    }
+    let workspace = SprintWorkspace::load(project_path)?;
+    let session_path = workspace.open_session()?;
+    let mut content = fs::read_to_string(&session_path)
+        .with_context(|| format!("Failed to read session note: {}", session_path.display()))?;
+
+    add_comms(&mut content, Local::now().time(), text);
+    fs::write(&session_path, content)
+        .with_context(|| format!("Failed to write session note: {}", session_path.display()))?;
+    println!(
+        "{}",
+        format!("✓ Noted in {}", workspace.layout.relative(&session_path).display()).green()
+    );
+    Ok(())
+}
+
+/// Execute `nexus session end`
+///
+/// # Flow:
+/// 1. Find the session in progress
+/// 2. List the commits made since it started
+/// 3. Fill in the Debrief (duration, commits, checklist, next task) and mark it ended
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory (where nexus.toml lives)
+/// * `args` - Debrief checklist answers
+///
+/// # Returns
+/// * `Ok(PathBuf)` - Path of the session note
+/// * `Err` - If there is no session in progress or the note cannot be written
+pub fn end(project_path: &Path, args: &EndArgs) -> Result<PathBuf> {
+    println!("{}", "🏁 Session End".bright_cyan().bold());
+    println!();
+
+    let workspace = SprintWorkspace::load(project_path)?;
+    let session_path = workspace.open_session()?;
+    let mut content = fs::read_to_string(&session_path)
+        .with_context(|| format!("Failed to read session note: {}", session_path.display()))?;
+
+    let start_commit = field(&content, "Start-Commit")
+        .map(str::trim)
+        .filter(|sha| !sha.is_empty())
+        .map(str::to_string);
+    let commits = match commits_since(&workspace.repo_path, start_commit.as_deref()) {
+        Ok(commits) => commits,
+        Err(e) => {
+            println!("{}", format!("  ⚠ Commits not recorded: {e:#}").yellow());
+            Vec::new()
+        }
+    };
+    let minutes = close_session(
+        &mut content,
+        &Debrief {
+            ended: Local::now().naive_local(),
+            commits: commits.clone(),
+            passed: args.passed,
+            next_task: args.next_task.clone(),
+        },
+    );
+    fs::write(&session_path, content)
+        .with_context(|| format!("Failed to write session note: {}", session_path.display()))?;
+
+    println!(
+        "{}",
+        format!("  ✓ {}", workspace.layout.relative(&session_path).display()).green()
+    );
+    if let Some(minutes) = minutes {
+        println!("{}", format!("  ✓ Duration: {minutes} min").green());
+    }
+    println!("{}", format!("  ✓ {} commit(s)", commits.len()).green());
+    for commit in &commits {
+        println!("      {} {}", commit.short_sha.dimmed(), commit.summary);
+    }
+    println!();
+    println!("{}", "✅ SESSION ENDED".bright_green().bold());
+
+    Ok(session_path)
+}
@@ -188,7 +188,7 @@ fn print_banner(state: &NexusState) -> Result<()> {
     println!();
     println!(
         "{}",
-        "Available commands: use, gate, unlock, sprint, plan, catalyst, diagnose, status, context, help, exit".dimmed()
+        "Available commands: use, gate, unlock, sprint, roadmap, session, plan, catalyst, diagnose, status, context, help, exit".dimmed()
     );
     println!("{}", "Type 'help' for more information.".dimmed());
     println!(
@@ -231,6 +231,8 @@ fn execute_command(
         "gate" => execute_gate(state, last_gate_error),
         "unlock" => execute_unlock(state),
         "sprint" => execute_sprint(state, args),
+        "roadmap" => execute_roadmap(state),
+        "session" => execute_session(state, args),
         "task" => execute_task_command(state, args),
         "start" => execute_task_command(state, &["start"]),
         "done" => execute_task_command(state, &["done"]),
@@ -298,6 +300,38 @@ fn print_help() {
     );
     println!(
         "  {}  <N>   Create/switch to sprint N", "sprint".cyan());
+    println!(
+        "  {} approve  Approve the active sprint (tasks + Definition of Done)",
+        "sprint".cyan()
+    );
+    println!(
+        "  {} close [--into N|--backlog]  Close the active sprint, carrying open tasks over",
+        "sprint".cyan()
+    );
+    println!(
+        "  {} finish [--delete-branch]  Merge and tag the approved sprint's branch",
+        "sprint".cyan()
+    );
+    println!(
+        "  {} abort [--force] [--remove]  Undo starting the active sprint",
+        "sprint".cyan()
+    );
+    println!(
+        "  {}      Show sprint dependencies and the critical path",
+        "roadmap".cyan()
+    );
+    println!(
+        "  {} start [--goal <text>]  Start a dev session note for the active sprint",
+        "session".cyan()
+    );
+    println!(
+        "  {} note <text>  Add a timestamped line to the session's Comms",
+        "session".cyan()
+    );
+    println!(
+        "  {} end [--passed|--failed] [--next <text>]  End the session and record its commits",
+        "session".cyan()
+    );
     println!(
         "  {}  <start|done> Manage ad-hoc tasks",
         "task".cyan()
@@ -423,6 +457,14 @@ fn execute_use(state: &mut NexusState, args: &[&str]) -> Result<()> {
             obsidian_path.display().to_string().dimmed()
         );
     }
+    if let Some((sprint, worktree)) = state.get_active_sprint_worktree() {
+        println!(
+            "  {} {} ({})",
+            "Sprint worktree:".bold(),
+            worktree.display().to_string().dimmed(),
+            sprint.cyan()
+        );
+    }
 
     Ok(())
 }
@@ -466,7 +508,53 @@ fn execute_sprint(state: &NexusState, args: &[&str]) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("No active project. Use 'use <project>' first."))?;
 
     if args.is_empty() {
-        anyhow::bail!("Usage: sprint <number>");
+        anyhow::bail!(
+            "Usage: sprint <number> | sprint approve | sprint close [--into N|--backlog] | sprint finish [--delete-branch] | sprint abort [--force] [--remove]"
+        );
+    }
+
+    if args[0] == "approve" {
+        let options = crate::commands::sprint_approve::ApproveOptions::default();
+        return crate::commands::sprint_approve::execute(&project_path, &options).map(|_| ());
+    }
+
+    if args[0] == "close" {
+        use crate::commands::sprint_close::CarryOverTarget;
+        let target = match &args[1..] {
+            [] => CarryOverTarget::NextSprint,
+            ["--backlog"] => CarryOverTarget::Backlog,
+            ["--into", number] => CarryOverTarget::Sprint(
+                number
+                    .parse()
+                    .context("Sprint number must be a positive integer")?,
+            ),
+            _ => anyhow::bail!("Usage: sprint close [--into N|--backlog]"),
+        };
+        return crate::commands::sprint_close::execute(&project_path, target).map(|_| ());
+    }
+
+    if args[0] == "finish" {
+        let finish_args = crate::commands::sprint_finish::FinishArgs {
+            delete_branch: match &args[1..] {
+                [] => false,
+                ["--delete-branch"] => true,
+                _ => anyhow::bail!("Usage: sprint finish [--delete-branch]"),
+            },
+            ..Default::default()
+        };
+        return crate::commands::sprint_finish::execute(&project_path, &finish_args);
+    }
+
+    if args[0] == "abort" {
+        let mut abort_args = crate::commands::sprint_abort::AbortArgs::default();
+        for arg in &args[1..] {
+            match *arg {
+                "--force" => abort_args.force = true,
+                "--remove" => abort_args.remove = true,
+                _ => anyhow::bail!("Usage: sprint abort [--force] [--remove]"),
+            }
+        }
+        return crate::commands::sprint_abort::execute(&project_path, abort_args);
     }
 
     let sprint_number: u32 = args[0]
@@ -480,10 +568,59 @@ fn execute_sprint(state: &NexusState, args: &[&str]) -> Result<()> {
     crate::commands::sprint::execute(&project_path, sprint_number)
 }
 
+/// Execute the roadmap command
+fn execute_roadmap(state: &NexusState) -> Result<()> {
+    let project_path = state
+        .get_active_repo_path()
+        .ok_or_else(|| anyhow::anyhow!("No active project. Use 'use <project>' first."))?;
+
+    crate::commands::roadmap::execute(&project_path)
+}
+
+/// Execute the session command
+fn execute_session(state: &NexusState, args: &[&str]) -> Result<()> {
+    let project_path = state
+        .get_active_repo_path()
+        .ok_or_else(|| anyhow::anyhow!("No active project. Use 'use <project>' first."))?;
+
+    match args {
+        ["start"] => crate::commands::session::start(&project_path, None).map(|_| ()),
+        ["start", "--goal", goal @ ..] if !goal.is_empty() => {
+            crate::commands::session::start(&project_path, Some(&goal.join(" "))).map(|_| ())
+        }
+        ["note", text @ ..] if !text.is_empty() => {
+            crate::commands::session::note(&project_path, &text.join(" "))
+        }
+        ["end", options @ ..] => {
+            let mut end_args = crate::commands::session::EndArgs::default();
+            let mut options = options.iter();
+            while let Some(option) = options.next() {
+                match *option {
+                    "--passed" if end_args.passed.is_none() => end_args.passed = Some(true),
+                    "--failed" if end_args.passed.is_none() => end_args.passed = Some(false),
+                    "--next" => {
+                        let next: Vec<&str> = options.by_ref().copied().collect();
+                        if next.is_empty() {
+                            anyhow::bail!("Usage: session end [--passed|--failed] [--next <text>]");
+                        }
+                        end_args.next_task = Some(next.join(" "));
+                    }
+                    _ => anyhow::bail!("Usage: session end [--passed|--failed] [--next <text>]"),
+                }
+            }
+            crate::commands::session::end(&project_path, &end_args).map(|_| ())
+        }
+        _ => anyhow::bail!(
+            "Usage: session start [--goal <text>] | session note <text> | session end [--passed|--failed] [--next <text>]"
+        ),
+    }
+}
+
 /// Execute the catalyst command - AI-powered planning document generation
 fn execute_catalyst(state: &NexusState, args: &[&str]) -> Result<()> {
     use crate::catalyst::CatalystEngine;
     use crate::llm::{LlmClient, LlmProvider};
+    use crate::schema::planning::PlanningSchema;
 
     // Parse flags
     let mut show_reasoning = false;
@@ -574,6 +711,8 @@ fn execute_catalyst(state: &NexusState, args: &[&str]) -> Result<()> {
         _ => anyhow::bail!("Unknown LLM provider: {}", llm_config.provider),
     };
 
+    let schema = PlanningSchema::for_project(&config)?;
+
     // Create runtime for async operations
     let runtime = tokio::runtime::Runtime::new()?;
 
@@ -589,6 +728,7 @@ fn execute_catalyst(state: &NexusState, args: &[&str]) -> Result<()> {
             project_id.clone(),
             obsidian_path.clone(),
             llm_client,
+            schema,
         )?;
 
         // Execute subcommand
@@ -920,7 +1060,12 @@ fn execute_watch(
     let new_watcher = SentinelWatcher::new(brain_config.qdrant_url.clone())?;
 
     // Start watching
-    new_watcher.watch_project(project_id.clone(), repo_path.clone(), obsidian_path.clone())?;
+    new_watcher.watch_project(
+        project_id.clone(),
+        repo_path.clone(),
+        obsidian_path.clone(),
+        config.layout(),
+    )?;
 
     // Store watcher
     *watcher.lock().unwrap() = Some(new_watcher);
@@ -1520,11 +1665,12 @@ fn find_model_paths() -> (String, String) {
 ///   Execute the init command from REPL
 fn execute_init_command(state: &NexusState, args: &[&str]) -> Result<()> {
     if args.is_empty() {
-        anyhow::bail!("Usage: init <project-name> [--mode sprint|adhoc] [--project]");
+        anyhow::bail!("Usage: init <project-name> [--mode sprint|adhoc] [--locale en|sv] [--project]");
     }
 
     let project_name = args[0];
     let mut mode = "sprint";
+    let mut locale = "en";
     let mut is_full_project = false;
 
     // Parse flags
@@ -1539,6 +1685,14 @@ fn execute_init_command(state: &NexusState, args: &[&str]) -> Result<()> {
                     anyhow::bail!("--mode requires a value (sprint or adhoc)");
                 }
             }
+            "--locale" => {
+                if i + 1 < args.len() {
+                    locale = args[i + 1];
+                    i += 2;
+                } else {
+                    anyhow::bail!("--locale requires a value (en or sv)");
+                }
+            }
             "--project" => {
                 is_full_project = true;
                 i += 1;
@@ -1553,6 +1707,7 @@ fn execute_init_command(state: &NexusState, args: &[&str]) -> Result<()> {
     crate::commands::init::execute(
         project_name,
         mode,
+        locale,
         is_full_project,
         Some(&state.repos_root),
         Some(&state.obsidian_vault_root),
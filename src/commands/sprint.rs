@@ -1,11 +1,14 @@
 //! Sprint Command Implementation
 //!
-//! Creates a new sprint branch and scaffolds the Obsidian workspace.
+//! Creates a new sprint branch (checked out in place, or in its own worktree
+//! with `[git] worktrees = true`) and scaffolds the Obsidian workspace.
 
-use crate::config::{ActiveSprintConfig, NexusConfig};
-use crate::git_ops::create_sprint_branch;
-use crate::planning::parse_mvp_sprints;
+use crate::config::NexusConfig;
+use crate::git_ops::{create_sprint_branch, create_sprint_worktree, head_commit};
+use crate::mvp::{parse_mvp_sprints, pin_mvp_task_ids};
+use crate::roadmap::Roadmap;
 use crate::scaffolding::scaffold_sprint_folder;
+use crate::templating::SprintTemplates;
 use anyhow::{Context, Result, bail};
 use colored::Colorize;
 use std::fs;
@@ -32,8 +35,8 @@ pub fn execute(project_path: &Path, sprint_number: u32) -> Result<()> {
     let mut config = NexusConfig::from_toml(&config_content)
         .with_context(|| format!("Failed to parse config from: {}", config_path.display()))?;
 
-    // Resolve planning path (where Obsidian vault lives)
-    let planning_path = config.get_planning_path();
+    // Resolve the vault folders (where the Obsidian vault lives)
+    let layout = config.layout();
 
     println!(
         "{}",
@@ -43,14 +46,14 @@ pub fn execute(project_path: &Path, sprint_number: u32) -> Result<()> {
     );
     println!(
         "{}",
-        format!("📁 Planning path: {}", planning_path.display()).bright_black()
+        format!("📁 Planning path: {}", layout.root.display()).bright_black()
     );
     println!();
 
     // Check active sprint status - enforce sequencing
     if let Some(state) = &config.state
         && let Some(active_sprint) = &state.active_sprint
-        && active_sprint.status != "approved"
+        && !active_sprint.is_finished()
     {
         println!(
             "{}",
@@ -72,6 +75,10 @@ pub fn execute(project_path: &Path, sprint_number: u32) -> Result<()> {
             "{}",
             "You must complete and approve the current sprint before starting a new one.".white()
         );
+        println!(
+            "{}",
+            format!("Run: nexus sprint approve {}", project_path.display()).bright_cyan()
+        );
         bail!(
             "Cannot start Sprint {sprint_number} until previous sprint is approved. Please complete the current sprint first."
         );
@@ -79,9 +86,7 @@ pub fn execute(project_path: &Path, sprint_number: u32) -> Result<()> {
 
     // Parse sprint data from MVP breakdown
     println!("{}", "📖 Parsing MVP breakdown...".bright_blue());
-    let mvp_path = planning_path
-        .join(&config.structure.planning_dir)
-        .join("05-MVP-Breakdown.md");
+    let mvp_path = layout.mvp_breakdown();
 
     if !mvp_path.exists() {
         bail!(
@@ -117,13 +122,101 @@ pub fn execute(project_path: &Path, sprint_number: u32) -> Result<()> {
         )
         .green()
     );
+
+    // Load the workspace templates before touching git, so a broken template
+    // leaves no branch behind
+    let templates = SprintTemplates::load(&config).context("Failed to load sprint templates")?;
+
+    // Check the dependency graph - every prerequisite sprint must be approved or closed
+    let roadmap = Roadmap::new(&sprints);
+    let problems = roadmap.problems();
+    if !problems.is_empty() {
+        println!("{}", "❌ SPRINT BLOCKED: Invalid sprint dependencies".bright_red().bold());
+        println!();
+        for problem in &problems {
+            println!("{}", format!("  [{}] {}", problem.rule, problem.message).bright_yellow());
+        }
+        println!();
+        bail!(
+            "Cannot start Sprint {sprint_number}: fix the dependencies in {} first (see `nexus gate`).",
+            mvp_path.display()
+        );
+    }
+
+    let unapproved: Vec<u32> = roadmap
+        .prerequisites(sprint_number)
+        .into_iter()
+        .filter(|number| !config.state.as_ref().is_some_and(|state| state.is_finished(*number)))
+        .collect();
+    if !unapproved.is_empty() {
+        println!(
+            "{}",
+            "❌ SPRINT BLOCKED: Prerequisites not approved"
+                .bright_red()
+                .bold()
+        );
+        println!();
+        for number in &unapproved {
+            let title = roadmap.sprint(*number).map_or("", |sprint| sprint.title.as_str());
+            println!("{}", format!("  Sprint {number}: {title}").bright_yellow());
+        }
+        println!();
+        println!(
+            "{}",
+            "Sprint dependencies come from 05-MVP-Breakdown.md. Approve these sprints first,".white()
+        );
+        println!(
+            "{}",
+            "or record earlier approvals in nexus.toml: [state] approved_sprints = [0, 1]".white()
+        );
+        let list = unapproved
+            .iter()
+            .map(|number| format!("Sprint {number}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        bail!("Cannot start Sprint {sprint_number} until its prerequisites are approved: {list}");
+    }
+
+    let estimate = sprint_data
+        .total_estimate()
+        .map(|estimate| format!(", ~{estimate}"))
+        .unwrap_or_default();
+    println!(
+        "{}",
+        format!(
+            "    {} task(s){estimate}, {} exit criteria",
+            sprint_data.all_tasks().len(),
+            sprint_data.exit_criteria.len()
+        )
+        .bright_black()
+    );
     println!();
 
     // Create Git branch
-    println!("{}", "🌿 Creating git branch...".bright_blue());
     // Use project_path (the actual git repo) instead of config.get_repo_path() (Obsidian vault)
-    create_sprint_branch(project_path, sprint_data.number, &sprint_data.name)
-        .context("Failed to create sprint branch")?;
+    let git = config.git.clone().unwrap_or_default();
+    // Remembered for `nexus sprint abort`
+    let previous_branch = head_commit(project_path).ok().and_then(|head| head.branch);
+    let worktree = if git.worktrees {
+        println!("{}", "🌿 Creating git worktree...".bright_blue());
+        let worktree = create_sprint_worktree(
+            project_path,
+            sprint_data.number,
+            &sprint_data.name,
+            &git.worktree_root(project_path),
+        )
+        .context("Failed to create sprint worktree")?;
+        println!(
+            "{}",
+            format!("  ✓ Worktree created: {}", worktree.display()).green()
+        );
+        Some(worktree)
+    } else {
+        println!("{}", "🌿 Creating git branch...".bright_blue());
+        create_sprint_branch(project_path, sprint_data.number, &sprint_data.name)
+            .context("Failed to create sprint branch")?;
+        None
+    };
 
     println!(
         "{}",
@@ -138,31 +231,62 @@ pub fn execute(project_path: &Path, sprint_number: u32) -> Result<()> {
     // Scaffold Obsidian folders
     println!("{}", "📁 Scaffolding sprint workspace...".bright_blue());
 
-    scaffold_sprint_folder(&planning_path, sprint_data)
+    scaffold_sprint_folder(&layout, &templates, sprint_data)
         .context("Failed to scaffold sprint folder")?;
 
+    let sprint_folder = layout.sprint_folder(sprint_data);
+    let sprint_folder = layout.relative(&sprint_folder);
     println!(
         "{}",
-        format!(
-            "  ✓ Created: 00-MANAGEMENT/sprints/sprint-{}-{}/",
-            sprint_data.number, sprint_data.name
-        )
-        .green()
+        format!("  ✓ Created: {}/", sprint_folder.display()).green()
     );
-    println!("{}", "  ✓ Tasks.md".green());
-    println!("{}", "  ✓ Sprint-Context.md".green());
-    println!("{}", "  ✓ approvals/".green());
-    println!("{}", "  ✓ sessions/".green());
+    for file in &templates.files {
+        println!("{}", format!("  ✓ {file}").green());
+    }
+    for folder in &templates.folders {
+        println!("{}", format!("  ✓ {folder}/").green());
+    }
+    // Dependencies on positional ids keep pointing at the same tasks from now on
+    let pinned = pin_mvp_task_ids(&mvp_path).context("Failed to pin task ids in MVP breakdown")?;
+    if !pinned.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "  ✓ Pinned task ids in {}: {}",
+                layout.relative(&mvp_path).display(),
+                pinned.join(", ")
+            )
+            .green()
+        );
+    }
     println!();
 
     // Update nexus.toml with active sprint
     println!("{}", "💾 Updating nexus.toml...".bright_blue());
 
     if let Some(ref mut state) = config.state {
-        state.active_sprint = Some(ActiveSprintConfig {
-            current: format!("sprint-{}", sprint_data.number),
-            status: "in_progress".to_string(),
-        });
+        if let Some(previous) = state
+            .active_sprint
+            .as_ref()
+            .and_then(|active| active.worktree.as_ref())
+        {
+            println!(
+                "{}",
+                format!(
+                    "  ⚠ The previous sprint's worktree stays at {}; remove it with `git worktree remove` when done",
+                    previous.display()
+                )
+                .yellow()
+            );
+        }
+        state.start_sprint(sprint_data.number, chrono::Local::now().date_naive());
+        // Recorded relative to the project root, like [git] worktree_dir
+        if let Some(active) = state.active_sprint.as_mut() {
+            active.previous_branch = previous_branch;
+            active.worktree = worktree
+                .as_ref()
+                .map(|worktree| worktree.strip_prefix(project_path).unwrap_or(worktree).to_path_buf());
+        }
     }
 
     let updated_toml = config.to_toml().context("Failed to serialize config")?;
@@ -185,21 +309,23 @@ pub fn execute(project_path: &Path, sprint_number: u32) -> Result<()> {
     );
     println!();
     println!("{}", "Next steps:".bright_cyan());
+    if let Some(worktree) = &worktree {
+        println!(
+            "  0. Work in the sprint worktree: {}",
+            format!("cd {}", worktree.display()).bright_yellow()
+        );
+    }
     println!(
         "  1. Review tasks in: {}",
-        format!(
-            "00-MANAGEMENT/sprints/sprint-{}-{}/Tasks.md",
-            sprint_data.number, sprint_data.name
-        )
-        .bright_yellow()
+        sprint_folder.join("Tasks.md").display().to_string().bright_yellow()
     );
     println!(
         "  2. Check scope boundaries in: {}",
-        format!(
-            "00-MANAGEMENT/sprints/sprint-{}-{}/Sprint-Context.md",
-            sprint_data.number, sprint_data.name
-        )
-        .bright_yellow()
+        sprint_folder
+            .join("Sprint-Context.md")
+            .display()
+            .to_string()
+            .bright_yellow()
     );
     println!("  3. Start implementing the tasks!");
     println!();
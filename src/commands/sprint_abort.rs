@@ -0,0 +1,201 @@
+//! Sprint Abort (`nexus sprint abort`)
+//!
+//! Undoes `nexus sprint <path> <N>` for a sprint started by mistake or
+//! cancelled: switches back to the branch the sprint was started from (or
+//! removes its worktree), deletes the sprint branch, archives the scaffolded
+//! workspace and makes the previous sprint active again.
+
+use crate::config::NexusConfig;
+use crate::git_ops::{branch_commits_ahead, delete_branch, head_commit, remove_sprint_worktree, switch_branch};
+use crate::mvp::parse_mvp_sprints;
+use crate::scaffolding::archive_sprint_folder;
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use std::fs;
+use std::path::Path;
+
+/// Options of `nexus sprint abort`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AbortArgs {
+    /// Delete the sprint branch even when it has commits of its own
+    pub force: bool,
+    /// Delete the sprint workspace instead of archiving it
+    pub remove: bool,
+}
+
+/// Execute `nexus sprint abort`
+///
+/// # Flow:
+/// 1. Verify the active sprint is still in progress, and that its branch has no
+///    commits of its own unless forced
+/// 2. Remove the sprint worktree, or switch back to the previous branch
+/// 3. Delete the sprint branch
+/// 4. Archive (or remove) the sprint workspace
+/// 5. Restore the previous active sprint in nexus.toml
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory (the git repository)
+/// * `args` - Whether to discard sprint commits and to delete the workspace
+///
+/// # Returns
+/// * `Ok(())` - Sprint aborted
+/// * `Err` - If there is no sprint in progress, the branch has commits and
+///   `force` is not set, or uncommitted work blocks the git steps
+pub fn execute(project_path: &Path, args: AbortArgs) -> Result<()> {
+    println!("{}", "↩️  Sprint Abort".bright_cyan().bold());
+    println!();
+
+    let config_path = project_path.join("nexus.toml");
+    let config_content = fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read nexus.toml from: {}", project_path.display()))?;
+    let mut config = NexusConfig::from_toml(&config_content)
+        .with_context(|| format!("Failed to parse config from: {}", config_path.display()))?;
+
+    let Some(active) = config
+        .state
+        .as_ref()
+        .and_then(|state| state.active_sprint.clone())
+    else {
+        bail!("No active sprint to abort");
+    };
+    if active.is_finished() {
+        bail!(
+            "{} is already {}; only a sprint in progress can be aborted",
+            active.current,
+            active.status
+        );
+    }
+    let sprint_number = active
+        .number()
+        .with_context(|| format!("Invalid active sprint identifier: {}", active.current))?;
+
+    let layout = config.layout();
+    let mvp_path = layout.mvp_breakdown();
+    let sprints =
+        parse_mvp_sprints(&mvp_path).context("Failed to parse sprints from MVP breakdown")?;
+    let sprint = sprints
+        .iter()
+        .find(|sprint| sprint.number == sprint_number)
+        .with_context(|| format!("Sprint {sprint_number} not found in MVP breakdown"))?;
+
+    println!(
+        "{}",
+        format!("  Aborting Sprint {}: {}", sprint.number, sprint.title).bright_white()
+    );
+    println!();
+
+    // Check before changing anything
+    let branch = format!("feature/sprint-{}-{}", sprint.number, sprint.name);
+    let git = config.git.clone().unwrap_or_default();
+    let previous_branch = active
+        .previous_branch
+        .clone()
+        .unwrap_or_else(|| git.base_branch.clone());
+    let commits = branch_commits_ahead(project_path, &branch, &previous_branch).with_context(|| {
+        format!("Cannot tell what {branch} adds; set [git] base_branch to the branch it was started from")
+    })?;
+    if let Some(commits) = commits
+        && commits > 0
+        && !args.force
+    {
+        println!("{}", "❌ ABORT BLOCKED: Sprint branch has commits".bright_red().bold());
+        println!();
+        println!(
+            "{}",
+            format!("  {branch} is {commits} commit(s) ahead of {previous_branch}").bright_yellow()
+        );
+        println!();
+        println!(
+            "{}",
+            "Finish or close the sprint instead, or pass --force to delete the commits.".white()
+        );
+        bail!("Cannot abort {}: its branch has {commits} commit(s) of its own", active.current);
+    }
+
+    println!("{}", "🌿 Restoring git...".bright_blue());
+    if let Some(worktree) = &active.worktree {
+        let worktree = project_path.join(worktree);
+        remove_sprint_worktree(project_path, &worktree)
+            .context("Failed to remove sprint worktree")?;
+        println!(
+            "{}",
+            format!("  ✓ Removed worktree: {}", worktree.display()).green()
+        );
+    } else if head_commit(project_path).ok().and_then(|head| head.branch).as_deref() == Some(branch.as_str()) {
+        // nexus.toml was rewritten when the sprint started; it is restored below
+        switch_branch(project_path, &previous_branch, &["nexus.toml"])
+            .with_context(|| format!("Failed to switch back to '{previous_branch}'"))?;
+        println!(
+            "{}",
+            format!("  ✓ Switched back to {previous_branch}").green()
+        );
+    }
+    match commits {
+        Some(commits) => {
+            delete_branch(project_path, &branch)?;
+            let discarded = if commits > 0 {
+                format!(" ({commits} commit(s) discarded)")
+            } else {
+                String::new()
+            };
+            println!("{}", format!("  ✓ Deleted {branch}{discarded}").green());
+        }
+        None => println!(
+            "{}",
+            format!("  ⚠ Branch {branch} not found, nothing to delete").yellow()
+        ),
+    }
+    println!();
+
+    let step = if args.remove { "📁 Removing sprint workspace..." } else { "📁 Archiving sprint workspace..." };
+    println!("{}", step.bright_blue());
+    let folder = layout.sprint_folder(sprint);
+    let vault_folder = format!("{}/", layout.relative(&folder).display());
+    if !folder.exists() {
+        println!(
+            "{}",
+            format!("  ⚠ {vault_folder} not found, nothing to remove").yellow()
+        );
+    } else if args.remove {
+        fs::remove_dir_all(&folder)
+            .with_context(|| format!("Failed to remove {}", folder.display()))?;
+        println!("{}", format!("  ✓ Removed {vault_folder}").green());
+    } else {
+        let stamp = chrono::Local::now().format("%Y-%m-%d-%H%M%S").to_string();
+        if let Some(archived) = archive_sprint_folder(&layout, sprint, &stamp)? {
+            println!(
+                "{}",
+                format!("  ✓ Archived {vault_folder} to {}", layout.relative(&archived).display()).green()
+            );
+        }
+    }
+    println!();
+
+    println!("{}", "💾 Updating nexus.toml...".bright_blue());
+    let restored = config.state.as_mut().and_then(|state| {
+        state.abort_sprint();
+        state.active_sprint.as_ref().map(|previous| {
+            format!("{} ({})", previous.current, previous.status)
+        })
+    });
+    let updated_toml = config.to_toml().context("Failed to serialize config")?;
+    fs::write(&config_path, updated_toml)
+        .with_context(|| format!("Failed to write config to: {}", config_path.display()))?;
+    match restored {
+        Some(previous) => println!(
+            "{}",
+            format!("  ✓ Active sprint restored: {previous}").green()
+        ),
+        None => println!("{}", "  ✓ No active sprint".green()),
+    }
+    println!();
+
+    println!("{}", "✅ SPRINT ABORTED".bright_green().bold());
+    println!();
+    println!(
+        "{}",
+        format!("Sprint {} is no longer active", sprint.number).bright_white()
+    );
+
+    Ok(())
+}
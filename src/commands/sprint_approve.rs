@@ -0,0 +1,332 @@
+//! Sprint Approval (`nexus sprint approve`)
+//!
+//! Approves the active sprint once its work is verifiably done: every task in
+//! the sprint's `Tasks.md` is checked off and the Definition of Done from the
+//! MVP breakdown is confirmed. The approval is recorded as a note in the
+//! sprint's `approvals/` folder (approver, time, commit and task summary)
+//! before nexus.toml marks the sprint approved.
+
+use crate::config::NexusConfig;
+use crate::git_ops::{default_author, head_commit};
+use crate::mvp::{
+    MvpTask, SprintData, count_tasks, parse_definition_of_done, parse_mvp_sprints,
+    parse_task_list, render_tasks, sum_estimates, unfinished,
+};
+use crate::planning::validate_all_checkboxes_checked;
+use crate::schema::planning::PlanningSchema;
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Local};
+use colored::Colorize;
+use std::fs;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+/// Options of `nexus sprint approve`
+#[derive(Debug, Clone, Default)]
+pub struct ApproveOptions {
+    /// Who approves the sprint (defaults to git user.name)
+    pub approver: Option<String>,
+    /// Free-text note for the approval record
+    pub note: Option<String>,
+    /// Confirm the Definition of Done without prompting
+    pub yes: bool,
+}
+
+/// Execute `nexus sprint approve`
+///
+/// # Flow:
+/// 1. Find the active sprint in nexus.toml (it must be in progress)
+/// 2. Verify every checkbox in the sprint's `Tasks.md`
+/// 3. Confirm each Definition of Done item (prompted, or `--yes`)
+/// 4. Write the approval record to `approvals/`
+/// 5. Mark the sprint approved in nexus.toml
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory (where nexus.toml lives)
+/// * `options` - Approver, note and confirmation mode
+///
+/// # Returns
+/// * `Ok(PathBuf)` - Path of the approval record
+/// * `Err` - If the sprint is not ready for approval or a file cannot be written
+pub fn execute(project_path: &Path, options: &ApproveOptions) -> Result<PathBuf> {
+    println!("{}", "✅ Sprint Approval".bright_cyan().bold());
+    println!();
+
+    let config_path = project_path.join("nexus.toml");
+    let config_content = fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read nexus.toml from: {}", project_path.display()))?;
+    let mut config = NexusConfig::from_toml(&config_content)
+        .with_context(|| format!("Failed to parse config from: {}", config_path.display()))?;
+
+    let Some(active) = config
+        .state
+        .as_ref()
+        .and_then(|state| state.active_sprint.clone())
+    else {
+        bail!("No active sprint to approve. Start one with: nexus sprint <project_path> <N>");
+    };
+    if active.is_finished() {
+        bail!("{} is already {}", active.current, active.status);
+    }
+    let sprint_number = active
+        .number()
+        .with_context(|| format!("Invalid active sprint identifier: {}", active.current))?;
+
+    let layout = config.layout();
+    let mvp_path = layout.mvp_breakdown();
+    let sprints =
+        parse_mvp_sprints(&mvp_path).context("Failed to parse sprints from MVP breakdown")?;
+    let sprint = sprints
+        .iter()
+        .find(|sprint| sprint.number == sprint_number)
+        .with_context(|| format!("Sprint {sprint_number} not found in MVP breakdown"))?;
+    println!(
+        "{}",
+        format!("📋 Sprint {}: {}", sprint.number, sprint.title)
+            .bright_white()
+            .bold()
+    );
+    println!();
+
+    // Phase 1: Every task in the sprint workspace is done
+    println!("{}", "🔍 Checking tasks...".bright_blue());
+    let folder = layout.sprint_folder(sprint);
+    let tasks_path = folder.join("Tasks.md");
+    if !tasks_path.exists() {
+        bail!(
+            "Sprint workspace not found: {}\nRun `nexus sprint` to scaffold it first.",
+            tasks_path.display()
+        );
+    }
+    let tasks_content = fs::read_to_string(&tasks_path)
+        .with_context(|| format!("Failed to read {}", tasks_path.display()))?;
+    let tasks = parse_task_list(&tasks_content, &sprint.id_prefix());
+    if !validate_all_checkboxes_checked(&tasks_path)? {
+        println!("{}", "❌ APPROVAL BLOCKED: Unfinished tasks".bright_red().bold());
+        println!();
+        for line in render_tasks(&unfinished(&tasks)).lines() {
+            println!("  {}", line.bright_yellow());
+        }
+        println!();
+        bail!(
+            "Cannot approve Sprint {sprint_number}: check off every task in {} first",
+            tasks_path.display()
+        );
+    }
+    let task_count = count_tasks(&tasks);
+    println!("{}", format!("  ✓ All {task_count} task(s) done").green());
+    println!();
+
+    // Phase 2: Definition of Done
+    println!("{}", "📐 Definition of Done...".bright_blue());
+    let schema = PlanningSchema::for_project(&config)?;
+    let mvp_content = fs::read_to_string(&mvp_path)
+        .with_context(|| format!("Failed to read {}", mvp_path.display()))?;
+    let definition_of_done = parse_definition_of_done(&mvp_content, &schema.header_matcher());
+    if definition_of_done.is_empty() {
+        println!(
+            "{}",
+            "  ⚠ No Definition of Done in the MVP breakdown, nothing to confirm".yellow()
+        );
+    } else {
+        confirm_definition_of_done(&definition_of_done, options.yes)?;
+    }
+    println!();
+
+    // Phase 3: Approval record
+    println!("{}", "📝 Recording approval...".bright_blue());
+    let approver = match &options.approver {
+        Some(approver) => approver.clone(),
+        None => default_author()
+            .context("Could not determine the approver; pass --approver or set git user.name")?,
+    };
+    // In worktree mode the sprint branch is checked out in the worktree, not the project
+    let head = head_commit(&active.checkout_path(project_path))
+        .context("Failed to read the commit being approved")?;
+    let now = Local::now();
+    let record = ApprovalRecord {
+        sprint,
+        approver: &approver,
+        approved_at: now,
+        commit: &head.sha,
+        branch: head.branch.as_deref(),
+        tasks: &tasks,
+        definition_of_done: &definition_of_done,
+        note: options.note.as_deref(),
+    };
+    let approvals_dir = folder.join("approvals");
+    fs::create_dir_all(&approvals_dir)
+        .with_context(|| format!("Failed to create {}", approvals_dir.display()))?;
+    let record_path = approvals_dir.join(format!("{}-approval.md", now.format("%Y-%m-%d-%H%M%S")));
+    fs::write(&record_path, record.to_markdown())
+        .with_context(|| format!("Failed to write {}", record_path.display()))?;
+    println!(
+        "{}",
+        format!(
+            "  ✓ {}",
+            layout.relative(&record_path).display()
+        )
+        .green()
+    );
+    println!();
+
+    // Phase 4: Only now mark the sprint approved
+    println!("{}", "💾 Updating nexus.toml...".bright_blue());
+    if let Some(active) = config
+        .state
+        .as_mut()
+        .and_then(|state| state.active_sprint.as_mut())
+    {
+        active.status = "approved".to_string();
+        active.approved = Some(now.date_naive());
+    }
+    let updated_toml = config.to_toml().context("Failed to serialize config")?;
+    fs::write(&config_path, updated_toml)
+        .with_context(|| format!("Failed to write config to: {}", config_path.display()))?;
+    println!("{}", "  ✓ Sprint marked approved".green());
+    println!();
+
+    println!("{}", "✅ SPRINT APPROVED".bright_green().bold());
+    println!();
+    println!(
+        "{}",
+        format!(
+            "Sprint {} approved by {} at {}",
+            sprint.number,
+            approver,
+            &head.sha[..head.sha.len().min(7)]
+        )
+        .bright_white()
+    );
+
+    Ok(record_path)
+}
+
+/// Confirm each Definition of Done item, or accept them all with `--yes`
+fn confirm_definition_of_done(items: &[String], yes: bool) -> Result<()> {
+    if yes {
+        for item in items {
+            println!("{}", format!("  ✓ {item}").green());
+        }
+        return Ok(());
+    }
+    if !std::io::stdin().is_terminal() {
+        bail!("Confirm the Definition of Done interactively, or pass --yes");
+    }
+
+    for item in items {
+        let confirmed = dialoguer::Confirm::new()
+            .with_prompt(format!("  {item}?"))
+            .default(false)
+            .show_default(true)
+            .interact()
+            .context("Failed to get user confirmation")?;
+        if !confirmed {
+            bail!("Definition of Done not met: {item}");
+        }
+    }
+    Ok(())
+}
+
+/// Contents of an approval record in `approvals/`
+struct ApprovalRecord<'a> {
+    sprint: &'a SprintData,
+    approver: &'a str,
+    approved_at: DateTime<Local>,
+    commit: &'a str,
+    branch: Option<&'a str>,
+    /// Tasks as checked off in `Tasks.md`
+    tasks: &'a [MvpTask],
+    definition_of_done: &'a [String],
+    note: Option<&'a str>,
+}
+
+impl ApprovalRecord<'_> {
+    /// Markdown note with frontmatter, so the vault can query approvals
+    fn to_markdown(&self) -> String {
+        let approved_at = self.approved_at.format("%Y-%m-%dT%H:%M:%S%:z");
+        let branch = self
+            .branch
+            .map(|branch| format!(" ({branch})"))
+            .unwrap_or_default();
+        let estimate = sum_estimates(self.tasks)
+            .map(|estimate| format!(", ~{estimate} estimated"))
+            .unwrap_or_default();
+
+        let mut record = format!(
+            "---\nsprint: {}\napproved_by: \"{}\"\napproved_at: {approved_at}\ncommit: {}\n---\n\n",
+            self.sprint.number,
+            self.approver.replace('"', "'"),
+            self.commit
+        );
+        record.push_str(&format!(
+            "# Sprint {} Approval: {}\n\n",
+            self.sprint.number, self.sprint.title
+        ));
+        record.push_str(&format!("**Approved by:** {}\n", self.approver));
+        record.push_str(&format!("**Approved at:** {approved_at}\n"));
+        record.push_str(&format!("**Commit:** `{}`{branch}\n\n", self.commit));
+
+        record.push_str("## Tasks\n\n");
+        record.push_str(&format!(
+            "{} task(s) done{estimate}\n\n",
+            count_tasks(self.tasks)
+        ));
+        record.push_str(&render_tasks(self.tasks));
+
+        if !self.sprint.exit_criteria.is_empty() {
+            record.push_str("\n## Exit Criteria\n\n");
+            for criterion in &self.sprint.exit_criteria {
+                record.push_str(&format!("- {criterion}\n"));
+            }
+        }
+        if !self.definition_of_done.is_empty() {
+            record.push_str("\n## Definition of Done\n\n");
+            for item in self.definition_of_done {
+                record.push_str(&format!("- [x] {item}\n"));
+            }
+        }
+        if let Some(note) = self.note {
+            record.push_str(&format!("\n## Notes\n\n{note}\n"));
+        }
+        record
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approval_record_markdown() {
+        let sprints = crate::mvp::parse_sprints(
+            "## Sprint 2: Sync\n- [ ] Engine ~4h\n  - [ ] Retry ~1h\n**Exit criteria:** Devices converge\n",
+        );
+        let tasks = parse_task_list("- [x] Engine 🆔 s2-1 ~4h\n  - [x] Retry 🆔 s2-1-1 ~1h\n", "s2");
+        let approved_at = DateTime::parse_from_rfc3339("2026-10-17T14:03:12+02:00")
+            .unwrap()
+            .with_timezone(&Local);
+        let record = ApprovalRecord {
+            sprint: &sprints[0],
+            approver: "Dana Lee",
+            approved_at,
+            commit: "0123456789abcdef",
+            branch: Some("feature/sprint-2-sync"),
+            tasks: &tasks,
+            definition_of_done: &["Builds without errors".to_string()],
+            note: Some("Demoed to the team"),
+        }
+        .to_markdown();
+
+        let frontmatter = crate::frontmatter::Frontmatter::parse(&record);
+        assert_eq!(frontmatter.text("sprint"), Some("2"));
+        assert_eq!(frontmatter.text("approved_by"), Some("Dana Lee"));
+        assert_eq!(frontmatter.text("commit"), Some("0123456789abcdef"));
+        assert!(record.contains("# Sprint 2 Approval: Sync\n"));
+        assert!(record.contains("**Commit:** `0123456789abcdef` (feature/sprint-2-sync)\n"));
+        assert!(record.contains("2 task(s) done, ~4h estimated\n\n- [x] Engine 🆔 s2-1 ~4h\n  - [x] Retry"));
+        assert!(record.contains("## Exit Criteria\n\n- Devices converge\n"));
+        assert!(record.contains("## Definition of Done\n\n- [x] Builds without errors\n"));
+        assert!(record.ends_with("## Notes\n\nDemoed to the team\n"));
+    }
+}
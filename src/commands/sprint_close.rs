@@ -0,0 +1,416 @@
+//! Sprint Close-Out (`nexus sprint close`)
+//!
+//! Ends the active sprint even when some of its tasks are still open. A
+//! close-out report (`Close-Out.md`) in the sprint folder lists what was done,
+//! what was not, the blockers noted in `Tasks.md` and how long the sprint ran.
+//! Unfinished tasks move to the next sprint's `Tasks.md`, or to the backlog
+//! note, tagged `#carried-over` with a link back to the report.
+
+use crate::config::NexusConfig;
+use crate::mvp::{
+    MvpTask, SprintData, carry_over, count_done, count_tasks, parse_mvp_sprints, parse_task_list,
+    render_tasks,
+};
+use crate::planning::{HeaderMatcher, find_section};
+use crate::roadmap::Roadmap;
+use crate::scaffolding::{carry_over_into_backlog, carry_over_into_sprint};
+use crate::templating::SprintTemplates;
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the close-out report in the sprint folder
+pub const CLOSE_OUT_FILE: &str = "Close-Out.md";
+
+/// Where the unfinished tasks of a closed sprint go
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CarryOverTarget {
+    /// The next unfinished sprint in roadmap order (the backlog when there is none)
+    #[default]
+    NextSprint,
+    /// A sprint chosen by number
+    Sprint(u32),
+    /// The backlog note (00-MANAGEMENT/Backlog.md)
+    Backlog,
+}
+
+/// Execute `nexus sprint close`
+///
+/// # Flow:
+/// 1. Find the active sprint in nexus.toml and its `Tasks.md`
+/// 2. Carry the unfinished tasks over to the target sprint or the backlog
+/// 3. Write the close-out report
+/// 4. Mark the sprint closed in nexus.toml, so the next sprint can start
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory (where nexus.toml lives)
+/// * `target` - Where unfinished tasks go
+///
+/// # Returns
+/// * `Ok(PathBuf)` - Path of the close-out report
+/// * `Err` - If there is no sprint to close or a file cannot be written
+pub fn execute(project_path: &Path, target: CarryOverTarget) -> Result<PathBuf> {
+    println!("{}", "🏁 Sprint Close-Out".bright_cyan().bold());
+    println!();
+
+    let config_path = project_path.join("nexus.toml");
+    let config_content = fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read nexus.toml from: {}", project_path.display()))?;
+    let mut config = NexusConfig::from_toml(&config_content)
+        .with_context(|| format!("Failed to parse config from: {}", config_path.display()))?;
+
+    let Some(active) = config
+        .state
+        .as_ref()
+        .and_then(|state| state.active_sprint.clone())
+    else {
+        bail!("No active sprint to close. Start one with: nexus sprint <project_path> <N>");
+    };
+    if active.status == "closed" {
+        bail!("{} is already closed", active.current);
+    }
+    let sprint_number = active
+        .number()
+        .with_context(|| format!("Invalid active sprint identifier: {}", active.current))?;
+
+    let layout = config.layout();
+    let mvp_path = layout.mvp_breakdown();
+    let sprints =
+        parse_mvp_sprints(&mvp_path).context("Failed to parse sprints from MVP breakdown")?;
+    let sprint = sprints
+        .iter()
+        .find(|sprint| sprint.number == sprint_number)
+        .with_context(|| format!("Sprint {sprint_number} not found in MVP breakdown"))?;
+    println!(
+        "{}",
+        format!("📋 Sprint {}: {}", sprint.number, sprint.title)
+            .bright_white()
+            .bold()
+    );
+    println!();
+
+    let folder = layout.sprint_folder(sprint);
+    let tasks_path = folder.join("Tasks.md");
+    let tasks_content = fs::read_to_string(&tasks_path).with_context(|| {
+        format!(
+            "Failed to read {}\nRun `nexus sprint` to scaffold the sprint workspace first.",
+            tasks_path.display()
+        )
+    })?;
+    let tasks = parse_task_list(&tasks_content, &sprint.id_prefix());
+    let carried = carry_over(&tasks, sprint_number);
+
+    // Phase 1: Carry unfinished tasks over
+    let report_link = format!(
+        "[[{}/{}|Sprint {}: {}]]",
+        vault_relative(&layout.root, &folder),
+        CLOSE_OUT_FILE.trim_end_matches(".md"),
+        sprint.number,
+        sprint.title
+    );
+    let destination = if carried.is_empty() {
+        println!("{}", "✓ All tasks done, nothing to carry over".green());
+        None
+    } else {
+        println!("{}", "📦 Carrying over unfinished tasks...".bright_blue());
+        let destination = match resolve_target(target, sprint_number, &sprints, &config)? {
+            Some(next) => {
+                let templates =
+                    SprintTemplates::load(&config).context("Failed to load sprint templates")?;
+                let path = carry_over_into_sprint(&layout, &templates, next, &report_link, &carried)?;
+                Destination {
+                    name: format!("Sprint {}: {}", next.number, next.title),
+                    link: format!("[[{}|Sprint {}: {}]]", note_link(&layout.root, &path), next.number, next.title),
+                    key: format!("sprint-{}", next.number),
+                }
+            }
+            None => {
+                let path = carry_over_into_backlog(&layout, sprint, &report_link, &carried)?;
+                Destination {
+                    name: "the backlog".to_string(),
+                    link: format!("[[{}|Backlog]]", note_link(&layout.root, &path)),
+                    key: "backlog".to_string(),
+                }
+            }
+        };
+        println!(
+            "{}",
+            format!(
+                "  ✓ {} task(s) carried over to {}",
+                count_tasks(&carried),
+                destination.name
+            )
+            .green()
+        );
+        Some(destination)
+    };
+    println!();
+
+    // Phase 2: Close-out report
+    println!("{}", "📝 Writing close-out report...".bright_blue());
+    let today = chrono::Local::now().date_naive();
+    let blockers = parse_blockers(&tasks_content);
+    let report = CloseOutReport {
+        sprint,
+        started: active.started,
+        closed: today,
+        tasks: &tasks,
+        carried: &carried,
+        destination: destination.as_ref(),
+        blockers: &blockers,
+    };
+    let report_path = folder.join(CLOSE_OUT_FILE);
+    fs::write(&report_path, report.to_markdown())
+        .with_context(|| format!("Failed to write {}", report_path.display()))?;
+    println!(
+        "{}",
+        format!("  ✓ {}", note_link(&layout.root, &report_path)).green()
+    );
+    println!();
+
+    // Phase 3: Mark the sprint closed
+    println!("{}", "💾 Updating nexus.toml...".bright_blue());
+    if let Some(active) = config
+        .state
+        .as_mut()
+        .and_then(|state| state.active_sprint.as_mut())
+    {
+        active.status = "closed".to_string();
+        active.closed = Some(today);
+    }
+    let updated_toml = config.to_toml().context("Failed to serialize config")?;
+    fs::write(&config_path, updated_toml)
+        .with_context(|| format!("Failed to write config to: {}", config_path.display()))?;
+    println!("{}", "  ✓ Sprint marked closed".green());
+    println!();
+
+    println!("{}", "✅ SPRINT CLOSED".bright_green().bold());
+    println!();
+    println!(
+        "{}",
+        format!(
+            "{} of {} task(s) done, {} carried over",
+            count_done(&tasks),
+            count_tasks(&tasks),
+            count_tasks(&carried)
+        )
+        .bright_white()
+    );
+
+    Ok(report_path)
+}
+
+/// Sprint that receives the unfinished tasks, or `None` for the backlog
+fn resolve_target<'a>(
+    target: CarryOverTarget,
+    current: u32,
+    sprints: &'a [SprintData],
+    config: &NexusConfig,
+) -> Result<Option<&'a SprintData>> {
+    let find = |number: u32| sprints.iter().find(|sprint| sprint.number == number);
+    match target {
+        CarryOverTarget::Backlog => Ok(None),
+        CarryOverTarget::Sprint(number) if number == current => {
+            bail!("Cannot carry tasks over into the sprint being closed (Sprint {number})")
+        }
+        CarryOverTarget::Sprint(number) => find(number)
+            .map(Some)
+            .with_context(|| format!("Sprint {number} not found in MVP breakdown")),
+        CarryOverTarget::NextSprint => {
+            let finished = |number: u32| {
+                config
+                    .state
+                    .as_ref()
+                    .is_some_and(|state| state.is_finished(number))
+            };
+            let order = Roadmap::new(sprints).sprint_order();
+            Ok(order
+                .iter()
+                .skip_while(|&&number| number != current)
+                .skip(1)
+                .find(|&&number| !finished(number))
+                .and_then(|&number| find(number)))
+        }
+    }
+}
+
+/// Items listed under `## Blockers` in `Tasks.md`, without the placeholder
+fn parse_blockers(tasks_content: &str) -> Vec<String> {
+    let Some((range, _)) = find_section(tasks_content, "Blockers", &HeaderMatcher::default()) else {
+        return Vec::new();
+    };
+    tasks_content[range]
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.trim().strip_prefix(['-', '*']))
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty() && item != "(none yet)")
+        .collect()
+}
+
+/// Vault-relative path of a note, without `.md`, for wikilinks
+fn note_link(planning_path: &Path, path: &Path) -> String {
+    vault_relative(planning_path, path)
+        .trim_end_matches(".md")
+        .to_string()
+}
+
+fn vault_relative(planning_path: &Path, path: &Path) -> String {
+    path.strip_prefix(planning_path)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Done tasks as a flat checklist
+fn done_lines(tasks: &[MvpTask], lines: &mut Vec<String>) {
+    for task in tasks {
+        if task.done {
+            lines.push(task.to_markdown());
+        }
+        done_lines(&task.subtasks, lines);
+    }
+}
+
+/// Where carried-over tasks went
+struct Destination {
+    name: String,
+    link: String,
+    /// `sprint-N` or `backlog`, for the report's frontmatter
+    key: String,
+}
+
+/// Contents of `Close-Out.md`
+struct CloseOutReport<'a> {
+    sprint: &'a SprintData,
+    started: Option<NaiveDate>,
+    closed: NaiveDate,
+    tasks: &'a [MvpTask],
+    /// Unfinished tasks, as carried over
+    carried: &'a [MvpTask],
+    destination: Option<&'a Destination>,
+    blockers: &'a [String],
+}
+
+impl CloseOutReport<'_> {
+    /// Calendar days from start to close, both included
+    fn duration_days(&self) -> Option<i64> {
+        self.started
+            .map(|started| (self.closed - started).num_days() + 1)
+    }
+
+    /// Markdown note with frontmatter, so velocity can be queried across sprints
+    fn to_markdown(&self) -> String {
+        let done = count_done(self.tasks);
+        let total = count_tasks(self.tasks);
+        let carried = count_tasks(self.carried);
+
+        let mut report = format!("---\nsprint: {}\nclosed: {}\n", self.sprint.number, self.closed);
+        if let Some(started) = self.started {
+            report.push_str(&format!("started: {started}\n"));
+        }
+        if let Some(days) = self.duration_days() {
+            report.push_str(&format!("duration_days: {days}\n"));
+        }
+        report.push_str(&format!(
+            "tasks_total: {total}\ntasks_done: {done}\ntasks_carried_over: {carried}\n"
+        ));
+        if let Some(destination) = self.destination {
+            report.push_str(&format!("carried_to: {}\n", destination.key));
+        }
+        report.push_str("---\n\n");
+
+        report.push_str(&format!(
+            "# Sprint {} Close-Out: {}\n\n",
+            self.sprint.number, self.sprint.title
+        ));
+        let started = self
+            .started
+            .map_or_else(|| "(not recorded)".to_string(), |started| started.to_string());
+        report.push_str(&format!("**Started:** {started}\n"));
+        report.push_str(&format!("**Closed:** {}\n", self.closed));
+        if let Some(days) = self.duration_days() {
+            report.push_str(&format!("**Duration:** {days} day(s)\n"));
+        }
+
+        report.push_str(&format!("\n## Done ({done} of {total})\n\n"));
+        let mut lines = Vec::new();
+        done_lines(self.tasks, &mut lines);
+        if lines.is_empty() {
+            report.push_str("- (none)\n");
+        } else {
+            report.push_str(&lines.join("\n"));
+            report.push('\n');
+        }
+
+        report.push_str(&format!("\n## Not Done ({carried})\n\n"));
+        if let Some(destination) = self.destination {
+            report.push_str(&format!("Carried over to {}\n", destination.link));
+            report.push_str(&render_tasks(self.carried));
+            report.push('\n');
+        } else {
+            report.push_str("- (none)\n");
+        }
+
+        report.push_str("\n## Blockers\n\n");
+        if self.blockers.is_empty() {
+            report.push_str("- (none)\n");
+        } else {
+            for blocker in self.blockers {
+                report.push_str(&format!("- {blocker}\n"));
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_blockers() {
+        let content = "# Tasks\n\n## Blockers\n- (none yet)\n- Waiting on API keys\n* Design review\n";
+        assert_eq!(parse_blockers(content), vec!["Waiting on API keys", "Design review"]);
+        assert!(parse_blockers("## Blockers\n- (none yet)\n").is_empty());
+    }
+
+    #[test]
+    fn test_close_out_report_markdown() {
+        let sprints = crate::mvp::parse_sprints("## Sprint 3: Polish\n- [ ] Placeholder\n");
+        let tasks = parse_task_list(
+            "- [x] Engine 🆔 s3-1\n- [ ] Status UI 🆔 s3-2\n  - [x] Layout 🆔 s3-2-1\n",
+            "s3",
+        );
+        let carried = carry_over(&tasks, 3);
+        let destination = Destination {
+            name: "Sprint 4: Launch".to_string(),
+            link: "[[sprint-4-launch/Tasks|Sprint 4: Launch]]".to_string(),
+            key: "sprint-4".to_string(),
+        };
+        let report = CloseOutReport {
+            sprint: &sprints[0],
+            started: NaiveDate::from_ymd_opt(2026, 10, 1),
+            closed: NaiveDate::from_ymd_opt(2026, 10, 14).unwrap(),
+            tasks: &tasks,
+            carried: &carried,
+            destination: Some(&destination),
+            blockers: &["Waiting on API keys".to_string()],
+        }
+        .to_markdown();
+
+        let frontmatter = crate::frontmatter::Frontmatter::parse(&report);
+        assert_eq!(frontmatter.text("duration_days"), Some("14"));
+        assert_eq!(frontmatter.text("tasks_done"), Some("2"));
+        assert_eq!(frontmatter.text("tasks_carried_over"), Some("1"));
+        assert_eq!(frontmatter.text("carried_to"), Some("sprint-4"));
+        assert!(report.contains("## Done (2 of 3)\n\n- [x] Engine 🆔 s3-1\n- [x] Layout 🆔 s3-2-1\n"));
+        assert!(report.contains(
+            "Carried over to [[sprint-4-launch/Tasks|Sprint 4: Launch]]\n\
+             - [ ] Status UI 🆔 s3-2 #carried-over [carried-from:: sprint-3]\n"
+        ));
+        assert!(report.ends_with("## Blockers\n\n- Waiting on API keys\n"));
+    }
+}
@@ -0,0 +1,201 @@
+//! Sprint Finish (`nexus sprint finish`)
+//!
+//! Finishes an approved sprint in git: merges its `feature/sprint-N-name`
+//! branch into the base branch, tags the result `sprint-N` with the task
+//! summary as the tag message, and optionally deletes the branch. A sprint
+//! worktree (`[git] worktrees = true`) is removed once the branch is merged.
+
+use crate::config::{GitConfig, NexusConfig};
+use crate::git_ops::{FinishOptions, MergeKind, MergeStrategy, finish_sprint_branch};
+use crate::mvp::{
+    MvpTask, SprintData, count_done, count_tasks, parse_mvp_sprints, parse_task_list, render_tasks,
+    sum_estimates,
+};
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use std::fs;
+use std::path::Path;
+
+/// Options of `nexus sprint finish`; unset values come from `[git]` in nexus.toml
+#[derive(Debug, Clone, Default)]
+pub struct FinishArgs {
+    /// Branch to merge into
+    pub base: Option<String>,
+    pub strategy: Option<MergeStrategy>,
+    /// Delete the sprint branch after merging
+    pub delete_branch: bool,
+}
+
+/// Execute `nexus sprint finish`
+///
+/// # Flow:
+/// 1. Verify the active sprint is approved
+/// 2. Merge its branch into the base branch (clean working tree required)
+/// 3. Tag the merge `sprint-N` with the task summary
+/// 4. Delete the sprint branch when asked to
+///
+/// # Arguments
+/// * `project_path` - Path to the project directory (the git repository)
+/// * `args` - Base branch, merge strategy and branch cleanup overrides
+///
+/// # Returns
+/// * `Ok(())` - Sprint branch merged and tagged
+/// * `Err` - If the sprint is not approved or the git operation fails
+pub fn execute(project_path: &Path, args: &FinishArgs) -> Result<()> {
+    println!("{}", "🏁 Sprint Finish".bright_cyan().bold());
+    println!();
+
+    let config_path = project_path.join("nexus.toml");
+    let config_content = fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read nexus.toml from: {}", project_path.display()))?;
+    let config = NexusConfig::from_toml(&config_content)
+        .with_context(|| format!("Failed to parse config from: {}", config_path.display()))?;
+
+    let Some(active) = config
+        .state
+        .as_ref()
+        .and_then(|state| state.active_sprint.as_ref())
+    else {
+        bail!("No active sprint to finish. Start one with: nexus sprint <project_path> <N>");
+    };
+    if active.status != "approved" {
+        println!("{}", "❌ FINISH BLOCKED: Sprint not approved".bright_red().bold());
+        println!();
+        println!("{}", format!("  Current active sprint: {}", active.current).bright_yellow());
+        println!("{}", format!("  Status: {}", active.status).bright_yellow());
+        println!();
+        println!(
+            "{}",
+            format!("Run: nexus sprint approve {}", project_path.display()).bright_cyan()
+        );
+        bail!("Cannot finish {} until it is approved", active.current);
+    }
+    let sprint_number = active
+        .number()
+        .with_context(|| format!("Invalid active sprint identifier: {}", active.current))?;
+
+    let layout = config.layout();
+    let mvp_path = layout.mvp_breakdown();
+    let sprints =
+        parse_mvp_sprints(&mvp_path).context("Failed to parse sprints from MVP breakdown")?;
+    let sprint = sprints
+        .iter()
+        .find(|sprint| sprint.number == sprint_number)
+        .with_context(|| format!("Sprint {sprint_number} not found in MVP breakdown"))?;
+
+    // Tasks as checked off in the sprint workspace, else as planned
+    let tasks_path = layout.sprint_folder(sprint).join("Tasks.md");
+    let tasks = match fs::read_to_string(&tasks_path) {
+        Ok(content) => parse_task_list(&content, &sprint.id_prefix()),
+        Err(_) => sprint.tasks.clone(),
+    };
+
+    let git = config.git.clone().unwrap_or_default();
+    let mut options = finish_options(&git, args, tag_message(sprint, &tasks));
+    options.worktree = active.worktree.as_ref().map(|worktree| project_path.join(worktree));
+    println!(
+        "{}",
+        format!(
+            "🔀 Merging feature/sprint-{}-{} into {}...",
+            sprint.number, sprint.name, options.base_branch
+        )
+        .bright_blue()
+    );
+    let outcome = finish_sprint_branch(project_path, sprint.number, &sprint.name, &options)?;
+
+    let merged = match outcome.merge {
+        MergeKind::UpToDate => "already merged".to_string(),
+        MergeKind::FastForward => "fast-forwarded".to_string(),
+        MergeKind::MergeCommit => "merge commit".to_string(),
+    };
+    let short = &outcome.commit[..outcome.commit.len().min(7)];
+    println!("{}", format!("  ✓ {} ({merged}, {short})", options.base_branch).green());
+    println!("{}", format!("  ✓ Tagged {}", outcome.tag).green());
+    if outcome.worktree_removed {
+        println!("{}", "  ✓ Removed sprint worktree".green());
+    }
+    if outcome.branch_deleted {
+        println!("{}", format!("  ✓ Deleted {}", outcome.branch).green());
+    }
+    println!();
+
+    println!("{}", "✅ SPRINT FINISHED".bright_green().bold());
+    println!();
+    println!(
+        "{}",
+        format!(
+            "Sprint {} is on {} as {}",
+            sprint.number, options.base_branch, outcome.tag
+        )
+        .bright_white()
+    );
+
+    Ok(())
+}
+
+/// Command-line overrides on top of `[git]`
+fn finish_options(git: &GitConfig, args: &FinishArgs, tag_message: String) -> FinishOptions {
+    FinishOptions {
+        base_branch: args.base.clone().unwrap_or_else(|| git.base_branch.clone()),
+        strategy: args.strategy.unwrap_or(git.merge_strategy),
+        tag_message,
+        delete_branch: args.delete_branch || git.delete_branch,
+        worktree: None,
+        // nexus sprint and nexus sprint approve rewrite it without committing
+        keep_changes: vec!["nexus.toml".to_string()],
+    }
+}
+
+/// Tag message: the sprint title, then a summary of its tasks
+fn tag_message(sprint: &SprintData, tasks: &[MvpTask]) -> String {
+    let (done, total) = (count_done(tasks), count_tasks(tasks));
+    let estimate = sum_estimates(tasks)
+        .map(|estimate| format!(", ~{estimate} estimated"))
+        .unwrap_or_default();
+    format!(
+        "Sprint {}: {}\n\n{done} of {total} task(s) done{estimate}\n\n{}\n",
+        sprint.number,
+        sprint.title,
+        render_tasks(tasks)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_message() {
+        let sprint = crate::mvp::parse_sprints("## Sprint 2: Sync\n- [ ] Engine ~4h\n").remove(0);
+        let tasks = parse_task_list("- [x] Engine 🆔 s2-1 ~4h\n- [x] Retry 🆔 s2-2 ~1h\n", "s2");
+        assert_eq!(
+            tag_message(&sprint, &tasks),
+            "Sprint 2: Sync\n\n2 of 2 task(s) done, ~5h estimated\n\n\
+             - [x] Engine 🆔 s2-1 ~4h\n- [x] Retry 🆔 s2-2 ~1h\n"
+        );
+    }
+
+    #[test]
+    fn test_finish_options_override_config() {
+        let git = GitConfig {
+            base_branch: "develop".to_string(),
+            merge_strategy: MergeStrategy::FastForward,
+            delete_branch: true,
+            ..GitConfig::default()
+        };
+        let options = finish_options(&git, &FinishArgs::default(), String::new());
+        assert_eq!(options.base_branch, "develop");
+        assert_eq!(options.strategy, MergeStrategy::FastForward);
+        assert!(options.delete_branch);
+
+        let args = FinishArgs {
+            base: Some("main".to_string()),
+            strategy: Some(MergeStrategy::MergeCommit),
+            delete_branch: false,
+        };
+        let options = finish_options(&GitConfig::default(), &args, String::new());
+        assert_eq!(options.base_branch, "main");
+        assert_eq!(options.strategy, MergeStrategy::MergeCommit);
+        assert!(!options.delete_branch);
+    }
+}
@@ -11,6 +11,7 @@ use std::path::Path;
 use crate::commands;
 use crate::config::NexusConfig;
 use crate::planning::parse_planning_documents;
+use crate::schema::planning::PlanningSchema;
 use crate::templating::generate_claude_md;
 
 /// Execute the unlock command
@@ -64,8 +65,8 @@ pub fn execute(project_path: &Path) -> Result<()> {
     println!();
 
     // Phase 3: Resolve Planning Path
-    let vault_root = config.get_planning_path();
-    let planning_dir = vault_root.join(&config.structure.planning_dir);
+    let layout = config.layout();
+    let planning_dir = &layout.planning_dir;
 
     if !planning_dir.exists() {
         anyhow::bail!(
@@ -76,8 +77,10 @@ pub fn execute(project_path: &Path) -> Result<()> {
 
     // Phase 4: Parse Planning Documents
     println!("{}", "🔧 Parsing planning documents...".cyan().bold());
-    let context =
-        parse_planning_documents(&planning_dir).context("Failed to parse planning documents")?;
+    let schema = PlanningSchema::for_project(&config)?;
+    let vault = crate::links::VaultIndex::build(&layout.root)?;
+    let context = parse_planning_documents(planning_dir, &schema, Some(&vault))
+        .context("Failed to parse planning documents")?;
     println!("  ✓ Planning documents parsed");
     println!("    📍 Project: {}", context.project_name.dimmed());
     println!();
@@ -101,7 +104,7 @@ pub fn execute(project_path: &Path) -> Result<()> {
     println!();
 
     // Phase 6: Git Initialization
-    init_git_repo(&repo_path, &claude_path)?;
+    init_git_repo(&repo_path, &claude_path, &config.structure.planning_dir)?;
     println!();
 
     // Phase 7: Success Output
@@ -133,7 +136,8 @@ pub fn execute(project_path: &Path) -> Result<()> {
 /// # Arguments
 /// * `repo_path` - Root directory of the repository
 /// * `claude_path` - Path to CLAUDE.md for staging
-fn init_git_repo(repo_path: &Path, claude_path: &Path) -> Result<()> {
+/// * `planning_dir` - Planning directory relative to the repository root, staged if present
+fn init_git_repo(repo_path: &Path, claude_path: &Path, planning_dir: &str) -> Result<()> {
     let git_dir = repo_path.join(".git");
 
     let repo = if git_dir.exists() {
@@ -167,10 +171,9 @@ fn init_git_repo(repo_path: &Path, claude_path: &Path) -> Result<()> {
         .context("Failed to stage CLAUDE.md")?;
 
     // Stage planning directory if it exists in repo
-    let planning_dir = repo_path.join("01-PLANNING");
-    if planning_dir.exists() {
+    if repo_path.join(planning_dir).exists() {
         index
-            .add_all(["01-PLANNING"].iter(), git2::IndexAddOption::DEFAULT, None)
+            .add_all([planning_dir].iter(), git2::IndexAddOption::DEFAULT, None)
             .context("Failed to stage planning directory")?;
     }
 
@@ -1,4 +1,7 @@
+use crate::layout::VaultLayout;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 // Forward declarations of config structs
@@ -48,6 +51,10 @@ pub struct NexusConfig {
     pub catalyst: Option<CatalystConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tasks: Option<TasksConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub planning: Option<PlanningConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git: Option<GitConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,10 +73,65 @@ pub struct StructureConfig {
     pub sprint_dir: String,
 }
 
+impl Default for StructureConfig {
+    fn default() -> Self {
+        Self {
+            planning_dir: "01-PLANNING".to_string(),
+            management_dir: "00-MANAGEMENT".to_string(),
+            sprint_dir: crate::layout::DEFAULT_SPRINT_DIR.to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GateConfig {
     pub heuristics_file: String,
     pub strict_mode: bool,
+    /// Heuristics profile the project inherits: a name in `~/.config/nexus/heuristics/`
+    /// or a path relative to the project root. The project heuristics file's own
+    /// `extends` takes precedence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+}
+
+/// Planning document set (`[planning]`); the built-in schema applies when absent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanningConfig {
+    /// Schema file with `[[documents]]` entries, relative to the project root
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    /// Whether the built-in documents are kept alongside the project's own
+    #[serde(default = "default_include_default")]
+    pub include_default: bool,
+    /// Inline document definitions (`[[planning.documents]]`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub documents: Vec<crate::schema::planning::DocumentSchema>,
+    /// How headings are compared with schema headers ("normalized" or "loose")
+    #[serde(default, skip_serializing_if = "is_default_matching")]
+    pub header_matching: crate::planning::HeaderMatching,
+    /// Other wordings accepted for a header in every document (`[planning.aliases]`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for PlanningConfig {
+    fn default() -> Self {
+        Self {
+            schema: None,
+            include_default: default_include_default(),
+            documents: Vec::new(),
+            header_matching: crate::planning::HeaderMatching::default(),
+            aliases: BTreeMap::new(),
+        }
+    }
+}
+
+fn is_default_matching(matching: &crate::planning::HeaderMatching) -> bool {
+    *matching == crate::planning::HeaderMatching::default()
+}
+
+fn default_include_default() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,19 +146,274 @@ pub struct StateConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub active_sprint: Option<ActiveSprintConfig>,
     pub is_unlocked: bool,
+    /// Numbers of sprints approved before the active one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approved_sprints: Vec<u32>,
+    /// Start and approval dates of earlier sprints
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sprint_history: Vec<SprintRecord>,
+}
+
+impl StateConfig {
+    /// Whether a sprint has been approved (listed, or the active sprint with status "approved")
+    pub fn is_approved(&self, sprint_number: u32) -> bool {
+        self.approved_sprints.contains(&sprint_number)
+            || self.active_sprint.as_ref().is_some_and(|active| {
+                active.status == "approved" && active.number() == Some(sprint_number)
+            })
+    }
+
+    /// Whether a sprint was closed out, its unfinished tasks carried over
+    pub fn is_closed(&self, sprint_number: u32) -> bool {
+        self.sprint_record(sprint_number).closed.is_some()
+    }
+
+    /// Whether a sprint no longer holds up the sprints after it (approved or closed)
+    pub fn is_finished(&self, sprint_number: u32) -> bool {
+        self.is_approved(sprint_number) || self.is_closed(sprint_number)
+    }
+
+    /// Recorded dates of a sprint (the active sprint first, then the history)
+    pub fn sprint_record(&self, sprint_number: u32) -> SprintRecord {
+        match &self.active_sprint {
+            Some(active) if active.number() == Some(sprint_number) => SprintRecord {
+                number: sprint_number,
+                started: active.started,
+                approved: active.approved,
+                closed: active.closed,
+            },
+            _ => self
+                .sprint_history
+                .iter()
+                .rev()
+                .find(|record| record.number == sprint_number)
+                .cloned()
+                .unwrap_or(SprintRecord {
+                    number: sprint_number,
+                    started: None,
+                    approved: None,
+                    closed: None,
+                }),
+        }
+    }
+
+    /// Make a sprint the active one, keeping the approval and dates of the one it replaces
+    ///
+    /// The replaced sprint is also kept on the new one, so `abort_sprint` can restore it.
+    pub fn start_sprint(&mut self, sprint_number: u32, today: NaiveDate) {
+        let previous = self.active_sprint.take();
+        let mut added_to_approved = false;
+        if let Some(previous) = &previous
+            && let Some(number) = previous.number()
+        {
+            if previous.status == "approved" && !self.approved_sprints.contains(&number) {
+                self.approved_sprints.push(number);
+                self.approved_sprints.sort_unstable();
+                added_to_approved = true;
+            }
+            if previous.started.is_some() || previous.approved.is_some() || previous.closed.is_some() {
+                self.sprint_history.retain(|record| record.number != number);
+                self.sprint_history.push(SprintRecord {
+                    number,
+                    started: previous.started,
+                    approved: previous.approved,
+                    closed: previous.closed,
+                });
+            }
+        }
+        self.active_sprint = Some(ActiveSprintConfig {
+            current: format!("sprint-{sprint_number}"),
+            status: "in_progress".to_string(),
+            started: Some(today),
+            approved: None,
+            closed: None,
+            worktree: None,
+            previous_branch: None,
+            added_to_approved: false,
+            // Only one level of undo: the replaced sprint forgets its own predecessor
+            previous: previous.map(|previous| {
+                Box::new(ActiveSprintConfig {
+                    added_to_approved,
+                    previous: None,
+                    ..previous
+                })
+            }),
+        });
+    }
+
+    /// Drop the active sprint and make the one it replaced active again
+    ///
+    /// Undoes `start_sprint`: the replaced sprint leaves the history and, if
+    /// `start_sprint` added it there, the approved list. Returns the sprint that was dropped.
+    pub fn abort_sprint(&mut self) -> Option<ActiveSprintConfig> {
+        let mut aborted = self.active_sprint.take()?;
+        if let Some(mut previous) = aborted.previous.take() {
+            if let Some(number) = previous.number() {
+                self.sprint_history.retain(|record| record.number != number);
+                if previous.added_to_approved {
+                    self.approved_sprints.retain(|approved| *approved != number);
+                }
+            }
+            previous.added_to_approved = false;
+            self.active_sprint = Some(*previous);
+        }
+        Some(aborted)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActiveSprintConfig {
     /// Current sprint identifier (e.g., "sprint-4")
     pub current: String,
-    /// Sprint status: "`in_progress`" or "approved"
+    /// Sprint status: "`in_progress`", "approved" or "closed"
     pub status: String,
+    /// Day the sprint was started ("2026-10-01")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started: Option<NaiveDate>,
+    /// Day the sprint was approved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approved: Option<NaiveDate>,
+    /// Day the sprint was closed out with `nexus sprint close`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed: Option<NaiveDate>,
+    /// Git worktree the sprint branch is checked out in (`[git] worktrees = true`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worktree: Option<PathBuf>,
+    /// Branch checked out when the sprint started; `nexus sprint abort` returns to it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_branch: Option<String>,
+    /// Whether starting the next sprint added this one to `approved_sprints` (set on `previous`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub added_to_approved: bool,
+    /// Active sprint this one replaced, restored by `nexus sprint abort`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<Box<ActiveSprintConfig>>,
+}
+
+impl ActiveSprintConfig {
+    /// Sprint number from the identifier ("sprint-4" gives 4)
+    pub fn number(&self) -> Option<u32> {
+        self.current.strip_prefix("sprint-")?.parse().ok()
+    }
+
+    /// Whether the sprint has ended (approved or closed), so the next one may start
+    pub fn is_finished(&self) -> bool {
+        self.status == "approved" || self.status == "closed"
+    }
+
+    /// Git checkout the sprint's commits are made in: its worktree, else the project itself
+    pub fn checkout_path(&self, project_path: &std::path::Path) -> PathBuf {
+        self.worktree
+            .as_ref()
+            .map_or_else(|| project_path.to_path_buf(), |worktree| project_path.join(worktree))
+    }
+}
+
+/// Dates of a sprint that is no longer the active one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SprintRecord {
+    pub number: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approved: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed: Option<NaiveDate>,
+}
+
+/// Sprint branch workflow (`[git]`); the defaults apply when absent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitConfig {
+    /// Branch that `nexus sprint finish` merges sprint branches into
+    #[serde(default = "default_base_branch")]
+    pub base_branch: String,
+    /// "merge-commit" (default) or "fast-forward"
+    #[serde(default)]
+    pub merge_strategy: crate::git_ops::MergeStrategy,
+    /// Whether `nexus sprint finish` deletes the sprint branch after merging
+    #[serde(default)]
+    pub delete_branch: bool,
+    /// Check each sprint branch out in its own worktree instead of in place
+    #[serde(default)]
+    pub worktrees: bool,
+    /// Directory for sprint worktrees, relative to the project root
+    #[serde(default = "default_worktree_dir")]
+    pub worktree_dir: String,
+}
+
+impl GitConfig {
+    /// Directory that holds the sprint worktrees of a project
+    pub fn worktree_root(&self, project_path: &std::path::Path) -> PathBuf {
+        project_path.join(&self.worktree_dir)
+    }
+}
+
+impl Default for GitConfig {
+    fn default() -> Self {
+        Self {
+            base_branch: default_base_branch(),
+            merge_strategy: crate::git_ops::MergeStrategy::default(),
+            delete_branch: false,
+            worktrees: false,
+            worktree_dir: default_worktree_dir(),
+        }
+    }
+}
+
+fn default_base_branch() -> String {
+    "main".to_string()
+}
+
+fn default_worktree_dir() -> String {
+    ".nexus/worktrees".to_string()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TemplatesConfig {
+    #[serde(default = "default_claude_template")]
     pub claude_template: String,
+    /// Folder of sprint workspace template overrides (`Tasks.md.tera`, ...),
+    /// relative to the project root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprint_templates: Option<String>,
+    /// Files rendered into each sprint workspace, each from `<file>.tera`
+    /// (default: Tasks.md and Sprint-Context.md)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprint_files: Option<Vec<String>>,
+    /// Empty folders created in each sprint workspace (default: approvals and sessions)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sprint_folders: Option<Vec<String>>,
+}
+
+impl TemplatesConfig {
+    /// Files rendered into each sprint workspace
+    pub fn sprint_files(&self) -> Vec<String> {
+        self.sprint_files
+            .clone()
+            .unwrap_or_else(|| vec!["Tasks.md".to_string(), "Sprint-Context.md".to_string()])
+    }
+
+    /// Empty folders created in each sprint workspace
+    pub fn sprint_folders(&self) -> Vec<String> {
+        self.sprint_folders
+            .clone()
+            .unwrap_or_else(|| vec!["approvals".to_string(), "sessions".to_string()])
+    }
+}
+
+impl Default for TemplatesConfig {
+    fn default() -> Self {
+        Self {
+            claude_template: default_claude_template(),
+            sprint_templates: None,
+            sprint_files: None,
+            sprint_folders: None,
+        }
+    }
+}
+
+fn default_claude_template() -> String {
+    "templates/CLAUDE.md.example".to_string()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -171,14 +488,11 @@ impl NexusConfig {
                 version: "0.1.0".to_string(),
                 obsidian_path: obsidian_path.clone(),
             },
-            structure: StructureConfig {
-                planning_dir: "01-PLANNING".to_string(),
-                management_dir: "00-MANAGEMENT".to_string(),
-                sprint_dir: "00-MANAGEMENT/Sprints".to_string(),
-            },
+            structure: StructureConfig::default(),
             gate: GateConfig {
                 heuristics_file: "Gate-Heuristics.json".to_string(),
                 strict_mode: true,
+                extends: None,
             },
             // Default planning_path to obsidian_path
             obsidian: Some(ObsidianConfig {
@@ -187,14 +501,16 @@ impl NexusConfig {
             state: Some(StateConfig {
                 active_sprint: None,
                 is_unlocked: false,
+                approved_sprints: Vec::new(),
+                sprint_history: Vec::new(),
             }),
-            templates: Some(TemplatesConfig {
-                claude_template: "templates/CLAUDE.md.example".to_string(),
-            }),
+            templates: Some(TemplatesConfig::default()),
             brain: None,    // Brain is disabled by default, configure in nexus.toml
             llm: None,      // LLM is disabled by default, configure in nexus.toml
             catalyst: None, // Catalyst uses defaults if not configured
             tasks: None,    // Tasks defaults to sprint mode if not configured
+            planning: None, // Built-in planning schema if not configured
+            git: None,      // Sprint branches merge into "main" if not configured
         }
     }
 
@@ -204,6 +520,11 @@ impl NexusConfig {
             .as_ref().map_or_else(|| PathBuf::from(&self.project.obsidian_path), |o| o.planning_path.clone())
     }
 
+    /// Where the `[structure]` folders are in the vault (below the planning path)
+    pub fn layout(&self) -> VaultLayout {
+        VaultLayout::new(&self.get_planning_path(), &self.structure)
+    }
+
     /// Get the vault/repo path (`obsidian_path`)
     pub fn get_repo_path(&self) -> PathBuf {
         PathBuf::from(&self.project.obsidian_path)
@@ -288,6 +609,38 @@ strict_mode = true
         assert!(!config.is_adhoc_mode());
     }
 
+    #[test]
+    fn test_git_config() {
+        let toml_git = r#"
+[project]
+name = "test"
+version = "0.1.0"
+obsidian_path = "/test"
+
+[structure]
+planning_dir = "01-PLANNING"
+management_dir = "00-MANAGEMENT"
+sprint_dir = "00-MANAGEMENT/Sprints"
+
+[gate]
+heuristics_file = "Gate-Heuristics.json"
+strict_mode = true
+
+[git]
+merge_strategy = "fast-forward"
+        "#;
+
+        let git = NexusConfig::from_toml(toml_git).unwrap().git.unwrap();
+        assert_eq!(git.base_branch, "main");
+        assert_eq!(git.merge_strategy, crate::git_ops::MergeStrategy::FastForward);
+        assert!(!git.delete_branch);
+        assert!(!git.worktrees);
+        assert_eq!(
+            git.worktree_root(std::path::Path::new("/repo")),
+            PathBuf::from("/repo/.nexus/worktrees")
+        );
+    }
+
     #[test]
     fn test_tasks_config_sprint_mode() {
         let toml_sprint_mode = r#"
@@ -411,5 +764,122 @@ mode = "adhoc"
             PathBuf::from("/test/00-MANAGEMENT/00-ADHOC-TASK.md")
         );
     }
+    #[test]
+    fn test_sprint_approval_state() {
+        let toml_state = r#"
+is_unlocked = true
+approved_sprints = [0, 1]
+
+[active_sprint]
+current = "sprint-2"
+status = "approved"
+        "#;
+
+        let mut state: StateConfig = toml::from_str(toml_state).unwrap();
+        assert!(state.is_approved(1));
+        assert!(state.is_approved(2));
+        assert!(!state.is_approved(3));
+
+        state.active_sprint.as_mut().unwrap().status = "in_progress".to_string();
+        assert!(!state.is_approved(2));
+    }
+
+    #[test]
+    fn test_start_sprint_keeps_previous_dates() {
+        let toml_state = r#"
+is_unlocked = true
+
+[active_sprint]
+current = "sprint-2"
+status = "approved"
+started = "2026-10-01"
+approved = "2026-10-09"
+        "#;
+
+        let mut state: StateConfig = toml::from_str(toml_state).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        state.start_sprint(3, today);
+
+        assert_eq!(state.approved_sprints, vec![2]);
+        assert_eq!(
+            state.sprint_record(2),
+            SprintRecord {
+                number: 2,
+                started: NaiveDate::from_ymd_opt(2026, 10, 1),
+                approved: NaiveDate::from_ymd_opt(2026, 10, 9),
+                closed: None,
+            }
+        );
+        assert_eq!(state.sprint_record(3).started, Some(today));
+        assert!(!state.is_approved(3));
+
+        // Dates survive a round trip through nexus.toml
+        let reloaded: StateConfig = toml::from_str(&toml::to_string(&state).unwrap()).unwrap();
+        assert_eq!(reloaded.sprint_history, state.sprint_history);
+    }
+
+    #[test]
+    fn test_closed_sprint_is_finished() {
+        let toml_state = r#"
+is_unlocked = true
+
+[active_sprint]
+current = "sprint-2"
+status = "closed"
+started = "2026-10-01"
+closed = "2026-10-09"
+        "#;
+
+        let mut state: StateConfig = toml::from_str(toml_state).unwrap();
+        assert!(state.active_sprint.as_ref().unwrap().is_finished());
+        assert!(state.is_finished(2));
+        assert!(!state.is_approved(2));
+
+        state.start_sprint(3, NaiveDate::from_ymd_opt(2026, 10, 12).unwrap());
+        assert!(state.approved_sprints.is_empty());
+        assert!(state.is_closed(2));
+        assert!(!state.is_finished(3));
+    }
+
+    #[test]
+    fn test_abort_sprint_restores_previous() {
+        let toml_state = r#"
+is_unlocked = true
+approved_sprints = [0, 1]
+
+[active_sprint]
+current = "sprint-2"
+status = "approved"
+started = "2026-10-01"
+approved = "2026-10-09"
+        "#;
+
+        let mut state: StateConfig = toml::from_str(toml_state).unwrap();
+        let before = toml::to_string(&state).unwrap();
+        state.start_sprint(3, NaiveDate::from_ymd_opt(2026, 10, 12).unwrap());
+        state.active_sprint.as_mut().unwrap().previous_branch = Some("main".to_string());
+
+        // The replaced sprint survives a round trip through nexus.toml
+        let mut state: StateConfig = toml::from_str(&toml::to_string(&state).unwrap()).unwrap();
+        let aborted = state.abort_sprint().unwrap();
+        assert_eq!(aborted.current, "sprint-3");
+        assert_eq!(aborted.previous_branch.as_deref(), Some("main"));
+        assert_eq!(toml::to_string(&state).unwrap(), before);
+
+        // Only one level of undo
+        assert!(state.abort_sprint().is_some());
+        assert!(state.active_sprint.is_none());
+        assert!(state.abort_sprint().is_none());
+
+        // An approval recorded by hand stays in the list
+        let toml_state = toml_state.replace("[0, 1]", "[0, 1, 2]");
+        let mut state: StateConfig = toml::from_str(&toml_state).unwrap();
+        let before = toml::to_string(&state).unwrap();
+        state.start_sprint(3, NaiveDate::from_ymd_opt(2026, 10, 12).unwrap());
+        let mut state: StateConfig = toml::from_str(&toml::to_string(&state).unwrap()).unwrap();
+        state.abort_sprint().unwrap();
+        assert_eq!(state.approved_sprints, vec![0, 1, 2]);
+        assert_eq!(toml::to_string(&state).unwrap(), before);
+    }
 }
 
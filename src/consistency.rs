@@ -0,0 +1,430 @@
+//! Cross-Document Consistency Checks
+//!
+//! The section-level validator in `planning.rs` looks at one document at a time.
+//! This pass reads the whole planning set through `parse_planning_documents` and
+//! reports terms that one document rules out while another one uses them, e.g.
+//! a library listed both under "Stack" and "What I will NOT use", or a sprint that
+//! builds a feature listed under "Never (things I will NOT build)".
+
+use crate::planning::{
+    LineIndex, PlanningContext, ValidationIssue, find_section, parse_planning_documents,
+};
+use crate::schema::planning::PlanningSchema;
+use anyhow::{Context, Result};
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+/// Field of `PlanningContext` holding the text of a section
+#[derive(Debug, Clone, Copy)]
+enum ContextField {
+    TechStack,
+    Dependencies,
+    TechnicalDecisions,
+    TechExclusions,
+    MvpScope,
+    AntiScope,
+    MvpBreakdown,
+}
+
+impl ContextField {
+    fn text(self, context: &PlanningContext) -> &str {
+        match self {
+            Self::TechStack => &context.tech_stack,
+            Self::Dependencies => &context.dependencies,
+            Self::TechnicalDecisions => &context.technical_decisions,
+            Self::TechExclusions => &context.tech_exclusions,
+            Self::MvpScope => &context.mvp_scope,
+            Self::AntiScope => &context.anti_scope,
+            Self::MvpBreakdown => &context.mvp_breakdown,
+        }
+    }
+}
+
+/// A section of a planning document
+struct Location {
+    file: &'static str,
+    /// Section header; the whole document when `None`
+    header: Option<&'static str>,
+    /// Parsed text of the location, used to skip documents that cannot conflict
+    field: Option<ContextField>,
+}
+
+/// A list of ruled-out terms and the places they must not appear
+struct ConsistencyCheck {
+    exclusions: Location,
+    /// How the exclusion list rules a term out, used in messages
+    relation: &'static str,
+    targets: &'static [Location],
+}
+
+const TECH_TARGETS: &[Location] = &[
+    Location {
+        file: "03-Tech-Stack.md",
+        header: Some("Stack (force yourself to choose NOW):"),
+        field: Some(ContextField::TechStack),
+    },
+    Location {
+        file: "03-Tech-Stack.md",
+        header: Some("Dependencies (max 10 important ones):"),
+        field: Some(ContextField::Dependencies),
+    },
+    Location {
+        file: "04-Architecture.md",
+        header: Some("Critical technical decisions:"),
+        field: Some(ContextField::TechnicalDecisions),
+    },
+];
+
+const SCOPE_TARGETS: &[Location] = &[
+    Location {
+        file: "02-Scope-and-Boundaries.md",
+        header: Some("MVP (Minimum Viable Product):"),
+        field: Some(ContextField::MvpScope),
+    },
+    Location {
+        file: "05-MVP-Breakdown.md",
+        header: None,
+        field: Some(ContextField::MvpBreakdown),
+    },
+];
+
+const CHECKS: &[ConsistencyCheck] = &[
+    ConsistencyCheck {
+        exclusions: Location {
+            file: "03-Tech-Stack.md",
+            header: Some("What I will NOT use:"),
+            field: Some(ContextField::TechExclusions),
+        },
+        relation: "excluded",
+        targets: TECH_TARGETS,
+    },
+    ConsistencyCheck {
+        exclusions: Location {
+            file: "02-Scope-and-Boundaries.md",
+            header: Some("Never (things I will NOT build):"),
+            field: Some(ContextField::AntiScope),
+        },
+        relation: "ruled out",
+        targets: SCOPE_TARGETS,
+    },
+    ConsistencyCheck {
+        exclusions: Location {
+            file: "01-Problem-and-Vision.md",
+            header: Some("Anti-vision (what this project is NOT):"),
+            field: Some(ContextField::AntiScope),
+        },
+        relation: "ruled out",
+        targets: SCOPE_TARGETS,
+    },
+    ConsistencyCheck {
+        exclusions: Location {
+            file: "02-Scope-and-Boundaries.md",
+            header: Some("Version 2 (NOT NOW - just document):"),
+            field: None,
+        },
+        relation: "deferred to Version 2",
+        targets: SCOPE_TARGETS,
+    },
+];
+
+/// A conflict found by the consistency pass
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ConsistencyFinding {
+    /// File name of the document where the conflicting term is used
+    pub file: String,
+    pub issue: ValidationIssue,
+}
+
+/// Raw planning documents, read once per run
+struct Documents<'a> {
+    planning_dir: &'a Path,
+    contents: HashMap<&'static str, Option<String>>,
+}
+
+impl Documents<'_> {
+    fn get(&mut self, file: &'static str) -> Result<Option<&str>> {
+        if !self.contents.contains_key(file) {
+            let path = self.planning_dir.join(file);
+            let content = if path.exists() {
+                Some(
+                    fs::read_to_string(&path)
+                        .with_context(|| format!("Failed to read {}", path.display()))?,
+                )
+            } else {
+                None
+            };
+            self.contents.insert(file, content);
+        }
+        Ok(self.contents[file].as_deref())
+    }
+}
+
+/// Checks the planning documents against each other
+///
+/// # Arguments
+/// * `planning_dir` - Directory containing the structured planning documents
+/// * `schema` - Planning schema used to extract the document context
+///
+/// # Returns
+/// * `Ok(Vec<ConsistencyFinding>)` - One finding per ruled-out term and section using it
+/// * `Err` - A planning document could not be read
+pub fn check_consistency(
+    planning_dir: &Path,
+    schema: &PlanningSchema,
+) -> Result<Vec<ConsistencyFinding>> {
+    // Positions come from the raw files, so embeds are not expanded here
+    let context = parse_planning_documents(planning_dir, schema, None)?;
+    let headers = schema.header_matcher();
+    let mut documents = Documents {
+        planning_dir,
+        contents: HashMap::new(),
+    };
+    let mut findings = Vec::new();
+
+    for check in CHECKS {
+        let source = &check.exclusions;
+        if source
+            .field
+            .is_some_and(|field| field.text(&context).trim().is_empty())
+        {
+            continue;
+        }
+        let Some(content) = documents.get(source.file)? else {
+            continue;
+        };
+        let Some((range, section)) = source.header.and_then(|h| find_section(content, h, &headers)) else {
+            continue;
+        };
+
+        let index = LineIndex::new(content);
+        let exclusions: Vec<(String, _)> = list_items(content, range)
+            .into_iter()
+            .filter_map(|(text, item)| {
+                exclusion_term(&text).map(|term| (term, index.span(item)))
+            })
+            .collect();
+
+        for (term, other_span) in exclusions {
+            let Ok(pattern) = Regex::new(&format!("(?i){}", regex::escape(&term))) else {
+                continue;
+            };
+
+            for target in check.targets {
+                // The parsed context tells us cheaply whether the term can appear at all
+                if let Some(field) = target.field
+                    && find_term(&pattern, field.text(&context), 0..field.text(&context).len())
+                        .is_none()
+                {
+                    continue;
+                }
+                let Some(target_content) = documents.get(target.file)? else {
+                    continue;
+                };
+                let (scope, target_section) = match target.header {
+                    Some(header) => match find_section(target_content, header, &headers) {
+                        Some((scope, text)) => (scope, Some(text)),
+                        None => continue,
+                    },
+                    None => (0..target_content.len(), None),
+                };
+
+                if let Some(found) = find_term(&pattern, target_content, scope) {
+                    let target_index = LineIndex::new(target_content);
+                    findings.push(ConsistencyFinding {
+                        file: target.file.to_string(),
+                        issue: ValidationIssue::CrossDocumentConflict {
+                            term: term.clone(),
+                            relation: check.relation.to_string(),
+                            span: target_index.span(found),
+                            section: target_section,
+                            other_file: source.file.to_string(),
+                            other_section: section.clone(),
+                            other_span,
+                        },
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
+/// Text and byte range of each top-level list item in a section
+fn list_items(content: &str, range: Range<usize>) -> Vec<(String, Range<usize>)> {
+    let section = &content[range.clone()];
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut current: Option<(String, Range<usize>)> = None;
+
+    let parser = Parser::new_ext(section, Options::ENABLE_TASKLISTS);
+    for (event, item_range) in parser.into_offset_iter() {
+        match event {
+            Event::Start(Tag::Item) => {
+                depth += 1;
+                if depth == 1 {
+                    current = Some((
+                        String::new(),
+                        range.start + item_range.start..range.start + item_range.end,
+                    ));
+                }
+            }
+            Event::End(TagEnd::Item) => {
+                depth = depth.saturating_sub(1);
+                if depth == 0
+                    && let Some(item) = current.take()
+                {
+                    items.push(item);
+                }
+            }
+            Event::Text(text) | Event::Code(text) if depth == 1 => {
+                if let Some((item_text, _)) = current.as_mut() {
+                    item_text.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    items
+}
+
+/// Extracts the ruled-out term from a list item
+///
+/// # Examples
+/// * "Not: GraphQL" -> "GraphQL"
+/// * "Microservices - too much overhead" -> "Microservices"
+/// * "[Example: \"Multiplayer\"]" -> `None` (template placeholder)
+fn exclusion_term(item: &str) -> Option<String> {
+    let mut text = item.trim();
+
+    // Unfilled template placeholders are not decisions
+    if text.starts_with('[') {
+        return None;
+    }
+
+    for prefix in ["not:", "not ", "no "] {
+        if text.len() >= prefix.len() && text[..prefix.len()].eq_ignore_ascii_case(prefix) {
+            text = text[prefix.len()..].trim_start();
+            break;
+        }
+    }
+
+    // Keep the term, drop the explanation that follows it
+    let end = [": ", " (", " - ", " — ", " – ", ", "]
+        .iter()
+        .filter_map(|separator| text.find(separator))
+        .min()
+        .unwrap_or(text.len());
+    let term = text[..end]
+        .trim()
+        .trim_matches(|c: char| matches!(c, '"' | '\'' | '`' | '.' | ':'))
+        .trim();
+
+    let words = term.split_whitespace().count();
+    (term.chars().count() >= 2 && (1..=4).contains(&words)).then(|| term.to_string())
+}
+
+/// First occurrence of the term in `range` that is not part of a longer word
+fn find_term(pattern: &Regex, content: &str, range: Range<usize>) -> Option<Range<usize>> {
+    let scope = &content[range.clone()];
+    pattern
+        .find_iter(scope)
+        .find(|found| {
+            let before = scope[..found.start()].chars().next_back();
+            let after = scope[found.end()..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        })
+        .map(|found| range.start + found.start()..range.start + found.end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_exclusion_term() {
+        assert_eq!(exclusion_term("Not: GraphQL").as_deref(), Some("GraphQL"));
+        assert_eq!(
+            exclusion_term("Microservices - too much overhead").as_deref(),
+            Some("Microservices")
+        );
+        assert_eq!(exclusion_term("\"AI chatbot\"").as_deref(), Some("AI chatbot"));
+        assert_eq!(exclusion_term("[Example: \"Multiplayer\"]"), None);
+        assert_eq!(
+            exclusion_term("Anything that needs a dedicated team of people to run"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_term_respects_word_boundaries() {
+        let pattern = Regex::new("(?i)rust").unwrap();
+        let content = "Trusty tools, then Rust.";
+        assert_eq!(find_term(&pattern, content, 0..content.len()), Some(19..23));
+
+        let pattern = Regex::new(&format!("(?i){}", regex::escape("C++"))).unwrap();
+        let content = "Core in C++ for speed";
+        assert_eq!(find_term(&pattern, content, 0..content.len()), Some(8..11));
+    }
+
+    #[test]
+    fn test_check_consistency_cites_both_documents() {
+        let temp_dir = TempDir::new().unwrap();
+        let planning = temp_dir.path();
+
+        fs::write(
+            planning.join("02-Scope-and-Boundaries.md"),
+            "# Scope\n\n## MVP (Minimum Viable Product):\n- Shared shopping list\n\n## Never (things I will NOT build):\n- Multiplayer\n- [Example: \"AI chatbot\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            planning.join("03-Tech-Stack.md"),
+            "# Tech\n\n## Stack (force yourself to choose NOW):\n- **Backend:** Rust with GraphQL\n\n## What I will NOT use:\n- Not: GraphQL\n- Not: microservices\n",
+        )
+        .unwrap();
+        fs::write(
+            planning.join("05-MVP-Breakdown.md"),
+            "# Sprints\n\n## Sprint 1: Lists\n- [ ] Build list sync\n\n## Sprint 2: Multiplayer mode\n- [ ] Invite friends\n",
+        )
+        .unwrap();
+
+        let findings = check_consistency(planning, &PlanningSchema::builtin().unwrap()).unwrap();
+        assert_eq!(findings.len(), 2, "{findings:#?}");
+
+        let graphql = &findings[0];
+        assert_eq!(graphql.file, "03-Tech-Stack.md");
+        let ValidationIssue::CrossDocumentConflict {
+            term,
+            span,
+            section,
+            other_file,
+            other_section,
+            other_span,
+            ..
+        } = &graphql.issue
+        else {
+            panic!("Expected CrossDocumentConflict");
+        };
+        assert_eq!(term, "GraphQL");
+        assert_eq!((span.start.line, span.start.column), (4, 26));
+        assert_eq!(section.as_deref(), Some("Stack (force yourself to choose NOW):"));
+        assert_eq!(other_file, "03-Tech-Stack.md");
+        assert_eq!(other_section, "What I will NOT use:");
+        assert_eq!(other_span.start.line, 7);
+
+        let multiplayer = &findings[1];
+        assert_eq!(multiplayer.file, "05-MVP-Breakdown.md");
+        assert!(
+            multiplayer
+                .issue
+                .message()
+                .contains("ruled out in 02-Scope-and-Boundaries.md › Never (things I will NOT build): (line 7)")
+        );
+    }
+}
@@ -7,6 +7,8 @@
 
 use crate::brain::{Layer, NexusBrain, SearchResult};
 use crate::config::NexusConfig;
+use crate::layout::VaultLayout;
+use crate::mvp::{self, MvpTask};
 use anyhow::{Context as AnyhowContext, Result};
 use std::path::Path;
 
@@ -18,11 +20,14 @@ pub struct ArchitectureContext {
 
 /// Sprint context retrieved from Obsidian files
 #[derive(Debug, Clone)]
-#[allow(clippy::struct_field_names)] // sprint_context is descriptive in this domain
 pub struct SprintContext {
     pub sprint_id: String,
-    pub tasks: String,
-    pub sprint_context: String,
+    /// Tasks not yet checked off in the sprint's Tasks.md
+    pub tasks: Vec<MvpTask>,
+    /// Focus statement from the MVP breakdown
+    pub focus: Option<String>,
+    /// Exit criteria from the MVP breakdown
+    pub exit_criteria: Vec<String>,
 }
 
 /// Complete context for LLM prompt injection
@@ -43,9 +48,7 @@ impl ActiveContext {
 /// Context template that wraps user input with retrieved context
 pub struct ContextTemplate {
     architecture_snippets: Vec<String>,
-    sprint_id: Option<String>,
-    unfinished_tasks: Option<String>,
-    sprint_context: Option<String>,
+    sprint: Option<SprintContext>,
     user_request: String,
 }
 
@@ -59,21 +62,9 @@ impl ContextTemplate {
             .map(|s| format!("From {}:\n{}", s.file_name(), s.content))
             .collect();
 
-        let (sprint_id, unfinished_tasks, sprint_context) = if let Some(sprint) = context.sprint {
-            (
-                Some(sprint.sprint_id),
-                Some(sprint.tasks),
-                Some(sprint.sprint_context),
-            )
-        } else {
-            (None, None, None)
-        };
-
         Self {
             architecture_snippets,
-            sprint_id,
-            unfinished_tasks,
-            sprint_context,
+            sprint: context.sprint,
             user_request,
         }
     }
@@ -95,21 +86,29 @@ impl ContextTemplate {
         }
 
         // Sprint context
-        if let Some(ref sprint_id) = self.sprint_id {
+        if let Some(ref sprint) = self.sprint {
             output.push_str("[CURRENT SPRINT STATE]\n");
             use std::fmt::Write;
-            let _ = writeln!(output, "Sprint: {sprint_id}\n");
+            let _ = writeln!(output, "Sprint: {}\n", sprint.sprint_id);
 
-            if let Some(ref tasks) = self.unfinished_tasks {
-                output.push_str("Unfinished Tasks:\n");
-                output.push_str(tasks);
-                output.push_str("\n\n");
+            if let Some(ref focus) = sprint.focus {
+                let _ = writeln!(output, "Focus: {focus}\n");
             }
 
-            if let Some(ref context) = self.sprint_context {
-                output.push_str("Sprint Context:\n");
-                output.push_str(context);
-                output.push_str("\n\n");
+            output.push_str("Unfinished Tasks:\n");
+            if sprint.tasks.is_empty() {
+                output.push_str("No unfinished tasks");
+            } else {
+                output.push_str(&mvp::render_tasks(&sprint.tasks));
+            }
+            output.push_str("\n\n");
+
+            if !sprint.exit_criteria.is_empty() {
+                output.push_str("Exit Criteria:\n");
+                for criterion in &sprint.exit_criteria {
+                    let _ = writeln!(output, "- {criterion}");
+                }
+                output.push('\n');
             }
         }
 
@@ -213,6 +212,9 @@ async fn retrieve_architecture_context(
 }
 
 /// Retrieve sprint context from Obsidian files
+///
+/// Tasks come from the sprint's Tasks.md (where they are checked off); focus and
+/// exit criteria from the sprint's section of the MVP breakdown.
 async fn retrieve_sprint_context(
     obsidian_root: &Path,
     config: &NexusConfig,
@@ -225,62 +227,47 @@ async fn retrieve_sprint_context(
         .map(|s| s.current.clone())
         .ok_or_else(|| anyhow::anyhow!("No active sprint configured"))?;
 
-    // Build paths to sprint files
-    let sprint_dir = obsidian_root
-        .join(&config.structure.sprint_dir)
-        .join(&sprint_id);
+    // The sprint's section of the MVP breakdown, if it can still be found
+    let layout = VaultLayout::new(obsidian_root, &config.structure);
+    let mvp_path = layout.mvp_breakdown();
+    let sprint = match tokio::fs::read_to_string(&mvp_path).await {
+        Ok(content) => mvp::parse_sprints(&content)
+            .into_iter()
+            .find(|sprint| format!("sprint-{}", sprint.number) == sprint_id),
+        Err(_) => None,
+    };
+
+    // Sprint folders are named sprint-{number}-{name}
+    let sprint_dir = sprint
+        .as_ref()
+        .map(|sprint| layout.sprint_folder(sprint))
+        .filter(|dir| dir.exists())
+        .unwrap_or_else(|| layout.find_sprint_folder(&sprint_id));
 
     let tasks_path = sprint_dir.join("Tasks.md");
-    let context_path = sprint_dir.join("Sprint-Context.md");
-
-    // Read files concurrently
-    let tasks_task = tokio::fs::read_to_string(&tasks_path);
-    let context_task = tokio::fs::read_to_string(&context_path);
-
-    let (tasks_result, context_result) = tokio::join!(tasks_task, context_task);
-
-    // Parse tasks file to extract unfinished tasks
-    let tasks_content = tasks_result.with_context(|| {
-        format!(
-            "Failed to read Tasks.md from: {}",
-            tasks_path.display()
-        )
-    })?;
-
-    let unfinished_tasks = extract_unfinished_tasks(&tasks_content);
+    let tasks_content = tokio::fs::read_to_string(&tasks_path)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to read Tasks.md from: {}",
+                tasks_path.display()
+            )
+        })?;
 
-    let sprint_context = context_result.with_context(|| {
-        format!(
-            "Failed to read Sprint-Context.md from: {}",
-            context_path.display()
-        )
-    })?;
+    let id_prefix = sprint_id.replacen("sprint-", "s", 1);
+    let tasks = mvp::unfinished(&mvp::parse_task_list(&tasks_content, &id_prefix));
+    let (focus, exit_criteria) = sprint
+        .map(|sprint| (sprint.focus, sprint.exit_criteria))
+        .unwrap_or_default();
 
     Ok(SprintContext {
         sprint_id,
-        tasks: unfinished_tasks,
-        sprint_context,
+        tasks,
+        focus,
+        exit_criteria,
     })
 }
 
-/// Extract unfinished tasks from Tasks.md content
-fn extract_unfinished_tasks(content: &str) -> String {
-    let mut unfinished = Vec::new();
-
-    for line in content.lines() {
-        // Look for unchecked task items: - [ ] or * [ ]
-        if line.contains("- [ ]") || line.contains("* [ ]") {
-            unfinished.push(line.trim().to_string());
-        }
-    }
-
-    if unfinished.is_empty() {
-        "No unfinished tasks".to_string()
-    } else {
-        unfinished.join("\n")
-    }
-}
-
 /// Generate a query embedding using the local ONNX model
 ///
 /// Uses the all-MiniLM-L6-v2 model (384 dimensions) running locally via ONNX Runtime.
@@ -313,26 +300,6 @@ fn generate_query_embedding(query: &str) -> Result<Vec<f32>> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_extract_unfinished_tasks() {
-        let content = r"
-# Sprint Tasks
-
-- [x] Completed task
-- [ ] Unfinished task 1
-- [ ] Unfinished task 2
-* [x] Another completed
-* [ ] Unfinished task 3
-        ";
-
-        let result = extract_unfinished_tasks(content);
-
-        assert!(result.contains("Unfinished task 1"));
-        assert!(result.contains("Unfinished task 2"));
-        assert!(result.contains("Unfinished task 3"));
-        assert!(!result.contains("Completed task"));
-    }
-
     #[test]
     fn test_context_template_render() {
         let context = ActiveContext {
@@ -341,8 +308,9 @@ mod tests {
             },
             sprint: Some(SprintContext {
                 sprint_id: "sprint-3".to_string(),
-                tasks: "- [ ] Task 1\n- [ ] Task 2".to_string(),
-                sprint_context: "Focus on feature X".to_string(),
+                tasks: mvp::parse_task_list("- [ ] Task 1 ~1h\n- [ ] Task 2", "s3"),
+                focus: Some("Feature X".to_string()),
+                exit_criteria: vec!["Feature X ships".to_string()],
             }),
         };
 
@@ -351,6 +319,9 @@ mod tests {
 
         assert!(rendered.contains("[CURRENT SPRINT STATE]"));
         assert!(rendered.contains("Sprint: sprint-3"));
+        assert!(rendered.contains("Focus: Feature X"));
+        assert!(rendered.contains("- [ ] Task 1 🆔 s3-1 ~1h\n- [ ] Task 2 🆔 s3-2"));
+        assert!(rendered.contains("Exit Criteria:\n- Feature X ships"));
         assert!(rendered.contains("[USER REQUEST]"));
         assert!(rendered.contains("Write a new function"));
     }
@@ -0,0 +1,596 @@
+//! Roadmap Export
+//!
+//! Renders the sprint roadmap for stakeholders who never open the vault:
+//!
+//! - Mermaid: a Gantt chart in a fenced block, so it can be written to a note
+//!   and embedded in the dashboard (`![[Roadmap]]`)
+//! - JSON and CSV: every sprint and task, with completion from the sprint's `Tasks.md`
+//! - iCalendar: sprint windows and due-dated tasks
+//!
+//! Sprint windows use the start and approval (or close) dates recorded in nexus.toml.
+//! Where a date is missing, the window is planned from the sprint's estimate
+//! (8 hours of work per day), after its prerequisites and no earlier than today.
+
+use crate::config::StateConfig;
+use crate::mvp::{DependencyRef, Estimate, MvpTask, SprintData};
+use crate::roadmap::{Roadmap, SprintStatus};
+use chrono::{Days, NaiveDate};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+/// Output format for `nexus export roadmap`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// Mermaid Gantt chart in a markdown code block
+    #[default]
+    Mermaid,
+    /// Sprints and tasks as JSON
+    Json,
+    /// One row per task
+    Csv,
+    /// iCalendar events for sprint windows and due dates
+    Ics,
+}
+
+/// Roadmap of a project, ready to render
+#[derive(Debug, Clone, Serialize)]
+pub struct RoadmapExport {
+    pub project: String,
+    pub generated: NaiveDate,
+    pub sprints: Vec<ExportedSprint>,
+    /// Ids of the top-level tasks on the critical path
+    pub critical_path: Vec<String>,
+    pub critical_path_estimate: Option<Estimate>,
+}
+
+/// A sprint with its schedule and progress
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedSprint {
+    /// The sprint, with task completion taken from `Tasks.md` when it exists
+    #[serde(flatten)]
+    pub sprint: SprintData,
+    /// `approved`, `closed`, `in_progress`, `ready` or `blocked`
+    pub status: &'static str,
+    /// Unapproved prerequisites of a blocked sprint
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<u32>,
+    pub estimate: Option<Estimate>,
+    pub start: NaiveDate,
+    /// Last day of the sprint
+    pub end: NaiveDate,
+    /// Whether the window is planned from estimates instead of recorded dates
+    pub planned: bool,
+    /// Whether the sprint holds a task on the critical path
+    pub critical: bool,
+    pub tasks_done: usize,
+    pub tasks_total: usize,
+    /// Tasks in `Tasks.md` carried over from an earlier sprint
+    pub tasks_carried_over: usize,
+    /// `tasks_md` or `mvp_breakdown`
+    pub completion_source: &'static str,
+}
+
+impl RoadmapExport {
+    /// Schedules the sprints of a roadmap and applies the progress in their `Tasks.md`
+    ///
+    /// `progress` holds the parsed `Tasks.md` of each sprint that has one. The
+    /// roadmap must be free of problems (cycles leave sprints out).
+    pub fn new(
+        project: &str,
+        roadmap: &Roadmap,
+        state: Option<&StateConfig>,
+        progress: &BTreeMap<u32, Vec<MvpTask>>,
+        today: NaiveDate,
+    ) -> Self {
+        let critical = roadmap.critical_path();
+        let critical_path: Vec<String> = critical
+            .iter()
+            .flat_map(|path| path.tasks.iter().map(|task| task.id.clone()))
+            .collect();
+
+        let mut ends: HashMap<u32, NaiveDate> = HashMap::new();
+        let mut scheduled = Vec::new();
+        for number in roadmap.sprint_order() {
+            let Some(sprint) = roadmap.sprint(number) else {
+                continue;
+            };
+            let status = roadmap.status(number, state);
+            let record = state.map(|state| state.sprint_record(number));
+            let started = record.as_ref().and_then(|record| record.started);
+            let ended = record
+                .as_ref()
+                .and_then(|record| record.approved.or(record.closed));
+
+            let after_prerequisites = roadmap
+                .direct_prerequisites(number)
+                .iter()
+                .filter_map(|prerequisite| ends.get(prerequisite))
+                .max()
+                .and_then(|end| end.checked_add_days(Days::new(1)));
+            let start = started.unwrap_or_else(|| after_prerequisites.map_or(today, |day| day.max(today)));
+            let days = sprint.total_estimate().map_or(1, Estimate::days);
+            let planned_end = start + Days::new(u64::from(days - 1));
+            let end = match ended {
+                Some(ended) => ended.max(start),
+                None if status == SprintStatus::InProgress => planned_end.max(today),
+                None => planned_end,
+            };
+            ends.insert(number, end);
+
+            let mut sprint = sprint.clone();
+            let completion_source = match progress.get(&number) {
+                Some(tasks) => {
+                    let done: HashMap<&str, bool> = flatten(tasks)
+                        .into_iter()
+                        .map(|(task, _)| (task.id.as_str(), task.done))
+                        .collect();
+                    apply_completion(&mut sprint.tasks, &done);
+                    "tasks_md"
+                }
+                None => "mvp_breakdown",
+            };
+            let all = flatten(&sprint.tasks);
+            let tasks_done = all.iter().filter(|(task, _)| task.done).count();
+            let tasks_total = all.len();
+            let tasks_carried_over = progress.get(&number).map_or(0, |tasks| {
+                flatten(tasks)
+                    .into_iter()
+                    .filter(|(task, _)| task.carried_from.is_some())
+                    .count()
+            });
+
+            scheduled.push(ExportedSprint {
+                critical: sprint
+                    .tasks
+                    .iter()
+                    .any(|task| critical_path.contains(&task.id)),
+                estimate: sprint.total_estimate(),
+                status: status.key(),
+                blocked_by: match status {
+                    SprintStatus::Blocked(waiting) => waiting,
+                    _ => Vec::new(),
+                },
+                start,
+                end,
+                planned: started.is_none() || ended.is_none(),
+                tasks_done,
+                tasks_total,
+                tasks_carried_over,
+                completion_source,
+                sprint,
+            });
+        }
+
+        Self {
+            project: project.to_string(),
+            generated: today,
+            sprints: scheduled,
+            critical_path,
+            critical_path_estimate: critical.map(|path| path.estimate),
+        }
+    }
+
+    pub fn render(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Mermaid => self.to_mermaid(),
+            ExportFormat::Json => self.to_json(),
+            ExportFormat::Csv => self.to_csv(),
+            ExportFormat::Ics => self.to_ics(),
+        }
+    }
+
+    /// Gantt chart with one bar per sprint and a milestone per due-dated task
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::from("```mermaid\ngantt\n");
+        let _ = writeln!(out, "    title {} roadmap", gantt_label(&self.project));
+        out.push_str("    dateFormat YYYY-MM-DD\n");
+        out.push_str("    axisFormat %b %d\n");
+
+        for exported in &self.sprints {
+            let sprint = &exported.sprint;
+            let _ = writeln!(out, "    section Sprint {}", sprint.number);
+
+            let mut tags = Vec::new();
+            if exported.critical {
+                tags.push("crit");
+            }
+            match exported.status {
+                "approved" | "closed" => tags.push("done"),
+                "in_progress" => tags.push("active"),
+                _ => {}
+            }
+            tags.push("");
+            let days = (exported.end - exported.start).num_days() + 1;
+            let _ = writeln!(
+                out,
+                "    {} :{}sprint{}, {}, {days}d",
+                gantt_label(&sprint.title),
+                tags.join(", "),
+                sprint.number,
+                exported.start
+            );
+
+            for (task, _) in flatten(&sprint.tasks) {
+                if let Some(due) = task.due {
+                    let done = if task.done { "done, " } else { "" };
+                    let _ = writeln!(
+                        out,
+                        "    {} :milestone, {done}{due}, 0d",
+                        gantt_label(&task.text)
+                    );
+                }
+            }
+        }
+        out.push_str("```\n");
+        out
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("roadmap serializes to JSON") + "\n"
+    }
+
+    /// One row per task and sub-task; sprints without tasks get a row of their own
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(
+            "sprint,sprint_title,sprint_status,sprint_start,sprint_end,task_id,parent_id,task,done,estimate,due,priority,tags,depends_on\n",
+        );
+        for exported in &self.sprints {
+            let sprint = &exported.sprint;
+            let sprint_fields = [
+                sprint.number.to_string(),
+                sprint.title.clone(),
+                exported.status.to_string(),
+                exported.start.to_string(),
+                exported.end.to_string(),
+            ];
+            let tasks = flatten(&sprint.tasks);
+            if tasks.is_empty() {
+                csv_row(&mut out, sprint_fields.iter().cloned().chain(vec![String::new(); 9]));
+            }
+            for (task, parent) in tasks {
+                let task_fields = [
+                    task.id.clone(),
+                    parent.unwrap_or_default().to_string(),
+                    task.text.clone(),
+                    task.done.to_string(),
+                    task.estimate.map(|estimate| estimate.to_string()).unwrap_or_default(),
+                    task.due.map(|due| due.to_string()).unwrap_or_default(),
+                    task.priority.map(|priority| priority.name().to_string()).unwrap_or_default(),
+                    task.tags.join(" "),
+                    join_dependencies(&task.depends_on),
+                ];
+                csv_row(&mut out, sprint_fields.iter().cloned().chain(task_fields));
+            }
+        }
+        out
+    }
+
+    /// All-day events for sprint windows and task due dates
+    pub fn to_ics(&self) -> String {
+        let slug = slug(&self.project);
+        let stamp = format!("{}T000000Z", self.generated.format("%Y%m%d"));
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//nexus//roadmap//EN".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+            format!("X-WR-CALNAME:{}", ics_text(&format!("{} roadmap", self.project))),
+        ];
+
+        for exported in &self.sprints {
+            let sprint = &exported.sprint;
+            let mut description = format!(
+                "Status: {}\n{} of {} tasks done",
+                exported.status.replace('_', " "),
+                exported.tasks_done,
+                exported.tasks_total
+            );
+            if let Some(focus) = &sprint.focus {
+                let _ = write!(description, "\nFocus: {focus}");
+            }
+            lines.extend([
+                "BEGIN:VEVENT".to_string(),
+                format!("UID:{slug}-sprint-{}@nexus", sprint.number),
+                format!("DTSTAMP:{stamp}"),
+                format!("DTSTART;VALUE=DATE:{}", exported.start.format("%Y%m%d")),
+                format!("DTEND;VALUE=DATE:{}", (exported.end + Days::new(1)).format("%Y%m%d")),
+                format!("SUMMARY:{}", ics_text(&sprint.heading_text())),
+                format!("DESCRIPTION:{}", ics_text(&description)),
+                format!(
+                    "STATUS:{}",
+                    if exported.planned { "TENTATIVE" } else { "CONFIRMED" }
+                ),
+                "TRANSP:TRANSPARENT".to_string(),
+                "END:VEVENT".to_string(),
+            ]);
+
+            for (task, _) in flatten(&sprint.tasks) {
+                let Some(due) = task.due else {
+                    continue;
+                };
+                let state = if task.done { "Done" } else { "Open" };
+                lines.extend([
+                    "BEGIN:VEVENT".to_string(),
+                    format!("UID:{slug}-task-{}@nexus", slug_part(&task.id)),
+                    format!("DTSTAMP:{stamp}"),
+                    format!("DTSTART;VALUE=DATE:{}", due.format("%Y%m%d")),
+                    format!("DTEND;VALUE=DATE:{}", (due + Days::new(1)).format("%Y%m%d")),
+                    format!("SUMMARY:{}", ics_text(&format!("{} ({})", task.text, task.id))),
+                    format!(
+                        "DESCRIPTION:{}",
+                        ics_text(&format!("{state} · {}", sprint.heading_text()))
+                    ),
+                    "TRANSP:TRANSPARENT".to_string(),
+                ]);
+                if !task.tags.is_empty() {
+                    let tags: Vec<String> = task.tags.iter().map(|tag| ics_text(tag)).collect();
+                    lines.push(format!("CATEGORIES:{}", tags.join(",")));
+                }
+                lines.push("END:VEVENT".to_string());
+            }
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        lines.iter().map(|line| fold_ics_line(line) + "\r\n").collect()
+    }
+}
+
+/// Every task and sub-task, depth first, with the id of its parent
+fn flatten(tasks: &[MvpTask]) -> Vec<(&MvpTask, Option<&str>)> {
+    fn collect<'a>(
+        tasks: &'a [MvpTask],
+        parent: Option<&'a str>,
+        all: &mut Vec<(&'a MvpTask, Option<&'a str>)>,
+    ) {
+        for task in tasks {
+            all.push((task, parent));
+            collect(&task.subtasks, Some(&task.id), all);
+        }
+    }
+
+    let mut all = Vec::new();
+    collect(tasks, None, &mut all);
+    all
+}
+
+/// Marks tasks done or open as `Tasks.md` has them (tasks it lacks keep their state)
+fn apply_completion(tasks: &mut [MvpTask], done: &HashMap<&str, bool>) {
+    for task in tasks {
+        if let Some(&state) = done.get(task.id.as_str()) {
+            task.done = state;
+        }
+        apply_completion(&mut task.subtasks, done);
+    }
+}
+
+fn join_dependencies(dependencies: &[DependencyRef]) -> String {
+    dependencies
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Gantt task names end at `:`, and `#` and `;` start comments or separate statements
+fn gantt_label(text: &str) -> String {
+    text.replace([':', ';', '#'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn csv_row(out: &mut String, fields: impl IntoIterator<Item = String>) {
+    let fields: Vec<String> = fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field
+            }
+        })
+        .collect();
+    out.push_str(&fields.join(","));
+    out.push('\n');
+}
+
+/// Escapes an iCalendar TEXT value
+fn ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Folds a content line to 75 octets, as RFC 5545 requires
+fn fold_ics_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
+
+fn slug(text: &str) -> String {
+    let slug = slug_part(&text.to_lowercase());
+    if slug.is_empty() { "project".to_string() } else { slug }
+}
+
+/// Keeps ASCII letters, digits, `-` and `_`; everything else becomes `-`
+fn slug_part(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '-' })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mvp::{parse_sprints, parse_task_list};
+
+    const BREAKDOWN: &str = "## Sprint 1: Storage
+- [x] Schema ~2h 🆔 schema
+- [ ] Migrations ~1d
+
+## Sprint 2: Sync: offline
+**Depends on:** sprint-1
+- [ ] Sync engine ~12h #backend 📅 2026-11-02
+  - [ ] Retry queue, with backoff ~1h
+
+## Sprint 3: Docs
+";
+
+    fn day(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    fn export(state: &str, progress: &BTreeMap<u32, Vec<MvpTask>>) -> RoadmapExport {
+        let sprints = parse_sprints(BREAKDOWN);
+        let state: StateConfig = toml::from_str(state).unwrap();
+        RoadmapExport::new("Demo App", &Roadmap::new(&sprints), Some(&state), progress, day(10, 12))
+    }
+
+    const IN_PROGRESS: &str = r#"
+is_unlocked = true
+
+[active_sprint]
+current = "sprint-1"
+status = "in_progress"
+started = "2026-10-05"
+"#;
+
+    #[test]
+    fn test_schedule_and_progress() {
+        let progress = BTreeMap::from([(
+            1,
+            parse_task_list("- [x] Schema 🆔 schema\n- [x] Migrations 🆔 s1-2\n", "s1"),
+        )]);
+        let export = export(IN_PROGRESS, &progress);
+        let windows: Vec<(u32, &str, NaiveDate, NaiveDate)> = export
+            .sprints
+            .iter()
+            .map(|sprint| (sprint.sprint.number, sprint.status, sprint.start, sprint.end))
+            .collect();
+        assert_eq!(
+            windows,
+            vec![
+                // Started on the 5th, 10h of work: planned through the 6th, still open today
+                (1, "in_progress", day(10, 5), day(10, 12)),
+                // Waits for Sprint 1, 12h of work is two days
+                (2, "blocked", day(10, 13), day(10, 14)),
+                (3, "ready", day(10, 12), day(10, 12)),
+            ]
+        );
+        assert_eq!(export.sprints[0].tasks_done, 2);
+        assert_eq!(export.sprints[0].completion_source, "tasks_md");
+        assert_eq!(export.sprints[1].blocked_by, vec![1]);
+        assert_eq!(export.critical_path, vec!["s1-2", "s2-1"]);
+
+        let json: serde_json::Value = serde_json::from_str(&export.to_json()).unwrap();
+        assert_eq!(json["sprints"][0]["title"], "Storage");
+        assert_eq!(json["sprints"][0]["tasks"][1]["done"], true);
+        assert_eq!(json["sprints"][1]["tasks"][0]["due"], "2026-11-02");
+        assert_eq!(json["critical_path_estimate"], "20h");
+    }
+
+    #[test]
+    fn test_approved_sprint_uses_recorded_dates() {
+        let state = r#"
+is_unlocked = true
+approved_sprints = [1]
+
+[[sprint_history]]
+number = 1
+started = "2026-10-01"
+approved = "2026-10-03"
+"#;
+        let export = export(state, &BTreeMap::new());
+        let storage = &export.sprints[0];
+        assert_eq!((storage.start, storage.end), (day(10, 1), day(10, 3)));
+        assert!(!storage.planned);
+        assert_eq!(storage.status, "approved");
+        assert_eq!(storage.completion_source, "mvp_breakdown");
+        assert_eq!(export.sprints[1].status, "ready");
+    }
+
+    #[test]
+    fn test_closed_sprint_ends_on_close_date() {
+        let state = r#"
+is_unlocked = true
+
+[active_sprint]
+current = "sprint-1"
+status = "closed"
+started = "2026-10-01"
+closed = "2026-10-04"
+"#;
+        let progress = BTreeMap::from([(
+            3,
+            crate::mvp::parse_task_list(
+                "- [ ] Write docs 🆔 s3-1\n- [ ] Retry 🆔 s1-2 #carried-over [carried-from:: sprint-1]\n",
+                "s3",
+            ),
+        )]);
+        let export = export(state, &progress);
+        let storage = &export.sprints[0];
+        assert_eq!(storage.status, "closed");
+        assert_eq!((storage.start, storage.end), (day(10, 1), day(10, 4)));
+        assert_eq!(export.sprints[1].status, "ready");
+        let docs = export.sprints.iter().find(|sprint| sprint.sprint.number == 3).unwrap();
+        assert_eq!(docs.tasks_carried_over, 1);
+    }
+
+    #[test]
+    fn test_mermaid_gantt() {
+        let mermaid = export(IN_PROGRESS, &BTreeMap::new()).to_mermaid();
+        assert!(mermaid.starts_with("```mermaid\ngantt\n    title Demo App roadmap\n"));
+        assert!(mermaid.contains("    section Sprint 1\n    Storage :crit, active, sprint1, 2026-10-05, 8d\n"));
+        assert!(mermaid.contains("    Sync offline :crit, sprint2, 2026-10-13, 2d\n"));
+        assert!(mermaid.contains("    Sync engine :milestone, 2026-11-02, 0d\n"));
+        assert!(mermaid.contains("    Docs :sprint3, 2026-10-12, 1d\n"));
+        assert!(mermaid.ends_with("```\n"));
+    }
+
+    #[test]
+    fn test_csv_rows() {
+        let csv = export(IN_PROGRESS, &BTreeMap::new()).to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 1 + 2 + 2 + 1);
+        assert_eq!(
+            lines[4],
+            "2,Sync: offline,blocked,2026-10-13,2026-10-14,s2-1-1,s2-1,\"Retry queue, with backoff\",false,1h,,,,"
+        );
+        assert_eq!(lines[5], "3,Docs,ready,2026-10-12,2026-10-12,,,,,,,,,");
+    }
+
+    #[test]
+    fn test_ics_events() {
+        let ics = export(IN_PROGRESS, &BTreeMap::new()).to_ics();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 4);
+        assert!(ics.contains("UID:demo-app-sprint-2@nexus\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20261013\r\nDTEND;VALUE=DATE:20261015\r\n"));
+        assert!(ics.contains("SUMMARY:Sprint 2: Sync: offline\r\n"));
+        assert!(ics.contains("SUMMARY:Sync engine (s2-1)\r\n"));
+        assert!(ics.contains("CATEGORIES:backend\r\n"));
+        assert!(ics.lines().all(|line| line.len() <= 76));
+    }
+
+    #[test]
+    fn test_ics_text_and_folding() {
+        assert_eq!(ics_text("a, b; c\\d\ne"), "a\\, b\\; c\\\\d\\ne");
+        let folded = fold_ics_line(&"x".repeat(160));
+        let parts: Vec<&str> = folded.split("\r\n ").collect();
+        assert_eq!(parts.iter().map(|part| part.len()).collect::<Vec<_>>(), vec![75, 74, 11]);
+    }
+}
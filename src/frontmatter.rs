@@ -0,0 +1,290 @@
+//! Frontmatter - Note Properties of Planning Documents
+//!
+//! Obsidian keeps note metadata in a YAML block at the very top of a file:
+//!
+//! ```text
+//! ---
+//! status: draft
+//! owner: dana
+//! reviewed: 2026-10-01
+//! tags: [planning, mvp]
+//! ---
+//! ```
+//!
+//! The block is parsed separately from the body, so it never counts towards
+//! section word totals or placeholder checks. Only the subset Obsidian
+//! properties use is supported: one `key: value` per line, with lists written
+//! inline (`[a, b]`) or as `- item` lines below the key.
+
+use chrono::NaiveDate;
+use pulldown_cmark::{Event, Options, Parser};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// Value of a frontmatter field
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum FrontmatterValue {
+    /// A scalar, as written (quotes removed)
+    Text(String),
+    List(Vec<String>),
+}
+
+impl FrontmatterValue {
+    /// Whether the field has no value (`status:` or `tags: []`)
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Text(text) => text.is_empty(),
+            Self::List(items) => items.is_empty(),
+        }
+    }
+
+    /// Whether the value is `expected` (ignoring case), or a list containing it
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Self::Text(text) => text.eq_ignore_ascii_case(expected),
+            Self::List(items) => items.iter().any(|item| item.eq_ignore_ascii_case(expected)),
+        }
+    }
+}
+
+impl std::fmt::Display for FrontmatterValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) => f.write_str(text),
+            Self::List(items) => write!(f, "[{}]", items.join(", ")),
+        }
+    }
+}
+
+/// Parsed frontmatter of a document (empty when the document has none)
+///
+/// Serializes as a plain map of fields, so templates can use
+/// `frontmatter["01-Problem-and-Vision.md"].owner`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Frontmatter {
+    fields: BTreeMap<String, FrontmatterValue>,
+    /// Byte range of each field's `key: value` line
+    #[serde(skip)]
+    lines: BTreeMap<String, Range<usize>>,
+    /// Byte range of the whole block, delimiters included
+    #[serde(skip)]
+    block: Option<Range<usize>>,
+}
+
+impl Frontmatter {
+    /// Parses the frontmatter block at the top of a document
+    pub fn parse(content: &str) -> Self {
+        let Some((block, inner)) = find_block(content) else {
+            return Self::default();
+        };
+
+        let mut frontmatter = Self {
+            block: Some(block),
+            ..Self::default()
+        };
+        // Key of the field that `- item` lines belong to
+        let mut list_key: Option<String> = None;
+        let mut offset = inner.start;
+
+        for line in content[inner].split_inclusive('\n') {
+            let line_start = offset;
+            offset += line.len();
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if let Some(item) = trimmed.strip_prefix('-')
+                && (item.is_empty() || item.starts_with(' '))
+                && let Some(key) = &list_key
+            {
+                let item = unquote(item.trim());
+                match frontmatter.fields.get_mut(key) {
+                    Some(FrontmatterValue::List(items)) => items.push(item),
+                    _ => {
+                        frontmatter
+                            .fields
+                            .insert(key.clone(), FrontmatterValue::List(vec![item]));
+                    }
+                }
+                if let Some(range) = frontmatter.lines.get_mut(key) {
+                    range.end = line_start + line.trim_end().len();
+                }
+                continue;
+            }
+
+            // Continuation lines and nested maps are not supported
+            if line.starts_with([' ', '\t']) {
+                continue;
+            }
+            let Some((key, value)) = trimmed.split_once(':') else {
+                continue;
+            };
+            let key = unquote(key.trim());
+            let value = value.trim();
+            list_key = value.is_empty().then(|| key.clone());
+
+            let value = match value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                Some(items) => FrontmatterValue::List(
+                    items
+                        .split(',')
+                        .map(|item| unquote(item.trim()))
+                        .filter(|item| !item.is_empty())
+                        .collect(),
+                ),
+                None => FrontmatterValue::Text(unquote(value)),
+            };
+            frontmatter
+                .lines
+                .insert(key.clone(), line_start..line_start + line.trim_end().len());
+            frontmatter.fields.insert(key, value);
+        }
+
+        frontmatter
+    }
+
+    /// Whether the document starts with a frontmatter block
+    pub fn is_present(&self) -> bool {
+        self.block.is_some()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&FrontmatterValue> {
+        self.fields.get(key)
+    }
+
+    /// Scalar value of a field
+    pub fn text(&self, key: &str) -> Option<&str> {
+        match self.fields.get(key)? {
+            FrontmatterValue::Text(text) => Some(text),
+            FrontmatterValue::List(_) => None,
+        }
+    }
+
+    /// Date value of a field (`2026-10-01`, or the date part of a date-time)
+    pub fn date(&self, key: &str) -> Option<NaiveDate> {
+        let text = self.text(key)?;
+        NaiveDate::parse_from_str(text.get(..10)?, "%Y-%m-%d").ok()
+    }
+
+    /// Byte range of a field's line (through its last `- item` line for lists)
+    pub fn line(&self, key: &str) -> Option<Range<usize>> {
+        self.lines.get(key).cloned()
+    }
+}
+
+/// Byte offset where the document body starts (after the frontmatter, if any)
+pub fn body_start(content: &str) -> usize {
+    find_block(content).map_or(0, |(block, _)| block.end)
+}
+
+/// Markdown events of a document body, with offsets into the whole document
+///
+/// The frontmatter is left out: parsed as markdown, its closing `---` would
+/// turn the last property lines into a heading.
+pub fn body_events(
+    content: &str,
+    options: Options,
+) -> impl Iterator<Item = (Event<'_>, Range<usize>)> {
+    let start = body_start(content);
+    Parser::new_ext(&content[start..], options)
+        .into_offset_iter()
+        .map(move |(event, range)| (event, range.start + start..range.end + start))
+}
+
+/// The frontmatter block and the range between its delimiters
+///
+/// The block must open on the first line with `---` and close with a `---`
+/// (or `...`) line.
+fn find_block(content: &str) -> Option<(Range<usize>, Range<usize>)> {
+    let content_start = usize::from(content.starts_with('\u{feff}')) * '\u{feff}'.len_utf8();
+    let mut lines = content[content_start..].split_inclusive('\n');
+    let first = lines.next()?;
+    if first.trim_end() != "---" || !first.ends_with('\n') {
+        return None;
+    }
+
+    let inner_start = content_start + first.len();
+    let mut offset = inner_start;
+    for line in lines {
+        let delimiter = line.trim_end();
+        if delimiter == "---" || delimiter == "..." {
+            return Some((content_start..offset + line.len(), inner_start..offset));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Removes one pair of matching quotes around a scalar
+fn unquote(value: &str) -> String {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner.to_string();
+        }
+    }
+    value.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parses_properties() {
+        let content = "---\nstatus: Final\nowner: \"Dana Lee\"\nreviewed: 2026-10-01T09:30\ntags: [planning, 'mvp']\naliases:\n  - Vision\n  - Why\n---\n# Vision\n";
+        let frontmatter = Frontmatter::parse(content);
+
+        assert!(frontmatter.is_present());
+        assert_eq!(frontmatter.text("owner"), Some("Dana Lee"));
+        assert!(frontmatter.get("status").unwrap().matches("final"));
+        assert_eq!(
+            frontmatter.date("reviewed"),
+            NaiveDate::from_ymd_opt(2026, 10, 1)
+        );
+        assert_eq!(
+            frontmatter.get("tags"),
+            Some(&FrontmatterValue::List(vec!["planning".into(), "mvp".into()]))
+        );
+        assert!(frontmatter.get("aliases").unwrap().matches("why"));
+        assert_eq!(&content[frontmatter.line("aliases").unwrap()], "aliases:\n  - Vision\n  - Why");
+        assert_eq!(&content[body_start(content)..], "# Vision\n");
+        assert_eq!(
+            serde_json::to_value(&frontmatter).unwrap()["tags"],
+            serde_json::json!(["planning", "mvp"])
+        );
+    }
+
+    #[test]
+    fn test_only_a_leading_block_is_frontmatter() {
+        // Not at the top, or never closed
+        assert!(!Frontmatter::parse("# Title\n---\nstatus: draft\n---\n").is_present());
+        assert!(!Frontmatter::parse("---\nstatus: draft\n").is_present());
+        assert!(Frontmatter::parse("--- \nstatus: draft\n...\n").is_present());
+        assert_eq!(body_start("# Title\n---\nstatus: draft\n---\n"), 0);
+        assert_eq!(body_start("---\n---\nBody"), 8);
+    }
+
+    #[test]
+    fn test_body_events_skip_frontmatter() {
+        let content = "---\ntitle: Plan\nstatus: TODO\n---\n# Vision\nText\n";
+        let texts: Vec<(String, &str)> = body_events(content, Options::empty())
+            .filter_map(|(event, range)| match event {
+                Event::Text(text) => Some((text.to_string(), &content[range])),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                ("Vision".to_string(), "Vision"),
+                ("Text".to_string(), "Text")
+            ]
+        );
+    }
+}
@@ -0,0 +1,200 @@
+//! Gate Cache - Incremental Gate Runs
+//!
+//! Stores a content hash and the validation issues of each planning document in
+//! `.nexus/gate-cache.json`, so unchanged documents are not parsed again. Every
+//! entry is tied to a fingerprint of the heuristics and planning schema; when
+//! either changes the whole cache is discarded.
+
+use crate::consistency::ConsistencyFinding;
+use crate::heuristics::GateHeuristics;
+use crate::schema::planning::PlanningSchema;
+use crate::transclusion::SourcedIssue;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Cache location, relative to the project root
+pub const CACHE_FILE: &str = ".nexus/gate-cache.json";
+
+/// Bumped whenever the cached data layout changes
+const CACHE_FORMAT: u32 = 3;
+
+/// Cached outcome of one planning document
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct CachedDocument {
+    /// SHA-256 of the file contents and of every file it embeds
+    pub hash: String,
+    pub issues: Vec<SourcedIssue>,
+}
+
+/// Cached outcome of the cross-document consistency pass
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct CachedConsistency {
+    /// Hash over the content hashes of every document in the schema
+    pub hash: String,
+    pub findings: Vec<ConsistencyFinding>,
+}
+
+/// Contents of `.nexus/gate-cache.json`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct GateCache {
+    format: u32,
+    /// Fingerprint of the heuristics, schema and nexus version the entries were produced with
+    fingerprint: String,
+    /// Entries keyed by document file name
+    #[serde(default)]
+    documents: BTreeMap<String, CachedDocument>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    consistency: Option<CachedConsistency>,
+}
+
+impl GateCache {
+    /// Creates an empty cache for the given fingerprint
+    pub fn empty(fingerprint: impl Into<String>) -> Self {
+        Self {
+            format: CACHE_FORMAT,
+            fingerprint: fingerprint.into(),
+            documents: BTreeMap::new(),
+            consistency: None,
+        }
+    }
+
+    /// Loads the cache, or starts an empty one
+    ///
+    /// A missing or unreadable file, an older format, or a different
+    /// fingerprint all yield an empty cache; the cache never fails a gate run.
+    pub fn load(path: &Path, fingerprint: &str) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<Self>(&content).ok())
+            .filter(|cache| cache.format == CACHE_FORMAT && cache.fingerprint == fingerprint)
+            .unwrap_or_else(|| Self::empty(fingerprint))
+    }
+
+    /// Writes the cache, creating `.nexus/` if needed
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+        let content = serde_json::to_string_pretty(self).context("Failed to serialize gate cache")?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write gate cache: {}", path.display()))
+    }
+
+    /// Issues cached for a document, if its contents are unchanged
+    pub fn document(&self, file_name: &str, hash: &str) -> Option<&[SourcedIssue]> {
+        self.documents
+            .get(file_name)
+            .filter(|cached| cached.hash == hash)
+            .map(|cached| cached.issues.as_slice())
+    }
+
+    /// Replaces the cached documents with the ones checked in this run
+    ///
+    /// Documents that were not checked (deleted or renamed files) are dropped.
+    pub fn set_documents(&mut self, documents: BTreeMap<String, CachedDocument>) {
+        self.documents = documents;
+    }
+
+    /// Consistency findings cached for the given document set hash
+    pub fn consistency(&self, hash: &str) -> Option<&[ConsistencyFinding]> {
+        self.consistency
+            .as_ref()
+            .filter(|cached| cached.hash == hash)
+            .map(|cached| cached.findings.as_slice())
+    }
+
+    pub fn set_consistency(&mut self, hash: String, findings: Vec<ConsistencyFinding>) {
+        self.consistency = Some(CachedConsistency { hash, findings });
+    }
+}
+
+/// SHA-256 of some bytes, as lowercase hex
+pub fn content_hash(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Fingerprint of everything besides document contents that affects results
+pub fn fingerprint(heuristics: &GateHeuristics, schema: &PlanningSchema) -> Result<String> {
+    let rules = &heuristics.rules;
+    let heuristics = serde_json::to_string(heuristics).context("Failed to serialize heuristics")?;
+    let schema = serde_json::to_string(schema).context("Failed to serialize planning schema")?;
+    // Results of frontmatter age checks change with the date alone
+    let today = if rules.iter().any(|rule| !rule.max_age_days.is_empty()) {
+        chrono::Local::now().date_naive().to_string()
+    } else {
+        String::new()
+    };
+    Ok(content_hash(
+        format!("{}\n{heuristics}\n{schema}\n{today}", env!("CARGO_PKG_VERSION")).as_bytes(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn missing_header() -> SourcedIssue {
+        crate::planning::ValidationIssue::MissingHeader {
+            header: "Mitigations:".to_string(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(CACHE_FILE);
+
+        let mut cache = GateCache::empty("abc");
+        let hash = content_hash(b"# Risks\n");
+        cache.set_documents(BTreeMap::from([(
+            "06-Risks.md".to_string(),
+            CachedDocument {
+                hash: hash.clone(),
+                issues: vec![missing_header()],
+            },
+        )]));
+        cache.save(&path).unwrap();
+
+        let loaded = GateCache::load(&path, "abc");
+        assert_eq!(loaded, cache);
+        assert_eq!(loaded.document("06-Risks.md", &hash), Some(&[missing_header()][..]));
+        assert_eq!(loaded.document("06-Risks.md", &content_hash(b"changed")), None);
+    }
+
+    #[test]
+    fn test_cache_discarded_on_fingerprint_change_or_corruption() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(CACHE_FILE);
+
+        let mut cache = GateCache::empty("abc");
+        cache.set_consistency("set".to_string(), Vec::new());
+        cache.save(&path).unwrap();
+        assert!(GateCache::load(&path, "abc").consistency("set").is_some());
+        assert_eq!(GateCache::load(&path, "def"), GateCache::empty("def"));
+
+        std::fs::write(&path, "{ not json").unwrap();
+        assert_eq!(GateCache::load(&path, "abc"), GateCache::empty("abc"));
+    }
+
+    #[test]
+    fn test_fingerprint_tracks_heuristics_and_schema() {
+        let heuristics = crate::heuristics::GateHeuristics::default();
+        let schema = PlanningSchema::builtin().unwrap();
+        let base = fingerprint(&heuristics, &schema).unwrap();
+        assert_eq!(base, fingerprint(&heuristics, &schema).unwrap());
+
+        let mut stricter = heuristics.clone();
+        stricter.min_section_length += 1;
+        assert_ne!(base, fingerprint(&stricter, &schema).unwrap());
+
+        let mut fewer = schema.clone();
+        fewer.documents.pop();
+        assert_ne!(base, fingerprint(&heuristics, &fewer).unwrap());
+    }
+}
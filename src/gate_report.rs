@@ -0,0 +1,721 @@
+//! Gate Report - Structured Gate Results
+//!
+//! Collects the outcome of a gate run so it can be rendered as terminal text,
+//! JSON for scripts, or SARIF 2.1.0 for code-scanning annotations.
+
+use crate::heuristics::{Severity, SeverityPolicy};
+use crate::planning::{SourceSpan, ValidationIssue};
+use crate::transclusion::SourcedIssue;
+use crate::waivers::Waiver;
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use serde_json::{Value, json};
+use std::path::{Path, PathBuf};
+
+/// Output format for `nexus gate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum ReportFormat {
+    /// Human-readable terminal output
+    #[default]
+    Text,
+    /// Structured JSON report
+    Json,
+    /// SARIF 2.1.0 log for code-scanning tools
+    Sarif,
+}
+
+/// Group of related checks in a gate run (one terminal block)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportGroup {
+    Dashboard,
+    PlanningDocuments,
+    AdhocDashboard,
+    AdhocPlanningDocuments,
+    CrossDocument,
+    ActiveSprint,
+}
+
+impl ReportGroup {
+    /// Stable identifier used in machine-readable output
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::Dashboard => "dashboard",
+            Self::PlanningDocuments => "planning",
+            Self::AdhocDashboard => "adhoc_dashboard",
+            Self::AdhocPlanningDocuments => "adhoc_planning",
+            Self::CrossDocument => "cross_document",
+            Self::ActiveSprint => "active_sprint",
+        }
+    }
+
+    /// Heading printed above the group in text output
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Dashboard => "📋 SCANNING DASHBOARD...",
+            Self::PlanningDocuments => "📝 SCANNING PLANNING DOCUMENTS...",
+            Self::AdhocDashboard => "📋 SCANNING ADHOC DASHBOARD...",
+            Self::AdhocPlanningDocuments => "📝 SCANNING ADHOC PLANNING DOCUMENTS...",
+            Self::CrossDocument => "🔗 CHECKING CROSS-DOCUMENT CONSISTENCY...",
+            Self::ActiveSprint => "🎯 SCANNING ACTIVE SPRINT...",
+        }
+    }
+}
+
+/// A validation issue with the severity it was reported at
+#[derive(Debug, Clone)]
+pub struct ReportedIssue {
+    pub issue: ValidationIssue,
+    pub severity: Severity,
+    /// Index into `GateReport::waivers` of the active waiver suppressing this issue
+    pub waiver: Option<usize>,
+    /// Embedded file the issue's span points into (`None` for the document itself)
+    pub source: Option<PathBuf>,
+}
+
+impl ReportedIssue {
+    /// Whether the issue closes the gate
+    pub fn blocks(&self) -> bool {
+        self.severity == Severity::Error && self.waiver.is_none()
+    }
+}
+
+/// A waiver and what it did during a gate run
+#[derive(Debug, Clone)]
+pub struct WaiverStatus {
+    pub waiver: Waiver,
+    /// Whether the expiry date has passed (the waiver no longer applies)
+    pub expired: bool,
+    /// Number of issues the waiver suppressed
+    pub matched: usize,
+}
+
+/// Result of checking a single document (or a required file that is missing)
+#[derive(Debug, Clone)]
+pub struct DocumentReport {
+    /// Display name (usually the file name)
+    pub name: String,
+    /// Path to the checked file or directory
+    pub path: PathBuf,
+    /// Whether the primary check for this document passed
+    pub passed: bool,
+    /// One-line summary shown next to the pass/fail marker
+    pub headline: String,
+    /// Validation issues found in the document
+    pub issues: Vec<ReportedIssue>,
+    /// Additional failed checks that are not tied to a position
+    pub failures: Vec<String>,
+    /// Non-fatal warnings (e.g. very large files)
+    pub warnings: Vec<String>,
+    /// Extra hint lines shown below the headline
+    pub details: Vec<String>,
+}
+
+impl DocumentReport {
+    /// Creates a passing document report
+    pub fn passed(name: impl Into<String>, path: &Path, headline: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            path: path.to_path_buf(),
+            passed: true,
+            headline: headline.into(),
+            issues: Vec::new(),
+            failures: Vec::new(),
+            warnings: Vec::new(),
+            details: Vec::new(),
+        }
+    }
+
+    /// Creates a failing document report
+    pub fn failed(name: impl Into<String>, path: &Path, headline: impl Into<String>) -> Self {
+        Self {
+            passed: false,
+            ..Self::passed(name, path, headline)
+        }
+    }
+
+    /// Attaches validation issues to the report
+    ///
+    /// Issues start as errors; `GateReport::push` applies the run's severity policy.
+    pub fn with_issues(self, issues: Vec<ValidationIssue>) -> Self {
+        self.with_sourced_issues(issues.into_iter().map(SourcedIssue::from).collect())
+    }
+
+    /// Attaches validation issues that may point into embedded files
+    pub fn with_sourced_issues(mut self, issues: Vec<SourcedIssue>) -> Self {
+        self.issues = issues
+            .into_iter()
+            .map(|SourcedIssue { issue, source }| ReportedIssue {
+                issue,
+                severity: Severity::Error,
+                waiver: None,
+                source,
+            })
+            .collect();
+        self
+    }
+
+    /// Number of unwaived issues reported at the given severity
+    pub fn count(&self, severity: Severity) -> usize {
+        self.issues
+            .iter()
+            .filter(|i| i.severity == severity && i.waiver.is_none())
+            .count()
+    }
+
+    /// Recomputes `passed` from the issues; documents without issues keep their status
+    fn update_status(&mut self) {
+        if !self.issues.is_empty() {
+            self.passed = !self.issues.iter().any(ReportedIssue::blocks);
+        }
+    }
+
+    /// Records an additional failed check
+    pub fn add_failure(&mut self, message: impl Into<String>) {
+        self.failures.push(message.into());
+    }
+
+    /// Whether the document passed every check
+    pub fn is_passing(&self) -> bool {
+        self.passed && self.failures.is_empty()
+    }
+}
+
+/// Documents checked within one report group
+#[derive(Debug, Clone)]
+pub struct ReportSection {
+    pub group: ReportGroup,
+    pub documents: Vec<DocumentReport>,
+}
+
+/// Complete result of a gate run
+#[derive(Debug, Clone)]
+pub struct GateReport {
+    /// Project name from nexus.toml
+    pub project: String,
+    /// Lifecycle phase label (e.g. "PHASE 1: PLANNING")
+    pub phase: String,
+    /// Root used to relativize file paths in machine-readable output
+    pub root: PathBuf,
+    /// Informational notices (e.g. bootstrap heuristics created)
+    pub notices: Vec<String>,
+    /// Checked groups in the order they ran
+    pub sections: Vec<ReportSection>,
+    /// Severity policy applied to issues as documents are added
+    pub policy: SeverityPolicy,
+    /// Waivers considered in this run, active and expired
+    pub waivers: Vec<WaiverStatus>,
+}
+
+impl GateReport {
+    /// Creates an empty report
+    pub fn new(project: impl Into<String>, phase: impl Into<String>, root: &Path) -> Self {
+        Self {
+            project: project.into(),
+            phase: phase.into(),
+            root: root.to_path_buf(),
+            notices: Vec::new(),
+            sections: Vec::new(),
+            policy: SeverityPolicy::default(),
+            waivers: Vec::new(),
+        }
+    }
+
+    /// Starts a new group; subsequent documents are added to it
+    pub fn begin(&mut self, group: ReportGroup) {
+        self.sections.push(ReportSection {
+            group,
+            documents: Vec::new(),
+        });
+    }
+
+    /// Adds a document to the current group
+    ///
+    /// Issue severities are resolved here; a document whose issues are all
+    /// warnings or info (after strict mode) passes.
+    pub fn push(&mut self, mut document: DocumentReport) {
+        if !document.issues.is_empty() {
+            for reported in &mut document.issues {
+                reported.severity = self.policy.severity_of(reported.issue.rule_id());
+            }
+            document.update_status();
+        }
+        if self.sections.is_empty() {
+            self.begin(ReportGroup::PlanningDocuments);
+        }
+        if let Some(section) = self.sections.last_mut() {
+            section.documents.push(document);
+        }
+    }
+
+    /// Iterates over all documents in all groups
+    pub fn documents(&self) -> impl Iterator<Item = (ReportGroup, &DocumentReport)> {
+        self.sections
+            .iter()
+            .flat_map(|s| s.documents.iter().map(move |d| (s.group, d)))
+    }
+
+    /// Whether the gate is open (every document passed)
+    pub fn passed(&self) -> bool {
+        self.documents().all(|(_, d)| d.is_passing())
+    }
+
+    /// Applies waivers to every collected issue
+    ///
+    /// Active waivers suppress the issues they cover; expired waivers are kept
+    /// for reporting but suppress nothing, so their issues block again.
+    pub fn apply_waivers(&mut self, waivers: Vec<Waiver>, today: NaiveDate) {
+        self.waivers = waivers
+            .into_iter()
+            .map(|waiver| WaiverStatus {
+                expired: waiver.is_expired(today),
+                waiver,
+                matched: 0,
+            })
+            .collect();
+
+        for section in &mut self.sections {
+            for document in &mut section.documents {
+                let relative = relative_path(&self.root, &document.path);
+                let names = [document.name.as_str(), relative.as_str()];
+
+                for reported in &mut document.issues {
+                    reported.waiver = self.waivers.iter().position(|status| {
+                        !status.expired && status.waiver.covers(&names, &reported.issue)
+                    });
+                    if let Some(index) = reported.waiver {
+                        self.waivers[index].matched += 1;
+                    }
+                }
+                document.update_status();
+            }
+        }
+    }
+
+    /// Number of issues suppressed by active waivers
+    pub fn waived_count(&self) -> usize {
+        self.waivers.iter().map(|status| status.matched).sum()
+    }
+
+    /// Number of issues reported at the given severity across all documents
+    pub fn count(&self, severity: Severity) -> usize {
+        self.documents().map(|(_, d)| d.count(severity)).sum()
+    }
+
+    /// Path of a file relative to the report root, with forward slashes
+    fn display_path(&self, path: &Path) -> String {
+        relative_path(&self.root, path)
+    }
+
+    /// Renders the report as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        let documents: Vec<Value> = self
+            .documents()
+            .map(|(group, doc)| {
+                let file = self.display_path(&doc.path);
+                let issues: Vec<Value> = doc
+                    .issues
+                    .iter()
+                    .map(|reported| {
+                        let mut value = issue_to_json(reported, &file, &self.waivers);
+                        if let Some(source) = &reported.source {
+                            value["file"] = json!(self.display_path(source));
+                            value["embedded_in"] = json!(file);
+                        }
+                        value
+                    })
+                    .collect();
+                json!({
+                    "group": group.id(),
+                    "name": doc.name,
+                    "file": file,
+                    "passed": doc.is_passing(),
+                    "summary": doc.headline,
+                    "issues": issues,
+                    "failures": doc.failures,
+                    "warnings": doc.warnings,
+                })
+            })
+            .collect();
+
+        let failed_documents = self.documents().filter(|(_, d)| !d.is_passing()).count();
+        let issue_count: usize = self
+            .documents()
+            .map(|(_, d)| d.issues.len() + d.failures.len())
+            .sum();
+
+        let report = json!({
+            "tool": "nexus-gate",
+            "version": env!("CARGO_PKG_VERSION"),
+            "project": self.project,
+            "phase": self.phase,
+            "notices": self.notices,
+            "documents": documents,
+            "waivers": self.waivers.iter().map(waiver_to_json).collect::<Vec<_>>(),
+            "summary": {
+                "documents": documents.len(),
+                "failed_documents": failed_documents,
+                "issues": issue_count,
+                "errors": self.count(Severity::Error),
+                "warnings": self.count(Severity::Warning),
+                "info": self.count(Severity::Info),
+                "waived": self.waived_count(),
+            },
+            "verdict": if self.passed() { "passed" } else { "failed" },
+            "exit_code": self.exit_code(),
+        });
+
+        serde_json::to_string_pretty(&report).context("Failed to serialize gate report")
+    }
+
+    /// Renders the report as a SARIF 2.1.0 log
+    pub fn to_sarif(&self) -> Result<String> {
+        let mut results = Vec::new();
+
+        for (_, doc) in self.documents() {
+            let file = self.display_path(&doc.path);
+            for reported in &doc.issues {
+                let issue = &reported.issue;
+                let location = match &reported.source {
+                    Some(source) => self.display_path(source),
+                    None => file.clone(),
+                };
+                let mut result =
+                    sarif_result(issue.rule_id(), &issue.message(), &location, issue.span());
+                result["level"] = json!(sarif_level(reported.severity));
+                if let ValidationIssue::CrossDocumentConflict {
+                    relation,
+                    other_file,
+                    other_span,
+                    ..
+                } = issue
+                {
+                    let other = Path::new(&file).with_file_name(other_file);
+                    result["relatedLocations"] = json!([{
+                        "id": 1,
+                        "physicalLocation":
+                            sarif_physical_location(&other.to_string_lossy(), Some(other_span)),
+                        "message": { "text": format!("Term {relation} here") },
+                    }]);
+                }
+                if let Some(status) = reported.waiver.map(|index| &self.waivers[index]) {
+                    result["suppressions"] = json!([{
+                        "kind": "external",
+                        "status": "accepted",
+                        "justification": status.waiver.reason,
+                    }]);
+                }
+                results.push(result);
+            }
+            if !doc.passed && doc.issues.is_empty() {
+                results.push(sarif_result(
+                    DOCUMENT_FAILURE_RULE,
+                    &format!("{}: {}", doc.name, doc.headline),
+                    &file,
+                    None,
+                ));
+            }
+            for failure in &doc.failures {
+                results.push(sarif_result(
+                    DOCUMENT_FAILURE_RULE,
+                    &format!("{} {}", doc.name, failure),
+                    &file,
+                    None,
+                ));
+            }
+        }
+
+        // Built-in rules plus any custom heuristics rules that produced results
+        let mut rule_list: Vec<(&str, &str)> = BUILTIN_RULES.to_vec();
+        for (_, doc) in self.documents() {
+            for reported in &doc.issues {
+                if let ValidationIssue::RuleViolation { rule, .. } = &reported.issue
+                    && !rule_list.iter().any(|(id, _)| id == rule)
+                {
+                    rule_list.push((rule, "Custom gate heuristics rule"));
+                }
+            }
+        }
+
+        let rules: Vec<Value> = rule_list
+            .iter()
+            .map(|(id, description)| {
+                json!({
+                    "id": id,
+                    "shortDescription": { "text": description },
+                    "defaultConfiguration": { "level": "error" },
+                })
+            })
+            .collect();
+
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "nexus-gate",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    }
+                },
+                // Columns come from `LineIndex`, which counts chars, not UTF-16 units
+                "columnKind": "unicodeCodePoints",
+                "results": results,
+                "properties": {
+                    "project": self.project,
+                    "phase": self.phase,
+                    "verdict": if self.passed() { "passed" } else { "failed" },
+                },
+            }],
+        });
+
+        serde_json::to_string_pretty(&log).context("Failed to serialize SARIF report")
+    }
+
+    /// Process exit code for this report (see `commands::gate` exit codes)
+    pub fn exit_code(&self) -> i32 {
+        if self.passed() {
+            0
+        } else {
+            crate::commands::gate::EXIT_GATE_CLOSED
+        }
+    }
+}
+
+/// Rule used for documents that fail without a specific validation issue
+const DOCUMENT_FAILURE_RULE: &str = "document-failure";
+
+/// Built-in checks by rule id, as advertised in SARIF output
+///
+/// Custom heuristics rules cannot reuse these ids (see `GateHeuristics::compile_rules`).
+pub const BUILTIN_RULES: &[(&str, &str)] = &[
+    ("section-too-short", "Planning section has fewer words than required"),
+    ("missing-header", "Required planning header is missing"),
+    ("illegal-string", "Placeholder text indicates incomplete planning"),
+    ("unchecked-checkbox", "Task checkbox has not been completed"),
+    (
+        "cross-document-conflict",
+        "Term ruled out in one planning document is used in another",
+    ),
+    ("broken-link", "Wikilink or embed target does not exist in the vault"),
+    ("unknown-dependency", "Sprint or task dependency is not in the MVP breakdown"),
+    ("dependency-cycle", "Sprints or tasks depend on each other in a cycle"),
+    ("duplicate-task-id", "Task id is used more than once in the MVP breakdown"),
+    (
+        DOCUMENT_FAILURE_RULE,
+        "Planning document is missing, unreadable, or structurally invalid",
+    ),
+];
+
+/// Path relative to `root`, with forward slashes
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// SARIF result level for a severity
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// Serialize a waiver with its status for this run
+fn waiver_to_json(status: &WaiverStatus) -> Value {
+    let mut value = serde_json::to_value(&status.waiver).unwrap_or_else(|_| json!({}));
+    if let Value::Object(map) = &mut value {
+        map.insert(
+            "status".to_string(),
+            json!(if status.expired { "expired" } else { "active" }),
+        );
+        map.insert("matched".to_string(), json!(status.matched));
+    }
+    value
+}
+
+/// Serialize an issue with its location fields merged in
+fn issue_to_json(reported: &ReportedIssue, file: &str, waivers: &[WaiverStatus]) -> Value {
+    let issue = &reported.issue;
+    let mut value = serde_json::to_value(issue).unwrap_or_else(|_| json!({}));
+    if let Value::Object(map) = &mut value {
+        map.insert("rule".to_string(), json!(issue.rule_id()));
+        map.insert("severity".to_string(), json!(reported.severity.as_str()));
+        map.insert(
+            "waived".to_string(),
+            json!(reported.waiver.map(|index| &waivers[index].waiver.reason)),
+        );
+        map.insert("file".to_string(), json!(file));
+        let span = issue.span();
+        map.insert("line".to_string(), json!(span.map(|s| s.start.line)));
+        map.insert("column".to_string(), json!(span.map(|s| s.start.column)));
+        map.insert("end_line".to_string(), json!(span.map(|s| s.end.line)));
+        map.insert("end_column".to_string(), json!(span.map(|s| s.end.column)));
+        map.insert("section".to_string(), json!(issue.section()));
+        map.insert("message".to_string(), json!(issue.message()));
+    }
+    value
+}
+
+/// Build a single SARIF result object
+fn sarif_result(rule_id: &str, message: &str, file: &str, span: Option<&SourceSpan>) -> Value {
+    json!({
+        "ruleId": rule_id,
+        "level": "error",
+        "message": { "text": message },
+        "locations": [{ "physicalLocation": sarif_physical_location(file, span) }],
+    })
+}
+
+/// Build a SARIF physical location, with a region when the span is known
+fn sarif_physical_location(file: &str, span: Option<&SourceSpan>) -> Value {
+    let mut physical_location = json!({ "artifactLocation": { "uri": file } });
+    if let Some(span) = span {
+        physical_location["region"] = json!({
+            "startLine": span.start.line,
+            "startColumn": span.start.column,
+            "endLine": span.end.line,
+            "endColumn": span.end.column,
+            "byteOffset": span.start.offset,
+            "byteLength": span.len(),
+        });
+    }
+    physical_location
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planning::SourcePosition;
+
+    fn sample_span() -> SourceSpan {
+        SourceSpan {
+            start: SourcePosition {
+                offset: 120,
+                line: 7,
+                column: 3,
+            },
+            end: SourcePosition {
+                offset: 123,
+                line: 7,
+                column: 6,
+            },
+        }
+    }
+
+    fn sample_report() -> GateReport {
+        let root = Path::new("/vault");
+        let mut report = GateReport::new("demo", "PHASE 1: PLANNING", root);
+        report.begin(ReportGroup::Dashboard);
+        report.push(DocumentReport::passed(
+            "00-START-HERE.md",
+            &root.join("00-MANAGEMENT/00-START-HERE.md"),
+            "Dashboard clean - all tasks completed",
+        ));
+        report.begin(ReportGroup::PlanningDocuments);
+        report.push(
+            DocumentReport::failed(
+                "03-Tech-Stack.md",
+                &root.join("01-PLANNING/03-Tech-Stack.md"),
+                "03-Tech-Stack.md",
+            )
+            .with_issues(vec![ValidationIssue::IllegalString {
+                string: "TBD".to_string(),
+                context: "TBD".to_string(),
+                span: sample_span(),
+                section: Some("Stack (force yourself to choose NOW):".to_string()),
+            }]),
+        );
+        report
+    }
+
+    #[test]
+    fn test_report_verdict() {
+        let report = sample_report();
+        assert!(!report.passed());
+        assert_eq!(report.exit_code(), 1);
+
+        let mut passing = GateReport::new("demo", "PHASE 1: PLANNING", Path::new("/vault"));
+        passing.push(DocumentReport::passed("a.md", Path::new("/vault/a.md"), "a.md"));
+        assert!(passing.passed());
+        assert_eq!(passing.exit_code(), 0);
+    }
+
+    #[test]
+    fn test_policy_downgrades_issues_on_push() {
+        let heuristics: crate::heuristics::GateHeuristics = serde_json::from_value(json!({
+            "min_section_length": 10,
+            "required_headers": [],
+            "illegal_strings": [],
+            "management_files": { "dashboard": "a.md", "require_all_checked": true },
+            "severities": { "illegal-string": "warning" },
+        }))
+        .unwrap();
+
+        let mut relaxed = GateReport::new("demo", "PHASE 1: PLANNING", Path::new("/vault"));
+        relaxed.policy = heuristics.severity_policy(false);
+        for (_, doc) in sample_report().documents() {
+            relaxed.push(doc.clone());
+        }
+        assert!(relaxed.passed());
+        assert_eq!(relaxed.count(Severity::Warning), 1);
+
+        let mut strict = GateReport::new("demo", "PHASE 1: PLANNING", Path::new("/vault"));
+        strict.policy = heuristics.severity_policy(true);
+        for (_, doc) in sample_report().documents() {
+            strict.push(doc.clone());
+        }
+        assert!(!strict.passed());
+        assert_eq!(strict.count(Severity::Error), 1);
+    }
+
+    #[test]
+    fn test_failure_marks_document_failed() {
+        let mut doc = DocumentReport::passed("a.md", Path::new("a.md"), "a.md");
+        assert!(doc.is_passing());
+        doc.add_failure("missing risk mitigation checkboxes");
+        assert!(!doc.is_passing());
+    }
+
+    #[test]
+    fn test_json_report_structure() {
+        let json: Value = serde_json::from_str(&sample_report().to_json().unwrap()).unwrap();
+
+        assert_eq!(json["verdict"], "failed");
+        assert_eq!(json["exit_code"], 1);
+        assert_eq!(json["documents"].as_array().unwrap().len(), 2);
+
+        let issue = &json["documents"][1]["issues"][0];
+        assert_eq!(issue["kind"], "illegal_string");
+        assert_eq!(issue["severity"], "error");
+        assert_eq!(issue["file"], "01-PLANNING/03-Tech-Stack.md");
+        assert_eq!(issue["line"], 7);
+        assert_eq!(issue["column"], 3);
+        assert_eq!(issue["end_column"], 6);
+        assert_eq!(issue["section"], "Stack (force yourself to choose NOW):");
+    }
+
+    #[test]
+    fn test_sarif_report_structure() {
+        let sarif: Value = serde_json::from_str(&sample_report().to_sarif().unwrap()).unwrap();
+
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(sarif["runs"][0]["columnKind"], "unicodeCodePoints");
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "illegal-string");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(
+            location["artifactLocation"]["uri"],
+            "01-PLANNING/03-Tech-Stack.md"
+        );
+        assert_eq!(location["region"]["startLine"], 7);
+        assert_eq!(location["region"]["startColumn"], 3);
+        assert_eq!(location["region"]["endColumn"], 6);
+        assert_eq!(location["region"]["byteOffset"], 120);
+        assert_eq!(location["region"]["byteLength"], 3);
+    }
+}
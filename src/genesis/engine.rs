@@ -5,6 +5,7 @@
 use crate::catalyst::engine::parse_vision_document;
 use crate::genesis::{build_genesis_user_prompt, parse_genesis_response, get_genesis_system_prompt};
 use crate::llm::LlmClient;
+use crate::schema::planning::PlanningSchema;
 use anyhow::{Context, Result};
 use colored::Colorize;
 use dialoguer::{theme::ColorfulTheme, Select};
@@ -17,14 +18,17 @@ pub struct GenesisEngine {
     planning_path: PathBuf,
     /// LLM client for generation
     llm_client: LlmClient,
+    /// Planning schema; the first document is the vision, the rest are generated
+    schema: PlanningSchema,
 }
 
 impl GenesisEngine {
     /// Create a new Genesis engine
-    pub fn new(planning_path: PathBuf, llm_client: LlmClient) -> Result<Self> {
+    pub fn new(planning_path: PathBuf, llm_client: LlmClient, schema: PlanningSchema) -> Result<Self> {
         Ok(Self {
             planning_path,
             llm_client,
+            schema,
         })
     }
 
@@ -32,7 +36,7 @@ impl GenesisEngine {
     ///
     /// This method:
     /// 1. Loads the vision document
-    /// 2. Calls the LLM to generate all 4 documents
+    /// 2. Calls the LLM to generate every document after the vision
     /// 3. Presents each document for user review
     /// 4. Writes approved documents to the vault
     pub async fn execute_genesis(&self) -> Result<()> {
@@ -62,8 +66,8 @@ impl GenesisEngine {
         println!();
 
         // Build prompt
-        let user_prompt = build_genesis_user_prompt(&vision);
-        let system_prompt = get_genesis_system_prompt();
+        let user_prompt = build_genesis_user_prompt(&self.schema, &vision);
+        let system_prompt = get_genesis_system_prompt(&self.schema);
 
         println!("{}", "═══════════════════════════════════════".cyan());
         println!("{}", "  Phase 2: LLM Generation".cyan().bold());
@@ -106,13 +110,15 @@ impl GenesisEngine {
         println!();
 
         // Parse response into documents
-        let documents = parse_genesis_response(&response);
+        let documents = parse_genesis_response(&self.schema, &response);
+        let expected = self.schema.documents.len() - 1;
 
-        if documents.len() != 4 {
+        if documents.len() != expected {
             anyhow::bail!(
-                "LLM did not generate all 4 documents. Got {} documents. \
+                "LLM did not generate all {} documents. Got {} documents. \
                 Response may need refinement.\n\n\
                 Check the raw response above to see if the LLM used the correct ---NEXT_DOC--- separator.",
+                expected,
                 documents.len()
             );
         }
@@ -4,10 +4,10 @@
 //! They differ from Catalyst prompts in their architectural focus.
 
 use crate::catalyst::generator::VisionData;
-use crate::schema::planning::PLANNING_DOCUMENTS;
+use crate::schema::planning::PlanningSchema;
 
 /// The internal Genesis prompt (dynamically generated from schema)
-pub fn get_genesis_system_prompt() -> String {
+pub fn get_genesis_system_prompt(schema: &PlanningSchema) -> String {
     let mut prompt = String::from(r#"You are the Nexus Catalyst. You are performing a PROJECT GENESIS. I will provide a 'Problem & Vision' (Doc 01). You must synthesize the complete foundational skeleton.
 
 DIFFERENTIATION RULE: Do not treat this as a bug fix or a feature addition. This is the creation of a new system.
@@ -16,10 +16,10 @@ REQUIRED OUTPUT (Separated by ---NEXT_DOC---):
 "#);
 
     // Skip the first document (Vision) as it is the input
-    let docs_to_generate: Vec<_> = PLANNING_DOCUMENTS.iter().skip(1).collect();
+    let docs_to_generate: Vec<_> = schema.documents.iter().skip(1).collect();
 
     for (i, doc) in docs_to_generate.iter().enumerate() {
-        prompt.push_str(&format!("\n{}. {}: {}\n", i + 1, doc.filename, doc.template_guidance));
+        prompt.push_str(&format!("\n{}. {}: {}\n", i + 1, doc.filename, doc.guidance));
     }
 
     prompt.push_str(r#"
@@ -35,7 +35,7 @@ DOCUMENT STRUCTURE:
 
     for (i, doc) in docs_to_generate.iter().enumerate() {
         prompt.push_str(&format!("\n{}:\n", doc.filename));
-        for header in doc.required_headers {
+        for header in &doc.required_headers {
             prompt.push_str(&format!("## {}\n[Content...]\n\n", header));
         }
         if i < docs_to_generate.len() - 1 {
@@ -47,9 +47,9 @@ DOCUMENT STRUCTURE:
 }
 
 /// Build the user prompt for Genesis
-pub fn build_genesis_user_prompt(vision: &VisionData) -> String {
-    let docs_count = PLANNING_DOCUMENTS.len() - 1;
-    let last_doc_index = PLANNING_DOCUMENTS.len();
+pub fn build_genesis_user_prompt(schema: &PlanningSchema, vision: &VisionData) -> String {
+    let docs_count = schema.documents.len() - 1;
+    let last_doc_index = schema.documents.len();
 
     format!(
         r#"Based on this vision, generate the complete foundational skeleton (Docs 02-{:02}):
@@ -86,15 +86,16 @@ Generate all {} planning documents now, separated by ---NEXT_DOC---. Remember:
 /// Parse the LLM response into individual documents
 ///
 /// # Arguments
+/// * `schema` - Planning schema; its documents after the vision are expected in order
 /// * `response` - The raw LLM response containing all documents
 ///
 /// # Returns
 /// * A vector of (filename, content) tuples
-pub fn parse_genesis_response(response: &str) -> Vec<(String, String)> {
+pub fn parse_genesis_response(schema: &PlanningSchema, response: &str) -> Vec<(String, String)> {
     let documents: Vec<&str> = response.split("---NEXT_DOC---").collect();
 
     // Skip the first document (Vision)
-    let expected_docs: Vec<_> = PLANNING_DOCUMENTS.iter().skip(1).collect();
+    let expected_docs: Vec<_> = schema.documents.iter().skip(1).collect();
 
     documents
         .iter()
@@ -105,7 +106,7 @@ pub fn parse_genesis_response(response: &str) -> Vec<(String, String)> {
 
                 // Remove filename header if present (e.g., "03-Tech-Stack.md:")
                 // LLMs often include this when they see it in the prompt structure
-                let filename = expected_docs[idx].filename;
+                let filename = expected_docs[idx].filename.as_str();
                 if clean_content.starts_with(filename) {
                     // Remove the filename line
                     if let Some(newline_pos) = clean_content.find('\n') {
@@ -138,7 +139,7 @@ mod tests {
             anti_vision: "Test anti-vision".to_string(),
         };
 
-        let prompt = build_genesis_user_prompt(&vision);
+        let prompt = build_genesis_user_prompt(&PlanningSchema::builtin().unwrap(), &vision);
         assert!(prompt.contains("Test problem"));
         assert!(prompt.contains("PROJECT GENESIS"));
     }
@@ -157,7 +158,7 @@ Content 3
 # Doc 4
 Content 4"#;
 
-        let docs = parse_genesis_response(response);
+        let docs = parse_genesis_response(&PlanningSchema::builtin().unwrap(), response);
         assert_eq!(docs.len(), 4);
         assert_eq!(docs[0].0, "02-Scope-and-Boundaries.md");
         assert_eq!(docs[1].0, "03-Tech-Stack.md");
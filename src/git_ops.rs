@@ -1,10 +1,11 @@
 //! Git Operations for Sprint Management
 //!
-//! Provides utilities for creating and managing sprint branches using git2.
+//! Provides utilities for creating, merging and tagging sprint branches using git2.
 
 use anyhow::{Context, Result, bail};
-use git2::{BranchType, Repository};
-use std::path::Path;
+use git2::{BranchType, Oid, Repository, Signature, Sort, WorktreeAddOptions, WorktreePruneOptions};
+use std::fs;
+use std::path::{Path, PathBuf};
 
 /// Create a new sprint branch and switch to it
 ///
@@ -56,9 +57,529 @@ pub fn create_sprint_branch(repo_path: &Path, sprint_number: u32, sprint_name: &
     Ok(())
 }
 
+/// Create a sprint branch and check it out in a new git worktree
+///
+/// Unlike `create_sprint_branch`, the main working tree is left alone, so it
+/// may be dirty or stay on another branch (e.g., to hotfix the previous sprint).
+///
+/// # Arguments
+/// * `repo_path` - Path to the git repository
+/// * `sprint_number` - Sprint number (e.g., 4)
+/// * `sprint_name` - Sprint slug (e.g., "the-sprint-orchestrator")
+/// * `worktrees_dir` - Directory that holds the sprint worktrees
+///
+/// # Returns
+/// * `Ok(PathBuf)` - Path of the new worktree (`worktrees_dir/sprint-N-name`)
+/// * `Err` - If the branch or worktree already exists, or the git operation fails
+pub fn create_sprint_worktree(
+    repo_path: &Path,
+    sprint_number: u32,
+    sprint_name: &str,
+    worktrees_dir: &Path,
+) -> Result<PathBuf> {
+    let repo = Repository::open(repo_path)
+        .with_context(|| format!("Failed to open git repository at: {}", repo_path.display()))?;
+
+    let branch_name = format!("feature/sprint-{sprint_number}-{sprint_name}");
+    if branch_exists(&repo, &branch_name)? {
+        bail!(
+            "Branch '{branch_name}' already exists. Please delete it first or use a different sprint."
+        );
+    }
+    let worktree_name = format!("sprint-{sprint_number}");
+    if repo.find_worktree(&worktree_name).is_ok() {
+        bail!("Worktree '{worktree_name}' already exists. Remove it with: git worktree remove");
+    }
+    let worktree_path = worktrees_dir.join(format!("sprint-{sprint_number}-{sprint_name}"));
+    if worktree_path.exists() {
+        bail!("Worktree directory already exists: {}", worktree_path.display());
+    }
+    fs::create_dir_all(worktrees_dir).with_context(|| {
+        format!("Failed to create worktree directory: {}", worktrees_dir.display())
+    })?;
+
+    let head_commit = repo
+        .head()
+        .context("Failed to get HEAD reference")?
+        .peel_to_commit()
+        .context("Failed to peel HEAD to commit")?;
+    let branch = repo
+        .branch(&branch_name, &head_commit, false)
+        .with_context(|| format!("Failed to create branch '{branch_name}'"))?;
+
+    let mut options = WorktreeAddOptions::new();
+    options.reference(Some(branch.get()));
+    repo.worktree(&worktree_name, &worktree_path, Some(&options))
+        .with_context(|| format!("Failed to create worktree at: {}", worktree_path.display()))?;
+
+    Ok(worktree_path)
+}
+
+/// Remove a sprint worktree, refusing while it holds work that is not committed
+///
+/// Modified, staged and untracked files all block the removal, since they
+/// would be lost with the worktree. The branch itself is kept.
+///
+/// # Returns
+/// * `Ok(())` - Worktree directory deleted and its git metadata pruned
+/// * `Err` - If the worktree has uncommitted work or is not a worktree of the repository
+pub fn remove_sprint_worktree(repo_path: &Path, worktree_path: &Path) -> Result<()> {
+    let repo = Repository::open(repo_path)
+        .with_context(|| format!("Failed to open git repository at: {}", repo_path.display()))?;
+    let worktree = find_worktree(&repo, worktree_path)?.with_context(|| {
+        format!(
+            "{} is not a worktree of {}",
+            worktree_path.display(),
+            repo_path.display()
+        )
+    })?;
+
+    ensure_worktree_committed(worktree_path)?;
+
+    worktree
+        .prune(Some(
+            WorktreePruneOptions::new()
+                .valid(true)
+                .locked(false)
+                .working_tree(true),
+        ))
+        .with_context(|| format!("Failed to remove worktree at: {}", worktree_path.display()))?;
+    Ok(())
+}
+
+/// Check that a worktree holds no modified, staged or untracked files
+fn ensure_worktree_committed(worktree_path: &Path) -> Result<()> {
+    if !worktree_path.exists() {
+        return Ok(());
+    }
+    let worktree_repo = Repository::open(worktree_path)
+        .with_context(|| format!("Failed to open worktree at: {}", worktree_path.display()))?;
+    let mut options = git2::StatusOptions::new();
+    options.include_untracked(true).recurse_untracked_dirs(true);
+    let statuses = worktree_repo
+        .statuses(Some(&mut options))
+        .context("Failed to get worktree status")?;
+    let uncommitted: Vec<String> = statuses
+        .iter()
+        .filter(|entry| !entry.status().is_ignored())
+        .filter_map(|entry| entry.path().map(str::to_string))
+        .collect();
+    if !uncommitted.is_empty() {
+        bail!(
+            "Worktree {} has uncommitted work. Commit or remove it first.\nFiles:\n  {}",
+            worktree_path.display(),
+            uncommitted.join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+/// The worktree of a repository checked out at a path
+fn find_worktree(repo: &Repository, worktree_path: &Path) -> Result<Option<git2::Worktree>> {
+    let wanted = worktree_path
+        .canonicalize()
+        .unwrap_or_else(|_| worktree_path.to_path_buf());
+    for name in repo.worktrees()?.iter().flatten() {
+        let worktree = repo.find_worktree(name)?;
+        let path = worktree
+            .path()
+            .canonicalize()
+            .unwrap_or_else(|_| worktree.path().to_path_buf());
+        if path == wanted {
+            return Ok(Some(worktree));
+        }
+    }
+    Ok(None)
+}
+
+/// How a finished sprint branch is merged into the base branch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeStrategy {
+    /// Always record a merge commit, even when a fast-forward is possible
+    #[default]
+    MergeCommit,
+    /// Move the base branch up to the sprint branch; refused when they have diverged
+    FastForward,
+}
+
+/// How to finish a sprint branch
+#[derive(Debug, Clone)]
+pub struct FinishOptions {
+    /// Branch the sprint branch is merged into (e.g., "main")
+    pub base_branch: String,
+    pub strategy: MergeStrategy,
+    /// Message of the annotated `sprint-N` tag
+    pub tag_message: String,
+    /// Delete the sprint branch after merging
+    pub delete_branch: bool,
+    /// Worktree the sprint branch is checked out in; removed after merging
+    pub worktree: Option<PathBuf>,
+    /// Files (relative to the repository root, e.g. "nexus.toml") whose
+    /// uncommitted changes do not block the finish and are kept on the base branch
+    pub keep_changes: Vec<String>,
+}
+
+/// What finishing a sprint branch did
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinishOutcome {
+    /// Sprint branch that was merged
+    pub branch: String,
+    /// Commit the base branch points at after the merge
+    pub commit: String,
+    /// How the base branch was updated
+    pub merge: MergeKind,
+    /// Name of the annotated tag (`sprint-N`)
+    pub tag: String,
+    pub branch_deleted: bool,
+    pub worktree_removed: bool,
+}
+
+/// How the base branch was updated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeKind {
+    /// The base branch already contained the sprint branch
+    UpToDate,
+    FastForward,
+    MergeCommit,
+}
+
+/// Merge a sprint branch into the base branch, tag the result and optionally delete the branch
+///
+/// The merge is computed in memory first: on conflicts nothing is changed and
+/// the conflicting paths are reported. The tag is created before the base branch
+/// moves, so a failure leaves the branches as they were. Afterwards the base
+/// branch is checked out. A sprint worktree must have all its work committed;
+/// it is removed after the merge.
+///
+/// # Arguments
+/// * `repo_path` - Path to the git repository
+/// * `sprint_number` - Sprint number (e.g., 4)
+/// * `sprint_name` - Sprint slug (e.g., "the-sprint-orchestrator")
+/// * `options` - Base branch, merge strategy, tag message and branch cleanup
+///
+/// # Returns
+/// * `Ok(FinishOutcome)` - Branch merged and tagged
+/// * `Err` - If the repo is dirty, a branch is missing, the tag exists, the
+///   merge conflicts, or a fast-forward is impossible
+pub fn finish_sprint_branch(
+    repo_path: &Path,
+    sprint_number: u32,
+    sprint_name: &str,
+    options: &FinishOptions,
+) -> Result<FinishOutcome> {
+    let repo = Repository::open(repo_path)
+        .with_context(|| format!("Failed to open git repository at: {}", repo_path.display()))?;
+    let keep: Vec<&str> = options.keep_changes.iter().map(String::as_str).collect();
+    ensure_clean_except(&repo, &keep)?;
+    if let Some(worktree) = &options.worktree {
+        ensure_worktree_committed(worktree)?;
+    }
+
+    let branch_name = format!("feature/sprint-{sprint_number}-{sprint_name}");
+    let base_name = &options.base_branch;
+    let tag_name = format!("sprint-{sprint_number}");
+    if branch_name == *base_name {
+        bail!("The base branch cannot be the sprint branch '{branch_name}'");
+    }
+    if !branch_exists(&repo, &branch_name)? {
+        bail!("Sprint branch '{branch_name}' not found");
+    }
+    if !branch_exists(&repo, base_name)? {
+        bail!("Base branch '{base_name}' not found. Set [git] base_branch in nexus.toml or pass --base.");
+    }
+    if repo.refname_to_id(&format!("refs/tags/{tag_name}")).is_ok() {
+        bail!("Tag '{tag_name}' already exists");
+    }
+
+    let branch_commit = repo
+        .find_branch(&branch_name, BranchType::Local)?
+        .get()
+        .peel_to_commit()
+        .with_context(|| format!("Failed to read the last commit of '{branch_name}'"))?;
+    let base_ref = format!("refs/heads/{base_name}");
+    let base_commit = repo
+        .find_reference(&base_ref)?
+        .peel_to_commit()
+        .with_context(|| format!("Failed to read the last commit of '{base_name}'"))?;
+    let signature = signature(&repo)?;
+
+    let merge_base = repo
+        .merge_base(base_commit.id(), branch_commit.id())
+        .with_context(|| format!("'{branch_name}' and '{base_name}' have no common history"))?;
+    let (merged, merge) = if merge_base == branch_commit.id() {
+        (base_commit.id(), MergeKind::UpToDate)
+    } else if merge_base == base_commit.id() && options.strategy == MergeStrategy::FastForward {
+        (branch_commit.id(), MergeKind::FastForward)
+    } else if options.strategy == MergeStrategy::FastForward {
+        bail!(
+            "Cannot fast-forward '{base_name}' to '{branch_name}': the branches have diverged. \
+             Use the merge-commit strategy or rebase the sprint branch."
+        );
+    } else {
+        let mut index = repo
+            .merge_commits(&base_commit, &branch_commit, None)
+            .with_context(|| format!("Failed to merge '{branch_name}' into '{base_name}'"))?;
+        if index.has_conflicts() {
+            let mut paths: Vec<String> = index
+                .conflicts()?
+                .filter_map(|conflict| {
+                    let entry = conflict.ok()?;
+                    let entry = entry.our.or(entry.their).or(entry.ancestor)?;
+                    Some(String::from_utf8_lossy(&entry.path).into_owned())
+                })
+                .collect();
+            paths.sort();
+            paths.dedup();
+            bail!(
+                "Merging '{branch_name}' into '{base_name}' conflicts; nothing was changed.\n\
+                 Resolve the conflicts on the sprint branch (e.g., merge '{base_name}' into it) and run again.\n\
+                 Conflicting files:\n  {}",
+                paths.join("\n  ")
+            );
+        }
+        let tree_id = index
+            .write_tree_to(&repo)
+            .context("Failed to write the merged tree")?;
+        let tree = repo.find_tree(tree_id)?;
+        // Not on any branch yet: the base branch moves once the tag exists
+        let commit = repo
+            .commit(
+                None,
+                &signature,
+                &signature,
+                &format!("Merge branch '{branch_name}' into {base_name}"),
+                &tree,
+                &[&base_commit, &branch_commit],
+            )
+            .context("Failed to create the merge commit")?;
+        (commit, MergeKind::MergeCommit)
+    };
+
+    let merged_object = repo.find_object(merged, None)?;
+    repo.tag(&tag_name, &merged_object, &signature, &options.tag_message, false)
+        .with_context(|| format!("Failed to create tag '{tag_name}'"))?;
+    if merge != MergeKind::UpToDate {
+        let moved = repo.reference_matching(
+            &base_ref,
+            merged,
+            true,
+            base_commit.id(),
+            &format!("nexus: merge {branch_name} into {base_name}"),
+        );
+        if let Err(e) = moved {
+            let _ = repo.tag_delete(&tag_name);
+            return Err(e).with_context(|| format!("Failed to update '{base_name}'"));
+        }
+    }
+
+    // Kept files survive the forced checkout with their uncommitted contents
+    let kept: Vec<(PathBuf, Vec<u8>)> = options
+        .keep_changes
+        .iter()
+        .map(|file| repo_path.join(file))
+        .filter_map(|path| fs::read(&path).ok().map(|content| (path, content)))
+        .collect();
+    checkout_branch(&repo, base_name)?;
+    for (path, content) in kept {
+        fs::write(&path, content)
+            .with_context(|| format!("Failed to restore {}", path.display()))?;
+    }
+
+    // The branch cannot be deleted while a worktree has it checked out
+    if let Some(worktree) = &options.worktree {
+        remove_sprint_worktree(repo_path, worktree)?;
+    }
+    if options.delete_branch {
+        repo.find_branch(&branch_name, BranchType::Local)?
+            .delete()
+            .with_context(|| format!("Failed to delete branch '{branch_name}'"))?;
+    }
+
+    Ok(FinishOutcome {
+        branch: branch_name,
+        commit: merged.to_string(),
+        merge,
+        tag: tag_name,
+        branch_deleted: options.delete_branch,
+        worktree_removed: options.worktree.is_some(),
+    })
+}
+
+/// Count the commits a branch has of its own, i.e. that are not on another branch
+///
+/// # Arguments
+/// * `repo_path` - Path to the git repository
+/// * `branch_name` - Branch to inspect (e.g., "feature/sprint-4-the-sprint-orchestrator")
+/// * `since_branch` - Branch it was created from (e.g., "main")
+///
+/// # Returns
+/// * `Ok(Some(n))` - `branch_name` is `n` commits ahead of `since_branch`
+/// * `Ok(None)` - `branch_name` does not exist
+/// * `Err` - If `since_branch` does not exist or the git operation fails
+pub fn branch_commits_ahead(repo_path: &Path, branch_name: &str, since_branch: &str) -> Result<Option<usize>> {
+    let repo = Repository::open(repo_path)
+        .with_context(|| format!("Failed to open git repository at: {}", repo_path.display()))?;
+    if !branch_exists(&repo, branch_name)? {
+        return Ok(None);
+    }
+    if !branch_exists(&repo, since_branch)? {
+        bail!("Branch '{since_branch}' not found");
+    }
+
+    let branch_commit = repo
+        .find_branch(branch_name, BranchType::Local)?
+        .get()
+        .peel_to_commit()
+        .with_context(|| format!("Failed to read the last commit of '{branch_name}'"))?;
+    let since_commit = repo
+        .find_branch(since_branch, BranchType::Local)?
+        .get()
+        .peel_to_commit()
+        .with_context(|| format!("Failed to read the last commit of '{since_branch}'"))?;
+    let (ahead, _behind) = repo
+        .graph_ahead_behind(branch_commit.id(), since_commit.id())
+        .with_context(|| format!("Failed to compare '{branch_name}' with '{since_branch}'"))?;
+
+    Ok(Some(ahead))
+}
+
+/// Check out another branch, refusing while tracked files have uncommitted changes
+///
+/// Changes to the files in `discard` (paths relative to the repository root,
+/// e.g. "nexus.toml") do not block the switch; they are overwritten.
+///
+/// # Returns
+/// * `Ok(())` - Branch checked out
+/// * `Err` - If the repo is dirty, the branch does not exist, or the checkout fails
+pub fn switch_branch(repo_path: &Path, branch_name: &str, discard: &[&str]) -> Result<()> {
+    let repo = Repository::open(repo_path)
+        .with_context(|| format!("Failed to open git repository at: {}", repo_path.display()))?;
+    ensure_clean_except(&repo, discard)?;
+    checkout_branch(&repo, branch_name)
+}
+
+/// Delete a local branch, whether or not it has been merged
+///
+/// # Returns
+/// * `Ok(())` - Branch deleted
+/// * `Err` - If the branch does not exist or is checked out (in any worktree)
+pub fn delete_branch(repo_path: &Path, branch_name: &str) -> Result<()> {
+    let repo = Repository::open(repo_path)
+        .with_context(|| format!("Failed to open git repository at: {}", repo_path.display()))?;
+    repo.find_branch(branch_name, BranchType::Local)
+        .with_context(|| format!("Failed to find branch '{branch_name}'"))?
+        .delete()
+        .with_context(|| format!("Failed to delete branch '{branch_name}'"))
+}
+
+/// Commit that HEAD points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeadCommit {
+    /// Full commit SHA
+    pub sha: String,
+    /// Checked-out branch, `None` for a detached HEAD
+    pub branch: Option<String>,
+}
+
+/// Read the commit and branch of HEAD
+///
+/// # Returns
+/// * `Ok(HeadCommit)` - HEAD resolves to a commit
+/// * `Err` - If the repository cannot be opened or has no commits yet
+pub fn head_commit(repo_path: &Path) -> Result<HeadCommit> {
+    let repo = Repository::open(repo_path)
+        .with_context(|| format!("Failed to open git repository at: {}", repo_path.display()))?;
+    let head = repo.head().context("Failed to get HEAD reference (no commits yet?)")?;
+    let commit = head
+        .peel_to_commit()
+        .context("Failed to peel HEAD to commit")?;
+
+    Ok(HeadCommit {
+        sha: commit.id().to_string(),
+        branch: head
+            .is_branch()
+            .then(|| head.shorthand().map(str::to_string))
+            .flatten(),
+    })
+}
+
+/// One-line description of a commit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitSummary {
+    /// Abbreviated SHA (7 characters)
+    pub short_sha: String,
+    /// First line of the commit message
+    pub summary: String,
+}
+
+/// List the commits on HEAD made after another commit, newest first
+///
+/// # Arguments
+/// * `repo_path` - Path to the git repository (or a worktree of it)
+/// * `since` - SHA of the commit to start after; `None` lists every commit of HEAD
+///
+/// # Returns
+/// * `Ok(Vec<CommitSummary>)` - Commits reachable from HEAD but not from `since`
+/// * `Err` - If the repository has no commits or `since` is not a commit in it
+pub fn commits_since(repo_path: &Path, since: Option<&str>) -> Result<Vec<CommitSummary>> {
+    let repo = Repository::open(repo_path)
+        .with_context(|| format!("Failed to open git repository at: {}", repo_path.display()))?;
+    let mut revwalk = repo.revwalk().context("Failed to walk the commit history")?;
+    revwalk
+        .push_head()
+        .context("Failed to get HEAD reference (no commits yet?)")?;
+    if let Some(since) = since {
+        let oid = Oid::from_str(since)
+            .ok()
+            .and_then(|oid| repo.find_commit(oid).ok())
+            .with_context(|| format!("Commit {since} not found"))?
+            .id();
+        revwalk.hide(oid)?;
+    }
+    revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME)?;
+
+    revwalk
+        .map(|oid| {
+            let commit = repo.find_commit(oid?)?;
+            let sha = commit.id().to_string();
+            Ok(CommitSummary {
+                short_sha: sha[..7].to_string(),
+                summary: commit.summary().unwrap_or_default().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Best-effort name of the person running the command: git `user.name`, then `$USER`
+pub fn default_author() -> Option<String> {
+    git2::Config::open_default()
+        .ok()
+        .and_then(|config| config.get_string("user.name").ok())
+        .or_else(|| std::env::var("USER").ok())
+        .filter(|name| !name.trim().is_empty())
+}
+
+/// Signature for commits and tags: the repository's `user.name`/`user.email`, else nexus
+fn signature(repo: &Repository) -> Result<Signature<'static>> {
+    match repo.signature() {
+        Ok(signature) => Ok(signature),
+        Err(_) => Signature::now(
+            &default_author().unwrap_or_else(|| "nexus".to_string()),
+            "nexus@localhost",
+        )
+        .context("Failed to create a git signature"),
+    }
+}
+
 /// Check if the working directory is clean (no uncommitted changes to tracked files)
 /// Untracked files are allowed - we only care about modifications to existing files
 fn ensure_clean_working_directory(repo: &Repository) -> Result<()> {
+    ensure_clean_except(repo, &[])
+}
+
+/// Like `ensure_clean_working_directory`, but changes to the `ignored` paths are allowed
+fn ensure_clean_except(repo: &Repository, ignored: &[&str]) -> Result<()> {
     let statuses = repo
         .statuses(None)
         .context("Failed to get repository status")?;
@@ -67,18 +588,18 @@ fn ensure_clean_working_directory(repo: &Repository) -> Result<()> {
     for entry in statuses.iter() {
         let status = entry.status();
         // Ignore untracked files (WT_NEW) - only fail on modifications to tracked files
-        if status.is_wt_modified()
+        if (status.is_wt_modified()
             || status.is_wt_deleted()
             || status.is_wt_renamed()
             || status.is_wt_typechange()
             || status.is_index_modified()
             || status.is_index_deleted()
             || status.is_index_renamed()
-            || status.is_index_new()
+            || status.is_index_new())
+            && let Some(path) = entry.path()
+            && !ignored.contains(&path)
         {
-            if let Some(path) = entry.path() {
-                dirty_files.push(path.to_string());
-            }
+            dirty_files.push(path.to_string());
         }
     }
 
@@ -239,6 +760,221 @@ mod tests {
         assert!(result.is_err(), "Modified tracked files should fail");
     }
 
+    #[test]
+    fn test_head_commit() {
+        let (_temp, repo_path) = create_test_repo();
+        let repo = Repository::open(&repo_path).unwrap();
+        let sha = repo.head().unwrap().peel_to_commit().unwrap().id().to_string();
+
+        create_sprint_branch(&repo_path, 5, "export").unwrap();
+        let head = head_commit(&repo_path).unwrap();
+        assert_eq!(head.sha, sha);
+        assert_eq!(head.branch.as_deref(), Some("feature/sprint-5-export"));
+    }
+
+    /// Helper to commit a file on the checked-out branch
+    fn commit_file(repo_path: &Path, file: &str, content: &str) {
+        let repo = Repository::open(repo_path).unwrap();
+        fs::write(repo_path.join(file), content).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new(file)).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let parent = repo.head().unwrap().peel_to_commit().unwrap();
+        let signature = git2::Signature::now("Test User", "test@example.com").unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, file, &tree, &[&parent])
+            .unwrap();
+    }
+
+    fn finish_options(base_branch: &str, strategy: MergeStrategy) -> FinishOptions {
+        FinishOptions {
+            base_branch: base_branch.to_string(),
+            strategy,
+            tag_message: "Sprint 1: Test\n".to_string(),
+            delete_branch: false,
+            worktree: None,
+            keep_changes: Vec::new(),
+        }
+    }
+
+    fn current_branch(repo_path: &Path) -> String {
+        head_commit(repo_path).unwrap().branch.unwrap()
+    }
+
+    #[test]
+    fn test_finish_sprint_branch_fast_forward() {
+        let (_temp, repo_path) = create_test_repo();
+        let base = current_branch(&repo_path);
+        create_sprint_branch(&repo_path, 1, "test").unwrap();
+        commit_file(&repo_path, "feature.txt", "done\n");
+        let sprint_head = head_commit(&repo_path).unwrap().sha;
+
+        let mut options = finish_options(&base, MergeStrategy::FastForward);
+        options.delete_branch = true;
+        let outcome = finish_sprint_branch(&repo_path, 1, "test", &options).unwrap();
+
+        assert_eq!(outcome.merge, MergeKind::FastForward);
+        assert_eq!(outcome.commit, sprint_head);
+        assert_eq!(current_branch(&repo_path), base);
+        let repo = Repository::open(&repo_path).unwrap();
+        assert!(!branch_exists(&repo, "feature/sprint-1-test").unwrap());
+        let tag = repo
+            .find_reference("refs/tags/sprint-1")
+            .unwrap()
+            .peel_to_tag()
+            .unwrap();
+        assert_eq!(tag.message(), Some("Sprint 1: Test\n"));
+        assert_eq!(tag.target_id().to_string(), sprint_head);
+    }
+
+    #[test]
+    fn test_finish_sprint_branch_merge_commit() {
+        let (_temp, repo_path) = create_test_repo();
+        let base = current_branch(&repo_path);
+        create_sprint_branch(&repo_path, 1, "test").unwrap();
+        commit_file(&repo_path, "feature.txt", "done\n");
+        checkout_branch(&Repository::open(&repo_path).unwrap(), &base).unwrap();
+        commit_file(&repo_path, "hotfix.txt", "fixed\n");
+
+        // Diverged branches cannot be fast-forwarded
+        let error = finish_sprint_branch(&repo_path, 1, "test", &finish_options(&base, MergeStrategy::FastForward))
+            .unwrap_err();
+        assert!(error.to_string().contains("diverged"));
+
+        let outcome =
+            finish_sprint_branch(&repo_path, 1, "test", &finish_options(&base, MergeStrategy::MergeCommit))
+                .unwrap();
+        assert_eq!(outcome.merge, MergeKind::MergeCommit);
+        let repo = Repository::open(&repo_path).unwrap();
+        let merge = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(merge.parent_count(), 2);
+        assert!(repo_path.join("feature.txt").exists() && repo_path.join("hotfix.txt").exists());
+        assert!(branch_exists(&repo, "feature/sprint-1-test").unwrap());
+
+        // The tag exists now, so finishing again is refused
+        let error = finish_sprint_branch(&repo_path, 1, "test", &finish_options(&base, MergeStrategy::MergeCommit))
+            .unwrap_err();
+        assert!(error.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_finish_sprint_branch_conflict_changes_nothing() {
+        let (_temp, repo_path) = create_test_repo();
+        let base = current_branch(&repo_path);
+        create_sprint_branch(&repo_path, 1, "test").unwrap();
+        commit_file(&repo_path, "README.md", "# Sprint version\n");
+        checkout_branch(&Repository::open(&repo_path).unwrap(), &base).unwrap();
+        commit_file(&repo_path, "README.md", "# Base version\n");
+        let base_head = head_commit(&repo_path).unwrap().sha;
+
+        let error =
+            finish_sprint_branch(&repo_path, 1, "test", &finish_options(&base, MergeStrategy::MergeCommit))
+                .unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("conflicts"), "{message}");
+        assert!(message.contains("README.md"), "{message}");
+
+        let repo = Repository::open(&repo_path).unwrap();
+        assert_eq!(head_commit(&repo_path).unwrap().sha, base_head);
+        assert_eq!(repo.state(), git2::RepositoryState::Clean);
+        assert!(repo.find_reference("refs/tags/sprint-1").is_err());
+        assert_eq!(fs::read_to_string(repo_path.join("README.md")).unwrap(), "# Base version\n");
+        ensure_clean_working_directory(&repo).unwrap();
+    }
+
+    #[test]
+    fn test_sprint_worktree_lifecycle() {
+        let (_temp, repo_path) = create_test_repo();
+        let base = current_branch(&repo_path);
+        // A dirty main working tree does not matter in worktree mode
+        fs::write(repo_path.join("README.md"), "# Hotfix in progress\n").unwrap();
+
+        let worktrees_dir = repo_path.join(".nexus/worktrees");
+        let worktree = create_sprint_worktree(&repo_path, 2, "sync", &worktrees_dir).unwrap();
+        assert_eq!(worktree, worktrees_dir.join("sprint-2-sync"));
+        assert_eq!(current_branch(&repo_path), base);
+        assert_eq!(current_branch(&worktree), "feature/sprint-2-sync");
+        assert!(create_sprint_worktree(&repo_path, 2, "sync", &worktrees_dir).is_err());
+
+        // Untracked work in the worktree blocks its removal
+        fs::write(worktree.join("notes.txt"), "draft\n").unwrap();
+        let error = remove_sprint_worktree(&repo_path, &worktree).unwrap_err();
+        assert!(error.to_string().contains("notes.txt"), "{error}");
+
+        fs::remove_file(worktree.join("notes.txt")).unwrap();
+        remove_sprint_worktree(&repo_path, &worktree).unwrap();
+        assert!(!worktree.exists());
+        let repo = Repository::open(&repo_path).unwrap();
+        assert!(repo.worktrees().unwrap().is_empty());
+        assert!(branch_exists(&repo, "feature/sprint-2-sync").unwrap());
+    }
+
+    #[test]
+    fn test_finish_sprint_branch_removes_worktree() {
+        let (_temp, repo_path) = create_test_repo();
+        let base = current_branch(&repo_path);
+        let worktree =
+            create_sprint_worktree(&repo_path, 1, "test", &repo_path.join(".nexus/worktrees")).unwrap();
+        commit_file(&worktree, "feature.txt", "done\n");
+
+        let mut options = finish_options(&base, MergeStrategy::MergeCommit);
+        options.worktree = Some(worktree.clone());
+        options.delete_branch = true;
+        let outcome = finish_sprint_branch(&repo_path, 1, "test", &options).unwrap();
+
+        assert!(outcome.worktree_removed && outcome.branch_deleted);
+        assert!(!worktree.exists());
+        assert!(repo_path.join("feature.txt").exists());
+        let repo = Repository::open(&repo_path).unwrap();
+        assert!(!branch_exists(&repo, "feature/sprint-1-test").unwrap());
+    }
+
+    #[test]
+    fn test_commits_since() {
+        let (_temp_dir, repo_path) = create_test_repo();
+        let start = head_commit(&repo_path).unwrap().sha;
+        assert!(commits_since(&repo_path, Some(&start)).unwrap().is_empty());
+        assert_eq!(commits_since(&repo_path, None).unwrap().len(), 1);
+
+        commit_file(&repo_path, "a.txt", "a\n");
+        commit_file(&repo_path, "b.txt", "b\n");
+        let commits = commits_since(&repo_path, Some(&start)).unwrap();
+        let summaries: Vec<&str> = commits.iter().map(|commit| commit.summary.as_str()).collect();
+        assert_eq!(summaries, ["b.txt", "a.txt"]);
+        assert_eq!(commits[0].short_sha, head_commit(&repo_path).unwrap().sha[..7]);
+        assert!(commits_since(&repo_path, Some("0123456789abcdef0123456789abcdef01234567")).is_err());
+    }
+
+    #[test]
+    fn test_abort_sprint_branch() {
+        let (_temp_dir, repo_path) = create_test_repo();
+        let base = current_branch(&repo_path);
+        let branch = "feature/sprint-4-abort";
+        create_sprint_branch(&repo_path, 4, "abort").unwrap();
+        assert_eq!(branch_commits_ahead(&repo_path, branch, &base).unwrap(), Some(0));
+
+        commit_file(&repo_path, "work.txt", "sprint work\n");
+        assert_eq!(branch_commits_ahead(&repo_path, branch, &base).unwrap(), Some(1));
+
+        // Uncommitted changes block the switch
+        fs::write(repo_path.join("work.txt"), "more work\n").unwrap();
+        assert!(switch_branch(&repo_path, &base, &[]).is_err());
+        assert_eq!(current_branch(&repo_path), branch);
+        fs::write(repo_path.join("README.md"), "# Changed\n").unwrap();
+        fs::write(repo_path.join("work.txt"), "sprint work\n").unwrap();
+
+        // The checked-out branch cannot be deleted
+        assert!(delete_branch(&repo_path, branch).is_err());
+        switch_branch(&repo_path, &base, &["README.md"]).unwrap();
+        assert_eq!(current_branch(&repo_path), base);
+        assert!(!repo_path.join("work.txt").exists());
+        assert_eq!(fs::read_to_string(repo_path.join("README.md")).unwrap(), "# Test Project\n");
+
+        delete_branch(&repo_path, branch).unwrap();
+        assert_eq!(branch_commits_ahead(&repo_path, branch, &base).unwrap(), None);
+        assert!(branch_commits_ahead(&repo_path, &base, "develop").is_err());
+    }
+
     #[test]
     fn test_branch_exists() {
         let (_temp, repo_path) = create_test_repo();
@@ -2,11 +2,20 @@
 //!
 //! Parses and loads validation rules from Gate-Heuristics.json.
 //! Defines the constraints used to validate planning documents.
+//!
+//! Two profiles are supported:
+//! - **Legacy (version 1)**: the flat format with one global `min_section_length`,
+//!   `required_headers` and literal `illegal_strings`. Files without a `version` field load as this.
+//! - **Rules (version 2)**: the flat fields plus a `rules` array of scoped checks
+//!   (per file glob and per section) with regex patterns, word limits, list and code block requirements.
 
-use anyhow::{Context, Result};
+use crate::gate_report::BUILTIN_RULES;
+use anyhow::{Context, Result, bail};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Management file configuration for dashboard validation
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
@@ -17,9 +26,212 @@ pub struct ManagementFiles {
     pub require_all_checked: bool,
 }
 
+/// Version written by `create_bootstrap_heuristics` and the newest version this build understands
+pub const HEURISTICS_VERSION: u32 = 2;
+
+/// Version assumed for heuristics files without a `version` field
+const LEGACY_HEURISTICS_VERSION: u32 = 1;
+
+const fn legacy_version() -> u32 {
+    LEGACY_HEURISTICS_VERSION
+}
+
+/// How much a failed check matters
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Shown for information only, never blocks
+    Info,
+    /// Blocks only in strict mode
+    Warning,
+    /// Always blocks the gate
+    #[default]
+    Error,
+}
+
+impl Severity {
+    /// Lowercase name used in reports
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+
+    fn is_error(&self) -> bool {
+        *self == Self::Error
+    }
+}
+
+/// Resolves the effective severity of each check for one gate run
+#[derive(Debug, Clone, Default)]
+pub struct SeverityPolicy {
+    /// Severity per rule id (built-in checks and custom rules); unlisted rules are errors
+    levels: HashMap<String, Severity>,
+    /// Whether warnings are promoted to errors (`gate.strict_mode`)
+    strict: bool,
+}
+
+impl SeverityPolicy {
+    /// Effective severity for a rule id after applying strict mode
+    pub fn severity_of(&self, rule_id: &str) -> Severity {
+        match self.levels.get(rule_id).copied().unwrap_or_default() {
+            Severity::Warning if self.strict => Severity::Error,
+            severity => severity,
+        }
+    }
+}
+
+/// A scoped validation rule (heuristics version 2)
+///
+/// A rule applies to every planning document whose file name matches `file`
+/// (all documents when omitted) and, when `section` is set, only to the content
+/// under the matching header, including its subsections. Every check that is set
+/// must hold; each failing check is reported separately.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct GateRule {
+    /// Stable identifier shown in reports (e.g. "success-criteria-measurable")
+    pub id: String,
+    /// File name or glob (`*`, `?`) the rule applies to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Header the rule is scoped to (matched like `required_headers`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    /// Regex that must match somewhere in the scope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    /// Regex that must not match anywhere in the scope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbid: Option<String>,
+    /// Minimum number of words in the scope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_words: Option<usize>,
+    /// Maximum number of words in the scope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_words: Option<usize>,
+    /// Minimum number of list items in the scope
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_list_items: Option<usize>,
+    /// Whether the scope must contain a fenced code block
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub require_code_block: bool,
+    /// Frontmatter fields that must be present with a value (e.g. `["owner"]`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub require_fields: Vec<String>,
+    /// Frontmatter fields that must have a given value (e.g. `{"status": "final"}`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub field_values: BTreeMap<String, String>,
+    /// Frontmatter date fields that must be at most this many days old (e.g. `{"reviewed": 30}`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub max_age_days: BTreeMap<String, u32>,
+    /// Message shown instead of the generated one when the rule fails
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// How a failure of this rule is treated
+    #[serde(default, skip_serializing_if = "Severity::is_error")]
+    pub severity: Severity,
+}
+
+impl GateRule {
+    /// Whether the rule defines at least one check
+    fn has_checks(&self) -> bool {
+        self.pattern.is_some()
+            || self.forbid.is_some()
+            || self.min_words.is_some()
+            || self.max_words.is_some()
+            || self.min_list_items.is_some()
+            || self.require_code_block
+            || !self.require_fields.is_empty()
+            || !self.field_values.is_empty()
+            || !self.max_age_days.is_empty()
+    }
+}
+
+/// A rule with its file glob and patterns compiled, ready for evaluation
+#[derive(Debug, Clone)]
+pub struct CompiledRule {
+    pub rule: GateRule,
+    file: Option<Regex>,
+    pub pattern: Option<Regex>,
+    pub forbid: Option<Regex>,
+}
+
+impl CompiledRule {
+    /// Compiles a rule, reporting which rule and field is invalid
+    pub fn compile(rule: &GateRule) -> Result<Self> {
+        if rule.id.trim().is_empty() {
+            bail!("Heuristics rule is missing an 'id'");
+        }
+        if !rule.has_checks() {
+            bail!(
+                "Heuristics rule '{}' has no checks (set pattern, forbid, min_words, max_words, min_list_items, require_code_block, require_fields, field_values or max_age_days)",
+                rule.id
+            );
+        }
+        if let (Some(min), Some(max)) = (rule.min_words, rule.max_words)
+            && min > max
+        {
+            bail!(
+                "Heuristics rule '{}' has min_words ({min}) greater than max_words ({max})",
+                rule.id
+            );
+        }
+
+        let compile = |field: &str, pattern: &str| {
+            Regex::new(pattern).with_context(|| {
+                format!("Invalid regex in '{field}' of heuristics rule '{}'", rule.id)
+            })
+        };
+
+        Ok(Self {
+            rule: rule.clone(),
+            file: rule
+                .file
+                .as_deref()
+                .map(|glob| compile("file", &glob_to_regex(glob)))
+                .transpose()?,
+            pattern: rule
+                .pattern
+                .as_deref()
+                .map(|p| compile("pattern", p))
+                .transpose()?,
+            forbid: rule
+                .forbid
+                .as_deref()
+                .map(|p| compile("forbid", p))
+                .transpose()?,
+        })
+    }
+
+    /// Whether the rule applies to the given planning file name
+    pub fn applies_to(&self, file_name: &str) -> bool {
+        self.file.as_ref().is_none_or(|glob| glob.is_match(file_name))
+    }
+}
+
+/// Converts a simple file glob (`*`, `?`) into an anchored regex
+fn glob_to_regex(glob: &str) -> String {
+    let mut pattern = String::from("^");
+    for c in glob.chars() {
+        match c {
+            '*' => pattern.push_str(".*"),
+            '?' => pattern.push('.'),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+    pattern
+}
+
 /// Root heuristics configuration
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct GateHeuristics {
+    /// Format version; files without one load as the legacy (version 1) profile
+    #[serde(default = "legacy_version")]
+    pub version: u32,
     /// Minimum word count for each planning section
     pub min_section_length: u32,
     /// Headers that must be present in planning documents
@@ -28,12 +240,73 @@ pub struct GateHeuristics {
     pub illegal_strings: Vec<String>,
     /// Management file validation rules
     pub management_files: ManagementFiles,
+    /// Severity overrides for built-in checks, keyed by rule id (e.g. "section-too-short")
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub severities: BTreeMap<String, Severity>,
+    /// Scoped rules (version 2 and later)
+    #[serde(default)]
+    pub rules: Vec<GateRule>,
+}
+
+impl GateHeuristics {
+    /// Checks the version and compiles all rules
+    ///
+    /// # Returns
+    /// * `Ok(Vec<CompiledRule>)` - Rules ready for evaluation (empty for legacy profiles)
+    /// * `Err` - Unsupported version, rules in a legacy file, or an invalid rule
+    ///   (including one that reuses the id of a built-in check)
+    pub fn compile_rules(&self) -> Result<Vec<CompiledRule>> {
+        if self.version > HEURISTICS_VERSION {
+            bail!(
+                "Unsupported heuristics version {} (this nexus supports up to {HEURISTICS_VERSION})",
+                self.version
+            );
+        }
+        if self.version < 2 && !self.rules.is_empty() {
+            bail!("Heuristics 'rules' require \"version\": {HEURISTICS_VERSION}");
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        self.rules
+            .iter()
+            .map(|rule| {
+                if !seen.insert(rule.id.as_str()) {
+                    bail!("Duplicate heuristics rule id '{}'", rule.id);
+                }
+                // Its severity, waivers and SARIF entry would apply to the built-in check
+                if BUILTIN_RULES.iter().any(|(id, _)| *id == rule.id) {
+                    bail!(
+                        "Heuristics rule id '{}' is a built-in check; use \"severities\" to change its severity",
+                        rule.id
+                    );
+                }
+                CompiledRule::compile(rule)
+            })
+            .collect()
+    }
+
+    /// Builds the severity policy for a gate run
+    ///
+    /// # Arguments
+    /// * `strict` - `gate.strict_mode` from nexus.toml; promotes warnings to errors
+    pub fn severity_policy(&self, strict: bool) -> SeverityPolicy {
+        let mut levels: HashMap<String, Severity> = self
+            .severities
+            .iter()
+            .map(|(id, severity)| (id.clone(), *severity))
+            .collect();
+        for rule in &self.rules {
+            levels.insert(rule.id.clone(), rule.severity);
+        }
+        SeverityPolicy { levels, strict }
+    }
 }
 
 impl Default for GateHeuristics {
     /// Provides hardcoded fallback values matching Gate-Heuristics.json
     fn default() -> Self {
         Self {
+            version: HEURISTICS_VERSION,
             min_section_length: 15,
             required_headers: vec![
                 "My problem (personal):".to_string(),
@@ -55,35 +328,525 @@ impl Default for GateHeuristics {
                 dashboard: "00-START-HERE.md".to_string(),
                 require_all_checked: true,
             },
+            severities: BTreeMap::new(),
+            rules: Vec::new(),
         }
     }
 }
 
-/// Loads heuristics from JSON file at the specified path
-///
-/// # Arguments
-/// * `path` - Path to the Gate-Heuristics.json file
+/// Layer name for values no heuristics file overrides
+pub const BUILT_IN_LAYER: &str = "built-in";
+/// Layer name of `default.json` in the profile directory, inherited by every project
+pub const GLOBAL_LAYER: &str = "global";
+/// Layer name of the project's own heuristics file
+pub const PROJECT_LAYER: &str = "project";
+/// File in the profile directory that every project inherits
+const GLOBAL_PROFILE_FILE: &str = "default.json";
+
+/// Override for a list of strings in a heuristics layer
 ///
-/// # Returns
-/// * `Ok(GateHeuristics)` - Successfully parsed heuristics
-/// * `Err` - File not found, invalid JSON, or deserialization error
+/// A JSON array replaces the inherited list; an object with `add` and/or
+/// `remove` edits it (e.g. `{ "add": ["WIP"] }` keeps the inherited strings).
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ListPatch {
+    Replace(Vec<String>),
+    Edit(ListEdit),
+}
+
+/// Entries to add to and remove from an inherited list
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ListEdit {
+    #[serde(default)]
+    pub add: Vec<String>,
+    #[serde(default)]
+    pub remove: Vec<String>,
+}
+
+/// Override for individual `management_files` fields
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ManagementFilesPatch {
+    pub dashboard: Option<String>,
+    pub require_all_checked: Option<bool>,
+}
+
+/// One heuristics file in a layered configuration
 ///
-/// # Example
-/// ```no_run
-/// use std::path::Path;
-/// use nexus_cli::heuristics::load_heuristics;
+/// Every field is optional: unset fields are inherited from the layer below
+/// (the profile named in `extends`, or ultimately the built-in defaults).
+/// Scalars replace, `severities` merge per rule id, and `rules` merge by id.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HeuristicsLayer {
+    /// Profile name (`<profile dir>/<name>.json`) or path of the layer this one extends
+    pub extends: Option<String>,
+    pub version: Option<u32>,
+    pub min_section_length: Option<u32>,
+    pub required_headers: Option<ListPatch>,
+    pub illegal_strings: Option<ListPatch>,
+    pub management_files: Option<ManagementFilesPatch>,
+    #[serde(default)]
+    pub severities: BTreeMap<String, Severity>,
+    #[serde(default)]
+    pub rules: Vec<GateRule>,
+    /// Ids of inherited rules to drop
+    #[serde(default)]
+    pub remove_rules: Vec<String>,
+}
+
+impl HeuristicsLayer {
+    /// Checks the parts of a layer that can be validated on their own
+    fn validate(&self) -> Result<()> {
+        let version = self.version.unwrap_or(LEGACY_HEURISTICS_VERSION);
+        if version > HEURISTICS_VERSION {
+            bail!(
+                "Unsupported heuristics version {version} (this nexus supports up to {HEURISTICS_VERSION})"
+            );
+        }
+        if version < 2 && !self.rules.is_empty() {
+            bail!("Heuristics 'rules' require \"version\": {HEURISTICS_VERSION}");
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        for rule in &self.rules {
+            if !seen.insert(rule.id.as_str()) {
+                bail!("Duplicate heuristics rule id '{}'", rule.id);
+            }
+            CompiledRule::compile(rule)?;
+        }
+        Ok(())
+    }
+}
+
+/// Where inherited heuristics layers come from
+#[derive(Debug, Clone, Default)]
+pub struct HeuristicsProfiles {
+    /// Directory with shared profiles; its `default.json` applies to every project
+    pub profile_dir: Option<PathBuf>,
+    /// Profile from `gate.extends` in nexus.toml, used when the project file has no `extends`
+    pub extends: Option<String>,
+    /// Directory that relative `extends` paths from nexus.toml resolve against
+    pub project_root: PathBuf,
+}
+
+impl HeuristicsProfiles {
+    /// Whether any layer sits between the built-in defaults and the project file
+    pub fn inherits(&self) -> bool {
+        self.extends.is_some()
+            || self
+                .profile_dir
+                .as_ref()
+                .is_some_and(|dir| dir.join(GLOBAL_PROFILE_FILE).exists())
+    }
+}
+
+/// Shared profile directory: `~/.config/nexus/heuristics`
+pub fn default_profile_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config/nexus/heuristics"))
+}
+
+/// A heuristics file that contributed to the effective configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerSource {
+    /// Name shown by `--explain-config` ("global", a profile name, "project")
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// One effective setting and the layer it came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectiveSetting {
+    /// Setting name (e.g. "min_section_length" or "illegal_strings")
+    pub key: String,
+    /// The value, or one entry when `entry` is set
+    pub value: String,
+    /// Whether this is one entry of a list or map setting
+    pub entry: bool,
+    pub layer: String,
+}
+
+/// Heuristics after merging every layer, with the origin of each value
+#[derive(Debug, Clone)]
+pub struct ResolvedHeuristics {
+    pub heuristics: GateHeuristics,
+    /// Files applied on top of the built-in defaults, lowest precedence first
+    pub layers: Vec<LayerSource>,
+    /// Layer name per setting key (`min_section_length`, `illegal_strings/TODO`, ...)
+    origins: HashMap<String, String>,
+}
+
+impl ResolvedHeuristics {
+    /// Layer that set the value behind a setting key
+    fn origin(&self, key: &str) -> String {
+        self.origins
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| BUILT_IN_LAYER.to_string())
+    }
+
+    /// Every effective setting in display order
+    pub fn settings(&self) -> Vec<EffectiveSetting> {
+        let h = &self.heuristics;
+        let mut settings = Vec::new();
+        let mut scalar = |key: &str, value: String| {
+            settings.push(EffectiveSetting {
+                key: key.to_string(),
+                value,
+                entry: false,
+                layer: self.origin(key),
+            });
+        };
+        scalar("version", h.version.to_string());
+        scalar("min_section_length", h.min_section_length.to_string());
+        scalar("management_files.dashboard", h.management_files.dashboard.clone());
+        scalar(
+            "management_files.require_all_checked",
+            h.management_files.require_all_checked.to_string(),
+        );
+
+        let lists: [(&str, Vec<(String, String)>); 4] = [
+            (
+                "required_headers",
+                h.required_headers.iter().map(|v| (v.clone(), v.clone())).collect(),
+            ),
+            (
+                "illegal_strings",
+                h.illegal_strings.iter().map(|v| (v.clone(), v.clone())).collect(),
+            ),
+            (
+                "severities",
+                h.severities
+                    .iter()
+                    .map(|(id, severity)| (id.clone(), format!("{id} = {}", severity.as_str())))
+                    .collect(),
+            ),
+            (
+                "rules",
+                h.rules
+                    .iter()
+                    .map(|rule| (rule.id.clone(), describe_rule(rule)))
+                    .collect(),
+            ),
+        ];
+        for (key, entries) in lists {
+            if entries.is_empty() {
+                settings.push(EffectiveSetting {
+                    key: key.to_string(),
+                    value: "(none)".to_string(),
+                    entry: false,
+                    layer: self.origin(key),
+                });
+            }
+            for (id, value) in entries {
+                settings.push(EffectiveSetting {
+                    key: key.to_string(),
+                    value,
+                    entry: true,
+                    layer: self.origin(&format!("{key}/{id}")),
+                });
+            }
+        }
+        settings
+    }
+}
+
+/// Rule id with its scope, e.g. "vision-measurable (01-*.md › Vision)"
+fn describe_rule(rule: &GateRule) -> String {
+    let scope: Vec<&str> = [rule.file.as_deref(), rule.section.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect();
+    if scope.is_empty() {
+        rule.id.clone()
+    } else {
+        format!("{} ({})", rule.id, scope.join(" › "))
+    }
+}
+
+/// Loads a heuristics file and, first, every layer it extends
 ///
-/// let heuristics = load_heuristics(Path::new("Gate-Heuristics.json"))?;
-/// # Ok::<(), anyhow::Error>(())
-/// ```
-pub fn load_heuristics(path: &Path) -> Result<GateHeuristics> {
-    let file = File::open(path)
+/// # Arguments
+/// * `path` - Heuristics file to load
+/// * `name` - Layer name for `--explain-config`
+/// * `inherited` - Reference (and its base directory) to extend when the file has no `extends`
+/// * `profile_dir` - Directory named profiles resolve against
+/// * `stack` - Files currently being resolved, for cycle detection
+/// * `layers` - Loaded layers, lowest precedence first
+fn load_layer_chain(
+    path: &Path,
+    name: String,
+    inherited: Option<(&str, &Path)>,
+    profile_dir: Option<&Path>,
+    stack: &mut Vec<PathBuf>,
+    layers: &mut Vec<(LayerSource, HeuristicsLayer)>,
+) -> Result<()> {
+    let canonical = std::fs::canonicalize(path)
         .with_context(|| format!("Failed to open heuristics file: {}", path.display()))?;
+    if stack.contains(&canonical) {
+        let chain: Vec<String> = stack
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|p| p.display().to_string())
+            .collect();
+        bail!("Heuristics 'extends' cycle: {}", chain.join(" → "));
+    }
+    // A profile reached through two chains applies once, at its lowest position
+    if layers.iter().any(|(source, _)| source.path == canonical) {
+        return Ok(());
+    }
 
-    let heuristics: GateHeuristics = serde_json::from_reader(file)
+    let file = File::open(path)
+        .with_context(|| format!("Failed to open heuristics file: {}", path.display()))?;
+    let layer: HeuristicsLayer = serde_json::from_reader(file)
         .with_context(|| format!("Failed to parse heuristics JSON: {}", path.display()))?;
+    layer
+        .validate()
+        .with_context(|| format!("Invalid heuristics rules in: {}", path.display()))?;
+
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let parent = layer
+        .extends
+        .as_deref()
+        .map(|reference| (reference, base_dir))
+        .or(inherited);
+    if let Some((reference, base_dir)) = parent {
+        let (parent_path, parent_name) = resolve_profile_reference(reference, base_dir, profile_dir)
+            .with_context(|| format!("Failed to resolve 'extends' in: {}", path.display()))?;
+        stack.push(canonical.clone());
+        let result = load_layer_chain(&parent_path, parent_name, None, profile_dir, stack, layers);
+        stack.pop();
+        result?;
+    }
+
+    layers.push((
+        LayerSource {
+            name,
+            path: canonical,
+        },
+        layer,
+    ));
+    Ok(())
+}
+
+/// Resolves an `extends` value to a file and a layer name
+///
+/// Values ending in `.json` or containing `/` are paths (relative to `base_dir`,
+/// `~/` expands to the home directory); anything else names a profile in `profile_dir`.
+fn resolve_profile_reference(
+    reference: &str,
+    base_dir: &Path,
+    profile_dir: Option<&Path>,
+) -> Result<(PathBuf, String)> {
+    if reference.ends_with(".json") || reference.contains('/') {
+        let path = match reference.strip_prefix("~/") {
+            Some(rest) => PathBuf::from(
+                std::env::var_os("HOME").context("Could not determine HOME directory")?,
+            )
+            .join(rest),
+            None => base_dir.join(reference),
+        };
+        let name = path
+            .file_stem()
+            .map_or_else(|| reference.to_string(), |stem| stem.to_string_lossy().to_string());
+        return Ok((path, name));
+    }
+
+    let dir = profile_dir.context("Could not determine the heuristics profile directory")?;
+    let path = dir.join(format!("{reference}.json"));
+    if !path.exists() {
+        bail!("Heuristics profile '{reference}' not found at: {}", path.display());
+    }
+    Ok((path, reference.to_string()))
+}
 
-    Ok(heuristics)
+/// Merges layers into the built-in defaults, recording where each value came from
+struct LayerMerge {
+    heuristics: GateHeuristics,
+    origins: HashMap<String, String>,
+    declared_version: Option<(u32, String)>,
+}
+
+impl LayerMerge {
+    fn apply(&mut self, layer: &HeuristicsLayer, name: &str) -> Result<()> {
+        let h = &mut self.heuristics;
+        let origins = &mut self.origins;
+        let mut set = |key: &str| {
+            origins.insert(key.to_string(), name.to_string());
+        };
+
+        if let Some(version) = layer.version
+            && self.declared_version.as_ref().is_none_or(|(v, _)| version >= *v)
+        {
+            self.declared_version = Some((version, name.to_string()));
+        }
+        if let Some(min) = layer.min_section_length {
+            h.min_section_length = min;
+            set("min_section_length");
+        }
+        if let Some(patch) = &layer.management_files {
+            if let Some(dashboard) = &patch.dashboard {
+                h.management_files.dashboard.clone_from(dashboard);
+                set("management_files.dashboard");
+            }
+            if let Some(require) = patch.require_all_checked {
+                h.management_files.require_all_checked = require;
+                set("management_files.require_all_checked");
+            }
+        }
+        for (id, severity) in &layer.severities {
+            h.severities.insert(id.clone(), *severity);
+            set(&format!("severities/{id}"));
+        }
+
+        if let Some(patch) = &layer.required_headers {
+            apply_list_patch(&mut h.required_headers, patch, "required_headers", name, origins);
+        }
+        if let Some(patch) = &layer.illegal_strings {
+            apply_list_patch(&mut h.illegal_strings, patch, "illegal_strings", name, origins);
+        }
+
+        for id in &layer.remove_rules {
+            let before = h.rules.len();
+            h.rules.retain(|rule| &rule.id != id);
+            if h.rules.len() == before {
+                bail!("'remove_rules' names rule '{id}', which no inherited layer defines");
+            }
+            origins.remove(&format!("rules/{id}"));
+        }
+        for rule in &layer.rules {
+            match h.rules.iter_mut().find(|existing| existing.id == rule.id) {
+                Some(existing) => *existing = rule.clone(),
+                None => h.rules.push(rule.clone()),
+            }
+            origins.insert(format!("rules/{}", rule.id), name.to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Applies a list override, tracking the origin of each entry as `<key>/<entry>`
+fn apply_list_patch(
+    list: &mut Vec<String>,
+    patch: &ListPatch,
+    key: &str,
+    layer: &str,
+    origins: &mut HashMap<String, String>,
+) {
+    match patch {
+        ListPatch::Replace(values) => {
+            for old in list.iter() {
+                origins.remove(&format!("{key}/{old}"));
+            }
+            list.clone_from(values);
+            origins.insert(key.to_string(), layer.to_string());
+            for value in values {
+                origins.insert(format!("{key}/{value}"), layer.to_string());
+            }
+        }
+        ListPatch::Edit(edit) => {
+            list.retain(|value| !edit.remove.contains(value));
+            for value in &edit.remove {
+                origins.remove(&format!("{key}/{value}"));
+            }
+            for value in &edit.add {
+                if !list.contains(value) {
+                    list.push(value.clone());
+                    origins.insert(format!("{key}/{value}"), layer.to_string());
+                }
+            }
+            if list.is_empty() {
+                origins.insert(key.to_string(), layer.to_string());
+            }
+        }
+    }
+}
+
+/// Resolves the effective heuristics from every layer
+///
+/// Layers, lowest precedence first: built-in defaults, `default.json` in the
+/// profile directory, the profile chain named by `extends` (in the project file,
+/// or `gate.extends` in nexus.toml), and the project file itself.
+///
+/// # Arguments
+/// * `project_file` - The project's heuristics file, if it exists
+/// * `profiles` - Where inherited layers come from
+///
+/// # Returns
+/// * `Ok(ResolvedHeuristics)` - Merged heuristics with the origin of each value
+/// * `Err` - Unreadable or invalid layer, unknown profile, or an `extends` cycle
+pub fn resolve_heuristics(
+    project_file: Option<&Path>,
+    profiles: &HeuristicsProfiles,
+) -> Result<ResolvedHeuristics> {
+    let profile_dir = profiles.profile_dir.as_deref();
+    let mut layers = Vec::new();
+    let mut stack = Vec::new();
+
+    if let Some(dir) = profile_dir {
+        let global = dir.join(GLOBAL_PROFILE_FILE);
+        if global.exists() {
+            load_layer_chain(&global, GLOBAL_LAYER.to_string(), None, profile_dir, &mut stack, &mut layers)?;
+        }
+    }
+
+    let inherited = profiles
+        .extends
+        .as_deref()
+        .map(|reference| (reference, profiles.project_root.as_path()));
+    match project_file {
+        Some(path) => load_layer_chain(
+            path,
+            PROJECT_LAYER.to_string(),
+            inherited,
+            profile_dir,
+            &mut stack,
+            &mut layers,
+        )?,
+        None => {
+            if let Some((reference, base_dir)) = inherited {
+                let (path, name) = resolve_profile_reference(reference, base_dir, profile_dir)
+                    .context("Failed to resolve 'gate.extends' from nexus.toml")?;
+                load_layer_chain(&path, name, None, profile_dir, &mut stack, &mut layers)?;
+            }
+        }
+    }
+
+    let mut merge = LayerMerge {
+        heuristics: GateHeuristics::default(),
+        origins: HashMap::new(),
+        declared_version: None,
+    };
+    for (source, layer) in &layers {
+        merge
+            .apply(layer, &source.name)
+            .with_context(|| format!("Invalid heuristics layer: {}", source.path.display()))?;
+    }
+
+    // Files that never declare a version are the legacy profile
+    let mut heuristics = merge.heuristics;
+    let mut origins = merge.origins;
+    match merge.declared_version {
+        Some((version, layer)) => {
+            heuristics.version = version;
+            origins.insert("version".to_string(), layer);
+        }
+        None => {
+            if let Some((source, _)) = layers.last() {
+                heuristics.version = LEGACY_HEURISTICS_VERSION;
+                origins.insert("version".to_string(), source.name.clone());
+            }
+        }
+    }
+    heuristics
+        .compile_rules()
+        .context("Invalid heuristics rules after merging layers")?;
+
+    Ok(ResolvedHeuristics {
+        heuristics,
+        layers: layers.into_iter().map(|(source, _)| source).collect(),
+        origins,
+    })
 }
 
 /// Create and save bootstrap heuristics to the specified path
@@ -118,44 +881,78 @@ pub fn create_bootstrap_heuristics(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Create a minimal project heuristics file that inherits everything
+///
+/// Used instead of the full bootstrap when a profile is inherited, so the
+/// bootstrap does not shadow the team's settings.
+pub fn create_bootstrap_override(path: &Path) -> Result<()> {
+    use std::fs;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+
+    let json = serde_json::to_string_pretty(&serde_json::json!({ "version": HEURISTICS_VERSION }))
+        .context("Failed to serialize bootstrap heuristics")?;
+    fs::write(path, json)
+        .with_context(|| format!("Failed to write bootstrap heuristics to: {}", path.display()))?;
+
+    Ok(())
+}
+
 /// Load heuristics with smart fallback:
 /// 1. Try stable path (`.nexus/gate-heuristics.json`)
 /// 2. Try legacy path (from config)
-/// 3. Create bootstrap heuristics at stable path and return defaults
+/// 3. Create bootstrap heuristics at stable path
+///
+/// The chosen file is the project layer; inherited profiles are merged below it.
 ///
 /// # Arguments
 /// * `stable_path` - Preferred stable location
 /// * `legacy_path` - Optional legacy location from config
+/// * `profiles` - Where inherited layers come from
 ///
 /// # Returns
-/// * `Ok(GateHeuristics)` - Loaded or created heuristics
-/// * `Err` - Critical failure (directory creation failed, etc.)
+/// * `Ok(ResolvedHeuristics)` - Loaded or created heuristics
+/// * `Err` - Critical failure (directory creation failed, invalid layer, etc.)
 pub fn load_heuristics_with_fallback(
     stable_path: &Path,
     legacy_path: Option<&Path>,
-) -> Result<GateHeuristics> {
+    profiles: &HeuristicsProfiles,
+) -> Result<ResolvedHeuristics> {
     // Try stable path first
     if stable_path.exists() {
-        return load_heuristics(stable_path);
+        return resolve_heuristics(Some(stable_path), profiles);
     }
 
     // Try legacy path if provided
     if let Some(legacy) = legacy_path && legacy.exists() {
-        return load_heuristics(legacy);
+        return resolve_heuristics(Some(legacy), profiles);
     }
 
     // Neither exists - create bootstrap at stable path
-    create_bootstrap_heuristics(stable_path)
-        .context("Failed to create bootstrap heuristics")?;
+    if profiles.inherits() {
+        create_bootstrap_override(stable_path)
+    } else {
+        create_bootstrap_heuristics(stable_path)
+    }
+    .context("Failed to create bootstrap heuristics")?;
 
-    Ok(GateHeuristics::default())
+    resolve_heuristics(Some(stable_path), profiles)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::io::Write;
-    use tempfile::NamedTempFile;
+    use tempfile::{NamedTempFile, TempDir};
+
+    /// Loads a single heuristics file with no inherited profiles
+    fn load_heuristics(path: &Path) -> Result<GateHeuristics> {
+        resolve_heuristics(Some(path), &HeuristicsProfiles::default())
+            .map(|resolved| resolved.heuristics)
+    }
 
     #[test]
     fn test_default_heuristics() {
@@ -240,9 +1037,214 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn write_heuristics(json: &str) -> NamedTempFile {
+        let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+        temp_file
+            .write_all(json.as_bytes())
+            .expect("Failed to write to temp file");
+        temp_file
+    }
+
+    const FLAT_FIELDS: &str = r#""min_section_length": 10,
+  "required_headers": [],
+  "illegal_strings": ["TODO"],
+  "management_files": { "dashboard": "00-START-HERE.md", "require_all_checked": true }"#;
+
+    #[test]
+    fn test_flat_format_loads_as_legacy_profile() {
+        let temp_file = write_heuristics(&format!("{{ {FLAT_FIELDS} }}"));
+
+        let heuristics = load_heuristics(temp_file.path()).unwrap();
+
+        assert_eq!(heuristics.version, 1);
+        assert!(heuristics.rules.is_empty());
+        assert!(heuristics.compile_rules().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_load_versioned_rules() {
+        let temp_file = write_heuristics(&format!(
+            r#"{{ "version": 2, {FLAT_FIELDS}, "rules": [
+  {{ "id": "measurable", "file": "01-*.md", "section": "Success criteria",
+     "pattern": "\\d", "message": "Success criteria need a number or date" }},
+  {{ "id": "no-hedging", "file": "03-Tech-Stack.md", "forbid": "(?i)\\b(maybe|probably)\\b" }},
+  {{ "id": "folder-tree", "section": "Folder structure", "require_code_block": true }}
+] }}"#
+        ));
+
+        let heuristics = load_heuristics(temp_file.path()).unwrap();
+        let rules = heuristics.compile_rules().unwrap();
+
+        assert_eq!(heuristics.version, 2);
+        assert_eq!(rules.len(), 3);
+        assert!(rules[0].applies_to("01-Problem-and-Vision.md"));
+        assert!(!rules[0].applies_to("03-Tech-Stack.md"));
+        assert!(rules[1].applies_to("03-Tech-Stack.md"));
+        assert!(rules[2].applies_to("04-Architecture.md"));
+        assert!(rules[1].forbid.as_ref().unwrap().is_match("Probably Postgres"));
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        let cases = [
+            (r#""version": 2"#, r#"{ "id": "bad", "pattern": "(" }"#, "Invalid regex"),
+            (r#""version": 2"#, r#"{ "id": "empty" }"#, "has no checks"),
+            (
+                r#""version": 2"#,
+                r#"{ "id": "range", "min_words": 10, "max_words": 5 }"#,
+                "greater than max_words",
+            ),
+            (r#""version": 1"#, r#"{ "id": "old", "min_words": 5 }"#, "require"),
+            (r#""version": 9"#, r#"{ "id": "new", "min_words": 5 }"#, "Unsupported"),
+        ];
+
+        for (version, rule, expected) in cases {
+            let temp_file =
+                write_heuristics(&format!(r#"{{ {version}, {FLAT_FIELDS}, "rules": [{rule}] }}"#));
+            let error = format!("{:#}", load_heuristics(temp_file.path()).unwrap_err());
+            assert!(error.contains(expected), "{rule}: {error}");
+        }
+    }
+
+    #[test]
+    fn test_severity_policy_strict_mode() {
+        let temp_file = write_heuristics(&format!(
+            r#"{{ "version": 2, {FLAT_FIELDS},
+  "severities": {{ "section-too-short": "warning", "illegal-string": "info" }},
+  "rules": [{{ "id": "thin-approach", "min_words": 100, "severity": "warning" }}] }}"#
+        ));
+        let heuristics = load_heuristics(temp_file.path()).unwrap();
+
+        let relaxed = heuristics.severity_policy(false);
+        assert_eq!(relaxed.severity_of("section-too-short"), Severity::Warning);
+        assert_eq!(relaxed.severity_of("thin-approach"), Severity::Warning);
+        assert_eq!(relaxed.severity_of("illegal-string"), Severity::Info);
+        assert_eq!(relaxed.severity_of("missing-header"), Severity::Error);
+
+        // Strict mode promotes warnings but leaves info alone
+        let strict = heuristics.severity_policy(true);
+        assert_eq!(strict.severity_of("section-too-short"), Severity::Error);
+        assert_eq!(strict.severity_of("thin-approach"), Severity::Error);
+        assert_eq!(strict.severity_of("illegal-string"), Severity::Info);
+    }
+
+    #[test]
+    fn test_custom_rule_cannot_reuse_builtin_id() {
+        let temp_file = write_heuristics(&format!(
+            r#"{{ "version": 2, {FLAT_FIELDS},
+  "rules": [{{ "id": "missing-header", "min_words": 1, "severity": "info" }}] }}"#
+        ));
+        let error = format!("{:#}", load_heuristics(temp_file.path()).unwrap_err());
+        assert!(error.contains("'missing-header' is a built-in check"), "{error}");
+    }
+
     #[test]
     fn test_load_heuristics_missing_file() {
         let result = load_heuristics(Path::new("/nonexistent/file.json"));
         assert!(result.is_err());
     }
+
+    /// Writes `<dir>/<name>` and returns its path
+    fn write_layer(dir: &Path, name: &str, json: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, json).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_layers_merge_with_origins() {
+        let temp_dir = TempDir::new().unwrap();
+        let profile_dir = temp_dir.path().join("profiles");
+        write_layer(&profile_dir, "default.json", r#"{ "min_section_length": 30 }"#);
+        write_layer(
+            &profile_dir,
+            "team.json",
+            r#"{ "version": 2,
+  "illegal_strings": ["TODO", "TBD"],
+  "severities": { "section-too-short": "warning" },
+  "rules": [{ "id": "no-maybe", "forbid": "maybe" }, { "id": "folder-tree", "section": "Folder structure", "require_code_block": true }] }"#,
+        );
+        let project = write_layer(
+            temp_dir.path(),
+            ".nexus/gate-heuristics.json",
+            r#"{ "extends": "team",
+  "illegal_strings": { "add": ["WIP"], "remove": ["TBD"] },
+  "management_files": { "require_all_checked": false },
+  "remove_rules": ["folder-tree"] }"#,
+        );
+
+        let profiles = HeuristicsProfiles {
+            profile_dir: Some(profile_dir),
+            extends: None,
+            project_root: temp_dir.path().to_path_buf(),
+        };
+        let resolved = resolve_heuristics(Some(&project), &profiles).unwrap();
+        let h = &resolved.heuristics;
+
+        let names: Vec<&str> = resolved.layers.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(names, ["global", "team", "project"]);
+        assert_eq!(h.version, 2);
+        assert_eq!(h.min_section_length, 30);
+        assert_eq!(h.illegal_strings, ["TODO", "WIP"]);
+        assert_eq!(h.required_headers, GateHeuristics::default().required_headers);
+        assert_eq!(h.management_files.dashboard, "00-START-HERE.md");
+        assert!(!h.management_files.require_all_checked);
+        assert_eq!(h.rules.len(), 1);
+
+        let origin = |key: &str, value: &str| {
+            resolved
+                .settings()
+                .into_iter()
+                .find(|s| s.key == key && s.value == value)
+                .map(|s| s.layer)
+                .unwrap()
+        };
+        assert_eq!(origin("min_section_length", "30"), "global");
+        assert_eq!(origin("version", "2"), "team");
+        assert_eq!(origin("illegal_strings", "TODO"), "team");
+        assert_eq!(origin("illegal_strings", "WIP"), "project");
+        assert_eq!(origin("required_headers", "Folder structure:"), "built-in");
+        assert_eq!(origin("severities", "section-too-short = warning"), "team");
+        assert_eq!(origin("rules", "no-maybe"), "team");
+        assert_eq!(origin("management_files.require_all_checked", "false"), "project");
+    }
+
+    #[test]
+    fn test_config_extends_applies_without_project_file() {
+        let temp_dir = TempDir::new().unwrap();
+        write_layer(temp_dir.path(), "shared/team.json", r#"{ "min_section_length": 42 }"#);
+
+        let profiles = HeuristicsProfiles {
+            profile_dir: None,
+            extends: Some("shared/team.json".to_string()),
+            project_root: temp_dir.path().to_path_buf(),
+        };
+        let resolved = resolve_heuristics(None, &profiles).unwrap();
+
+        assert_eq!(resolved.heuristics.min_section_length, 42);
+        assert_eq!(resolved.layers[0].name, "team");
+    }
+
+    #[test]
+    fn test_extends_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let a = write_layer(temp_dir.path(), "a.json", r#"{ "extends": "b.json" }"#);
+        write_layer(temp_dir.path(), "b.json", r#"{ "extends": "a.json" }"#);
+        let missing = write_layer(temp_dir.path(), "c.json", r#"{ "extends": "nope" }"#);
+        let bad_remove = write_layer(temp_dir.path(), "d.json", r#"{ "remove_rules": ["ghost"] }"#);
+
+        let profiles = HeuristicsProfiles {
+            profile_dir: Some(temp_dir.path().join("profiles")),
+            ..HeuristicsProfiles::default()
+        };
+        for (path, expected) in [
+            (a, "cycle"),
+            (missing, "profile 'nope' not found"),
+            (bad_remove, "ghost"),
+        ] {
+            let error = format!("{:#}", resolve_heuristics(Some(&path), &profiles).unwrap_err());
+            assert!(error.contains(expected), "{error}");
+        }
+    }
 }
@@ -0,0 +1,180 @@
+//! Vault Layout
+//!
+//! Resolves where planning documents, management notes and sprint workspaces
+//! live in a vault, from `[structure]` in nexus.toml. Modules build vault
+//! paths through `VaultLayout` rather than joining folder names themselves,
+//! so a project with its own structure works everywhere.
+
+use crate::config::StructureConfig;
+use crate::mvp::SprintData;
+use std::path::{Path, PathBuf};
+
+/// MVP breakdown in the planning directory
+pub const MVP_BREAKDOWN_FILE: &str = "05-MVP-Breakdown.md";
+
+/// Dashboard in the management directory
+pub const DASHBOARD_FILE: &str = "00-START-HERE.md";
+
+/// Default of `sprint_dir`
+pub const DEFAULT_SPRINT_DIR: &str = "00-MANAGEMENT/sprints";
+
+/// `sprint_dir` written by `nexus init` before sprint folders honored it; the
+/// folders were always created in `00-MANAGEMENT/sprints`, so it means that
+const LEGACY_SPRINT_DIR: &str = "00-MANAGEMENT/Sprints";
+
+/// Absolute paths of the folders in `[structure]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VaultLayout {
+    /// Vault root: `[obsidian] planning_path`, else `[project] obsidian_path`
+    pub root: PathBuf,
+    /// Planning documents (01-PLANNING/)
+    pub planning_dir: PathBuf,
+    /// Dashboard, backlog and other management notes (00-MANAGEMENT/)
+    pub management_dir: PathBuf,
+    /// Sprint workspaces (00-MANAGEMENT/sprints/)
+    pub sprint_dir: PathBuf,
+}
+
+impl VaultLayout {
+    /// Resolve the `[structure]` folders against a vault root
+    pub fn new(root: &Path, structure: &StructureConfig) -> Self {
+        let sprint_dir = if structure.sprint_dir == LEGACY_SPRINT_DIR {
+            DEFAULT_SPRINT_DIR
+        } else {
+            structure.sprint_dir.as_str()
+        };
+        Self {
+            root: root.to_path_buf(),
+            planning_dir: root.join(&structure.planning_dir),
+            management_dir: root.join(&structure.management_dir),
+            sprint_dir: root.join(sprint_dir),
+        }
+    }
+
+    /// The MVP breakdown: {planning_dir}/05-MVP-Breakdown.md
+    pub fn mvp_breakdown(&self) -> PathBuf {
+        self.planning_dir.join(MVP_BREAKDOWN_FILE)
+    }
+
+    /// The dashboard: {management_dir}/00-START-HERE.md
+    pub fn dashboard(&self) -> PathBuf {
+        self.management_dir.join(DASHBOARD_FILE)
+    }
+
+    /// Backlog note for carried-over tasks that no sprint has taken: {management_dir}/Backlog.md
+    pub fn backlog(&self) -> PathBuf {
+        self.management_dir.join("Backlog.md")
+    }
+
+    /// Workspace folder of a sprint: {sprint_dir}/sprint-{number}-{name}/
+    pub fn sprint_folder(&self, sprint_data: &SprintData) -> PathBuf {
+        self.sprint_dir
+            .join(format!("sprint-{}-{}", sprint_data.number, sprint_data.name))
+    }
+
+    /// Workspace folder of a sprint known only by its identifier ("sprint-4")
+    ///
+    /// The first `sprint-4-*` folder when there is one, else `sprint-4` itself.
+    pub fn find_sprint_folder(&self, sprint_id: &str) -> PathBuf {
+        let prefix = format!("{sprint_id}-");
+        let mut matches: Vec<PathBuf> = std::fs::read_dir(&self.sprint_dir)
+            .into_iter()
+            .flatten()
+            .filter_map(std::result::Result::ok)
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect();
+        matches.sort();
+        matches
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| self.sprint_dir.join(sprint_id))
+    }
+
+    /// Sprint workspace template overrides kept in the vault: {management_dir}/templates/sprint/
+    pub fn sprint_templates_dir(&self) -> PathBuf {
+        self.management_dir.join("templates").join("sprint")
+    }
+
+    /// Dev session template copied by `nexus init --project`: dev-sessions/_session-template.md
+    pub fn session_template(&self) -> PathBuf {
+        self.root.join("dev-sessions").join("_session-template.md")
+    }
+
+    /// Where aborted sprint workspaces are archived: {sprint_dir}/aborted/
+    pub fn aborted_dir(&self) -> PathBuf {
+        self.sprint_dir.join("aborted")
+    }
+
+    /// Whether a path is a planning document (or below the planning directory)
+    pub fn is_planning(&self, path: &Path) -> bool {
+        path.starts_with(&self.planning_dir)
+    }
+
+    /// Whether a path is inside a sprint workspace
+    pub fn is_sprint(&self, path: &Path) -> bool {
+        path.starts_with(&self.sprint_dir)
+    }
+
+    /// A path relative to the vault root, for messages; other paths are returned as they are
+    pub fn relative<'a>(&self, path: &'a Path) -> &'a Path {
+        path.strip_prefix(&self.root).unwrap_or(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn structure(planning_dir: &str, management_dir: &str, sprint_dir: &str) -> StructureConfig {
+        StructureConfig {
+            planning_dir: planning_dir.to_string(),
+            management_dir: management_dir.to_string(),
+            sprint_dir: sprint_dir.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_custom_layout() {
+        let layout = VaultLayout::new(Path::new("/vault"), &structure("plan", "mgmt", "work/sprints"));
+        let sprint = crate::mvp::parse_sprints("## Sprint 2: Sync\n- [ ] Engine\n").remove(0);
+
+        assert_eq!(layout.mvp_breakdown(), Path::new("/vault/plan/05-MVP-Breakdown.md"));
+        assert_eq!(layout.dashboard(), Path::new("/vault/mgmt/00-START-HERE.md"));
+        assert_eq!(layout.backlog(), Path::new("/vault/mgmt/Backlog.md"));
+        assert_eq!(layout.sprint_folder(&sprint), Path::new("/vault/work/sprints/sprint-2-sync"));
+        assert!(layout.is_planning(Path::new("/vault/plan/03-Tech-Stack.md")));
+        assert!(!layout.is_planning(Path::new("/vault/planning/03-Tech-Stack.md")));
+        assert!(layout.is_sprint(Path::new("/vault/work/sprints/sprint-2-sync/Tasks.md")));
+        assert_eq!(
+            layout.relative(Path::new("/vault/plan/03-Tech-Stack.md")),
+            Path::new("plan/03-Tech-Stack.md")
+        );
+    }
+
+    #[test]
+    fn test_legacy_sprint_dir_means_lowercase() {
+        let layout = VaultLayout::new(
+            Path::new("/vault"),
+            &structure("01-PLANNING", "00-MANAGEMENT", "00-MANAGEMENT/Sprints"),
+        );
+        assert_eq!(layout, VaultLayout::new(Path::new("/vault"), &StructureConfig::default()));
+        assert_eq!(layout.sprint_dir, Path::new("/vault/00-MANAGEMENT/sprints"));
+    }
+
+    #[test]
+    fn test_find_sprint_folder() {
+        let temp_dir = TempDir::new().unwrap();
+        let layout = VaultLayout::new(temp_dir.path(), &StructureConfig::default());
+        assert_eq!(layout.find_sprint_folder("sprint-4"), layout.sprint_dir.join("sprint-4"));
+
+        std::fs::create_dir_all(layout.sprint_dir.join("sprint-40-later")).unwrap();
+        std::fs::create_dir_all(layout.sprint_dir.join("sprint-4-the-sprint-orchestrator")).unwrap();
+        assert_eq!(
+            layout.find_sprint_folder("sprint-4"),
+            layout.sprint_dir.join("sprint-4-the-sprint-orchestrator")
+        );
+    }
+}
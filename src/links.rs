@@ -0,0 +1,404 @@
+//! Obsidian Links - Wikilink and Embed Resolution
+//!
+//! Finds wikilinks (`[[note]]`, `[[note#Heading]]`, `[[note#^block]]`,
+//! `[[note|alias]]`) and embeds (`![[diagram.png]]`) in planning documents and
+//! resolves them against the vault the way Obsidian does: by file name anywhere
+//! in the vault, or by path relative to the vault root. Links inside code spans
+//! and code blocks are ignored.
+
+use crate::frontmatter::body_events;
+use crate::planning::{LineIndex, ValidationIssue, normalize_header};
+use anyhow::{Context, Result};
+use pulldown_cmark::{Event, Options, Tag};
+use regex::Regex;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Build output and dependency folders that are never link targets
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules"];
+
+/// `[[...]]` or `![[...]]` on a single line
+static WIKILINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(!?)\[\[([^\[\]\n]+?)\]\]").expect("valid wikilink regex"));
+
+/// The part of a link after `#`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Anchor {
+    /// `#Heading` or nested `#Heading#Subheading`
+    Heading(Vec<String>),
+    /// `#^block-id`
+    Block(String),
+}
+
+/// A wikilink or embed found in a document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WikiLink {
+    /// `![[...]]` rather than `[[...]]`
+    pub embed: bool,
+    /// Target as written, without the alias (e.g. "04-Architecture#Data model")
+    pub target: String,
+    /// Note or file part of the target; empty for links within the same note
+    pub note: String,
+    pub anchor: Option<Anchor>,
+    /// Byte range of the whole link, brackets included
+    pub range: Range<usize>,
+}
+
+/// Finds every wikilink and embed outside code
+pub fn find_links(content: &str) -> Vec<WikiLink> {
+    let code = code_ranges(content);
+    WIKILINK
+        .captures_iter(content)
+        .filter_map(|captures| {
+            let whole = captures.get(0)?;
+            if code.iter().any(|range| range.contains(&whole.start())) {
+                return None;
+            }
+            // Aliases use `|`, escaped as `\|` inside tables
+            let inner = &captures[2];
+            let target = inner
+                .split('|')
+                .next()
+                .unwrap_or(inner)
+                .trim_end_matches('\\')
+                .trim();
+            let (note, anchor) = match target.split_once('#') {
+                Some((note, anchor)) => (note.trim(), parse_anchor(anchor)),
+                None => (target, None),
+            };
+            Some(WikiLink {
+                embed: !captures[1].is_empty(),
+                target: target.to_string(),
+                note: note.to_string(),
+                anchor,
+                range: whole.range(),
+            })
+        })
+        .collect()
+}
+
+fn parse_anchor(anchor: &str) -> Option<Anchor> {
+    let anchor = anchor.trim();
+    if let Some(block) = anchor.strip_prefix('^') {
+        return Some(Anchor::Block(block.trim().to_string()));
+    }
+    let headings: Vec<String> = anchor
+        .split('#')
+        .map(str::trim)
+        .filter(|heading| !heading.is_empty())
+        .map(str::to_string)
+        .collect();
+    (!headings.is_empty()).then_some(Anchor::Heading(headings))
+}
+
+/// Byte ranges of code spans and code blocks
+fn code_ranges(content: &str) -> Vec<Range<usize>> {
+    body_events(content, Options::empty())
+        .filter_map(|(event, range)| match event {
+            Event::Code(_) | Event::Start(Tag::CodeBlock(_)) => Some(range),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Headings of a document as (start offset, text)
+fn headings(content: &str) -> Vec<(usize, String)> {
+    let mut headings = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+    for (event, range) in body_events(content, Options::empty()) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => current = Some((range.start, String::new())),
+            Event::End(pulldown_cmark::TagEnd::Heading(_)) => {
+                if let Some((start, text)) = current.take() {
+                    headings.push((start, text.trim().to_string()));
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, heading)) = current.as_mut() {
+                    heading.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+    headings
+}
+
+/// Comparison key for headings: lowercase words, punctuation dropped
+fn anchor_key(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .map(|ch| if ch.is_alphanumeric() { ch } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether a heading matches a link anchor
+///
+/// Anchors may leave out trailing parentheticals and punctuation, so
+/// `#Data model` matches "Data model (main entities):".
+pub fn heading_matches(heading: &str, anchor: &str) -> bool {
+    let anchor = anchor_key(anchor);
+    let heading_key = anchor_key(heading);
+    heading_key == anchor
+        || anchor_key(&normalize_header(heading)) == anchor
+        || heading_key.starts_with(&format!("{anchor} "))
+}
+
+/// Whether a document defines the block id (`... ^block-id` at the end of a line)
+fn has_block(content: &str, block: &str) -> bool {
+    let marker = format!("^{block}");
+    content.lines().any(|line| {
+        let line = line.trim_end();
+        line == marker || line.ends_with(&format!(" {marker}"))
+    })
+}
+
+/// Files in the vault, for resolving link targets
+#[derive(Debug, Clone)]
+pub struct VaultIndex {
+    root: PathBuf,
+    /// Paths relative to the root, with forward slashes
+    files: Vec<String>,
+}
+
+impl VaultIndex {
+    /// Indexes every file below `root`, skipping hidden directories (`.obsidian`, `.git`, ...)
+    /// and build output
+    pub fn build(root: &Path) -> Result<Self> {
+        let mut files = Vec::new();
+        let mut pending = vec![root.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            let entries = std::fs::read_dir(&dir)
+                .with_context(|| format!("Failed to read vault directory: {}", dir.display()))?;
+            for entry in entries.filter_map(std::result::Result::ok) {
+                let path = entry.path();
+                if entry.file_name().to_string_lossy().starts_with('.') {
+                    continue;
+                }
+                if path.is_dir() {
+                    if !SKIPPED_DIRS.contains(&entry.file_name().to_string_lossy().as_ref()) {
+                        pending.push(path);
+                    }
+                } else if let Ok(relative) = path.strip_prefix(root) {
+                    files.push(relative.to_string_lossy().replace('\\', "/"));
+                }
+            }
+        }
+        files.sort();
+        Ok(Self {
+            root: root.to_path_buf(),
+            files,
+        })
+    }
+
+    /// Indexed files, relative to the root with forward slashes, sorted
+    pub fn files(&self) -> &[String] {
+        &self.files
+    }
+
+    /// Resolves a link's note part to a file, relative to the linking document
+    ///
+    /// Notes without an extension are markdown files. An exact vault path wins,
+    /// then a file in the linking document's folder, then the shortest path.
+    pub fn resolve(&self, note: &str, from: &Path) -> Option<PathBuf> {
+        let note = note.trim().trim_start_matches('/').replace('\\', "/");
+        if note.is_empty() {
+            return Some(from.to_path_buf());
+        }
+        let has_extension = Path::new(&note).extension().is_some_and(|ext| {
+            // "v1.2 Notes" has a dot but no real extension
+            !ext.to_string_lossy().contains(' ')
+        });
+        let wanted = if has_extension { note } else { format!("{note}.md") }.to_lowercase();
+
+        let from_dir = from
+            .parent()
+            .and_then(|dir| dir.strip_prefix(&self.root).ok())
+            .map(|dir| dir.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+        let candidates: Vec<&String> = self
+            .files
+            .iter()
+            .filter(|file| {
+                let file = file.to_lowercase();
+                file == wanted || file.ends_with(&format!("/{wanted}"))
+            })
+            .collect();
+
+        let best = candidates
+            .iter()
+            .find(|file| file.to_lowercase() == wanted)
+            .or_else(|| {
+                candidates.iter().find(|file| {
+                    Path::new(file.as_str())
+                        .parent()
+                        .is_some_and(|dir| dir.to_string_lossy() == from_dir)
+                })
+            })
+            .or_else(|| candidates.iter().min_by_key(|file| file.len()))?;
+        Some(self.root.join(best.as_str()))
+    }
+}
+
+/// Why a link does not resolve, or `None` if it does
+///
+/// `source` is the content of the linking document (for same-note anchors).
+pub fn check_link(link: &WikiLink, from: &Path, source: &str, vault: &VaultIndex) -> Option<String> {
+    let Some(target) = vault.resolve(&link.note, from) else {
+        let kind = if Path::new(&link.note).extension().is_some() && !link.note.ends_with(".md") {
+            "file"
+        } else {
+            "note"
+        };
+        return Some(format!("{kind} '{}' not found in the vault", link.note));
+    };
+    let anchor = link.anchor.as_ref()?;
+
+    let content = if link.note.is_empty() {
+        source.to_string()
+    } else {
+        std::fs::read_to_string(&target).unwrap_or_default()
+    };
+    let name = target
+        .file_name()
+        .map_or_else(|| link.note.clone(), |name| name.to_string_lossy().to_string());
+
+    match anchor {
+        Anchor::Heading(path) => {
+            let headings = headings(&content);
+            // Nested anchors must appear in order
+            let mut found_at = 0;
+            for heading in path {
+                match headings
+                    .iter()
+                    .skip(found_at)
+                    .position(|(_, text)| heading_matches(text, heading))
+                {
+                    Some(position) => found_at += position + 1,
+                    None => return Some(format!("heading '{heading}' not found in {name}")),
+                }
+            }
+            None
+        }
+        Anchor::Block(block) => {
+            (!has_block(&content, block)).then(|| format!("block '^{block}' not found in {name}"))
+        }
+    }
+}
+
+/// Reports every wikilink and embed in a document that does not resolve
+pub fn find_broken_links(file_path: &Path, content: &str, vault: &VaultIndex) -> Vec<ValidationIssue> {
+    let index = LineIndex::new(content);
+    let sections = headings(content);
+    find_links(content)
+        .into_iter()
+        .filter_map(|link| {
+            let reason = check_link(&link, file_path, content, vault)?;
+            let section = sections
+                .iter()
+                .take_while(|(start, _)| *start < link.range.start)
+                .last()
+                .map(|(_, text)| text.clone());
+            Some(ValidationIssue::BrokenLink {
+                target: link.target,
+                embed: link.embed,
+                reason,
+                span: index.span(link.range),
+                section,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn vault() -> (TempDir, VaultIndex) {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::create_dir_all(root.join("01-PLANNING")).unwrap();
+        std::fs::create_dir_all(root.join("assets")).unwrap();
+        std::fs::create_dir_all(root.join(".obsidian")).unwrap();
+        std::fs::write(
+            root.join("01-PLANNING/04-Architecture.md"),
+            "# Architecture\n\n## Data model (main entities):\n\nUsers and projects. ^entities\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("assets/diagram.png"), [0u8; 4]).unwrap();
+        std::fs::write(root.join(".obsidian/hidden.md"), "").unwrap();
+        let index = VaultIndex::build(root).unwrap();
+        (temp_dir, index)
+    }
+
+    #[test]
+    fn test_find_links() {
+        let content = "See [[04-Architecture#Data model|the model]] and ![[diagram.png]].\n\n\
+                       `[[in code]]`\n\n```\n[[in block]]\n```\n\n| a | [[Note\\|alias]] |\n";
+        let links = find_links(content);
+
+        assert_eq!(links.len(), 3);
+        assert_eq!(links[0].note, "04-Architecture");
+        assert_eq!(links[0].anchor, Some(Anchor::Heading(vec!["Data model".to_string()])));
+        assert_eq!(&content[links[0].range.clone()], "[[04-Architecture#Data model|the model]]");
+        assert!(links[1].embed);
+        assert_eq!(links[1].target, "diagram.png");
+        assert_eq!(links[2].target, "Note");
+
+        let block = find_links("[[04-Architecture#^entities]]");
+        assert_eq!(block[0].anchor, Some(Anchor::Block("entities".to_string())));
+    }
+
+    #[test]
+    fn test_resolve_links() {
+        let (temp_dir, vault) = vault();
+        let from = temp_dir.path().join("01-PLANNING/05-MVP-Breakdown.md");
+        let check = |link: &str| {
+            let links = find_links(link);
+            check_link(&links[0], &from, "# MVP\n\n## Sprint 1:\n", &vault)
+        };
+
+        assert_eq!(check("[[04-Architecture]]"), None);
+        assert_eq!(check("[[01-PLANNING/04-Architecture.md]]"), None);
+        assert_eq!(check("[[04-architecture#Data model]]"), None);
+        assert_eq!(check("[[04-Architecture#Architecture#Data model (main entities):]]"), None);
+        assert_eq!(check("[[04-Architecture#^entities]]"), None);
+        assert_eq!(check("![[diagram.png]]"), None);
+        assert_eq!(check("[[#Sprint 1]]"), None);
+
+        assert_eq!(check("[[03-Tech-Stack]]").unwrap(), "note '03-Tech-Stack' not found in the vault");
+        assert_eq!(check("![[flow.png]]").unwrap(), "file 'flow.png' not found in the vault");
+        assert_eq!(check("[[hidden]]").unwrap(), "note 'hidden' not found in the vault");
+        assert_eq!(
+            check("[[04-Architecture#Deployment]]").unwrap(),
+            "heading 'Deployment' not found in 04-Architecture.md"
+        );
+        assert_eq!(
+            check("[[04-Architecture#^missing]]").unwrap(),
+            "block '^missing' not found in 04-Architecture.md"
+        );
+    }
+
+    #[test]
+    fn test_find_broken_links_reports_position_and_section() {
+        let (temp_dir, vault) = vault();
+        let from = temp_dir.path().join("01-PLANNING/05-MVP-Breakdown.md");
+        let content = "# MVP\n\n## Sprint 1:\n\nBuild [[04-Architecture]] then [[Old-Name#Intro]].\n";
+
+        let issues = find_broken_links(&from, content, &vault);
+        assert_eq!(issues.len(), 1);
+        let ValidationIssue::BrokenLink { target, embed, span, section, .. } = &issues[0] else {
+            panic!("Expected BrokenLink");
+        };
+        assert_eq!(target, "Old-Name#Intro");
+        assert!(!embed);
+        assert_eq!((span.start.line, span.start.column), (5, 32));
+        assert_eq!(section.as_deref(), Some("Sprint 1:"));
+        assert_eq!(issues[0].rule_id(), "broken-link");
+    }
+}
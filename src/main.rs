@@ -5,20 +5,32 @@ mod brain;
 mod catalyst;
 mod commands;
 mod config;
+mod consistency;
 mod context;
 mod embeddings;
+mod export;
+mod frontmatter;
+mod gate_cache;
+mod gate_report;
 mod genesis;
 mod git_ops;
 mod heuristics;
 mod history;
+mod layout;
+mod links;
 mod llm;
 mod memory;
+mod mvp;
 mod planning;
+mod roadmap;
 mod schema;
 mod scaffolding;
 mod session;
+mod session_log;
 mod state;
 mod templating;
+mod transclusion;
+mod waivers;
 mod watcher;
 
 #[derive(Parser)]
@@ -41,11 +53,30 @@ enum Commands {
         /// Initialize as a full project (Git repo + Obsidian vault with planning docs)
         #[arg(long)]
         project: bool,
+        /// Language of the planning templates: "en" (default) or "sv"
+        #[arg(long, default_value = "en")]
+        locale: String,
     },
     /// Check if planning documents are complete and ready
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     Gate {
         /// Path to the project directory
-        project_path: PathBuf,
+        #[arg(required = true)]
+        project_path: Option<PathBuf>,
+        /// Report format: "text" (default), "json" or "sarif"
+        #[arg(long, value_enum, default_value_t = gate_report::ReportFormat::Text)]
+        format: gate_report::ReportFormat,
+        /// Print the effective heuristics and which layer each value came from, then exit
+        #[arg(long)]
+        explain_config: bool,
+        /// Validate every document again instead of reusing .nexus/gate-cache.json
+        #[arg(long)]
+        no_cache: bool,
+        /// Re-run the gate on every save until it opens
+        #[arg(long, conflicts_with_all = ["format", "explain_config", "no_cache"])]
+        watch: bool,
+        #[command(subcommand)]
+        action: Option<GateAction>,
     },
     /// Generate CLAUDE.md from completed planning documents
     Unlock {
@@ -53,11 +84,48 @@ enum Commands {
         project_path: PathBuf,
     },
     /// Create a new sprint branch with scoped context
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     Sprint {
         /// Path to the project directory
-        project_path: PathBuf,
+        #[arg(required = true)]
+        project_path: Option<PathBuf>,
         /// Sprint number to activate
-        sprint_number: u32,
+        #[arg(required = true)]
+        sprint_number: Option<u32>,
+        #[command(subcommand)]
+        action: Option<SprintAction>,
+    },
+    /// Show the sprint dependency graph and its critical path
+    Roadmap {
+        /// Path to the project directory
+        project_path: PathBuf,
+    },
+    /// Log dev sessions of the active sprint
+    Session {
+        #[command(subcommand)]
+        action: SessionAction,
+    },
+    /// Move the vault's planning, management and sprint folders and rewrite links to them
+    MigrateLayout {
+        /// Path to the project directory
+        project_path: PathBuf,
+        /// New planning directory, relative to the vault root
+        #[arg(long)]
+        planning_dir: Option<String>,
+        /// New management directory, relative to the vault root
+        #[arg(long)]
+        management_dir: Option<String>,
+        /// New sprint directory (by default it moves with the management directory it is in)
+        #[arg(long)]
+        sprint_dir: Option<String>,
+        /// Show what would move without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Export project data for people outside the vault
+    Export {
+        #[command(subcommand)]
+        target: ExportTarget,
     },
     /// Start an interactive shell (REPL)
     Shell,
@@ -86,22 +154,225 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum SessionAction {
+    /// Start a session note in the active sprint's sessions/ folder
+    Start {
+        /// Path to the project directory
+        project_path: PathBuf,
+        /// The one thing this session is meant to get done
+        #[arg(long)]
+        goal: Option<String>,
+    },
+    /// Add a timestamped line to the session's Comms
+    Note {
+        /// Path to the project directory
+        project_path: PathBuf,
+        /// Text of the note
+        #[arg(required = true)]
+        text: Vec<String>,
+    },
+    /// End the session and record its duration and commits in the Debrief
+    End {
+        /// Path to the project directory
+        project_path: PathBuf,
+        /// Build and tests pass
+        #[arg(long, conflicts_with = "failed")]
+        passed: bool,
+        /// Build or tests fail
+        #[arg(long)]
+        failed: bool,
+        /// Where to pick up next session
+        #[arg(long = "next")]
+        next_task: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ExportTarget {
+    /// Sprint roadmap with dates and task completion
+    Roadmap {
+        /// Path to the project directory
+        project_path: PathBuf,
+        /// Output format: "mermaid" (default), "json", "csv" or "ics"
+        #[arg(long, value_enum, default_value_t = export::ExportFormat::Mermaid)]
+        format: export::ExportFormat,
+        /// Write to this file instead of standard output
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum SprintAction {
+    /// Approve the active sprint once its tasks and Definition of Done are met
+    Approve {
+        /// Path to the project directory
+        project_path: PathBuf,
+        /// Who approves the sprint (defaults to git user.name)
+        #[arg(long)]
+        approver: Option<String>,
+        /// Note to include in the approval record
+        #[arg(long)]
+        note: Option<String>,
+        /// Confirm the Definition of Done without prompting
+        #[arg(long, short)]
+        yes: bool,
+    },
+    /// Close the active sprint, carrying unfinished tasks over
+    Close {
+        /// Path to the project directory
+        project_path: PathBuf,
+        /// Carry unfinished tasks into this sprint (default: the next one in roadmap order)
+        #[arg(long, value_name = "N")]
+        into: Option<u32>,
+        /// Carry unfinished tasks into the backlog note instead of a sprint
+        #[arg(long, conflicts_with = "into")]
+        backlog: bool,
+    },
+    /// Merge the approved sprint's branch into the base branch and tag it
+    Finish {
+        /// Path to the project directory
+        project_path: PathBuf,
+        /// Branch to merge into (default: [git] base_branch, or "main")
+        #[arg(long)]
+        base: Option<String>,
+        /// Merge strategy (default: [git] merge_strategy, or "merge-commit")
+        #[arg(long, value_enum)]
+        strategy: Option<git_ops::MergeStrategy>,
+        /// Delete the sprint branch after merging
+        #[arg(long)]
+        delete_branch: bool,
+    },
+    /// Undo starting the active sprint: restore the previous branch and sprint
+    Abort {
+        /// Path to the project directory
+        project_path: PathBuf,
+        /// Delete the sprint branch even when it has commits of its own
+        #[arg(long)]
+        force: bool,
+        /// Delete the sprint workspace instead of archiving it
+        #[arg(long)]
+        remove: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum GateAction {
+    /// Accept a known gap: suppress an issue until an expiry date
+    Waive {
+        /// Path to the project directory
+        project_path: PathBuf,
+        /// Rule id of the issue (e.g. "illegal-string", "section-too-short")
+        #[arg(long)]
+        rule: String,
+        /// File name or project-relative path of the document
+        #[arg(long)]
+        file: String,
+        /// Only waive issues in this section
+        #[arg(long)]
+        section: Option<String>,
+        /// Only waive issues whose message contains this text (e.g. "TBD")
+        #[arg(long = "match")]
+        contains: Option<String>,
+        /// Why the gap is accepted
+        #[arg(long)]
+        reason: String,
+        /// Who accepts it (defaults to git user.name)
+        #[arg(long)]
+        author: Option<String>,
+        /// Last day the waiver applies (YYYY-MM-DD)
+        #[arg(long)]
+        expires: chrono::NaiveDate,
+    },
+}
+
 fn main() {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Init { project_name, mode, project } => {
-            if let Err(e) = commands::init::execute(&project_name, &mode, project, None, None) {
+        Commands::Init {
+            project_name,
+            mode,
+            project,
+            locale,
+        } => {
+            if let Err(e) = commands::init::execute(&project_name, &mode, &locale, project, None, None) {
                 eprintln!("{e}");
                 std::process::exit(1);
             }
         }
-        Commands::Gate { project_path } => {
-            if let Err(e) = commands::gate::execute(&project_path) {
-                eprintln!("{e}");
+        Commands::Gate {
+            action:
+                Some(GateAction::Waive {
+                    project_path,
+                    rule,
+                    file,
+                    section,
+                    contains,
+                    reason,
+                    author,
+                    expires,
+                }),
+            ..
+        } => {
+            let waiver = waivers::Waiver {
+                rule,
+                file,
+                section,
+                contains,
+                reason,
+                author: author.unwrap_or_default(),
+                expires,
+            };
+            if let Err(e) = commands::gate::execute_waive(&project_path, waiver) {
+                eprintln!("{e:#}");
                 std::process::exit(1);
             }
         }
+        Commands::Gate {
+            project_path: Some(project_path),
+            explain_config: true,
+            action: None,
+            ..
+        } => {
+            if let Err(e) = commands::gate::execute_explain_config(&project_path) {
+                eprintln!("{e:#}");
+                std::process::exit(commands::gate::EXIT_GATE_ERROR);
+            }
+        }
+        Commands::Gate {
+            project_path: Some(project_path),
+            watch: true,
+            action: None,
+            ..
+        } => {
+            if let Err(e) = commands::gate_watch::execute(&project_path) {
+                eprintln!("{e:#}");
+                std::process::exit(commands::gate::EXIT_GATE_ERROR);
+            }
+        }
+        Commands::Gate {
+            project_path: Some(project_path),
+            format,
+            explain_config: false,
+            no_cache,
+            watch: false,
+            action: None,
+        } => match commands::gate::execute_with_format(&project_path, format, !no_cache) {
+            Ok(report) => {
+                if !report.passed() {
+                    std::process::exit(commands::gate::EXIT_GATE_CLOSED);
+                }
+            }
+            Err(e) => {
+                eprintln!("{e:#}");
+                std::process::exit(commands::gate::EXIT_GATE_ERROR);
+            }
+        },
+        Commands::Gate {
+            project_path: None, ..
+        } => unreachable!("clap requires a project path when no gate action is given"),
         Commands::Unlock { project_path } => {
             if let Err(e) = commands::unlock::execute(&project_path) {
                 eprintln!("{e}");
@@ -109,14 +380,151 @@ fn main() {
             }
         }
         Commands::Sprint {
-            project_path,
-            sprint_number,
+            action:
+                Some(SprintAction::Approve {
+                    project_path,
+                    approver,
+                    note,
+                    yes,
+                }),
+            ..
+        } => {
+            let options = commands::sprint_approve::ApproveOptions { approver, note, yes };
+            if let Err(e) = commands::sprint_approve::execute(&project_path, &options) {
+                eprintln!("{e:#}");
+                std::process::exit(1);
+            }
+        }
+        Commands::Sprint {
+            action:
+                Some(SprintAction::Close {
+                    project_path,
+                    into,
+                    backlog,
+                }),
+            ..
+        } => {
+            let target = match (into, backlog) {
+                (_, true) => commands::sprint_close::CarryOverTarget::Backlog,
+                (Some(number), false) => commands::sprint_close::CarryOverTarget::Sprint(number),
+                (None, false) => commands::sprint_close::CarryOverTarget::NextSprint,
+            };
+            if let Err(e) = commands::sprint_close::execute(&project_path, target) {
+                eprintln!("{e:#}");
+                std::process::exit(1);
+            }
+        }
+        Commands::Sprint {
+            action:
+                Some(SprintAction::Finish {
+                    project_path,
+                    base,
+                    strategy,
+                    delete_branch,
+                }),
+            ..
+        } => {
+            let args = commands::sprint_finish::FinishArgs {
+                base,
+                strategy,
+                delete_branch,
+            };
+            if let Err(e) = commands::sprint_finish::execute(&project_path, &args) {
+                eprintln!("{e:#}");
+                std::process::exit(1);
+            }
+        }
+        Commands::Sprint {
+            action:
+                Some(SprintAction::Abort {
+                    project_path,
+                    force,
+                    remove,
+                }),
+            ..
+        } => {
+            let args = commands::sprint_abort::AbortArgs { force, remove };
+            if let Err(e) = commands::sprint_abort::execute(&project_path, args) {
+                eprintln!("{e:#}");
+                std::process::exit(1);
+            }
+        }
+        Commands::Sprint {
+            project_path: Some(project_path),
+            sprint_number: Some(sprint_number),
+            action: None,
         } => {
             if let Err(e) = commands::sprint::execute(&project_path, sprint_number) {
                 eprintln!("{e}");
                 std::process::exit(1);
             }
         }
+        Commands::Sprint { .. } => {
+            unreachable!("clap requires a project path and sprint number when no sprint action is given")
+        }
+        Commands::Roadmap { project_path } => {
+            if let Err(e) = commands::roadmap::execute(&project_path) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        }
+        Commands::Session { action } => {
+            let result = match action {
+                SessionAction::Start { project_path, goal } => {
+                    commands::session::start(&project_path, goal.as_deref()).map(|_| ())
+                }
+                SessionAction::Note { project_path, text } => {
+                    commands::session::note(&project_path, &text.join(" "))
+                }
+                SessionAction::End {
+                    project_path,
+                    passed,
+                    failed,
+                    next_task,
+                } => {
+                    let args = commands::session::EndArgs {
+                        passed: (passed || failed).then_some(passed),
+                        next_task,
+                    };
+                    commands::session::end(&project_path, &args).map(|_| ())
+                }
+            };
+            if let Err(e) = result {
+                eprintln!("{e:#}");
+                std::process::exit(1);
+            }
+        }
+        Commands::MigrateLayout {
+            project_path,
+            planning_dir,
+            management_dir,
+            sprint_dir,
+            dry_run,
+        } => {
+            let args = commands::migrate_layout::MigrateArgs {
+                planning_dir,
+                management_dir,
+                sprint_dir,
+                dry_run,
+            };
+            if let Err(e) = commands::migrate_layout::execute(&project_path, &args) {
+                eprintln!("{e:#}");
+                std::process::exit(1);
+            }
+        }
+        Commands::Export {
+            target:
+                ExportTarget::Roadmap {
+                    project_path,
+                    format,
+                    output,
+                },
+        } => {
+            if let Err(e) = commands::export::execute_roadmap(&project_path, format, output.as_deref()) {
+                eprintln!("{e:#}");
+                std::process::exit(1);
+            }
+        }
         Commands::Shell => {
             if let Err(e) = commands::shell::execute() {
                 eprintln!("{e}");
@@ -0,0 +1,1110 @@
+//! MVP Breakdown Model
+//!
+//! Typed model of the sprints in `05-MVP-Breakdown.md`. Each `## Sprint N: Title`
+//! section has an optional focus, dependencies on other sprints or tasks, a task
+//! list and exit criteria:
+//!
+//! ```text
+//! ## Sprint 2: Sync (days 5-7)
+//! _Focus: Offline-first sync._
+//! **Depends on:** sprint-1
+//!
+//! - [ ] Sync engine 🆔 sync ~4h #backend ⏫ 📅 2026-11-01
+//!   - [ ] Conflict resolution ~2h
+//!   - [ ] Retry queue ~30m
+//! - [ ] Sync status UI ~2h #frontend ⛔ sync
+//! - [ ] Settings screen [depends-on:: s1-2]
+//! **Exit criteria:**
+//! - Two devices converge within 5 seconds
+//! - No data loss while offline
+//! ```
+//!
+//! Task metadata follows the Obsidian Tasks plugin (`🆔` id, `⛔` depends on,
+//! `📅` due date, `🔺⏫🔼🔽⏬` priority) and Dataview inline fields (`[id:: ...]`,
+//! `[depends-on:: ...]`, `[estimate:: ...]`). A task without an id gets one from
+//! its position: `s2-1` is the first task of Sprint 2, `s2-1-2` the second
+//! sub-task of that task. Sub-tasks of a task with an explicit id number on from
+//! it (`sync-1`).
+
+use crate::frontmatter::body_start;
+use crate::planning::{HeaderMatcher, find_section};
+use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use regex::Regex;
+use serde::{Serialize, Serializer};
+use std::collections::HashSet;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// `- [ ] text`, with the indentation, checkbox state and text captured
+static TASK_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(\s*)[-*+] \[([ xX])\](?:\s+(.*))?$").expect("valid task line regex")
+});
+/// A list item that is not a task (exit criteria)
+static BULLET_LINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*(?:[-*+]|\d+\.)\s+(.+)$").expect("valid bullet regex")
+});
+static INLINE_FIELD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\[\s*(id|depends-on|estimate|carried-from)\s*::\s*([^\]]*)\]").expect("valid inline field regex")
+});
+static TASK_ID: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"🆔\s*([\w-]+)").expect("valid id regex"));
+static DEPENDS_ON: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"⛔\s*([\w-]+(?:\s*,\s*[\w-]+)*)").expect("valid depends-on regex")
+});
+static DUE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"📅\s*(\d{4}-\d{2}-\d{2})").expect("valid due date regex"));
+static PRIORITY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("(🔺|⏫|🔼|🔽|⏬)\u{FE0F}?").expect("valid priority regex"));
+static ESTIMATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|\s)~(\d+(?:\.\d+)?(?:m|h|d))\b").expect("valid estimate regex")
+});
+static TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^|\s)#([\w/-]+)").expect("valid tag regex"));
+
+/// Header of the checklist every sprint has to meet before it is approved
+pub const DEFINITION_OF_DONE: &str = "Definition of Done (each sprint):";
+
+/// Minutes in an estimated day (`~1d`)
+const MINUTES_PER_DAY: u32 = 8 * 60;
+
+/// Task priority, as the Obsidian Tasks plugin writes it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    Lowest,
+    Low,
+    Medium,
+    High,
+    Highest,
+}
+
+impl Priority {
+    fn from_emoji(emoji: &str) -> Option<Self> {
+        Some(match emoji.trim_end_matches('\u{FE0F}') {
+            "🔺" => Self::Highest,
+            "⏫" => Self::High,
+            "🔼" => Self::Medium,
+            "🔽" => Self::Low,
+            "⏬" => Self::Lowest,
+            _ => return None,
+        })
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Highest => "highest",
+            Self::High => "high",
+            Self::Medium => "medium",
+            Self::Low => "low",
+            Self::Lowest => "lowest",
+        }
+    }
+
+    pub fn emoji(self) -> &'static str {
+        match self {
+            Self::Highest => "🔺",
+            Self::High => "⏫",
+            Self::Medium => "🔼",
+            Self::Low => "🔽",
+            Self::Lowest => "⏬",
+        }
+    }
+}
+
+/// Effort estimate: `30m`, `2h`, `1.5h` or `1d` (a day is 8 hours)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Estimate {
+    minutes: u32,
+}
+
+impl Estimate {
+    pub fn from_minutes(minutes: u32) -> Self {
+        Self { minutes }
+    }
+
+    pub fn minutes(self) -> u32 {
+        self.minutes
+    }
+
+    /// Working days, rounded up (at least one)
+    pub fn days(self) -> u32 {
+        self.minutes.div_ceil(MINUTES_PER_DAY).max(1)
+    }
+
+    /// Parses `2h`, `~2h`, `90m` or `1.5d`
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().trim_start_matches('~');
+        let unit = text.chars().last()?;
+        let scale = match unit {
+            'm' => 1.0,
+            'h' => 60.0,
+            'd' => f64::from(MINUTES_PER_DAY),
+            _ => return None,
+        };
+        let amount: f64 = text[..text.len() - 1].trim().parse().ok()?;
+        if !amount.is_finite() || amount < 0.0 {
+            return None;
+        }
+        // Truncation is fine: estimates are far below u32::MAX minutes
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let minutes = (amount * scale).round() as u32;
+        Some(Self { minutes })
+    }
+}
+
+impl std::ops::Add for Estimate {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_minutes(self.minutes + other.minutes)
+    }
+}
+
+impl std::iter::Sum for Estimate {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |total, estimate| total + estimate)
+    }
+}
+
+impl std::fmt::Display for Estimate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let minutes = self.minutes;
+        if minutes >= MINUTES_PER_DAY && minutes.is_multiple_of(MINUTES_PER_DAY) {
+            write!(f, "{}d", minutes / MINUTES_PER_DAY)
+        } else if minutes >= 60 && minutes.is_multiple_of(60) {
+            write!(f, "{}h", minutes / 60)
+        } else if minutes >= 60 && minutes.is_multiple_of(30) {
+            write!(f, "{}.5h", minutes / 60)
+        } else {
+            write!(f, "{minutes}m")
+        }
+    }
+}
+
+impl Serialize for Estimate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// What a task or sprint waits for
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DependencyRef {
+    /// Every task of a sprint (`sprint-1` or `Sprint 1`)
+    Sprint(u32),
+    /// One task, by id
+    Task(String),
+}
+
+impl DependencyRef {
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().trim_matches(['[', ']']).trim();
+        if text.is_empty() {
+            return None;
+        }
+        let sprint = text
+            .get(..6)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("sprint"))
+            .and_then(|_| text[6..].trim_start_matches(['-', ' ']).parse().ok());
+        Some(match sprint {
+            Some(number) => Self::Sprint(number),
+            None => Self::Task(text.to_string()),
+        })
+    }
+
+    /// Parses a comma-separated list, skipping empty entries
+    fn parse_list(text: &str) -> Vec<Self> {
+        text.split(',').filter_map(Self::parse).collect()
+    }
+}
+
+impl std::fmt::Display for DependencyRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sprint(number) => write!(f, "sprint-{number}"),
+            Self::Task(id) => f.write_str(id),
+        }
+    }
+}
+
+impl Serialize for DependencyRef {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// One task of a sprint
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MvpTask {
+    /// Explicit (`🆔`) or positional id, unique within the breakdown
+    pub id: String,
+    /// Task text with the metadata removed
+    pub text: String,
+    pub done: bool,
+    pub estimate: Option<Estimate>,
+    pub tags: Vec<String>,
+    pub depends_on: Vec<DependencyRef>,
+    pub due: Option<NaiveDate>,
+    pub priority: Option<Priority>,
+    pub subtasks: Vec<MvpTask>,
+    /// Sprint the task was carried over from (`[carried-from:: sprint-3]`)
+    pub carried_from: Option<u32>,
+    /// Byte range of the task line in the parsed content
+    #[serde(skip)]
+    pub line: Range<usize>,
+}
+
+impl MvpTask {
+    /// Parses the text of a task line (after the checkbox)
+    ///
+    /// `default_id` is used when the text has no `🆔` or `[id:: ...]`.
+    fn parse(text: &str, done: bool, default_id: String, line: Range<usize>) -> Self {
+        let mut task = Self {
+            id: default_id,
+            text: String::new(),
+            done,
+            estimate: None,
+            tags: Vec::new(),
+            depends_on: Vec::new(),
+            due: None,
+            priority: None,
+            subtasks: Vec::new(),
+            carried_from: None,
+            line,
+        };
+
+        let mut rest = text.to_string();
+        for captures in INLINE_FIELD.captures_iter(text) {
+            let value = captures[2].trim();
+            match captures[1].to_lowercase().as_str() {
+                "id" if !value.is_empty() => task.id = value.to_string(),
+                "depends-on" => task.depends_on.extend(DependencyRef::parse_list(value)),
+                "estimate" => task.estimate = Estimate::parse(value),
+                "carried-from" => {
+                    task.carried_from = match DependencyRef::parse(value) {
+                        Some(DependencyRef::Sprint(number)) => Some(number),
+                        _ => value.parse().ok(),
+                    }
+                }
+                _ => {}
+            }
+        }
+        rest = INLINE_FIELD.replace_all(&rest, " ").into_owned();
+
+        if let Some(captures) = TASK_ID.captures(&rest) {
+            task.id = captures[1].to_string();
+        }
+        rest = TASK_ID.replace_all(&rest, " ").into_owned();
+        for captures in DEPENDS_ON.captures_iter(&rest) {
+            task.depends_on.extend(DependencyRef::parse_list(&captures[1]));
+        }
+        rest = DEPENDS_ON.replace_all(&rest, " ").into_owned();
+        if let Some(captures) = DUE.captures(&rest) {
+            task.due = NaiveDate::parse_from_str(&captures[1], "%Y-%m-%d").ok();
+        }
+        rest = DUE.replace_all(&rest, " ").into_owned();
+        if let Some(found) = PRIORITY.find(&rest) {
+            task.priority = Priority::from_emoji(found.as_str());
+        }
+        rest = PRIORITY.replace_all(&rest, " ").into_owned();
+        if let Some(captures) = ESTIMATE.captures(&rest) {
+            task.estimate = Estimate::parse(&captures[1]);
+        }
+        rest = ESTIMATE.replace_all(&rest, " ").into_owned();
+        // Obsidian tags need at least one non-digit ("#1" is not a tag)
+        task.tags = TAG
+            .captures_iter(&rest)
+            .map(|captures| captures[1].to_string())
+            .filter(|tag| tag.chars().any(|c| !c.is_ascii_digit()))
+            .collect();
+        rest = TAG
+            .replace_all(&rest, |captures: &regex::Captures| {
+                if captures[1].chars().all(|c| c.is_ascii_digit()) {
+                    captures[0].to_string()
+                } else {
+                    " ".to_string()
+                }
+            })
+            .into_owned();
+
+        task.text = rest.split_whitespace().collect::<Vec<_>>().join(" ");
+        task
+    }
+
+    /// Own estimate, or the sum of the sub-tasks' estimates
+    pub fn total_estimate(&self) -> Option<Estimate> {
+        self.estimate.or_else(|| sum_estimates(&self.subtasks))
+    }
+
+    /// The task line as markdown, metadata written the Obsidian Tasks way
+    pub fn to_markdown(&self) -> String {
+        let mut line = format!(
+            "- [{}] {} 🆔 {}",
+            if self.done { 'x' } else { ' ' },
+            self.text,
+            self.id
+        );
+        if let Some(estimate) = self.estimate {
+            line.push_str(&format!(" ~{estimate}"));
+        }
+        for tag in &self.tags {
+            line.push_str(&format!(" #{tag}"));
+        }
+        if !self.depends_on.is_empty() {
+            let ids: Vec<String> = self.depends_on.iter().map(ToString::to_string).collect();
+            line.push_str(&format!(" ⛔ {}", ids.join(",")));
+        }
+        if let Some(priority) = self.priority {
+            line.push_str(&format!(" {}", priority.emoji()));
+        }
+        if let Some(due) = self.due {
+            line.push_str(&format!(" 📅 {due}"));
+        }
+        if let Some(sprint) = self.carried_from {
+            line.push_str(&format!(" [carried-from:: sprint-{sprint}]"));
+        }
+        line
+    }
+}
+
+/// Tag of tasks moved to a later sprint by `nexus sprint close`
+pub const CARRIED_OVER_TAG: &str = "carried-over";
+
+/// Unfinished tasks of a sprint, tagged as carried over from it
+///
+/// Tasks keep their ids, so they can be traced back to the sprint they came from.
+pub fn carry_over(tasks: &[MvpTask], from_sprint: u32) -> Vec<MvpTask> {
+    fn tag(tasks: &mut [MvpTask], from_sprint: u32) {
+        for task in tasks {
+            if !task.tags.iter().any(|tag| tag == CARRIED_OVER_TAG) {
+                task.tags.push(CARRIED_OVER_TAG.to_string());
+            }
+            task.carried_from = Some(from_sprint);
+            tag(&mut task.subtasks, from_sprint);
+        }
+    }
+
+    let mut carried = unfinished(tasks);
+    tag(&mut carried, from_sprint);
+    carried
+}
+
+/// Sum of the tasks' estimates (`None` when no task has one)
+pub fn sum_estimates(tasks: &[MvpTask]) -> Option<Estimate> {
+    let estimates: Vec<Estimate> = tasks.iter().filter_map(MvpTask::total_estimate).collect();
+    (!estimates.is_empty()).then(|| estimates.into_iter().sum())
+}
+
+/// Number of tasks, sub-tasks included
+pub fn count_tasks(tasks: &[MvpTask]) -> usize {
+    tasks
+        .iter()
+        .map(|task| 1 + count_tasks(&task.subtasks))
+        .sum()
+}
+
+/// Number of done tasks, sub-tasks included
+pub fn count_done(tasks: &[MvpTask]) -> usize {
+    tasks
+        .iter()
+        .map(|task| usize::from(task.done) + count_done(&task.subtasks))
+        .sum()
+}
+
+/// Tasks as a nested markdown checklist (two spaces per level)
+pub fn render_tasks(tasks: &[MvpTask]) -> String {
+    fn render(tasks: &[MvpTask], depth: usize, lines: &mut Vec<String>) {
+        for task in tasks {
+            lines.push(format!("{}{}", "  ".repeat(depth), task.to_markdown()));
+            render(&task.subtasks, depth + 1, lines);
+        }
+    }
+
+    let mut lines = Vec::new();
+    render(tasks, 0, &mut lines);
+    lines.join("\n")
+}
+
+/// Tasks that are not done, with their unfinished sub-tasks
+///
+/// A done task stays in the list while one of its sub-tasks is open.
+pub fn unfinished(tasks: &[MvpTask]) -> Vec<MvpTask> {
+    tasks
+        .iter()
+        .filter_map(|task| {
+            let subtasks = unfinished(&task.subtasks);
+            (!task.done || !subtasks.is_empty()).then(|| MvpTask {
+                subtasks,
+                ..task.clone()
+            })
+        })
+        .collect()
+}
+
+/// Parses a markdown task list (such as a sprint's Tasks.md)
+///
+/// Tasks without an id are numbered from `id_prefix` (`s4` gives `s4-1`, `s4-2`, ...).
+pub fn parse_task_list(content: &str, id_prefix: &str) -> Vec<MvpTask> {
+    let mut tasks = Vec::new();
+    let mut open = OpenTasks::new(body_lines(content).map(|(line, _)| line));
+    for (line, range) in body_lines(content) {
+        if let Some(captures) = TASK_LINE.captures(line) {
+            open.push(&mut tasks, id_prefix, &captures, range);
+        }
+    }
+    tasks
+}
+
+/// Lines of the document body with their byte ranges (line endings excluded)
+fn body_lines(content: &str) -> impl Iterator<Item = (&str, Range<usize>)> {
+    let mut offset = body_start(content);
+    content[offset..].split_inclusive('\n').map(move |raw| {
+        let start = offset;
+        offset += raw.len();
+        let line = raw.trim_end_matches(['\n', '\r']);
+        (line, start..start + line.len())
+    })
+}
+
+/// Parent chain of the task line being read, for nesting by indentation
+struct OpenTasks {
+    /// Indentation width and position among its siblings of each open task
+    stack: Vec<(usize, usize)>,
+    /// Ids the tasks set themselves, which positional ids skip
+    explicit: HashSet<String>,
+}
+
+impl OpenTasks {
+    fn new<'a>(lines: impl Iterator<Item = &'a str>) -> Self {
+        Self {
+            stack: Vec::new(),
+            explicit: lines
+                .filter(|line| TASK_LINE.is_match(line))
+                .filter_map(explicit_id)
+                .collect(),
+        }
+    }
+
+    fn push(
+        &mut self,
+        tasks: &mut Vec<MvpTask>,
+        id_prefix: &str,
+        captures: &regex::Captures,
+        line: Range<usize>,
+    ) {
+        let indent = indent_width(&captures[1]);
+        while self.stack.last().is_some_and(|&(open, _)| open >= indent) {
+            self.stack.pop();
+        }
+
+        let mut siblings = tasks;
+        let mut parent_id = id_prefix.to_string();
+        for &(_, index) in &self.stack {
+            parent_id.clone_from(&siblings[index].id);
+            siblings = &mut siblings[index].subtasks;
+        }
+
+        // A pinned id (`🆔 s2-3`) keeps its number when tasks are inserted above it
+        let default_id = (siblings.len() + 1..)
+            .map(|position| format!("{parent_id}-{position}"))
+            .find(|id| !self.explicit.contains(id) && !siblings.iter().any(|task| task.id == *id))
+            .unwrap_or_default();
+        let done = !captures[2].trim().is_empty();
+        let text = captures.get(3).map_or("", |text| text.as_str());
+        siblings.push(MvpTask::parse(text, done, default_id, line));
+        self.stack.push((indent, siblings.len() - 1));
+    }
+}
+
+/// Width of leading whitespace, a tab counting as four spaces
+fn indent_width(indent: &str) -> usize {
+    indent.chars().map(|c| if c == '\t' { 4 } else { 1 }).sum()
+}
+
+/// Represents a single sprint extracted from MVP breakdown
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SprintData {
+    /// Sprint number (e.g., 4)
+    pub number: u32,
+    /// Sprint name/slug (e.g., "the-sprint-orchestrator")
+    pub name: String,
+    /// Sprint title (e.g., "The Sprint Orchestrator (The Leash)")
+    pub title: String,
+    /// Focus statement (`_Focus: ..._`)
+    pub focus: Option<String>,
+    /// Sprints and tasks that must be done first (`**Depends on:** ...`)
+    pub depends_on: Vec<DependencyRef>,
+    pub tasks: Vec<MvpTask>,
+    /// Exit criteria, one per line or list item
+    pub exit_criteria: Vec<String>,
+    /// Byte range of the `## Sprint` heading line in the parsed content
+    #[serde(skip)]
+    pub heading: Range<usize>,
+}
+
+impl SprintData {
+    /// Heading text (`Sprint 4: The Sprint Orchestrator`)
+    pub fn heading_text(&self) -> String {
+        format!("Sprint {}: {}", self.number, self.title)
+    }
+
+    /// Prefix of positional task ids (`s4`)
+    pub fn id_prefix(&self) -> String {
+        format!("s{}", self.number)
+    }
+
+    /// Every task and sub-task, depth first
+    pub fn all_tasks(&self) -> Vec<&MvpTask> {
+        fn collect<'a>(tasks: &'a [MvpTask], all: &mut Vec<&'a MvpTask>) {
+            for task in tasks {
+                all.push(task);
+                collect(&task.subtasks, all);
+            }
+        }
+
+        let mut all = Vec::new();
+        collect(&self.tasks, &mut all);
+        all
+    }
+
+    pub fn total_estimate(&self) -> Option<Estimate> {
+        sum_estimates(&self.tasks)
+    }
+}
+
+/// Parse sprints from 05-MVP-Breakdown.md
+///
+/// # Arguments
+/// * `mvp_breakdown_path` - Path to 05-MVP-Breakdown.md
+///
+/// # Returns
+/// * `Ok(Vec<SprintData>)` - List of all sprints found
+/// * `Err` - File could not be read
+pub fn parse_mvp_sprints(mvp_breakdown_path: &Path) -> Result<Vec<SprintData>> {
+    let content = fs::read_to_string(mvp_breakdown_path).with_context(|| {
+        format!(
+            "Failed to read MVP breakdown: {}",
+            mvp_breakdown_path.display()
+        )
+    })?;
+
+    Ok(parse_sprints(&content))
+}
+
+/// Write the positional ids of referenced tasks into the MVP breakdown
+///
+/// # Returns
+/// * `Ok(Vec<String>)` - Ids written (the file is left alone when there are none)
+/// * `Err` - File could not be read or written
+pub fn pin_mvp_task_ids(mvp_breakdown_path: &Path) -> Result<Vec<String>> {
+    let content = fs::read_to_string(mvp_breakdown_path).with_context(|| {
+        format!(
+            "Failed to read MVP breakdown: {}",
+            mvp_breakdown_path.display()
+        )
+    })?;
+    let (pinned, ids) = pin_referenced_task_ids(&content);
+    if !ids.is_empty() {
+        fs::write(mvp_breakdown_path, pinned).with_context(|| {
+            format!(
+                "Failed to write MVP breakdown: {}",
+                mvp_breakdown_path.display()
+            )
+        })?;
+    }
+    Ok(ids)
+}
+
+/// Adds `🆔` to tasks that dependencies refer to by their positional id
+///
+/// A positional id (`s2-3`) moves to another task as soon as one is inserted
+/// above it; written out, it stays with its task. Returns the updated content
+/// and the ids written, in document order.
+pub fn pin_referenced_task_ids(content: &str) -> (String, Vec<String>) {
+    let sprints = parse_sprints(content);
+    let tasks: Vec<&MvpTask> = sprints.iter().flat_map(SprintData::all_tasks).collect();
+    let referenced: HashSet<&str> = sprints
+        .iter()
+        .flat_map(|sprint| &sprint.depends_on)
+        .chain(tasks.iter().flat_map(|task| &task.depends_on))
+        .filter_map(|dependency| match dependency {
+            DependencyRef::Task(id) => Some(id.as_str()),
+            DependencyRef::Sprint(_) => None,
+        })
+        .collect();
+
+    let mut unpinned: Vec<&MvpTask> = tasks
+        .into_iter()
+        .filter(|task| {
+            referenced.contains(task.id.as_str()) && explicit_id(&content[task.line.clone()]).is_none()
+        })
+        .collect();
+    unpinned.sort_by_key(|task| task.line.start);
+
+    let mut updated = content.to_string();
+    for task in unpinned.iter().rev() {
+        updated.insert_str(task.line.end, &format!(" 🆔 {}", task.id));
+    }
+    (updated, unpinned.iter().map(|task| task.id.clone()).collect())
+}
+
+/// Id a task line sets itself (`🆔` or `[id:: ...]`)
+fn explicit_id(line: &str) -> Option<String> {
+    TASK_ID
+        .captures(line)
+        .map(|captures| captures[1].to_string())
+        .or_else(|| {
+            INLINE_FIELD
+                .captures_iter(line)
+                .filter(|captures| captures[1].eq_ignore_ascii_case("id"))
+                .map(|captures| captures[2].trim().to_string())
+                .find(|id| !id.is_empty())
+        })
+}
+
+/// Parse sprints from the content of an MVP breakdown
+///
+/// Extracts sprint sections with their focus, dependencies, tasks and exit
+/// criteria. Uses a line-based approach: every `## Sprint N: Title` heading
+/// starts a sprint, any other H2 heading ends it.
+pub fn parse_sprints(content: &str) -> Vec<SprintData> {
+    let mut sprints = Vec::new();
+    let mut current_sprint: Option<SprintData> = None;
+    let mut current_lines = Vec::new();
+
+    for (line, range) in body_lines(content) {
+        let trimmed = line.trim();
+
+        // Detect H2 headings (sprint sections)
+        if trimmed.starts_with("## ") {
+            // Save previous sprint
+            if let Some(mut sprint) = current_sprint.take() {
+                extract_sprint_details(&mut sprint, &current_lines);
+                sprints.push(sprint);
+                current_lines.clear();
+            }
+
+            // Start new sprint
+            if trimmed.starts_with("## Sprint") {
+                let header = trimmed.strip_prefix("##").unwrap().trim();
+                current_sprint = parse_sprint_header(header);
+                if let Some(sprint) = &mut current_sprint {
+                    sprint.heading = range;
+                }
+            }
+        } else if current_sprint.is_some() {
+            // Collect content for current sprint
+            current_lines.push((line, range));
+        }
+    }
+
+    // Save the last sprint
+    if let Some(mut sprint) = current_sprint {
+        extract_sprint_details(&mut sprint, &current_lines);
+        sprints.push(sprint);
+    }
+
+    sprints
+}
+
+/// Parse a sprint header to extract number and name
+///
+/// Examples:
+/// - "Sprint 0: Setup (day 1)" -> (0, "setup", "Setup (day 1)")
+/// - "Sprint 4: The Sprint Orchestrator (The Leash)" -> (4, "the-sprint-orchestrator", "The Sprint Orchestrator (The Leash)")
+fn parse_sprint_header(header: &str) -> Option<SprintData> {
+    // Pattern: "Sprint X: Title"
+    let parts: Vec<&str> = header.splitn(2, ':').collect();
+    if parts.len() != 2 {
+        return None;
+    }
+
+    // Extract sprint number
+    let sprint_prefix = parts[0].trim();
+    let number = sprint_prefix
+        .strip_prefix("Sprint ")
+        .and_then(|s| s.parse::<u32>().ok())?;
+
+    // Extract title and generate slug
+    let title = parts[1].trim().to_string();
+    let name = generate_sprint_slug(&title);
+
+    Some(SprintData {
+        number,
+        name,
+        title,
+        focus: None,
+        depends_on: Vec::new(),
+        tasks: Vec::new(),
+        exit_criteria: Vec::new(),
+        heading: 0..0,
+    })
+}
+
+/// Items of the Definition of Done section, written as tasks or plain list items
+///
+/// `headers` carries the planning schema's aliases, so translated headings are found too.
+pub fn parse_definition_of_done(content: &str, headers: &HeaderMatcher) -> Vec<String> {
+    let Some((range, _)) = find_section(content, DEFINITION_OF_DONE, headers) else {
+        return Vec::new();
+    };
+    content[range]
+        .lines()
+        .skip(1)
+        .filter_map(|line| match TASK_LINE.captures(line) {
+            Some(captures) => captures.get(3).map(|text| text.as_str().trim().to_string()),
+            None => BULLET_LINE
+                .captures(line)
+                .map(|captures| captures[1].trim().to_string()),
+        })
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// Generate a URL-friendly slug from sprint title
+///
+/// "The Sprint Orchestrator (The Leash)" -> "the-sprint-orchestrator"
+fn generate_sprint_slug(title: &str) -> String {
+    // Remove parenthetical suffixes
+    let clean_title = title.split('(').next().unwrap_or(title).trim();
+
+    // Convert to lowercase and replace spaces with hyphens
+    clean_title
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Extract focus, dependencies, tasks and exit criteria from sprint content
+fn extract_sprint_details(sprint: &mut SprintData, lines: &[(&str, Range<usize>)]) {
+    let id_prefix = sprint.id_prefix();
+    let mut open = OpenTasks::new(lines.iter().map(|(line, _)| *line));
+    // Whether list items below are exit criteria (after an `**Exit criteria:**` line)
+    let mut in_exit_criteria = false;
+
+    for (line, range) in lines {
+        let line = *line;
+        if let Some(captures) = TASK_LINE.captures(line) {
+            open.push(&mut sprint.tasks, &id_prefix, &captures, range.clone());
+            in_exit_criteria = false;
+        } else if let Some(focus) = labeled(line, "focus") {
+            sprint.focus = Some(focus.to_string()).filter(|focus| !focus.is_empty());
+        } else if let Some(criteria) = labeled(line, "exit criteria") {
+            if !criteria.is_empty() {
+                sprint.exit_criteria.push(criteria.to_string());
+            }
+            in_exit_criteria = true;
+        } else if let Some(dependencies) =
+            labeled(line, "depends on").or_else(|| labeled(line, "depends-on"))
+        {
+            sprint.depends_on.extend(DependencyRef::parse_list(dependencies));
+        } else if in_exit_criteria && let Some(captures) = BULLET_LINE.captures(line) {
+            sprint.exit_criteria.push(captures[1].trim().to_string());
+        } else if !line.trim().is_empty() {
+            in_exit_criteria = false;
+        }
+    }
+}
+
+/// Value of a `Label: value` line, with `**`/`_` emphasis around either part removed
+fn labeled<'a>(line: &'a str, label: &str) -> Option<&'a str> {
+    let text = line.trim().trim_start_matches(['*', '_']);
+    let prefix = text.get(..label.len())?;
+    if !prefix.eq_ignore_ascii_case(label) {
+        return None;
+    }
+    let value = text[label.len()..]
+        .trim_start_matches(['*', '_'])
+        .strip_prefix(':')?;
+    Some(
+        value
+            .trim_start_matches(['*', '_'])
+            .trim()
+            .trim_end_matches(['*', '_'])
+            .trim(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sprint_header() {
+        let result = parse_sprint_header("Sprint 4: The Sprint Orchestrator (The Leash)");
+        assert!(result.is_some());
+
+        let sprint = result.unwrap();
+        assert_eq!(sprint.number, 4);
+        assert_eq!(sprint.name, "the-sprint-orchestrator");
+        assert_eq!(sprint.title, "The Sprint Orchestrator (The Leash)");
+    }
+
+    #[test]
+    fn test_parse_sprint_header_simple() {
+        let result = parse_sprint_header("Sprint 0: Setup (day 1)");
+        assert!(result.is_some());
+
+        let sprint = result.unwrap();
+        assert_eq!(sprint.number, 0);
+        assert_eq!(sprint.name, "setup");
+        assert_eq!(sprint.title, "Setup (day 1)");
+    }
+
+    #[test]
+    fn test_parse_sprint_header_invalid() {
+        assert!(parse_sprint_header("Not a sprint header").is_none());
+        assert!(parse_sprint_header("Sprint X: Invalid").is_none());
+        assert!(parse_sprint_header("Random text").is_none());
+    }
+
+    #[test]
+    fn test_generate_sprint_slug() {
+        assert_eq!(
+            generate_sprint_slug("The Sprint Orchestrator (The Leash)"),
+            "the-sprint-orchestrator"
+        );
+        assert_eq!(generate_sprint_slug("Setup (day 1)"), "setup");
+        assert_eq!(
+            generate_sprint_slug("The Gatekeeper (The Enforcer)"),
+            "the-gatekeeper"
+        );
+        assert_eq!(generate_sprint_slug("Init Command"), "init-command");
+    }
+
+    #[test]
+    fn test_parse_definition_of_done() {
+        let content = "## Sprint 1: Core\n- [ ] Task\n\n## Definition of Done (each sprint):\n- [ ] Builds without errors\n- [x] Tested\n* Session log updated\n\n---\nDone when: ...\n";
+        let headers = crate::schema::planning::PlanningSchema::builtin()
+            .unwrap()
+            .header_matcher();
+        assert_eq!(
+            parse_definition_of_done(content, &headers),
+            vec!["Builds without errors", "Tested", "Session log updated"]
+        );
+
+        // Translated heading, through the schema's aliases
+        let swedish = "## Definition av klart (varje sprint):\n- [ ] Bygger utan fel\n";
+        assert_eq!(parse_definition_of_done(swedish, &headers), vec!["Bygger utan fel"]);
+        assert!(parse_definition_of_done("## Sprint 1: Core\n", &headers).is_empty());
+    }
+
+    #[test]
+    fn test_parse_mvp_sprints() {
+        let content = r"# MVP broken into sprints
+
+## Sprint 0: Setup (day 1)
+- [x] Create nexus repo with Cargo.toml
+- [x] Add clap with derive feature
+- [ ] Create templates/ folder
+**Exit criteria:** `nexus init` all parse
+
+## Sprint 1: Init Command (days 2-3)
+_Focus: Configuration and initialization._
+
+- [x] Implement config.rs to read/write nexus.toml
+- [ ] Implement init command
+**Exit criteria:** `nexus init test-project` creates folder
+
+## Sprint 4: The Sprint Orchestrator (The Leash)
+_Focus: Creating the Tactical Staging Area._
+
+- [ ] **MVP Parser:** Extract specific sprint tasks
+- [ ] **Branching Logic:** Use the `git2` crate
+**Exit criteria:** `nexus sprint X` creates a clean branch
+
+## Definition of Done (each sprint):
+- [ ] Builds without errors
+";
+
+        let mut temp_file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut temp_file, content.as_bytes()).unwrap();
+
+        let result = parse_mvp_sprints(temp_file.path()).unwrap();
+
+        assert_eq!(result.len(), 3, "Should parse 3 sprints");
+
+        // Check Sprint 0
+        assert_eq!(result[0].number, 0);
+        assert_eq!(result[0].name, "setup");
+        assert_eq!(result[0].title, "Setup (day 1)");
+        assert_eq!(result[0].tasks.len(), 3);
+        assert_eq!(result[0].tasks[0].text, "Create nexus repo with Cargo.toml");
+        assert!(result[0].tasks[0].done);
+        assert_eq!(result[0].exit_criteria, vec!["`nexus init` all parse"]);
+        assert_eq!(result[0].focus, None);
+
+        // Check Sprint 1
+        assert_eq!(result[1].number, 1);
+        assert_eq!(result[1].name, "init-command");
+        assert_eq!(result[1].tasks[1].id, "s1-2");
+        assert_eq!(
+            result[1].focus.as_deref(),
+            Some("Configuration and initialization.")
+        );
+
+        // Check Sprint 4 (the Definition of Done section is not part of it)
+        assert_eq!(result[2].number, 4);
+        assert_eq!(result[2].name, "the-sprint-orchestrator");
+        assert_eq!(result[2].tasks.len(), 2);
+        assert_eq!(
+            result[2].tasks[0].text,
+            "**MVP Parser:** Extract specific sprint tasks"
+        );
+    }
+
+    #[test]
+    fn test_parse_task_metadata_and_nesting() {
+        let content = "## Sprint 2: Sync
+**Depends on:** Sprint 1, s0-3
+
+- [ ] Sync engine 🆔 sync ~4h #backend ⏫ 📅 2026-11-01
+  - [x] Conflict resolution ~2h
+  - [ ] Retry queue ~30m
+    - [ ] Backoff
+- [ ] Sync status UI ~1.5h #frontend ⛔ sync,s1-2
+- [ ] Settings screen [depends-on:: sprint-1] [estimate:: 1d] [id:: settings] for #2
+**Exit criteria:**
+- Two devices converge within 5 seconds
+1. No data loss while offline
+
+Notes after the criteria
+- not a criterion
+";
+        let sprints = parse_sprints(content);
+        let sprint = &sprints[0];
+        assert_eq!(
+            sprint.depends_on,
+            vec![DependencyRef::Sprint(1), DependencyRef::Task("s0-3".into())]
+        );
+
+        let engine = &sprint.tasks[0];
+        assert_eq!(engine.id, "sync");
+        assert_eq!(engine.text, "Sync engine");
+        assert_eq!(engine.estimate, Estimate::parse("4h"));
+        assert_eq!(engine.tags, vec!["backend"]);
+        assert_eq!(engine.priority, Some(Priority::High));
+        assert_eq!(engine.due, NaiveDate::from_ymd_opt(2026, 11, 1));
+        let ids: Vec<&str> = sprint.all_tasks().iter().map(|task| task.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec!["sync", "sync-1", "sync-2", "sync-2-1", "s2-2", "settings"]
+        );
+        assert!(engine.subtasks[0].done);
+
+        let status = &sprint.tasks[1];
+        assert_eq!(status.text, "Sync status UI");
+        assert_eq!(status.estimate, Some(Estimate::from_minutes(90)));
+        assert_eq!(
+            status.depends_on,
+            vec![DependencyRef::Task("sync".into()), DependencyRef::Task("s1-2".into())]
+        );
+
+        let settings = &sprint.tasks[2];
+        assert_eq!(settings.text, "Settings screen for #2");
+        assert_eq!(settings.depends_on, vec![DependencyRef::Sprint(1)]);
+        assert_eq!(settings.estimate.unwrap().to_string(), "1d");
+
+        assert_eq!(
+            sprint.exit_criteria,
+            vec!["Two devices converge within 5 seconds", "No data loss while offline"]
+        );
+        // 4h (own estimate wins over sub-tasks) + 1.5h + 1d
+        assert_eq!(sprint.total_estimate().unwrap().to_string(), "13.5h");
+    }
+
+    #[test]
+    fn test_rendered_tasks_parse_back() {
+        let content = "## Sprint 3: Polish
+- [ ] Sync engine 🆔 sync ~4h #backend ⏫ 📅 2026-11-01
+  - [x] Conflict resolution ~2h
+- [ ] Status UI ⛔ sync,sprint-2
+";
+        let sprint = &parse_sprints(content)[0];
+        let rendered = render_tasks(&sprint.tasks);
+        assert_eq!(
+            rendered,
+            "- [ ] Sync engine 🆔 sync ~4h #backend ⏫ 📅 2026-11-01\n  \
+             - [x] Conflict resolution 🆔 sync-1 ~2h\n\
+             - [ ] Status UI 🆔 s3-2 ⛔ sync,sprint-2"
+        );
+        assert_eq!(render_tasks(&parse_task_list(&rendered, "other")), rendered);
+        assert_eq!(&content[sprint.heading.clone()], "## Sprint 3: Polish");
+        assert_eq!(&content[sprint.tasks[1].line.clone()], "- [ ] Status UI ⛔ sync,sprint-2");
+
+        // Done sub-tasks drop out; a parent stays while it has open sub-tasks
+        let open = unfinished(&sprint.tasks);
+        assert_eq!(open.len(), 2);
+        assert!(open[0].subtasks.is_empty());
+    }
+
+    #[test]
+    fn test_carry_over_tags_unfinished_tasks() {
+        let tasks = parse_task_list(
+            "- [x] Engine 🆔 s3-1
+- [ ] Status UI 🆔 s3-2 #frontend
+  - [ ] Dark mode 🆔 s3-2-1
+",
+            "s3",
+        );
+        assert_eq!((count_done(&tasks), count_tasks(&tasks)), (1, 3));
+        let carried = carry_over(&tasks, 3);
+        assert_eq!(carried.len(), 1);
+        assert_eq!(carried[0].id, "s3-2");
+        assert_eq!(carried[0].tags, vec!["frontend", CARRIED_OVER_TAG]);
+        assert_eq!(carried[0].subtasks[0].carried_from, Some(3));
+
+        let rendered = render_tasks(&carried);
+        assert_eq!(
+            rendered,
+            "- [ ] Status UI 🆔 s3-2 #frontend #carried-over [carried-from:: sprint-3]\n  \
+             - [ ] Dark mode 🆔 s3-2-1 #carried-over [carried-from:: sprint-3]"
+        );
+        // Carried tasks keep their origin when they are read back (or carried again)
+        let reread = parse_task_list(&rendered, "s4");
+        assert_eq!(reread, parse_task_list(&render_tasks(&carry_over(&reread, 3)), "s4"));
+        assert_eq!(reread[0].carried_from, Some(3));
+    }
+
+    #[test]
+    fn test_pinned_ids_survive_inserted_tasks() {
+        let content = "## Sprint 1: Storage
+- [ ] Schema
+- [ ] Migrations [id:: migrations]
+- [ ] Queries
+
+## Sprint 2: Sync
+**Depends on:** s1-1
+- [ ] Engine ⛔ s1-3, migrations
+";
+        let (pinned, ids) = pin_referenced_task_ids(content);
+        assert_eq!(ids, vec!["s1-1", "s1-3"]);
+        assert!(pinned.contains("- [ ] Schema 🆔 s1-1\n"));
+        assert!(pinned.contains("- [ ] Queries 🆔 s1-3\n"));
+        assert_eq!(pin_referenced_task_ids(&pinned), (pinned.clone(), Vec::new()));
+
+        // Inserting a task above the referenced ones leaves them their ids
+        let edited = pinned.replace("- [ ] Schema", "- [ ] Research\n- [ ] Schema");
+        let sprints = parse_sprints(&edited);
+        let text_of = |id: &str| {
+            sprints[0]
+                .tasks
+                .iter()
+                .find(|task| task.id == id)
+                .map(|task| task.text.clone())
+        };
+        assert_eq!(text_of("s1-1").as_deref(), Some("Schema"));
+        assert_eq!(text_of("s1-3").as_deref(), Some("Queries"));
+
+        assert_eq!(text_of("s1-2").as_deref(), Some("Research"));
+
+        // Without pinning, the same edit gives s1-1 to another task
+        let sprints = parse_sprints(&content.replace("- [ ] Schema", "- [ ] Research\n- [ ] Schema"));
+        assert_eq!(sprints[0].tasks[0].id, "s1-1");
+        assert_eq!(sprints[0].tasks[0].text, "Research");
+    }
+}
@@ -5,21 +5,96 @@
 
 #![allow(clippy::similar_names)] // context/content are domain-appropriate names
 
-use crate::heuristics::GateHeuristics;
+use crate::frontmatter::{Frontmatter, body_events, body_start};
+use crate::heuristics::{CompiledRule, GateHeuristics};
+use crate::links::VaultIndex;
+use crate::mvp::{SprintData, parse_sprints};
+use crate::schema::planning::{PlanningSchema, WHOLE_DOCUMENT};
+use crate::transclusion::expand_embeds;
 use anyhow::{Context, Result};
-use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
-use std::collections::{HashMap, HashSet};
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Tag, TagEnd};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
+/// A byte offset in a source file resolved to a 1-based line and column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SourcePosition {
+    /// Byte offset from the start of the file
+    pub offset: usize,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column, counted in characters
+    pub column: usize,
+}
+
+/// A range of source text that an issue refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SourceSpan {
+    pub start: SourcePosition,
+    pub end: SourcePosition,
+}
+
+impl SourceSpan {
+    /// Length of the span in bytes
+    pub fn len(&self) -> usize {
+        self.end.offset.saturating_sub(self.start.offset)
+    }
+}
+
+/// Maps byte offsets in a document to line/column positions
+pub struct LineIndex<'a> {
+    source: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    /// Builds the line table for a document
+    pub fn new(source: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    /// Resolves a byte offset to a position (clamped to the document length)
+    pub fn position(&self, offset: usize) -> SourcePosition {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line_index = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line_index];
+        SourcePosition {
+            offset,
+            line: line_index + 1,
+            column: self.source[line_start..offset].chars().count() + 1,
+        }
+    }
+
+    /// Resolves a byte range to a span
+    pub fn span(&self, range: std::ops::Range<usize>) -> SourceSpan {
+        SourceSpan {
+            start: self.position(range.start),
+            end: self.position(range.end),
+        }
+    }
+}
+
 /// Represents a single validation issue found in a document
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ValidationIssue {
     /// Section has fewer words than required minimum
     SectionTooShort {
         header: String,
         word_count: usize,
         required: usize,
+        /// Span of the whole section, from its heading to the last content line
+        span: SourceSpan,
     },
     /// Required header is missing from the document
     MissingHeader { header: String },
@@ -27,13 +102,188 @@ pub enum ValidationIssue {
     IllegalString {
         string: String,
         context: String,
-        line_estimate: usize,
+        /// Span of the matched string
+        span: SourceSpan,
+        /// Header of the section the string was found in
+        section: Option<String>,
     },
     /// Unchecked checkbox found in dashboard
     UncheckedCheckbox {
         context: String,
-        line_estimate: usize,
+        /// Span of the list item line holding the checkbox
+        span: SourceSpan,
+        /// Header of the section the checkbox belongs to
+        section: Option<String>,
+    },
+    /// A term ruled out in one planning document is used in another
+    CrossDocumentConflict {
+        /// Conflicting term as written in the exclusion list
+        term: String,
+        /// How the other document rules it out (e.g. "excluded")
+        relation: String,
+        /// Span of the term where it is used
+        span: SourceSpan,
+        /// Section the term is used in
+        section: Option<String>,
+        /// File name of the document that rules the term out
+        other_file: String,
+        /// Section of that document listing the term
+        other_section: String,
+        /// Span of the list item in that document
+        other_span: SourceSpan,
+    },
+    /// A wikilink or embed whose target does not exist in the vault
+    BrokenLink {
+        /// Link target as written, without the alias (e.g. "04-Architecture#Data model")
+        target: String,
+        /// Whether the link is an embed (`![[...]]`)
+        embed: bool,
+        /// What did not resolve (note, file, heading or block)
+        reason: String,
+        /// Span of the link, brackets included
+        span: SourceSpan,
+        /// Section the link appears in
+        section: Option<String>,
     },
+    /// A scoped heuristics rule did not hold
+    RuleViolation {
+        /// Rule identifier from the heuristics file
+        rule: String,
+        message: String,
+        /// Span of the offending text, or of the section heading; `None` for whole-document scopes
+        span: Option<SourceSpan>,
+        /// Header of the section the rule is scoped to
+        section: Option<String>,
+    },
+    /// A sprint or task dependency in the MVP breakdown is broken
+    InvalidDependency {
+        /// `unknown-dependency`, `dependency-cycle` or `duplicate-task-id`
+        rule: String,
+        message: String,
+        /// Span of the task line or sprint heading
+        span: SourceSpan,
+        /// Heading of the sprint (e.g. "Sprint 1: Core")
+        section: Option<String>,
+    },
+}
+
+impl ValidationIssue {
+    /// Stable rule identifier used in machine-readable reports
+    pub fn rule_id(&self) -> &str {
+        match self {
+            Self::SectionTooShort { .. } => "section-too-short",
+            Self::MissingHeader { .. } => "missing-header",
+            Self::IllegalString { .. } => "illegal-string",
+            Self::UncheckedCheckbox { .. } => "unchecked-checkbox",
+            Self::CrossDocumentConflict { .. } => "cross-document-conflict",
+            Self::BrokenLink { .. } => "broken-link",
+            Self::RuleViolation { rule, .. } | Self::InvalidDependency { rule, .. } => rule,
+        }
+    }
+
+    /// Source span of the issue, if it has a position in the document
+    pub fn span(&self) -> Option<&SourceSpan> {
+        match self {
+            Self::SectionTooShort { span, .. }
+            | Self::IllegalString { span, .. }
+            | Self::UncheckedCheckbox { span, .. }
+            | Self::CrossDocumentConflict { span, .. }
+            | Self::BrokenLink { span, .. }
+            | Self::InvalidDependency { span, .. } => Some(span),
+            Self::RuleViolation { span, .. } => span.as_ref(),
+            Self::MissingHeader { .. } => None,
+        }
+    }
+
+    /// Mutable access to the issue's own span (not `other_span` of cross-document conflicts)
+    pub fn span_mut(&mut self) -> Option<&mut SourceSpan> {
+        match self {
+            Self::SectionTooShort { span, .. }
+            | Self::IllegalString { span, .. }
+            | Self::UncheckedCheckbox { span, .. }
+            | Self::CrossDocumentConflict { span, .. }
+            | Self::BrokenLink { span, .. }
+            | Self::InvalidDependency { span, .. } => Some(span),
+            Self::RuleViolation { span, .. } => span.as_mut(),
+            Self::MissingHeader { .. } => None,
+        }
+    }
+
+    /// `file:line:col` location for terminal output, if the issue has a position
+    pub fn location(&self, file_path: &Path) -> Option<String> {
+        self.span().map(|span| {
+            format!(
+                "{}:{}:{}",
+                file_path.display(),
+                span.start.line,
+                span.start.column
+            )
+        })
+    }
+
+    /// Section (header) the issue relates to
+    pub fn section(&self) -> Option<&str> {
+        match self {
+            Self::SectionTooShort { header, .. } | Self::MissingHeader { header } => Some(header),
+            Self::IllegalString { section, .. }
+            | Self::UncheckedCheckbox { section, .. }
+            | Self::CrossDocumentConflict { section, .. }
+            | Self::BrokenLink { section, .. }
+            | Self::RuleViolation { section, .. }
+            | Self::InvalidDependency { section, .. } => section.as_deref(),
+        }
+    }
+
+    /// Plain-text description of the issue (no colors)
+    pub fn message(&self) -> String {
+        match self {
+            Self::SectionTooShort {
+                header,
+                word_count,
+                required,
+                ..
+            } => format!("Section '{header}' too short: {word_count} words (need {required})"),
+            Self::MissingHeader { header } => format!("Missing required header: {header}"),
+            Self::IllegalString { string, context, .. } => {
+                format!("Illegal string '{string}' found: {context}")
+            }
+            Self::UncheckedCheckbox { context, .. } => format!("Unchecked task: {context}"),
+            Self::CrossDocumentConflict {
+                term,
+                relation,
+                other_file,
+                other_section,
+                other_span,
+                ..
+            } => format!(
+                "'{term}' is used here but {relation} in {other_file} › {other_section} (line {})",
+                other_span.start.line
+            ),
+            Self::BrokenLink {
+                target,
+                embed,
+                reason,
+                ..
+            } => {
+                let (kind, bang) = if *embed { ("embed", "!") } else { ("link", "") };
+                format!("Broken {kind} {bang}[[{target}]]: {reason}")
+            }
+            Self::RuleViolation { message, .. } | Self::InvalidDependency { message, .. } => {
+                message.clone()
+            }
+        }
+    }
+}
+
+/// A heading found in a document, with its position
+#[derive(Debug, Clone, PartialEq)]
+pub struct SectionHeader {
+    /// Heading text without the leading `#` markers
+    pub text: String,
+    /// Heading level (1-6)
+    pub level: usize,
+    /// Span of the heading line
+    pub span: SourceSpan,
 }
 
 /// Overall validation result for a planning document
@@ -43,6 +293,10 @@ pub struct ValidationResult {
     pub issues: Vec<ValidationIssue>,
     /// Sections found in the document with their word counts
     pub sections: HashMap<String, usize>,
+    /// Headings found in the document, in order
+    pub headers: Vec<SectionHeader>,
+    /// Properties from the document's frontmatter block
+    pub frontmatter: Frontmatter,
     /// Whether the document passes validation
     pub passed: bool,
 }
@@ -53,6 +307,8 @@ impl ValidationResult {
         Self {
             issues: Vec::new(),
             sections: HashMap::new(),
+            headers: Vec::new(),
+            frontmatter: Frontmatter::default(),
             passed: true,
         }
     }
@@ -70,123 +326,194 @@ impl ValidationResult {
     }
 }
 
-/// Validates a planning document against heuristics using streaming parser
-///
-/// # Arguments
-/// * `file_path` - Path to the markdown file to validate
-/// * `heuristics` - Validation rules to apply
-///
-/// # Returns
-/// * `Ok(ValidationResult)` - Validation completed (may contain issues)
-/// * `Err` - File could not be read or parsed
-///
-/// # Memory Efficiency
-/// This function uses event-based streaming and does NOT load the entire file into memory.
-#[allow(dead_code)]
-pub fn validate_planning_document(
-    file_path: &Path,
-    heuristics: &GateHeuristics,
-) -> Result<ValidationResult> {
-    let content = fs::read_to_string(file_path)
-        .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+/// Heading currently being read during the streaming pass
+struct PendingHeading {
+    level: HeadingLevel,
+    range: std::ops::Range<usize>,
+    text: String,
+}
 
-    let mut result = ValidationResult::new();
-    let parser = Parser::new(&content);
+/// Section whose content is currently being counted
+struct OpenSection {
+    header: String,
+    level: HeadingLevel,
+    start: usize,
+    words: usize,
+}
 
-    // Tracking state during streaming
-    let mut current_header: Option<String> = None;
-    let mut current_header_level: Option<pulldown_cmark::HeadingLevel> = None;
-    let mut current_section_words: Vec<String> = Vec::new();
-    let mut found_headers: HashSet<String> = HashSet::new();
-    let mut line_number: usize = 1;
+/// Streams through a document once, recording headings, section word counts,
+/// short sections and illegal strings with exact source spans
+///
+/// `is_illegal(text, illegal)` decides whether a text node contains a forbidden string.
+fn scan_document<F>(
+    content: &str,
+    min_word_count: usize,
+    illegal_strings: &[String],
+    is_illegal: F,
+) -> ValidationResult
+where
+    F: Fn(&str, &str) -> bool,
+{
+    let index = LineIndex::new(content);
+    let mut result = ValidationResult::new();
+    let mut heading: Option<PendingHeading> = None;
+    let mut section: Option<OpenSection> = None;
 
-    for event in parser {
+    for (event, range) in body_events(content, Options::empty()) {
         match event {
             Event::Start(Tag::Heading { level, .. }) => {
                 // Save previous section if exists
-                if let Some(ref header) = current_header {
-                    let word_count = current_section_words.len();
-                    result.sections.insert(header.clone(), word_count);
-
-                    // Only validate word count for H2+ headers (skip H1 document titles)
-                    if let Some(header_level) = current_header_level {
-                        if header_level as u32 >= 2 && word_count < heuristics.min_section_length as usize {
-                            result.add_issue(ValidationIssue::SectionTooShort {
-                                header: header.clone(),
-                                word_count,
-                                required: heuristics.min_section_length as usize,
-                            });
-                        }
-                    }
+                if let Some(open) = section.take() {
+                    close_section(&mut result, open, range.start, min_word_count, content, &index);
                 }
-                // Reset for new section
-                current_section_words.clear();
-                current_header = None;
-                current_header_level = Some(level);
+                heading = Some(PendingHeading {
+                    level,
+                    range,
+                    text: String::new(),
+                });
             }
             Event::End(TagEnd::Heading(_)) => {
                 // Header text is now complete
-                if let Some(header_text) = current_header.as_ref() {
-                    found_headers.insert(header_text.clone());
+                if let Some(pending) = heading.take() {
+                    let text = pending.text.trim().to_string();
+                    result.headers.push(SectionHeader {
+                        text: text.clone(),
+                        level: pending.level as usize,
+                        span: index.span(trim_range_end(content, pending.range.clone())),
+                    });
+                    section = Some(OpenSection {
+                        header: text,
+                        level: pending.level,
+                        start: pending.range.start,
+                        words: 0,
+                    });
+                }
+            }
+            Event::Code(code) => {
+                if let Some(pending) = heading.as_mut() {
+                    pending.text.push_str(&code);
                 }
             }
             Event::Text(text) => {
                 // If we're in a header, capture the header text
-                if current_header.is_none() {
-                    current_header = Some(text.to_string());
-                } else {
-                    // Count words in section content
-                    let words: Vec<&str> = text.split_whitespace().collect();
-                    for word in &words {
-                        current_section_words.push(word.to_string());
-                    }
+                if let Some(pending) = heading.as_mut() {
+                    pending.text.push_str(&text);
+                    continue;
+                }
 
-                    // Check for illegal strings
-                    for illegal in &heuristics.illegal_strings {
-                        if text.contains(illegal.as_str()) {
-                            result.add_issue(ValidationIssue::IllegalString {
-                                string: illegal.clone(),
-                                context: text.chars().take(50).collect(),
-                                line_estimate: line_number,
-                            });
-                        }
-                    }
+                // Count words in section content
+                if let Some(open) = section.as_mut() {
+                    open.words += text.split_whitespace().count();
+                }
 
-                    // Estimate line numbers
-                    line_number += text.matches('\n').count();
+                // Check for illegal strings
+                for illegal in illegal_strings {
+                    if is_illegal(&text, illegal) {
+                        let source = &content[range.clone()];
+                        let span = find_ignore_ascii_case(source, illegal).map_or_else(
+                            || range.clone(),
+                            |at| range.start + at..range.start + at + illegal.len(),
+                        );
+                        result.add_issue(ValidationIssue::IllegalString {
+                            string: illegal.clone(),
+                            context: text.chars().take(50).collect(),
+                            span: index.span(span),
+                            section: section.as_ref().map(|open| open.header.clone()),
+                        });
+                    }
                 }
             }
-            Event::SoftBreak | Event::HardBreak => {
-                line_number += 1;
-            }
             _ => {}
         }
     }
 
     // Save final section
-    if let Some(header) = current_header {
-        let word_count = current_section_words.len();
-        result.sections.insert(header.clone(), word_count);
-
-        // Only validate word count for H2+ headers (skip H1 document titles)
-        if let Some(header_level) = current_header_level {
-            if header_level as u32 >= 2 && word_count < heuristics.min_section_length as usize {
-                result.add_issue(ValidationIssue::SectionTooShort {
-                    header: header.clone(),
-                    word_count,
-                    required: heuristics.min_section_length as usize,
-                });
-            }
-        }
+    if let Some(open) = section {
+        close_section(&mut result, open, content.len(), min_word_count, content, &index);
     }
 
+    result.frontmatter = Frontmatter::parse(content);
+    result
+}
+
+/// Records a finished section and flags it if it is too short
+fn close_section(
+    result: &mut ValidationResult,
+    section: OpenSection,
+    end: usize,
+    min_word_count: usize,
+    content: &str,
+    index: &LineIndex,
+) {
+    result.sections.insert(section.header.clone(), section.words);
+
+    // Only validate word count for H2+ headers (skip H1 document titles)
+    if section.level != HeadingLevel::H1 && section.words < min_word_count {
+        let range = trim_range_end(content, section.start..end);
+        result.add_issue(ValidationIssue::SectionTooShort {
+            header: section.header,
+            word_count: section.words,
+            required: min_word_count,
+            span: index.span(range),
+        });
+    }
+}
+
+/// Shrinks a byte range so it does not end in trailing whitespace
+fn trim_range_end(content: &str, range: std::ops::Range<usize>) -> std::ops::Range<usize> {
+    let trimmed = content[range.clone()].trim_end().len();
+    range.start..range.start + trimmed
+}
+
+/// Finds the byte offset of `needle` in `haystack`, ignoring ASCII case
+fn find_ignore_ascii_case(haystack: &str, needle: &str) -> Option<usize> {
+    if needle.is_empty() {
+        return None;
+    }
+    haystack.char_indices().map(|(i, _)| i).find(|&i| {
+        haystack
+            .get(i..i + needle.len())
+            .is_some_and(|candidate| candidate.eq_ignore_ascii_case(needle))
+    })
+}
+
+/// Validates a planning document against heuristics using streaming parser
+///
+/// # Arguments
+/// * `file_path` - Path to the markdown file to validate
+/// * `heuristics` - Validation rules to apply
+///
+/// # Returns
+/// * `Ok(ValidationResult)` - Validation completed (may contain issues)
+/// * `Err` - File could not be read or parsed
+///
+/// # Memory Efficiency
+/// This function uses event-based streaming and does NOT build a document tree.
+#[allow(dead_code)]
+pub fn validate_planning_document(
+    file_path: &Path,
+    heuristics: &GateHeuristics,
+) -> Result<ValidationResult> {
+    let content = fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+
+    let mut result = scan_document(
+        &content,
+        heuristics.min_section_length as usize,
+        &heuristics.illegal_strings,
+        |text, illegal| text.contains(illegal),
+    );
+
     // Check for missing required headers
-    for required_header in &heuristics.required_headers {
-        if !found_headers.contains(required_header) {
-            result.add_issue(ValidationIssue::MissingHeader {
-                header: required_header.clone(),
-            });
-        }
+    let found_headers: HashSet<&str> = result.headers.iter().map(|h| h.text.as_str()).collect();
+    let missing: Vec<String> = heuristics
+        .required_headers
+        .iter()
+        .filter(|required| !found_headers.contains(required.as_str()))
+        .cloned()
+        .collect();
+    for header in missing {
+        result.add_issue(ValidationIssue::MissingHeader { header });
     }
 
     Ok(result)
@@ -198,7 +525,7 @@ pub fn validate_planning_document(
 /// * "Dependencies (important ones):" -> "Dependencies"
 /// * "Flow (user journey):" -> "Flow"
 /// * "Folder structure:" -> "Folder structure:"
-fn normalize_header(header: &str) -> String {
+pub fn normalize_header(header: &str) -> String {
     // Remove text in parentheses and trim
     let mut result = String::new();
     let mut in_parens = false;
@@ -213,6 +540,73 @@ fn normalize_header(header: &str) -> String {
     result.trim().to_string()
 }
 
+/// How document headings are compared with the headers a schema names
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HeaderMatching {
+    /// Parentheticals ignored, case-insensitive
+    #[default]
+    Normalized,
+    /// Also ignores punctuation and spacing ("Why these choices" matches "Why these choices?")
+    Loose,
+}
+
+/// Compares document headings with schema headers and their aliases
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeaderMatcher {
+    matching: HeaderMatching,
+    /// Alternative wordings, keyed by the header as the schema names it
+    aliases: BTreeMap<String, Vec<String>>,
+}
+
+impl HeaderMatcher {
+    pub fn new(matching: HeaderMatching, aliases: BTreeMap<String, Vec<String>>) -> Self {
+        Self { matching, aliases }
+    }
+
+    /// Comparison key of a header
+    fn key(&self, header: &str) -> String {
+        let normalized = normalize_header(header).to_lowercase();
+        match self.matching {
+            HeaderMatching::Normalized => normalized,
+            HeaderMatching::Loose => normalized
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+
+    /// Keys of a wanted header and of every alias it has
+    fn wanted_keys(&self, wanted: &str) -> Vec<String> {
+        let key = self.key(wanted);
+        let mut keys = vec![key.clone()];
+        for (header, aliases) in &self.aliases {
+            if self.key(header) == key {
+                keys.extend(aliases.iter().map(|alias| self.key(alias)));
+            }
+        }
+        keys.retain(|key| !key.is_empty());
+        keys
+    }
+
+    /// Whether a heading contains the wanted header or one of its aliases
+    ///
+    /// This is how required headers are checked: "Stack (choose NOW):" satisfies "Stack:".
+    pub fn matches(&self, heading: &str, wanted: &str) -> bool {
+        let heading = self.key(heading);
+        self.wanted_keys(wanted)
+            .iter()
+            .any(|key| heading.contains(key.as_str()))
+    }
+
+    /// Whether a heading is the wanted header or one of its aliases
+    pub fn same(&self, heading: &str, wanted: &str) -> bool {
+        let heading = self.key(heading);
+        self.wanted_keys(wanted).contains(&heading)
+    }
+}
+
 /// Validates a planning document with specific required headers (context-aware validation)
 ///
 /// # Arguments
@@ -233,113 +627,315 @@ pub fn validate_planning_document_with_headers(
     let content = fs::read_to_string(file_path)
         .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
 
-    let mut result = ValidationResult::new();
-    let parser = Parser::new(&content);
+    Ok(validate_planning_content(
+        &content,
+        required_headers,
+        &HeaderMatcher::default(),
+        min_word_count,
+        illegal_strings,
+    ))
+}
 
-    // Tracking state during streaming
-    let mut current_header: Option<String> = None;
-    let mut current_header_level: Option<pulldown_cmark::HeadingLevel> = None;
-    let mut current_section_words: Vec<String> = Vec::new();
-    let mut found_headers: HashSet<String> = HashSet::new();
-    let mut line_number: usize = 1;
+/// Validates planning document text with specific required headers
+///
+/// Same checks as `validate_planning_document_with_headers`, for text that is
+/// already in memory (e.g. a document with its embeds expanded).
+pub fn validate_planning_content(
+    content: &str,
+    required_headers: &[String],
+    headers: &HeaderMatcher,
+    min_word_count: usize,
+    illegal_strings: &[String],
+) -> ValidationResult {
+    // Check for illegal strings (standalone placeholders only)
+    let mut result = scan_document(content, min_word_count, illegal_strings, |text, illegal| {
+        is_standalone_placeholder(text, illegal)
+    });
 
-    for event in parser {
-        match event {
-            Event::Start(Tag::Heading { level, .. }) => {
-                // Save previous section if exists
-                if let Some(ref header) = current_header {
-                    let word_count = current_section_words.len();
-                    result.sections.insert(header.clone(), word_count);
-
-                    // Only validate word count for H2+ headers (skip H1 document titles)
-                    if let Some(header_level) = current_header_level {
-                        if header_level != pulldown_cmark::HeadingLevel::H1 && word_count < min_word_count {
-                            result.add_issue(ValidationIssue::SectionTooShort {
-                                header: header.clone(),
-                                word_count,
-                                required: min_word_count,
-                            });
-                        }
+    // Check for missing required headers (or one of their aliases)
+    for required_header in required_headers {
+        let found = result
+            .headers
+            .iter()
+            .any(|h| headers.matches(&h.text, required_header));
+        if !found {
+            result.add_issue(ValidationIssue::MissingHeader {
+                header: required_header.clone(),
+            });
+        }
+    }
+
+    result
+}
+
+/// Markdown structure needed to evaluate scoped heuristics rules
+#[derive(Default)]
+struct DocumentFacts {
+    /// Headings as (level, byte range, text)
+    headings: Vec<(HeadingLevel, std::ops::Range<usize>, String)>,
+    /// Text outside headings as (start offset, word count)
+    words: Vec<(usize, usize)>,
+    /// Start offsets of list items
+    list_items: Vec<usize>,
+    /// Start offsets of fenced code blocks
+    code_blocks: Vec<usize>,
+}
+
+impl DocumentFacts {
+    fn collect(content: &str) -> Self {
+        let mut facts = Self::default();
+        let mut heading: Option<PendingHeading> = None;
+
+        for (event, range) in body_events(content, Options::empty()) {
+            match event {
+                Event::Start(Tag::Heading { level, .. }) => {
+                    heading = Some(PendingHeading {
+                        level,
+                        range,
+                        text: String::new(),
+                    });
+                }
+                Event::End(TagEnd::Heading(_)) => {
+                    if let Some(pending) = heading.take() {
+                        facts.headings.push((
+                            pending.level,
+                            pending.range,
+                            pending.text.trim().to_string(),
+                        ));
                     }
                 }
-                // Reset for new section
-                current_section_words.clear();
-                current_header = None;
-                current_header_level = Some(level);
-            }
-            Event::End(TagEnd::Heading(_)) => {
-                // Header text is now complete
-                if let Some(header_text) = current_header.as_ref() {
-                    found_headers.insert(header_text.clone());
+                Event::Text(text) | Event::Code(text) => match heading.as_mut() {
+                    Some(pending) => pending.text.push_str(&text),
+                    None => facts
+                        .words
+                        .push((range.start, text.split_whitespace().count())),
+                },
+                Event::Start(Tag::Item) => facts.list_items.push(range.start),
+                Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(_))) => {
+                    facts.code_blocks.push(range.start);
                 }
+                _ => {}
             }
-            Event::Text(text) => {
-                // If we're in a header, capture the header text
-                if current_header.is_none() {
-                    current_header = Some(text.to_string());
-                } else {
-                    // Count words in section content
-                    let words: Vec<&str> = text.split_whitespace().collect();
-                    for word in &words {
-                        current_section_words.push(word.to_string());
-                    }
+        }
 
-                    // Check for illegal strings (standalone placeholders only)
-                    for illegal in illegal_strings {
-                        // Check if it's a standalone placeholder (not part of a sentence)
-                        if is_standalone_placeholder(&text, illegal) {
-                            result.add_issue(ValidationIssue::IllegalString {
-                                string: illegal.clone(),
-                                context: text.chars().take(50).collect(),
-                                line_estimate: line_number,
-                            });
-                        }
-                    }
+        facts
+    }
 
-                    // Estimate line numbers
-                    line_number += text.matches('\n').count();
-                }
+    /// Byte range of the section under `header`, including its subsections
+    fn section_range(
+        &self,
+        header: &str,
+        headers: &HeaderMatcher,
+        content_len: usize,
+    ) -> Option<(std::ops::Range<usize>, &std::ops::Range<usize>, &str)> {
+        let position = self
+            .headings
+            .iter()
+            .position(|(_, _, text)| headers.matches(text, header))?;
+        let (level, heading_range, text) = &self.headings[position];
+        let end = self.headings[position + 1..]
+            .iter()
+            .find(|(next_level, _, _)| next_level <= level)
+            .map_or(content_len, |(_, range, _)| range.start);
+        Some((heading_range.start..end, heading_range, text.as_str()))
+    }
+}
+
+/// Finds the section under a header, including its subsections
+///
+/// Headers match like `required_headers` (parentheticals ignored, case-insensitive,
+/// aliases accepted).
+///
+/// # Returns
+/// * `Some((range, header))` - Byte range from the heading to the next heading of the same
+///   or higher level, and the header text as written in the document
+/// * `None` - No matching header
+pub fn find_section(
+    content: &str,
+    header: &str,
+    headers: &HeaderMatcher,
+) -> Option<(std::ops::Range<usize>, String)> {
+    let facts = DocumentFacts::collect(content);
+    facts
+        .section_range(header, headers, content.len())
+        .map(|(range, _, text)| (range, text.to_string()))
+}
+
+/// Evaluates scoped heuristics rules against planning document text
+///
+/// # Arguments
+/// * `content` - Document text (with embeds expanded, if any)
+/// * `file_name` - Name used to match each rule's `file` glob
+/// * `rules` - Compiled rules from the heuristics file
+/// * `headers` - How rule `section` names are matched against headings
+/// * `today` - Date that frontmatter `max_age_days` checks are measured against
+///
+/// # Returns
+/// * One `RuleViolation` per failing check
+pub fn validate_rules_content(
+    content: &str,
+    file_name: &str,
+    rules: &[CompiledRule],
+    headers: &HeaderMatcher,
+    today: chrono::NaiveDate,
+) -> Vec<ValidationIssue> {
+    let applicable: Vec<&CompiledRule> = rules.iter().filter(|r| r.applies_to(file_name)).collect();
+    if applicable.is_empty() {
+        return Vec::new();
+    }
+
+    let index = LineIndex::new(content);
+    let facts = DocumentFacts::collect(content);
+    let frontmatter = Frontmatter::parse(content);
+    let mut issues = Vec::new();
+
+    for compiled in applicable {
+        let rule = &compiled.rule;
+        let violation = |detail: String, span: Option<SourceSpan>, section: Option<&str>| {
+            ValidationIssue::RuleViolation {
+                rule: rule.id.clone(),
+                message: rule.message.clone().unwrap_or(detail),
+                span,
+                section: section.map(str::to_string),
             }
-            Event::SoftBreak | Event::HardBreak => {
-                line_number += 1;
+        };
+
+        // Resolve the scope: a section (with subsections) or the whole document
+        let (scope, anchor, section) = match &rule.section {
+            Some(header) => match facts.section_range(header, headers, content.len()) {
+                Some((scope, heading, text)) => (
+                    scope,
+                    Some(index.span(trim_range_end(content, heading.clone()))),
+                    Some(text),
+                ),
+                None => {
+                    issues.push(violation(
+                        format!("Section '{header}' required by rule '{}' not found", rule.id),
+                        None,
+                        Some(header),
+                    ));
+                    continue;
+                }
+            },
+            None => (0..content.len(), None, None),
+        };
+        let scope_text = &content[scope.clone()];
+        let in_scope = |offset: &usize| scope.contains(offset);
+
+        if let Some(pattern) = &compiled.pattern
+            && !pattern.is_match(scope_text)
+        {
+            issues.push(violation(
+                format!("No text matches required pattern /{}/", pattern.as_str()),
+                anchor,
+                section,
+            ));
+        }
+
+        if let Some(forbid) = &compiled.forbid {
+            for found in forbid.find_iter(scope_text) {
+                let start = scope.start + found.start();
+                issues.push(violation(
+                    format!("Forbidden text '{}'", found.as_str()),
+                    Some(index.span(start..start + found.len())),
+                    section,
+                ));
             }
-            _ => {}
         }
-    }
 
-    // Save final section
-    if let Some(header) = current_header {
-        let word_count = current_section_words.len();
-        result.sections.insert(header.clone(), word_count);
-
-        // Only validate word count for H2+ headers (skip H1 document titles)
-        if let Some(header_level) = current_header_level {
-            if header_level != pulldown_cmark::HeadingLevel::H1 && word_count < min_word_count {
-                result.add_issue(ValidationIssue::SectionTooShort {
-                    header: header.clone(),
-                    word_count,
-                    required: min_word_count,
-                });
+        let word_count: usize = facts
+            .words
+            .iter()
+            .filter(|(offset, _)| in_scope(offset))
+            .map(|(_, words)| words)
+            .sum();
+        if let Some(min) = rule.min_words
+            && word_count < min
+        {
+            issues.push(violation(
+                format!("Only {word_count} words (need at least {min})"),
+                anchor,
+                section,
+            ));
+        }
+        if let Some(max) = rule.max_words
+            && word_count > max
+        {
+            issues.push(violation(
+                format!("{word_count} words (allowed at most {max})"),
+                anchor,
+                section,
+            ));
+        }
+
+        if let Some(min) = rule.min_list_items {
+            let items = facts.list_items.iter().filter(|o| in_scope(o)).count();
+            if items < min {
+                issues.push(violation(
+                    format!("Only {items} list items (need at least {min})"),
+                    anchor,
+                    section,
+                ));
             }
         }
-    }
 
-    // Check for missing required headers
-    for required_header in required_headers {
-        // Normalize both required and found headers for flexible matching
-        let normalized_required = normalize_header(required_header);
-        let found = found_headers.iter().any(|h| {
-            let normalized_found = normalize_header(h);
-            normalized_found.to_lowercase().contains(&normalized_required.to_lowercase())
-        });
-        if !found {
-            result.add_issue(ValidationIssue::MissingHeader {
-                header: required_header.clone(),
-            });
+        if rule.require_code_block && !facts.code_blocks.iter().any(in_scope) {
+            issues.push(violation(
+                "Missing fenced code block".to_string(),
+                anchor,
+                section,
+            ));
+        }
+
+        // Frontmatter checks apply to the whole document, whatever the scope
+        let field_span = |field: &str| frontmatter.line(field).map(|range| index.span(range));
+        for field in &rule.require_fields {
+            match frontmatter.get(field) {
+                Some(value) if !value.is_empty() => {}
+                Some(_) => issues.push(violation(
+                    format!("Frontmatter field '{field}' is empty"),
+                    field_span(field),
+                    None,
+                )),
+                None => issues.push(violation(
+                    format!("Frontmatter field '{field}' is missing"),
+                    None,
+                    None,
+                )),
+            }
+        }
+        for (field, expected) in &rule.field_values {
+            match frontmatter.get(field) {
+                Some(value) if value.matches(expected) => {}
+                Some(value) => issues.push(violation(
+                    format!("Frontmatter field '{field}' is '{value}' (must be '{expected}')"),
+                    field_span(field),
+                    None,
+                )),
+                None => issues.push(violation(
+                    format!("Frontmatter field '{field}' is missing (must be '{expected}')"),
+                    None,
+                    None,
+                )),
+            }
+        }
+        for (field, max_age) in &rule.max_age_days {
+            let detail = match (frontmatter.get(field), frontmatter.date(field)) {
+                (None, _) => format!("Frontmatter field '{field}' is missing (need a date within {max_age} days)"),
+                (Some(_), None) => format!("Frontmatter field '{field}' is not a date (YYYY-MM-DD)"),
+                (Some(_), Some(date)) => {
+                    let age = (today - date).num_days();
+                    if age <= i64::from(*max_age) {
+                        continue;
+                    }
+                    format!("Frontmatter field '{field}' is {age} days old (allowed at most {max_age})")
+                }
+            };
+            issues.push(violation(detail, field_span(field), None));
         }
     }
 
-    Ok(result)
+    issues
 }
 
 /// Checks if a placeholder string is standalone (not part of a descriptive sentence)
@@ -412,59 +1008,81 @@ pub fn validate_dashboard_checkboxes(file_path: &Path) -> Result<ValidationResul
         .with_context(|| format!("Failed to read dashboard file: {}", file_path.display()))?;
 
     let mut result = ValidationResult::new();
+    let index = LineIndex::new(&content);
 
     // Enable task list parsing
     let mut options = Options::empty();
     options.insert(Options::ENABLE_TASKLISTS);
-    let parser = Parser::new_ext(&content, options);
+
+    /// List item being read; nested items are kept on a stack
+    struct OpenItem {
+        start: usize,
+        unchecked_marker: Option<usize>,
+        text: Vec<String>,
+    }
 
     // Tracking state during streaming
-    let mut line_number: usize = 1;
-    let mut current_item_text: Vec<String> = Vec::new();
-    let mut current_item_is_unchecked: Option<usize> = None; // Store line number if unchecked
+    let mut items: Vec<OpenItem> = Vec::new();
+    let mut current_section: Option<String> = None;
+    let mut in_heading = false;
 
-    for event in parser {
+    for (event, range) in body_events(&content, options) {
         match event {
+            Event::Start(Tag::Heading { .. }) => {
+                in_heading = true;
+                current_section = Some(String::new());
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                in_heading = false;
+                current_section = current_section.map(|s| s.trim().to_string());
+            }
             Event::Start(Tag::Item) => {
-                // Reset item tracking
-                current_item_text.clear();
-                current_item_is_unchecked = None;
+                items.push(OpenItem {
+                    start: range.start,
+                    unchecked_marker: None,
+                    text: Vec::new(),
+                });
             }
-            Event::TaskListMarker(checked) => {
-                // If checkbox is unchecked, mark it for later
-                if !checked {
-                    current_item_is_unchecked = Some(line_number);
+            Event::TaskListMarker(false) => {
+                // Checkbox is unchecked, mark it for later
+                if let Some(item) = items.last_mut() {
+                    item.unchecked_marker = Some(range.start);
                 }
             }
             Event::Text(text) => {
-                // Capture text for context if we're tracking an unchecked item
-                if current_item_is_unchecked.is_some() {
-                    current_item_text.push(text.to_string());
+                if in_heading {
+                    if let Some(section) = current_section.as_mut() {
+                        section.push_str(&text);
+                    }
+                } else if let Some(item) = items.last_mut()
+                    && item.unchecked_marker.is_some()
+                {
+                    // Capture text for context if we're tracking an unchecked item
+                    item.text.push(text.to_string());
                 }
-
-                // Track line numbers
-                line_number += text.matches('\n').count();
-            }
-            Event::SoftBreak | Event::HardBreak => {
-                line_number += 1;
             }
             Event::End(TagEnd::Item) => {
                 // If this was an unchecked item, record the issue now with full context
-                if let Some(item_line) = current_item_is_unchecked {
-                    let context = if current_item_text.is_empty() {
+                if let Some(item) = items.pop()
+                    && let Some(marker) = item.unchecked_marker
+                {
+                    let context = if item.text.is_empty() {
                         "(no text)".to_string()
                     } else {
-                        current_item_text.join(" ").chars().take(50).collect()
+                        item.text.join(" ").chars().take(50).collect()
                     };
 
+                    // Span covers the checkbox line only, not nested items
+                    let line_end = content[marker..]
+                        .find('\n')
+                        .map_or(content.len(), |i| marker + i);
+
                     result.add_issue(ValidationIssue::UncheckedCheckbox {
                         context,
-                        line_estimate: item_line,
+                        span: index.span(trim_range_end(&content, item.start..line_end)),
+                        section: current_section.clone(),
                     });
                 }
-
-                // Reset tracking
-                current_item_is_unchecked = None;
             }
             _ => {}
         }
@@ -492,10 +1110,55 @@ pub struct PlanningContext {
     pub user_flow: String,
     pub technical_decisions: String,
     pub mvp_breakdown: String,
+    /// Sprints of the document that fills `mvp_breakdown`
+    pub sprints: Vec<SprintData>,
     pub generation_date: String,
+    /// Frontmatter of each planning document, keyed by file name
+    pub frontmatter: BTreeMap<String, Frontmatter>,
 }
 
 impl PlanningContext {
+    /// Field names a planning schema can map sections to
+    pub const FIELDS: &[&str] = &[
+        "problem_statement",
+        "vision",
+        "problem_details",
+        "mvp_scope",
+        "anti_scope",
+        "tech_constraints",
+        "tech_stack",
+        "stack_justification",
+        "tech_exclusions",
+        "dependencies",
+        "folder_structure",
+        "data_model",
+        "user_flow",
+        "technical_decisions",
+        "mvp_breakdown",
+    ];
+
+    /// Mutable access to a text field by name (see `FIELDS`)
+    pub fn field_mut(&mut self, name: &str) -> Option<&mut String> {
+        Some(match name {
+            "problem_statement" => &mut self.problem_statement,
+            "vision" => &mut self.vision,
+            "problem_details" => &mut self.problem_details,
+            "mvp_scope" => &mut self.mvp_scope,
+            "anti_scope" => &mut self.anti_scope,
+            "tech_constraints" => &mut self.tech_constraints,
+            "tech_stack" => &mut self.tech_stack,
+            "stack_justification" => &mut self.stack_justification,
+            "tech_exclusions" => &mut self.tech_exclusions,
+            "dependencies" => &mut self.dependencies,
+            "folder_structure" => &mut self.folder_structure,
+            "data_model" => &mut self.data_model,
+            "user_flow" => &mut self.user_flow,
+            "technical_decisions" => &mut self.technical_decisions,
+            "mvp_breakdown" => &mut self.mvp_breakdown,
+            _ => return None,
+        })
+    }
+
     /// Create a new empty planning context
     pub fn new(project_name: String) -> Self {
         Self {
@@ -515,21 +1178,22 @@ impl PlanningContext {
             user_flow: String::new(),
             technical_decisions: String::new(),
             mvp_breakdown: String::new(),
+            sprints: Vec::new(),
             generation_date: chrono::Utc::now().format("%Y-%m-%d %H:%M UTC").to_string(),
+            frontmatter: BTreeMap::new(),
         }
     }
 }
 
 /// Extract sections from a markdown file as a `HashMap`
 fn extract_sections(content: &str) -> HashMap<String, String> {
-    let parser = Parser::new(content);
     let mut sections: HashMap<String, String> = HashMap::new();
 
     let mut current_header: Option<String> = None;
     let mut current_content: Vec<String> = Vec::new();
     let mut in_header = false;
 
-    for event in parser {
+    for (event, _) in body_events(content, Options::empty()) {
         match event {
             Event::Start(Tag::Heading { .. }) => {
                 // Save previous section
@@ -549,15 +1213,11 @@ fn extract_sections(content: &str) -> HashMap<String, String> {
                     current_content.push(text.to_string());
                 }
             }
-            Event::Code(code) => {
-                if !in_header && current_header.is_some() {
-                    current_content.push(format!("`{code}`"));
-                }
+            Event::Code(code) if !in_header && current_header.is_some() => {
+                current_content.push(format!("`{code}`"));
             }
-            Event::SoftBreak | Event::HardBreak => {
-                if !in_header && current_header.is_some() {
-                    current_content.push("\n".to_string());
-                }
+            Event::SoftBreak | Event::HardBreak if !in_header && current_header.is_some() => {
+                current_content.push("\n".to_string());
             }
             _ => {}
         }
@@ -572,7 +1232,17 @@ fn extract_sections(content: &str) -> HashMap<String, String> {
 }
 
 /// Parse planning documents and extract content for template
-pub fn parse_planning_documents(planning_dir: &Path) -> Result<PlanningContext> {
+///
+/// Each document's `context` mapping in the schema names the section that fills
+/// a `PlanningContext` field. When several sections map to one field, they are
+/// joined in schema order. Headers match like `required_headers` (parentheticals ignored,
+/// aliases accepted).
+/// With a vault index, `![[...]]` embeds are replaced by the embedded content first.
+pub fn parse_planning_documents(
+    planning_dir: &Path,
+    schema: &PlanningSchema,
+    vault: Option<&VaultIndex>,
+) -> Result<PlanningContext> {
     // Get project name from parent directory or default
     let project_name = planning_dir
         .parent()
@@ -582,245 +1252,52 @@ pub fn parse_planning_documents(planning_dir: &Path) -> Result<PlanningContext>
         .to_string();
 
     let mut context = PlanningContext::new(project_name);
+    let headers = schema.header_matcher();
 
-    // Parse 01-Problem-and-Vision.md
-    let problem_vision_path = planning_dir.join("01-Problem-and-Vision.md");
-    if problem_vision_path.exists() {
-        let content = fs::read_to_string(&problem_vision_path)
-            .context("Failed to read 01-Problem-and-Vision.md")?;
-        let sections = extract_sections(&content);
-
-        if let Some(solution) = sections.get("Solution in ONE SENTENCE:") {
-            context.problem_statement.clone_from(solution);
-        }
-        if let Some(success) = sections.get("Success criteria (3 months):") {
-            context.vision.clone_from(success);
-        }
-        if let Some(problem) = sections.get("My problem (personal):") {
-            context.problem_details.clone_from(problem);
-        }
-        if let Some(anti) = sections.get("Anti-vision (what this project is NOT):") {
-            context.anti_scope.clone_from(anti);
-        }
-    }
-
-    // Parse 02-Scope-and-Boundaries.md
-    let scope_path = planning_dir.join("02-Scope-and-Boundaries.md");
-    if scope_path.exists() {
-        let content =
-            fs::read_to_string(&scope_path).context("Failed to read 02-Scope-and-Boundaries.md")?;
-        let sections = extract_sections(&content);
-
-        if let Some(mvp) = sections.get("MVP (Minimum Viable Product):") {
-            context.mvp_scope.clone_from(mvp);
-        }
-        if let Some(never) = sections.get("Never (things I will NOT build):") {
-            if !context.anti_scope.is_empty() {
-                context.anti_scope.push_str("\n\n");
-            }
-            context.anti_scope.push_str(never);
-        }
-        if let Some(constraints) = sections.get("Tech constraints:") {
-            context.tech_constraints.clone_from(constraints);
-        }
-    }
-
-    // Parse 03-Tech-Stack.md
-    let tech_stack_path = planning_dir.join("03-Tech-Stack.md");
-    if tech_stack_path.exists() {
-        let content =
-            fs::read_to_string(&tech_stack_path).context("Failed to read 03-Tech-Stack.md")?;
-        let sections = extract_sections(&content);
-
-        if let Some(stack) = sections.get("Stack (force yourself to choose NOW):") {
-            context.tech_stack.clone_from(stack);
+    for document in &schema.documents {
+        let path = planning_dir.join(&document.filename);
+        if !path.exists() {
+            continue;
         }
-        if let Some(why) = sections.get("Why these choices?") {
-            context.stack_justification.clone_from(why);
+        let mut content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", document.filename))?;
+        let frontmatter = Frontmatter::parse(&content);
+        if frontmatter.is_present() {
+            context
+                .frontmatter
+                .insert(document.filename.clone(), frontmatter);
         }
-        if let Some(not_use) = sections.get("What I will NOT use:") {
-            context.tech_exclusions.clone_from(not_use);
+        if document.context.is_empty() {
+            continue;
         }
-        if let Some(deps) = sections.get("Dependencies (max 10 important ones):") {
-            context.dependencies.clone_from(deps);
+        if let Some(vault) = vault {
+            content = expand_embeds(&path, &content, vault).text;
         }
-    }
-
-    // Parse 04-Architecture.md
-    let arch_path = planning_dir.join("04-Architecture.md");
-    if arch_path.exists() {
-        let content =
-            fs::read_to_string(&arch_path).context("Failed to read 04-Architecture.md")?;
+        let body = content[body_start(&content)..].to_string();
         let sections = extract_sections(&content);
-
-        if let Some(folder) = sections.get("Folder structure:") {
-            context.folder_structure.clone_from(folder);
-        }
-        if let Some(data) = sections.get("Data model (main entities):") {
-            context.data_model.clone_from(data);
-        }
-        if let Some(flow) = sections.get("Flow (user journey):") {
-            context.user_flow.clone_from(flow);
-        }
-        if let Some(decisions) = sections.get("Critical technical decisions:") {
-            context.technical_decisions.clone_from(decisions);
+        if document.context.contains_key("mvp_breakdown") {
+            context.sprints.extend(parse_sprints(&content));
         }
-    }
-
-    // Parse 05-MVP-Breakdown.md
-    let mvp_path = planning_dir.join("05-MVP-Breakdown.md");
-    if mvp_path.exists() {
-        let content =
-            fs::read_to_string(&mvp_path).context("Failed to read 05-MVP-Breakdown.md")?;
-        // For MVP breakdown, we want the whole document
-        context.mvp_breakdown = content;
-    }
-
-    Ok(context)
-}
 
-/// Represents a single sprint extracted from MVP breakdown
-#[derive(Debug, Clone, PartialEq)]
-pub struct SprintData {
-    /// Sprint number (e.g., 4)
-    pub number: u32,
-    /// Sprint name/slug (e.g., "the-sprint-orchestrator")
-    pub name: String,
-    /// Sprint title (e.g., "The Sprint Orchestrator (The Leash)")
-    pub title: String,
-    /// Extracted task list as markdown string
-    pub tasks: String,
-    /// Focus statement and exit criteria
-    pub context: String,
-}
-
-/// Parse sprints from 05-MVP-Breakdown.md
-///
-/// Extracts sprint sections with their tasks, focus, and exit criteria.
-/// Uses a simpler line-based approach for reliable extraction.
-///
-/// # Arguments
-/// * `mvp_breakdown_path` - Path to 05-MVP-Breakdown.md
-///
-/// # Returns
-/// * `Ok(Vec<SprintData>)` - List of all sprints found
-/// * `Err` - File could not be read or parsed
-pub fn parse_mvp_sprints(mvp_breakdown_path: &Path) -> Result<Vec<SprintData>> {
-    let content = fs::read_to_string(mvp_breakdown_path).with_context(|| {
-        format!(
-            "Failed to read MVP breakdown: {}",
-            mvp_breakdown_path.display()
-        )
-    })?;
-
-    let mut sprints = Vec::new();
-    let mut current_sprint: Option<SprintData> = None;
-    let mut current_content = String::new();
-
-    for line in content.lines() {
-        let trimmed = line.trim();
-
-        // Detect H2 headings (sprint sections)
-        if trimmed.starts_with("## Sprint") {
-            // Save previous sprint
-            if let Some(mut sprint) = current_sprint.take() {
-                extract_tasks_and_context(&mut sprint, &current_content);
-                sprints.push(sprint);
-                current_content.clear();
-            }
-
-            // Start new sprint
-            let header = trimmed.strip_prefix("##").unwrap().trim();
-            current_sprint = parse_sprint_header(header);
-        } else if trimmed.starts_with("## ") {
-            // Other H2 heading - save current sprint if exists
-            if let Some(mut sprint) = current_sprint.take() {
-                extract_tasks_and_context(&mut sprint, &current_content);
-                sprints.push(sprint);
-                current_content.clear();
+        for (field, header) in &document.context {
+            let text = if header == WHOLE_DOCUMENT {
+                Some(&body)
+            } else {
+                sections
+                    .iter()
+                    .find(|(heading, _)| headers.same(heading, header))
+                    .map(|(_, text)| text)
+            };
+            if let (Some(text), Some(target)) = (text, context.field_mut(field)) {
+                if !target.is_empty() {
+                    target.push_str("\n\n");
+                }
+                target.push_str(text);
             }
-        } else if current_sprint.is_some() {
-            // Collect content for current sprint
-            current_content.push_str(line);
-            current_content.push('\n');
-        }
-    }
-
-    // Save the last sprint
-    if let Some(mut sprint) = current_sprint {
-        extract_tasks_and_context(&mut sprint, &current_content);
-        sprints.push(sprint);
-    }
-
-    Ok(sprints)
-}
-
-/// Parse a sprint header to extract number and name
-///
-/// Examples:
-/// - "Sprint 0: Setup (day 1)" -> (0, "setup", "Setup (day 1)")
-/// - "Sprint 4: The Sprint Orchestrator (The Leash)" -> (4, "the-sprint-orchestrator", "The Sprint Orchestrator (The Leash)")
-fn parse_sprint_header(header: &str) -> Option<SprintData> {
-    // Pattern: "Sprint X: Title"
-    let parts: Vec<&str> = header.splitn(2, ':').collect();
-    if parts.len() != 2 {
-        return None;
-    }
-
-    // Extract sprint number
-    let sprint_prefix = parts[0].trim();
-    let number = sprint_prefix
-        .strip_prefix("Sprint ")
-        .and_then(|s| s.parse::<u32>().ok())?;
-
-    // Extract title and generate slug
-    let title = parts[1].trim().to_string();
-    let name = generate_sprint_slug(&title);
-
-    Some(SprintData {
-        number,
-        name,
-        title,
-        tasks: String::new(),
-        context: String::new(),
-    })
-}
-
-/// Generate a URL-friendly slug from sprint title
-///
-/// "The Sprint Orchestrator (The Leash)" -> "the-sprint-orchestrator"
-fn generate_sprint_slug(title: &str) -> String {
-    // Remove parenthetical suffixes
-    let clean_title = title.split('(').next().unwrap_or(title).trim();
-
-    // Convert to lowercase and replace spaces with hyphens
-    clean_title
-        .to_lowercase()
-        .split_whitespace()
-        .collect::<Vec<_>>()
-        .join("-")
-}
-
-/// Extract tasks and context from sprint content
-fn extract_tasks_and_context(sprint: &mut SprintData, content: &str) {
-    let mut tasks = Vec::new();
-    let mut context_parts = Vec::new();
-
-    for line in content.lines() {
-        let trimmed = line.trim();
-
-        // Detect task lines
-        if trimmed.starts_with("- [x]") || trimmed.starts_with("- [ ]") {
-            tasks.push(trimmed.to_string());
-        }
-        // Detect focus lines (italic emphasis)
-        else if trimmed.starts_with("_Focus:") || trimmed.starts_with("**Exit") {
-            context_parts.push(trimmed.to_string());
         }
     }
 
-    sprint.tasks = tasks.join("\n");
-    sprint.context = context_parts.join("\n");
+    Ok(context)
 }
 
 /// Update the dashboard with planning completion timestamp
@@ -864,11 +1341,10 @@ pub fn validate_all_checkboxes_checked(file_path: &Path) -> Result<bool> {
 
     let mut options = Options::empty();
     options.insert(Options::ENABLE_TASKLISTS);
-    let parser = Parser::new_ext(&content, options);
 
     let mut has_unchecked = false;
 
-    for event in parser {
+    for (event, _) in body_events(&content, options) {
         if let Event::TaskListMarker(checked) = event
             && !checked {
                 has_unchecked = true;
@@ -939,10 +1415,10 @@ Definition of Done description with sufficient detail and word count to pass val
 
     #[test]
     fn test_validate_document_with_short_section() {
-        let content = r"# Problem
+        let content = r"## Problem
 Just a few words here.
 
-# Vision
+## Vision
 Not enough content.
 ";
 
@@ -1005,6 +1481,47 @@ This is a problem section with adequate word count for validation purposes and t
         assert_eq!(missing_header_issues.len(), 5);
     }
 
+    #[test]
+    fn test_header_matcher_aliases_and_loose_matching() {
+        let aliases: BTreeMap<String, Vec<String>> = [(
+            "Why these choices?".to_string(),
+            vec!["Varför dessa val?".to_string()],
+        )]
+        .into();
+        let headers = HeaderMatcher::new(HeaderMatching::Normalized, aliases.clone());
+        assert!(headers.same("VARFÖR DESSA VAL?", "Why these choices?"));
+        assert!(headers.matches("Varför dessa val? (motivering)", "Why these choices?"));
+        assert!(!headers.same("Varför dessa val", "Why these choices?"));
+        assert!(!headers.matches("Varför dessa val?", "What I will NOT use:"));
+
+        // Loose matching also ignores punctuation and spacing
+        let headers = HeaderMatcher::new(HeaderMatching::Loose, aliases);
+        assert!(headers.same("Varför dessa val", "Why these choices?"));
+        assert!(headers.same("why these  choices", "Why these choices?"));
+        assert!(headers.matches("Tech constraints", "Tech constraints:"));
+    }
+
+    #[test]
+    fn test_translated_templates_satisfy_builtin_schema() {
+        let schema = PlanningSchema::builtin().unwrap();
+        let headers = schema.header_matcher();
+        let locale_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("templates/locales/sv");
+        for document in &schema.documents {
+            let content = fs::read_to_string(locale_dir.join(&document.filename)).unwrap();
+            let result =
+                validate_planning_content(&content, &document.required_headers, &headers, 0, &[]);
+            assert!(
+                !result
+                    .issues
+                    .iter()
+                    .any(|issue| matches!(issue, ValidationIssue::MissingHeader { .. })),
+                "{}: {:?}",
+                document.filename,
+                result.issues
+            );
+        }
+    }
+
     #[test]
     fn test_validate_dashboard_all_checked() {
         let content = r"# Dashboard
@@ -1136,127 +1653,314 @@ Another regular list:
     }
 
     #[test]
-    fn test_parse_sprint_header() {
-        let result = parse_sprint_header("Sprint 4: The Sprint Orchestrator (The Leash)");
-        assert!(result.is_some());
+    fn test_line_index_positions() {
+        let index = LineIndex::new("ab\nçd\n\nx");
 
-        let sprint = result.unwrap();
-        assert_eq!(sprint.number, 4);
-        assert_eq!(sprint.name, "the-sprint-orchestrator");
-        assert_eq!(sprint.title, "The Sprint Orchestrator (The Leash)");
+        assert_eq!(
+            index.position(0),
+            SourcePosition {
+                offset: 0,
+                line: 1,
+                column: 1
+            }
+        );
+        // Columns count characters, not bytes
+        assert_eq!(index.position(5).line, 2);
+        assert_eq!(index.position(5).column, 2);
+        assert_eq!(index.position(8).line, 4);
+        // Offsets past the end clamp to the last position
+        assert_eq!(index.position(100).offset, 9);
     }
 
     #[test]
-    fn test_parse_sprint_header_simple() {
-        let result = parse_sprint_header("Sprint 0: Setup (day 1)");
-        assert!(result.is_some());
+    fn test_illegal_string_exact_position() {
+        let content = "## Problem\n\nDone.\n\n- Database: [TBD]\n";
 
-        let sprint = result.unwrap();
-        assert_eq!(sprint.number, 0);
-        assert_eq!(sprint.name, "setup");
-        assert_eq!(sprint.title, "Setup (day 1)");
-    }
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
 
-    #[test]
-    fn test_parse_sprint_header_invalid() {
-        assert!(parse_sprint_header("Not a sprint header").is_none());
-        assert!(parse_sprint_header("Sprint X: Invalid").is_none());
-        assert!(parse_sprint_header("Random text").is_none());
-    }
+        let result = validate_planning_document_with_headers(
+            temp_file.path(),
+            &[],
+            0,
+            &["TBD".to_string()],
+        )
+        .unwrap();
 
-    #[test]
-    fn test_generate_sprint_slug() {
-        assert_eq!(
-            generate_sprint_slug("The Sprint Orchestrator (The Leash)"),
-            "the-sprint-orchestrator"
-        );
-        assert_eq!(generate_sprint_slug("Setup (day 1)"), "setup");
-        assert_eq!(
-            generate_sprint_slug("The Gatekeeper (The Enforcer)"),
-            "the-gatekeeper"
-        );
-        assert_eq!(generate_sprint_slug("Init Command"), "init-command");
+        assert_eq!(result.issues.len(), 1);
+        let span = result.issues[0].span().unwrap();
+        assert_eq!(span.start.line, 5);
+        assert_eq!(span.start.column, 14);
+        assert_eq!(span.end.column, 17);
+        assert_eq!(&content[span.start.offset..span.end.offset], "TBD");
+        assert_eq!(result.issues[0].section(), Some("Problem"));
     }
 
     #[test]
-    fn test_parse_mvp_sprints() {
-        let content = r"# MVP broken into sprints
+    fn test_short_section_span_covers_section() {
+        let content = "# Title\n\n## Problem\nToo short.\n\n## Vision\nAlso short.\n";
 
-## Sprint 0: Setup (day 1)
-- [x] Create nexus repo with Cargo.toml
-- [x] Add clap with derive feature
-- [ ] Create templates/ folder
-**Exit criteria:** `nexus init` all parse
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
 
-## Sprint 1: Init Command (days 2-3)
-_Focus: Configuration and initialization._
+        let result = validate_planning_document_with_headers(temp_file.path(), &[], 5, &[]).unwrap();
 
-- [x] Implement config.rs to read/write nexus.toml
-- [ ] Implement init command
-**Exit criteria:** `nexus init test-project` creates folder
+        let spans: Vec<_> = result.issues.iter().filter_map(ValidationIssue::span).collect();
+        assert_eq!(spans.len(), 2);
+        assert_eq!((spans[0].start.line, spans[0].end.line), (3, 4));
+        assert_eq!((spans[1].start.line, spans[1].end.line), (6, 7));
+        assert_eq!(
+            &content[spans[0].start.offset..spans[0].end.offset],
+            "## Problem\nToo short."
+        );
 
-## Sprint 4: The Sprint Orchestrator (The Leash)
-_Focus: Creating the Tactical Staging Area._
+        let levels: Vec<_> = result.headers.iter().map(|h| (h.level, h.span.start.line)).collect();
+        assert_eq!(levels, vec![(1, 1), (2, 3), (2, 6)]);
+    }
 
-- [ ] **MVP Parser:** Extract specific sprint tasks
-- [ ] **Branching Logic:** Use the `git2` crate
-**Exit criteria:** `nexus sprint X` creates a clean branch
-";
+    #[test]
+    fn test_unchecked_checkbox_positions_with_nesting() {
+        let content = "# Dashboard\n\n## Setup\n- [x] Parent done\n  - [ ] Nested pending\n- [ ] Top pending\n  - [x] Child done\n";
 
         let mut temp_file = NamedTempFile::new().unwrap();
         temp_file.write_all(content.as_bytes()).unwrap();
 
-        let result = parse_mvp_sprints(temp_file.path()).unwrap();
-
-        assert_eq!(result.len(), 3, "Should parse 3 sprints");
+        let result = validate_dashboard_checkboxes(temp_file.path()).unwrap();
 
-        // Check Sprint 0
-        assert_eq!(result[0].number, 0);
-        assert_eq!(result[0].name, "setup");
-        assert_eq!(result[0].title, "Setup (day 1)");
-        assert!(result[0].tasks.contains("Create nexus repo"));
-        assert!(result[0].context.contains("Exit criteria"));
+        let positions: Vec<_> = result
+            .issues
+            .iter()
+            .map(|issue| {
+                let span = issue.span().unwrap();
+                (span.start.line, span.start.column, span.end.line)
+            })
+            .collect();
+        assert_eq!(positions, vec![(5, 3, 5), (6, 1, 6)]);
+        assert!(result.issues.iter().all(|i| i.section() == Some("Setup")));
+    }
 
-        // Check Sprint 1
-        assert_eq!(result[1].number, 1);
-        assert_eq!(result[1].name, "init-command");
-        assert!(result[1].tasks.contains("Implement config.rs"));
-        assert!(result[1].context.contains("Focus"));
+    fn compile(rules: &[crate::heuristics::GateRule]) -> Vec<CompiledRule> {
+        rules.iter().map(|r| CompiledRule::compile(r).unwrap()).collect()
+    }
 
-        // Check Sprint 4
-        assert_eq!(result[2].number, 4);
-        assert_eq!(result[2].name, "the-sprint-orchestrator");
-        assert!(result[2].tasks.contains("MVP Parser"));
+    #[test]
+    fn test_validate_rules_scopes_and_checks() {
+        use crate::heuristics::GateRule;
+
+        let content = "# Vision\n\n## Success criteria\nPeople use it daily.\n\n### Notes\n- one\n\n## Stack\nMaybe Rust.\n";
+
+        let rules = compile(&[
+            GateRule {
+                id: "measurable".to_string(),
+                section: Some("Success criteria".to_string()),
+                pattern: Some(r"\d".to_string()),
+                min_list_items: Some(1),
+                message: Some("Success criteria need a number or date".to_string()),
+                ..GateRule::default()
+            },
+            GateRule {
+                id: "no-hedging".to_string(),
+                forbid: Some(r"(?i)\bmaybe\b".to_string()),
+                ..GateRule::default()
+            },
+            GateRule {
+                id: "other-file".to_string(),
+                file: Some("03-*.md".to_string()),
+                min_words: Some(1000),
+                ..GateRule::default()
+            },
+            GateRule {
+                id: "folder-tree".to_string(),
+                section: Some("Folder structure".to_string()),
+                require_code_block: true,
+                ..GateRule::default()
+            },
+        ]);
+
+        let issues = validate_rules_content(
+            content,
+            "01-Problem-and-Vision.md",
+            &rules,
+            &HeaderMatcher::default(),
+            chrono::NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+        );
+        let ids: Vec<_> = issues.iter().map(ValidationIssue::rule_id).collect();
+        assert_eq!(ids, vec!["measurable", "no-hedging", "folder-tree"]);
+
+        // Pattern failure points at the section heading; list item in the subsection counts
+        assert_eq!(issues[0].message(), "Success criteria need a number or date");
+        assert_eq!(issues[0].span().unwrap().start.line, 3);
+        assert_eq!(issues[0].section(), Some("Success criteria"));
+
+        // Forbidden match has an exact span
+        let span = issues[1].span().unwrap();
+        assert_eq!(&content[span.start.offset..span.end.offset], "Maybe");
+        assert_eq!((span.start.line, span.start.column), (10, 1));
+
+        // Missing section is reported without a position
+        assert!(issues[2].span().is_none());
+        assert!(issues[2].message().contains("not found"));
     }
 
     #[test]
-    fn test_extract_tasks_and_context() {
-        let content = r"
-_Focus: Test focus statement._
+    fn test_frontmatter_is_not_body_text() {
+        let content = "---\nstatus: TODO\nowner: dana\n---\n## Vision\nA calm planning tool for one.\n";
+        let result = validate_planning_content(
+            content,
+            &["Vision".to_string()],
+            &HeaderMatcher::default(),
+            5,
+            &["TODO".to_string()],
+        );
 
-- [x] Task one completed
-- [ ] Task two pending
-- [ ] Task three also pending
+        // No placeholder match, no heading made of the property lines
+        assert!(result.issues.is_empty(), "{:?}", result.issues);
+        assert_eq!(result.headers.len(), 1);
+        assert_eq!(result.headers[0].span.start.line, 5);
+        assert_eq!(result.sections["Vision"], 6);
+        assert_eq!(result.frontmatter.text("status"), Some("TODO"));
+    }
 
-Some random text here.
+    #[test]
+    fn test_validate_rules_section_follows_header_aliases() {
+        use crate::heuristics::GateRule;
+
+        let content = "## Varför dessa val?\nFör att det är enkelt.\n";
+        let rules = compile(&[GateRule {
+            id: "reasoning".to_string(),
+            section: Some("Why these choices?".to_string()),
+            min_words: Some(3),
+            ..GateRule::default()
+        }]);
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+
+        // Without the alias the section is not found
+        let issues =
+            validate_rules_content(content, "03-Tech-Stack.md", &rules, &HeaderMatcher::default(), today);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message().contains("not found"));
+
+        let aliases: BTreeMap<String, Vec<String>> = [(
+            "Why these choices?".to_string(),
+            vec!["Varför dessa val".to_string()],
+        )]
+        .into();
+        let headers = HeaderMatcher::new(HeaderMatching::Loose, aliases);
+        let issues = validate_rules_content(content, "03-Tech-Stack.md", &rules, &headers, today);
+        assert!(issues.is_empty(), "{issues:?}");
+    }
 
-**Exit criteria:** All tests pass
-";
+    #[test]
+    fn test_validate_rules_checks_frontmatter() {
+        use crate::heuristics::GateRule;
+
+        let content = "---\nstatus: draft\nowner:\nreviewed: 2026-08-01\n---\n## Vision\nText\n";
+        let rules = compile(&[GateRule {
+            id: "ready-to-unlock".to_string(),
+            require_fields: vec!["owner".to_string(), "approver".to_string()],
+            field_values: [("status".to_string(), "final".to_string())].into(),
+            max_age_days: [("reviewed".to_string(), 30)].into(),
+            ..GateRule::default()
+        }]);
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+
+        let issues = validate_rules_content(content, "01-Problem-and-Vision.md", &rules, &HeaderMatcher::default(), today);
+        let messages: Vec<_> = issues.iter().map(ValidationIssue::message).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Frontmatter field 'owner' is empty",
+                "Frontmatter field 'approver' is missing",
+                "Frontmatter field 'status' is 'draft' (must be 'final')",
+                "Frontmatter field 'reviewed' is 61 days old (allowed at most 30)",
+            ]
+        );
+        // Present fields point at their line
+        assert_eq!(issues[2].span().unwrap().start.line, 2);
+        assert!(issues[1].span().is_none());
 
-        let mut sprint = SprintData {
-            number: 1,
-            name: "test".to_string(),
-            title: "Test Sprint".to_string(),
-            tasks: String::new(),
-            context: String::new(),
-        };
+        let content = "---\nstatus: Final\nowner: dana\napprover: [lee]\nreviewed: 2026-09-15\n---\n";
+        assert!(validate_rules_content(content, "01-Problem-and-Vision.md", &rules, &HeaderMatcher::default(), today).is_empty());
+    }
 
-        extract_tasks_and_context(&mut sprint, content);
+    #[test]
+    fn test_parse_planning_documents_follows_schema() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let planning_dir = temp_dir.path().join("01-PLANNING");
+        fs::create_dir_all(&planning_dir).unwrap();
+        fs::write(
+            planning_dir.join("01-Problem-and-Vision.md"),
+            "## Anti-vision (what this project is NOT):\nNot a social network\n",
+        )
+        .unwrap();
+        fs::write(
+            planning_dir.join("02-Scope-and-Boundaries.md"),
+            "## Never (things I will NOT build):\nNo payments\n",
+        )
+        .unwrap();
+        // The template's wording of the header, matched with the parenthetical ignored
+        fs::write(
+            planning_dir.join("03-Tech-Stack.md"),
+            "## Dependencies (important ones):\n1. serde\n",
+        )
+        .unwrap();
+        fs::write(planning_dir.join("06-Risks.md"), "## Top risks:\nVendor lock-in\n").unwrap();
+
+        let mut schema = PlanningSchema::builtin().unwrap();
+        let context = parse_planning_documents(&planning_dir, &schema, None).unwrap();
+        assert_eq!(context.anti_scope, "Not a social network\n\nNo payments");
+        assert_eq!(context.dependencies, "serde");
+
+        // A project document can feed a context field too
+        let mut risks = schema.documents[0].clone();
+        risks.filename = "06-Risks.md".to_string();
+        risks.context = [("technical_decisions".to_string(), "Top risks:".to_string())].into();
+        schema.documents.push(risks);
+        let context = parse_planning_documents(&planning_dir, &schema, None).unwrap();
+        assert_eq!(context.technical_decisions, "Vendor lock-in");
+        // Frontmatter is exposed per document and never part of a section
+        fs::write(
+            planning_dir.join("06-Risks.md"),
+            "---\nowner: dana\n---\n## Top risks:\nVendor lock-in\n",
+        )
+        .unwrap();
+        let context = parse_planning_documents(&planning_dir, &schema, None).unwrap();
+        assert_eq!(context.technical_decisions, "Vendor lock-in");
+        assert_eq!(context.frontmatter["06-Risks.md"].text("owner"), Some("dana"));
+        assert!(!context.frontmatter.contains_key("03-Tech-Stack.md"));
+    }
 
-        assert!(sprint.tasks.contains("Task one completed"));
-        assert!(sprint.tasks.contains("Task two pending"));
-        assert!(sprint.tasks.contains("Task three also pending"));
-        assert!(sprint.context.contains("Focus: Test focus statement"));
-        assert!(sprint.context.contains("Exit criteria"));
+    #[test]
+    fn test_parse_planning_documents_with_aliases() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let planning_dir = temp_dir.path().join("01-PLANNING");
+        fs::create_dir_all(&planning_dir).unwrap();
+        fs::write(
+            planning_dir.join("01-Problem-and-Vision.md"),
+            "## Lösning i EN MENING:\nEn app för delade inköpslistor\n",
+        )
+        .unwrap();
+        fs::write(
+            planning_dir.join("03-Tech-Stack.md"),
+            "## Varför dessa val?\nSQLite räcker\n\n## Beroenden (max 10 viktiga):\n1. serde\n",
+        )
+        .unwrap();
+
+        let mut schema = PlanningSchema::builtin().unwrap();
+        let context = parse_planning_documents(&planning_dir, &schema, None).unwrap();
+        assert_eq!(context.problem_statement, "En app för delade inköpslistor");
+        assert_eq!(context.stack_justification, "SQLite räcker");
+        assert_eq!(context.dependencies, "serde");
+
+        // Project aliases apply to every document
+        fs::write(planning_dir.join("03-Tech-Stack.md"), "## Motivering\nSQLite räcker\n").unwrap();
+        schema.header_matching = HeaderMatching::Loose;
+        schema.aliases = [(
+            "Why these choices?".to_string(),
+            vec!["Motivering:".to_string()],
+        )]
+        .into();
+        let context = parse_planning_documents(&planning_dir, &schema, None).unwrap();
+        assert_eq!(context.stack_justification, "SQLite räcker");
     }
 }
@@ -0,0 +1,598 @@
+//! Sprint Dependency Graph
+//!
+//! Builds the graph of sprints and tasks from the MVP breakdown model. A sprint
+//! waits for its sprint-level dependencies and for every one of its tasks; a
+//! task waits for the tasks and sprints it names (`⛔`, `[depends-on:: ...]`),
+//! for its sub-tasks, and for the dependencies of its sprint.
+//!
+//! The gate reports cycles, references to unknown tasks or sprints, and task ids
+//! used twice. `nexus sprint` uses the graph to find the sprints that must be
+//! approved first, and `nexus roadmap` prints it with its critical path.
+
+use crate::config::StateConfig;
+use crate::mvp::{DependencyRef, Estimate, MvpTask, SprintData, parse_sprints};
+use crate::planning::{LineIndex, ValidationIssue};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::ops::Range;
+
+/// A problem in the dependency graph
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphProblem {
+    /// `unknown-dependency`, `dependency-cycle` or `duplicate-task-id`
+    pub rule: &'static str,
+    pub message: String,
+    /// Byte range of the task line or sprint heading the problem is reported at
+    pub range: Range<usize>,
+    /// Heading of the sprint the problem is in
+    pub sprint: String,
+}
+
+/// Longest chain of dependent tasks, weighted by estimate
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriticalPath<'a> {
+    /// Top-level tasks in the order they have to be done
+    pub tasks: Vec<&'a MvpTask>,
+    /// Sum of the tasks' estimates (tasks without one count as zero)
+    pub estimate: Estimate,
+}
+
+/// Where a sprint stands, given the approvals recorded in nexus.toml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SprintStatus {
+    Approved,
+    /// Ended with `nexus sprint close`, its unfinished tasks carried over
+    Closed,
+    InProgress,
+    /// Every prerequisite is approved or closed
+    Ready,
+    /// Waiting for these unfinished prerequisites
+    Blocked(Vec<u32>),
+}
+
+impl SprintStatus {
+    /// Machine-readable status (`approved`, `closed`, `in_progress`, `ready`, `blocked`)
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Approved => "approved",
+            Self::Closed => "closed",
+            Self::InProgress => "in_progress",
+            Self::Ready => "ready",
+            Self::Blocked(_) => "blocked",
+        }
+    }
+}
+
+impl std::fmt::Display for SprintStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Approved => f.write_str("approved"),
+            Self::Closed => f.write_str("closed"),
+            Self::InProgress => f.write_str("in progress"),
+            Self::Ready => f.write_str("ready"),
+            Self::Blocked(waiting) => {
+                let waiting: Vec<String> =
+                    waiting.iter().map(|number| format!("Sprint {number}")).collect();
+                write!(f, "blocked by {}", waiting.join(", "))
+            }
+        }
+    }
+}
+
+/// Weight of a task chain: estimated minutes, then number of tasks
+type Weight = (u32, usize);
+
+/// Sprint and task dependency graph of an MVP breakdown
+pub struct Roadmap<'a> {
+    sprints: &'a [SprintData],
+    /// Sprint number and top-level task of every task id (first definition wins)
+    tasks: HashMap<&'a str, (u32, &'a MvpTask)>,
+}
+
+impl<'a> Roadmap<'a> {
+    pub fn new(sprints: &'a [SprintData]) -> Self {
+        let mut tasks = HashMap::new();
+        for sprint in sprints {
+            for top in &sprint.tasks {
+                for task in std::iter::once(top).chain(descendants(top)) {
+                    tasks.entry(task.id.as_str()).or_insert((sprint.number, top));
+                }
+            }
+        }
+        Self { sprints, tasks }
+    }
+
+    pub fn sprints(&self) -> &'a [SprintData] {
+        self.sprints
+    }
+
+    pub fn sprint(&self, number: u32) -> Option<&'a SprintData> {
+        self.sprints.iter().find(|sprint| sprint.number == number)
+    }
+
+    fn resolves(&self, dependency: &DependencyRef) -> bool {
+        match dependency {
+            DependencyRef::Sprint(number) => self.sprint(*number).is_some(),
+            DependencyRef::Task(id) => self.tasks.contains_key(id.as_str()),
+        }
+    }
+
+    /// Cycles, unknown references and duplicate task ids, in document order
+    pub fn problems(&self) -> Vec<GraphProblem> {
+        let mut problems = Vec::new();
+        let mut seen: HashMap<&str, u32> = HashMap::new();
+
+        for sprint in self.sprints {
+            for dependency in &sprint.depends_on {
+                if !self.resolves(dependency) {
+                    problems.push(GraphProblem {
+                        rule: "unknown-dependency",
+                        message: format!(
+                            "Sprint {} depends on {}, which is not in the MVP breakdown",
+                            sprint.number,
+                            describe(dependency)
+                        ),
+                        range: sprint.heading.clone(),
+                        sprint: sprint.heading_text(),
+                    });
+                }
+            }
+
+            for task in sprint.all_tasks() {
+                if let Some(first) = seen.insert(task.id.as_str(), sprint.number) {
+                    problems.push(GraphProblem {
+                        rule: "duplicate-task-id",
+                        message: format!(
+                            "Task id '{}' is already used in Sprint {first}",
+                            task.id
+                        ),
+                        range: task.line.clone(),
+                        sprint: sprint.heading_text(),
+                    });
+                }
+                for dependency in &task.depends_on {
+                    if !self.resolves(dependency) {
+                        problems.push(GraphProblem {
+                            rule: "unknown-dependency",
+                            message: format!(
+                                "Task '{}' depends on {}, which is not in the MVP breakdown",
+                                task.id,
+                                describe(dependency)
+                            ),
+                            range: task.line.clone(),
+                            sprint: sprint.heading_text(),
+                        });
+                    }
+                }
+            }
+        }
+
+        problems.extend(self.cycles());
+        problems
+    }
+
+    /// What a node waits for, unknown references left out
+    ///
+    /// Only the first definition of a task id takes part; duplicates are
+    /// reported on their own.
+    fn edges(&self) -> BTreeMap<DependencyRef, Vec<DependencyRef>> {
+        let mut edges: BTreeMap<DependencyRef, Vec<DependencyRef>> = BTreeMap::new();
+        for sprint in self.sprints {
+            let sprint_dependencies: Vec<DependencyRef> = sprint
+                .depends_on
+                .iter()
+                .filter(|dependency| self.resolves(dependency))
+                .cloned()
+                .collect();
+
+            let node = edges.entry(DependencyRef::Sprint(sprint.number)).or_default();
+            node.extend(sprint_dependencies.iter().cloned());
+            node.extend(
+                sprint
+                    .tasks
+                    .iter()
+                    .filter(|task| self.sprint_of_task(&task.id) == Some(sprint.number))
+                    .map(|task| DependencyRef::Task(task.id.clone())),
+            );
+
+            for task in sprint.all_tasks() {
+                let key = DependencyRef::Task(task.id.clone());
+                if edges.contains_key(&key) {
+                    continue;
+                }
+                let node = edges.entry(key).or_default();
+                node.extend(
+                    task.depends_on
+                        .iter()
+                        .filter(|dependency| self.resolves(dependency))
+                        .cloned(),
+                );
+                node.extend(
+                    task.subtasks
+                        .iter()
+                        .map(|subtask| DependencyRef::Task(subtask.id.clone())),
+                );
+                node.extend(sprint_dependencies.iter().cloned());
+            }
+        }
+        edges
+    }
+
+    /// One problem per dependency cycle
+    fn cycles(&self) -> Vec<GraphProblem> {
+        fn visit(
+            node: &DependencyRef,
+            edges: &BTreeMap<DependencyRef, Vec<DependencyRef>>,
+            path: &mut Vec<DependencyRef>,
+            done: &mut BTreeSet<DependencyRef>,
+            cycles: &mut Vec<Vec<DependencyRef>>,
+        ) {
+            if done.contains(node) {
+                return;
+            }
+            if let Some(start) = path.iter().position(|open| open == node) {
+                cycles.push(path[start..].to_vec());
+                return;
+            }
+            path.push(node.clone());
+            for next in edges.get(node).into_iter().flatten() {
+                visit(next, edges, path, done, cycles);
+            }
+            path.pop();
+            done.insert(node.clone());
+        }
+
+        let edges = self.edges();
+        let mut cycles = Vec::new();
+        let mut done = BTreeSet::new();
+        for node in edges.keys() {
+            visit(node, &edges, &mut Vec::new(), &mut done, &mut cycles);
+        }
+
+        cycles
+            .into_iter()
+            .map(|cycle| {
+                let mut names: Vec<String> = cycle.iter().map(ToString::to_string).collect();
+                names.push(names[0].clone());
+                let (range, sprint) = self.location(&cycle[0]);
+                GraphProblem {
+                    rule: "dependency-cycle",
+                    message: format!("Dependency cycle: {}", names.join(" → ")),
+                    range,
+                    sprint,
+                }
+            })
+            .collect()
+    }
+
+    /// Line and sprint heading of a node
+    fn location(&self, node: &DependencyRef) -> (Range<usize>, String) {
+        let sprint = match node {
+            DependencyRef::Sprint(number) => self.sprint(*number),
+            DependencyRef::Task(id) => self
+                .tasks
+                .get(id.as_str())
+                .and_then(|(number, _)| self.sprint(*number)),
+        };
+        let Some(sprint) = sprint else {
+            return (0..0, String::new());
+        };
+        let range = match node {
+            DependencyRef::Task(id) => sprint
+                .all_tasks()
+                .into_iter()
+                .find(|task| task.id == *id)
+                .map_or_else(|| sprint.heading.clone(), |task| task.line.clone()),
+            DependencyRef::Sprint(_) => sprint.heading.clone(),
+        };
+        (range, sprint.heading_text())
+    }
+
+    /// Sprint that defines a task id first
+    fn sprint_of_task(&self, id: &str) -> Option<u32> {
+        self.tasks.get(id).map(|(number, _)| *number)
+    }
+
+    /// Sprint a dependency belongs to
+    fn sprint_of(&self, dependency: &DependencyRef) -> Option<u32> {
+        match dependency {
+            DependencyRef::Sprint(number) => self.sprint(*number).map(|sprint| sprint.number),
+            DependencyRef::Task(id) => self.sprint_of_task(id),
+        }
+    }
+
+    /// Other sprints a sprint directly depends on (itself or through its tasks)
+    pub fn direct_prerequisites(&self, number: u32) -> BTreeSet<u32> {
+        let Some(sprint) = self.sprint(number) else {
+            return BTreeSet::new();
+        };
+        sprint
+            .depends_on
+            .iter()
+            .chain(sprint.all_tasks().into_iter().flat_map(|task| &task.depends_on))
+            .filter_map(|dependency| self.sprint_of(dependency))
+            .filter(|&other| other != number)
+            .collect()
+    }
+
+    /// Every sprint that has to be finished before a sprint can start
+    pub fn prerequisites(&self, number: u32) -> BTreeSet<u32> {
+        let mut found = BTreeSet::new();
+        let mut pending = vec![number];
+        while let Some(current) = pending.pop() {
+            for prerequisite in self.direct_prerequisites(current) {
+                if prerequisite != number && found.insert(prerequisite) {
+                    pending.push(prerequisite);
+                }
+            }
+        }
+        found
+    }
+
+    /// Status of a sprint, from the approvals and active sprint in `state`
+    pub fn status(&self, number: u32, state: Option<&StateConfig>) -> SprintStatus {
+        let finished = |number: u32| state.is_some_and(|state| state.is_finished(number));
+        if state.is_some_and(|state| state.is_approved(number)) {
+            return SprintStatus::Approved;
+        }
+        if state.is_some_and(|state| state.is_closed(number)) {
+            return SprintStatus::Closed;
+        }
+        if state
+            .and_then(|state| state.active_sprint.as_ref())
+            .is_some_and(|active| active.number() == Some(number))
+        {
+            return SprintStatus::InProgress;
+        }
+        let waiting: Vec<u32> = self
+            .prerequisites(number)
+            .into_iter()
+            .filter(|prerequisite| !finished(*prerequisite))
+            .collect();
+        if waiting.is_empty() {
+            SprintStatus::Ready
+        } else {
+            SprintStatus::Blocked(waiting)
+        }
+    }
+
+    /// Sprint numbers in an order that respects their prerequisites
+    ///
+    /// Among sprints that are ready, the lowest number comes first. Sprints
+    /// caught in a cycle are left out.
+    pub fn sprint_order(&self) -> Vec<u32> {
+        let mut remaining: BTreeMap<u32, BTreeSet<u32>> = self
+            .sprints
+            .iter()
+            .map(|sprint| (sprint.number, self.direct_prerequisites(sprint.number)))
+            .collect();
+        let mut order = Vec::new();
+        while let Some(&next) = remaining
+            .iter()
+            .find(|(_, prerequisites)| prerequisites.iter().all(|p| order.contains(p)))
+            .map(|(number, _)| number)
+        {
+            remaining.remove(&next);
+            order.push(next);
+        }
+        order
+    }
+
+    /// Longest chain of dependent top-level tasks, by estimate then task count
+    ///
+    /// `None` when the breakdown has no tasks or the graph has a cycle.
+    pub fn critical_path(&self) -> Option<CriticalPath<'a>> {
+        // What each top-level task waits for, as top-level tasks
+        let mut waits: Vec<(&'a MvpTask, BTreeSet<&'a str>)> = Vec::new();
+        for sprint in self.sprints {
+            for top in &sprint.tasks {
+                let mut prerequisites = BTreeSet::new();
+                let dependencies = sprint.depends_on.iter().chain(
+                    std::iter::once(top)
+                        .chain(descendants(top))
+                        .flat_map(|task| &task.depends_on),
+                );
+                for dependency in dependencies {
+                    match dependency {
+                        DependencyRef::Task(id) => {
+                            if let Some((_, owner)) = self.tasks.get(id.as_str()) {
+                                prerequisites.insert(owner.id.as_str());
+                            }
+                        }
+                        DependencyRef::Sprint(number) => {
+                            if let Some(other) = self.sprint(*number) {
+                                prerequisites.extend(other.tasks.iter().map(|task| task.id.as_str()));
+                            }
+                        }
+                    }
+                }
+                prerequisites.remove(top.id.as_str());
+                waits.push((top, prerequisites));
+            }
+        }
+        let index: HashMap<&str, usize> = waits
+            .iter()
+            .enumerate()
+            .map(|(i, (task, _))| (task.id.as_str(), i))
+            .collect();
+
+        // Heaviest chain ending at each task, and the task before it
+        let mut best: Vec<Option<(Weight, Option<usize>)>> = vec![None; waits.len()];
+        let mut visiting = vec![false; waits.len()];
+        fn chain(
+            i: usize,
+            waits: &[(&MvpTask, BTreeSet<&str>)],
+            index: &HashMap<&str, usize>,
+            best: &mut [Option<(Weight, Option<usize>)>],
+            visiting: &mut [bool],
+        ) -> Option<Weight> {
+            if let Some((weight, _)) = best[i] {
+                return Some(weight);
+            }
+            if visiting[i] {
+                return None;
+            }
+            visiting[i] = true;
+            let mut heaviest: (Weight, Option<usize>) = ((0, 0), None);
+            for id in &waits[i].1 {
+                let Some(&previous) = index.get(id) else {
+                    continue;
+                };
+                let weight = chain(previous, waits, index, best, visiting)?;
+                if weight > heaviest.0 {
+                    heaviest = (weight, Some(previous));
+                }
+            }
+            visiting[i] = false;
+            let own = waits[i].0.total_estimate().map_or(0, Estimate::minutes);
+            let weight = (heaviest.0.0 + own, heaviest.0.1 + 1);
+            best[i] = Some((weight, heaviest.1));
+            Some(weight)
+        }
+
+        let mut end: Option<(Weight, usize)> = None;
+        for i in 0..waits.len() {
+            let weight = chain(i, &waits, &index, &mut best, &mut visiting)?;
+            if end.is_none_or(|(heaviest, _)| weight > heaviest) {
+                end = Some((weight, i));
+            }
+        }
+
+        let ((minutes, _), mut last) = end?;
+        let mut tasks = vec![waits[last].0];
+        while let Some((_, Some(previous))) = best[last] {
+            tasks.push(waits[previous].0);
+            last = previous;
+        }
+        tasks.reverse();
+        Some(CriticalPath {
+            tasks,
+            estimate: Estimate::from_minutes(minutes),
+        })
+    }
+}
+
+/// Every sub-task below a task, depth first
+fn descendants(task: &MvpTask) -> Vec<&MvpTask> {
+    task.subtasks
+        .iter()
+        .flat_map(|subtask| std::iter::once(subtask).chain(descendants(subtask)))
+        .collect()
+}
+
+fn describe(dependency: &DependencyRef) -> String {
+    match dependency {
+        DependencyRef::Sprint(number) => format!("Sprint {number}"),
+        DependencyRef::Task(id) => format!("task '{id}'"),
+    }
+}
+
+/// Dependency problems of an MVP breakdown, as gate issues
+pub fn check_dependencies(content: &str) -> Vec<ValidationIssue> {
+    let sprints = parse_sprints(content);
+    let index = LineIndex::new(content);
+    Roadmap::new(&sprints)
+        .problems()
+        .into_iter()
+        .map(|problem| ValidationIssue::InvalidDependency {
+            rule: problem.rule.to_string(),
+            message: problem.message,
+            span: index.span(problem.range),
+            section: Some(problem.sprint),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BREAKDOWN: &str = "# MVP
+## Sprint 0: Setup
+- [ ] Create repo ~1h
+
+## Sprint 1: Core
+- [ ] Schema ~2h 🆔 schema
+- [ ] Queries ~3h ⛔ schema
+
+## Sprint 2: Sync
+**Depends on:** sprint-0
+- [ ] Sync engine ~4h ⛔ s1-2
+  - [ ] Retry queue ~1h
+- [ ] Settings ~1h
+
+## Sprint 3: Polish
+**Depends on:** Sprint 2
+- [ ] Dark mode ~30m
+";
+
+    #[test]
+    fn test_prerequisites_and_order() {
+        let sprints = parse_sprints(BREAKDOWN);
+        let roadmap = Roadmap::new(&sprints);
+        assert!(roadmap.problems().is_empty());
+
+        assert_eq!(roadmap.direct_prerequisites(2), BTreeSet::from([0, 1]));
+        assert_eq!(roadmap.prerequisites(3), BTreeSet::from([0, 1, 2]));
+        assert!(roadmap.prerequisites(1).is_empty());
+        assert_eq!(roadmap.sprint_order(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_critical_path() {
+        let sprints = parse_sprints(BREAKDOWN);
+        let path = Roadmap::new(&sprints).critical_path().unwrap();
+        let ids: Vec<&str> = path.tasks.iter().map(|task| task.id.as_str()).collect();
+        // Sprint 2 waits for Sprint 0; the engine also waits for the queries
+        assert_eq!(ids, vec!["schema", "s1-2", "s2-1", "s3-1"]);
+        assert_eq!(path.estimate.to_string(), "9.5h");
+    }
+
+    #[test]
+    fn test_problems() {
+        let content = "## Sprint 1: Core
+**Depends on:** sprint-2, sprint-7
+- [ ] Schema 🆔 schema
+- [ ] Queries ⛔ auth
+
+## Sprint 2: Sync
+- [ ] Engine ⛔ s1-2
+- [ ] Again 🆔 schema
+";
+        let sprints = parse_sprints(content);
+        let roadmap = Roadmap::new(&sprints);
+        let problems: Vec<(&str, String)> = roadmap
+            .problems()
+            .into_iter()
+            .map(|problem| (problem.rule, problem.message))
+            .collect();
+        assert_eq!(
+            problems,
+            vec![
+                (
+                    "unknown-dependency",
+                    "Sprint 1 depends on Sprint 7, which is not in the MVP breakdown".to_string()
+                ),
+                (
+                    "unknown-dependency",
+                    "Task 's1-2' depends on task 'auth', which is not in the MVP breakdown"
+                        .to_string()
+                ),
+                (
+                    "duplicate-task-id",
+                    "Task id 'schema' is already used in Sprint 1".to_string()
+                ),
+                (
+                    "dependency-cycle",
+                    "Dependency cycle: sprint-2 → s2-1 → s1-2 → sprint-2".to_string()
+                ),
+            ]
+        );
+        assert!(roadmap.critical_path().is_none());
+        assert_eq!(roadmap.sprint_order(), Vec::<u32>::new());
+
+        let issues = check_dependencies(content);
+        assert_eq!(issues[1].rule_id(), "unknown-dependency");
+        assert_eq!(issues[1].span().unwrap().start.line, 4);
+        assert_eq!(issues[1].section(), Some("Sprint 1: Core"));
+    }
+}
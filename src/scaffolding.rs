@@ -2,36 +2,49 @@
 //!
 //! Creates the Obsidian folder structure for sprint management.
 
-use crate::planning::SprintData;
+use crate::layout::VaultLayout;
+use crate::mvp::{MvpTask, SprintData, render_tasks};
+use crate::planning::{HeaderMatcher, find_section};
+use crate::templating::SprintTemplates;
 use anyhow::{Context, Result};
 use std::fs;
-use std::path::Path;
+use std::path::PathBuf;
+
+/// Task list of a sprint workspace
+pub const TASKS_FILE: &str = "Tasks.md";
+
+/// Heading of the `Tasks.md` section holding tasks carried over from earlier sprints
+const CARRIED_OVER_HEADING: &str = "## Carried Over";
+
+/// Heading of the `Tasks.md` section linking the sprint's dev session notes
+const SESSIONS_HEADING: &str = "## Sessions";
 
 /// Scaffold the sprint folder structure in the Obsidian vault
 ///
-/// Creates the complete folder structure for a sprint:
-/// - 00-MANAGEMENT/sprints/sprint-{number}-{name}/
-///   - Tasks.md (extracted tasks from MVP)
-///   - Sprint-Context.md (scope and boundaries)
+/// Creates the folder of a sprint with the files and folders configured in
+/// `[templates]`, by default:
+/// - {sprint_dir}/sprint-{number}-{name}/
+///   - Tasks.md (extracted tasks from MVP, with their ids and metadata)
+///   - Sprint-Context.md (focus, exit criteria, scope and boundaries)
 ///   - approvals/ (empty folder for approval artifacts)
 ///   - sessions/ (empty folder for dev session notes)
 ///
+/// Tasks carried over into an existing Tasks.md are kept.
+///
 /// # Arguments
-/// * `planning_path` - Path to the Obsidian vault root (where 00-MANAGEMENT lives)
+/// * `layout` - Folders of the Obsidian vault
+/// * `templates` - Templates of the workspace files
 /// * `sprint_data` - Parsed sprint information from MVP breakdown
 ///
 /// # Returns
 /// * `Ok(())` - Sprint folder created successfully
-/// * `Err` - If folder creation or file writing fails
-pub fn scaffold_sprint_folder(planning_path: &Path, sprint_data: &SprintData) -> Result<()> {
-    // Build sprint folder path
-    let sprint_folder = planning_path
-        .join("00-MANAGEMENT")
-        .join("sprints")
-        .join(format!(
-            "sprint-{}-{}",
-            sprint_data.number, sprint_data.name
-        ));
+/// * `Err` - If a template fails to render, or folder creation or file writing fails
+pub fn scaffold_sprint_folder(
+    layout: &VaultLayout,
+    templates: &SprintTemplates,
+    sprint_data: &SprintData,
+) -> Result<()> {
+    let sprint_folder = layout.sprint_folder(sprint_data);
 
     // Create main sprint folder
     fs::create_dir_all(&sprint_folder).with_context(|| {
@@ -41,130 +54,234 @@ pub fn scaffold_sprint_folder(planning_path: &Path, sprint_data: &SprintData) ->
         )
     })?;
 
-    // Create Tasks.md
-    create_tasks_file(&sprint_folder, sprint_data)?;
-
-    // Create Sprint-Context.md
-    create_context_file(&sprint_folder, sprint_data)?;
-
-    // Create approvals/ folder
-    let approvals_dir = sprint_folder.join("approvals");
-    fs::create_dir_all(&approvals_dir).with_context(|| {
-        format!(
-            "Failed to create approvals folder: {}",
-            approvals_dir.display()
-        )
-    })?;
+    for file in &templates.files {
+        let mut content = templates.render(file, sprint_data, layout)?;
+        let path = sprint_folder.join(file);
+        if file == TASKS_FILE
+            && let Ok(existing) = fs::read_to_string(&path)
+            && let Some(section) = carried_over_section(&existing)
+        {
+            insert_before_notes(&mut content, &existing[section]);
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create folder: {}", parent.display()))?;
+        }
+        fs::write(&path, content)
+            .with_context(|| format!("Failed to write {file}: {}", path.display()))?;
+    }
 
-    // Create sessions/ folder
-    let sessions_dir = sprint_folder.join("sessions");
-    fs::create_dir_all(&sessions_dir).with_context(|| {
-        format!(
-            "Failed to create sessions folder: {}",
-            sessions_dir.display()
-        )
-    })?;
+    for folder in &templates.folders {
+        let dir = sprint_folder.join(folder);
+        fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create {folder} folder: {}", dir.display()))?;
+    }
 
     Ok(())
 }
 
-/// Create Tasks.md with extracted task list
-fn create_tasks_file(sprint_folder: &Path, sprint_data: &SprintData) -> Result<()> {
-    let tasks_content = format!(
-        r"# Sprint {} Tasks: {}
-
-## Task List
-{}
+/// Add tasks carried over from an earlier sprint to a sprint's Tasks.md
+///
+/// The sprint workspace is scaffolded first when it does not exist yet.
+/// `origin` links back to where the tasks came from (a wikilink to the
+/// close-out report).
+///
+/// # Returns
+/// * `Ok(PathBuf)` - Path of the updated Tasks.md
+/// * `Err` - If the workspace cannot be scaffolded or Tasks.md cannot be written
+pub fn carry_over_into_sprint(
+    layout: &VaultLayout,
+    templates: &SprintTemplates,
+    sprint_data: &SprintData,
+    origin: &str,
+    tasks: &[MvpTask],
+) -> Result<PathBuf> {
+    let tasks_path = layout.sprint_folder(sprint_data).join(TASKS_FILE);
+    if !tasks_path.exists() {
+        scaffold_sprint_folder(layout, templates, sprint_data)?;
+    }
+    // Without Tasks.md in `[templates] sprint_files` it only holds carried-over tasks
+    let mut content = if tasks_path.exists() {
+        fs::read_to_string(&tasks_path)
+            .with_context(|| format!("Failed to read Tasks.md: {}", tasks_path.display()))?
+    } else {
+        String::new()
+    };
+
+    let block = format!("From {origin}\n{}\n", render_tasks(tasks));
+    match carried_over_section(&content) {
+        Some(section) => {
+            let mut at = section.end;
+            if !content[..at].ends_with("\n\n") {
+                content.insert(at, '\n');
+                at += 1;
+            }
+            content.insert_str(at, &format!("{block}\n"));
+        }
+        None => insert_before_notes(&mut content, &format!("{CARRIED_OVER_HEADING}\n\n{block}")),
+    }
 
-## Notes
-- Update task status as you progress
-- Mark completed tasks with [x]
-- Add blockers or issues below
+    fs::write(&tasks_path, content)
+        .with_context(|| format!("Failed to write Tasks.md: {}", tasks_path.display()))?;
+    Ok(tasks_path)
+}
 
-## Blockers
-- (none yet)
-",
-        sprint_data.number, sprint_data.title, sprint_data.tasks
-    );
+/// Link a dev session note from a sprint's Tasks.md, under `## Sessions`
+///
+/// `link` is the wikilink to add as a list item.
+///
+/// # Returns
+/// * `Ok(PathBuf)` - Path of the updated Tasks.md
+/// * `Err` - If Tasks.md cannot be written
+pub fn link_session(layout: &VaultLayout, sprint_data: &SprintData, link: &str) -> Result<PathBuf> {
+    let tasks_path = layout.sprint_folder(sprint_data).join(TASKS_FILE);
+    let mut content = fs::read_to_string(&tasks_path).unwrap_or_default();
+
+    let header = SESSIONS_HEADING.trim_start_matches('#').trim();
+    match find_section(&content, header, &HeaderMatcher::default()) {
+        Some((section, _)) => {
+            let at = content[..section.end].trim_end().len();
+            content.insert_str(at, &format!("\n- {link}"));
+        }
+        None => insert_before_notes(&mut content, &format!("{SESSIONS_HEADING}\n\n- {link}")),
+    }
 
-    let tasks_path = sprint_folder.join("Tasks.md");
-    fs::write(&tasks_path, tasks_content)
+    fs::write(&tasks_path, content)
         .with_context(|| format!("Failed to write Tasks.md: {}", tasks_path.display()))?;
-
-    Ok(())
+    Ok(tasks_path)
 }
 
-/// Create Sprint-Context.md with boundary rules and scope
-fn create_context_file(sprint_folder: &Path, sprint_data: &SprintData) -> Result<()> {
-    let context_content = format!(
-        r"# Sprint {} Context: {}
-
-## Focus
-{}
-
-## Scope Boundaries
-
-### Allowed (MVP Only)
-- Implement features exactly as specified in the sprint tasks
-- Add necessary error handling and validation
-- Write tests for new functionality
-- Update documentation for changes made
-
-### Forbidden (Outside MVP Scope)
-- Adding features not in the task list
-- Refactoring existing code unless required for the task
-- Optimizations beyond basic functionality
-- UI/UX improvements not specified in tasks
-- Additional dependencies not approved in planning
-
-## Success Criteria
-- All tasks marked complete
-- Tests pass
-- Code builds without warnings
-- Sprint approved by commander
-
-## Resources
-- Planning docs: `01-PLANNING/`
-- MVP breakdown: `01-PLANNING/05-MVP-Breakdown.md`
-- Tech stack: `01-PLANNING/03-Tech-Stack.md`
-",
-        sprint_data.number, sprint_data.title, sprint_data.context
-    );
+/// Add tasks carried over from a sprint to the backlog note
+///
+/// # Returns
+/// * `Ok(PathBuf)` - Path of the backlog note
+/// * `Err` - If the note cannot be written
+pub fn carry_over_into_backlog(
+    layout: &VaultLayout,
+    sprint_data: &SprintData,
+    origin: &str,
+    tasks: &[MvpTask],
+) -> Result<PathBuf> {
+    let backlog_path = layout.backlog();
+    let mut content = match fs::read_to_string(&backlog_path) {
+        Ok(content) => content,
+        Err(_) => "# Backlog\n\nUnfinished tasks of closed sprints, waiting for a sprint to take them.\n"
+            .to_string(),
+    };
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(&format!(
+        "\n## From Sprint {}: {}\n\nFrom {origin}\n{}\n",
+        sprint_data.number,
+        sprint_data.title,
+        render_tasks(tasks)
+    ));
+
+    if let Some(parent) = backlog_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create folder: {}", parent.display()))?;
+    }
+    fs::write(&backlog_path, content)
+        .with_context(|| format!("Failed to write backlog: {}", backlog_path.display()))?;
+    Ok(backlog_path)
+}
 
-    let context_path = sprint_folder.join("Sprint-Context.md");
-    fs::write(&context_path, context_content).with_context(|| {
+/// Move an aborted sprint's workspace to {sprint_dir}/aborted/
+///
+/// The folder keeps its name with `suffix` appended (e.g., the abort time), so
+/// a sprint aborted twice does not overwrite its first archive.
+///
+/// # Returns
+/// * `Ok(Some(PathBuf))` - Path of the archived folder
+/// * `Ok(None)` - The sprint has no workspace
+/// * `Err` - If the folder cannot be moved
+pub fn archive_sprint_folder(
+    layout: &VaultLayout,
+    sprint_data: &SprintData,
+    suffix: &str,
+) -> Result<Option<PathBuf>> {
+    let sprint_folder = layout.sprint_folder(sprint_data);
+    if !sprint_folder.exists() {
+        return Ok(None);
+    }
+    let archive_dir = layout.aborted_dir();
+    fs::create_dir_all(&archive_dir)
+        .with_context(|| format!("Failed to create folder: {}", archive_dir.display()))?;
+    let archived = archive_dir.join(format!(
+        "sprint-{}-{}-{suffix}",
+        sprint_data.number, sprint_data.name
+    ));
+    fs::rename(&sprint_folder, &archived).with_context(|| {
         format!(
-            "Failed to write Sprint-Context.md: {}",
-            context_path.display()
+            "Failed to move {} to {}",
+            sprint_folder.display(),
+            archived.display()
         )
     })?;
+    Ok(Some(archived))
+}
 
-    Ok(())
+/// Byte range of the carried-over section, from its heading to the next heading
+fn carried_over_section(content: &str) -> Option<std::ops::Range<usize>> {
+    let header = CARRIED_OVER_HEADING.trim_start_matches('#').trim();
+    find_section(content, header, &HeaderMatcher::default()).map(|(range, _)| range)
+}
+
+/// Insert a section before `## Notes`, or at the end when there is none
+fn insert_before_notes(content: &mut String, section: &str) {
+    let mut section = section.trim_end().to_string();
+    section.push_str("\n\n");
+    match content.find("\n## Notes") {
+        Some(at) => content.insert_str(at + 1, &section),
+        None => {
+            if !content.ends_with('\n') {
+                content.push('\n');
+            }
+            content.push('\n');
+            content.push_str(&section);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::{NexusConfig, TemplatesConfig};
+    use std::path::Path;
     use tempfile::TempDir;
 
+    /// Default layout and templates of a vault
+    fn workspace(planning_path: &Path) -> (VaultLayout, SprintTemplates) {
+        let config = NexusConfig::new("test".to_string(), planning_path.display().to_string());
+        (config.layout(), SprintTemplates::load(&config).unwrap())
+    }
+
     fn create_test_sprint_data() -> SprintData {
-        SprintData {
-            number: 4,
-            name: "the-sprint-orchestrator".to_string(),
-            title: "The Sprint Orchestrator (The Leash)".to_string(),
-            tasks: "- [ ] Task one\n- [ ] Task two\n- [ ] Task three".to_string(),
-            context: "_Focus: Creating the Tactical Staging Area._\n**Exit criteria:** All tasks complete".to_string(),
-        }
+        crate::mvp::parse_sprints(
+            "## Sprint 4: The Sprint Orchestrator (The Leash)
+_Focus: Creating the Tactical Staging Area._
+**Depends on:** sprint-3
+
+- [ ] Task one ~2h #cli
+  - [ ] Sub-task of one
+- [ ] Task two ⛔ s4-1
+- [ ] Task three ~30m
+**Exit criteria:** All tasks complete
+",
+        )
+        .remove(0)
     }
 
     #[test]
     fn test_scaffold_sprint_folder_creates_structure() {
         let temp_dir = TempDir::new().unwrap();
         let planning_path = temp_dir.path();
+        let (layout, templates) = workspace(planning_path);
 
         let sprint_data = create_test_sprint_data();
 
-        let result = scaffold_sprint_folder(planning_path, &sprint_data);
+        let result = scaffold_sprint_folder(&layout, &templates, &sprint_data);
         assert!(result.is_ok(), "Should create sprint folder successfully");
 
         // Verify main folder exists
@@ -205,9 +322,10 @@ mod tests {
     fn test_tasks_file_contains_correct_content() {
         let temp_dir = TempDir::new().unwrap();
         let planning_path = temp_dir.path();
+        let (layout, templates) = workspace(planning_path);
         let sprint_data = create_test_sprint_data();
 
-        scaffold_sprint_folder(planning_path, &sprint_data).unwrap();
+        scaffold_sprint_folder(&layout, &templates, &sprint_data).unwrap();
 
         let tasks_file = planning_path
             .join("00-MANAGEMENT")
@@ -223,6 +341,12 @@ mod tests {
         );
         assert!(content.contains("Task one"), "Should contain tasks");
         assert!(content.contains("Task two"), "Should contain tasks");
+        assert!(
+            content.contains("- [ ] Task one 🆔 s4-1 ~2h #cli\n  - [ ] Sub-task of one 🆔 s4-1-1"),
+            "Should render ids, metadata and sub-tasks"
+        );
+        assert!(content.contains("- [ ] Task two 🆔 s4-2 ⛔ s4-1"));
+        assert!(content.contains("**Estimated effort:** ~2.5h"));
         assert!(content.contains("Blockers"), "Should have blockers section");
     }
 
@@ -230,9 +354,10 @@ mod tests {
     fn test_context_file_contains_correct_content() {
         let temp_dir = TempDir::new().unwrap();
         let planning_path = temp_dir.path();
+        let (layout, templates) = workspace(planning_path);
         let sprint_data = create_test_sprint_data();
 
-        scaffold_sprint_folder(planning_path, &sprint_data).unwrap();
+        scaffold_sprint_folder(&layout, &templates, &sprint_data).unwrap();
 
         let context_file = planning_path
             .join("00-MANAGEMENT")
@@ -263,16 +388,22 @@ mod tests {
             content.contains("Creating the Tactical Staging Area"),
             "Should include focus text"
         );
+        assert!(
+            content.contains("## Exit Criteria\n- All tasks complete\n"),
+            "Should list exit criteria"
+        );
+        assert!(content.contains("**Depends on:** sprint-3"));
     }
 
     #[test]
     fn test_scaffold_creates_nested_directories() {
         let temp_dir = TempDir::new().unwrap();
         let planning_path = temp_dir.path();
+        let (layout, templates) = workspace(planning_path);
         let sprint_data = create_test_sprint_data();
 
         // Don't pre-create any directories - test that scaffold creates them all
-        let result = scaffold_sprint_folder(planning_path, &sprint_data);
+        let result = scaffold_sprint_folder(&layout, &templates, &sprint_data);
         assert!(
             result.is_ok(),
             "Should create all nested directories: {:?}",
@@ -288,4 +419,155 @@ mod tests {
         let sprints_dir = management_dir.join("sprints");
         assert!(sprints_dir.exists(), "Should create sprints directory");
     }
+
+    #[test]
+    fn test_scaffold_with_custom_templates_and_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let planning_path = temp_dir.path();
+        let mut config = NexusConfig::new("test".to_string(), planning_path.display().to_string());
+        config.templates = Some(TemplatesConfig {
+            sprint_templates: Some("sprint-templates".to_string()),
+            sprint_files: Some(vec![
+                "Tasks.md".to_string(),
+                "Sprint-Context.md".to_string(),
+                "Retro.md".to_string(),
+            ]),
+            sprint_folders: Some(vec!["approvals".to_string()]),
+            ..TemplatesConfig::default()
+        });
+        let layout = config.layout();
+
+        // Vault override for the context, project override for the new file
+        fs::create_dir_all(layout.sprint_templates_dir()).unwrap();
+        fs::write(
+            layout.sprint_templates_dir().join("Sprint-Context.md.tera"),
+            "# {{ sprint.title }}\n\n## Forbidden\n- Unsafe code\n\nSee [[Coding-Standards]]\n",
+        )
+        .unwrap();
+        fs::create_dir_all(planning_path.join("sprint-templates")).unwrap();
+        fs::write(
+            planning_path.join("sprint-templates/Retro.md.tera"),
+            "# Retro {{ sprint.number }} ({{ project.name }})\n{% for task in sprint.tasks %}- {{ task.id }}\n{% endfor %}",
+        )
+        .unwrap();
+
+        let templates = SprintTemplates::load(&config).unwrap();
+        let sprint_data = create_test_sprint_data();
+        scaffold_sprint_folder(&layout, &templates, &sprint_data).unwrap();
+
+        let folder = layout.sprint_folder(&sprint_data);
+        assert!(
+            fs::read_to_string(folder.join("Tasks.md"))
+                .unwrap()
+                .contains("## Task List\n- [ ] Task one 🆔 s4-1")
+        );
+        assert_eq!(
+            fs::read_to_string(folder.join("Sprint-Context.md")).unwrap(),
+            "# The Sprint Orchestrator (The Leash)\n\n## Forbidden\n- Unsafe code\n\nSee [[Coding-Standards]]\n"
+        );
+        assert_eq!(
+            fs::read_to_string(folder.join("Retro.md")).unwrap(),
+            "# Retro 4 (test)\n- s4-1\n- s4-2\n- s4-3\n"
+        );
+        assert!(folder.join("approvals").is_dir());
+        assert!(!folder.join("sessions").exists());
+    }
+
+    #[test]
+    fn test_carry_over_into_sprint_survives_rescaffold() {
+        let temp_dir = TempDir::new().unwrap();
+        let planning_path = temp_dir.path();
+        let (layout, templates) = workspace(planning_path);
+        let sprint_data = create_test_sprint_data();
+        let carried = crate::mvp::carry_over(
+            &crate::mvp::parse_task_list("- [ ] Leftover 🆔 s3-2\n", "s3"),
+            3,
+        );
+
+        // The workspace is scaffolded when the next sprint has not started yet
+        let tasks_path =
+            carry_over_into_sprint(&layout, &templates, &sprint_data, "[[Close-Out|Sprint 3]]", &carried)
+                .unwrap();
+        carry_over_into_sprint(&layout, &templates, &sprint_data, "[[Backlog]]", &carried).unwrap();
+        let expected = "## Carried Over\n\n\
+                        From [[Close-Out|Sprint 3]]\n\
+                        - [ ] Leftover 🆔 s3-2 #carried-over [carried-from:: sprint-3]\n\n\
+                        From [[Backlog]]\n\
+                        - [ ] Leftover 🆔 s3-2 #carried-over [carried-from:: sprint-3]\n\n\
+                        ## Notes\n";
+        let content = fs::read_to_string(&tasks_path).unwrap();
+        assert!(content.contains(expected), "{content}");
+
+        // Scaffolding the sprint again keeps the carried-over tasks
+        scaffold_sprint_folder(&layout, &templates, &sprint_data).unwrap();
+        let content = fs::read_to_string(&tasks_path).unwrap();
+        assert!(content.contains("Task one"));
+        assert!(content.contains(expected), "{content}");
+    }
+
+    #[test]
+    fn test_link_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let (layout, templates) = workspace(temp_dir.path());
+        let sprint_data = create_test_sprint_data();
+        scaffold_sprint_folder(&layout, &templates, &sprint_data).unwrap();
+
+        link_session(&layout, &sprint_data, "[[sessions/2026-10-17-0930|Session 1]]").unwrap();
+        let tasks_path =
+            link_session(&layout, &sprint_data, "[[sessions/2026-10-17-1400|Session 2]]").unwrap();
+
+        let content = fs::read_to_string(tasks_path).unwrap();
+        assert!(
+            content.contains(
+                "## Sessions\n\n- [[sessions/2026-10-17-0930|Session 1]]\n\
+                 - [[sessions/2026-10-17-1400|Session 2]]\n\n## Notes\n"
+            ),
+            "{content}"
+        );
+    }
+
+    #[test]
+    fn test_carry_over_into_backlog_appends() {
+        let temp_dir = TempDir::new().unwrap();
+        let planning_path = temp_dir.path();
+        let (layout, _) = workspace(planning_path);
+        let sprint_data = create_test_sprint_data();
+        let carried = crate::mvp::carry_over(&sprint_data.tasks[1..2], 4);
+
+        carry_over_into_backlog(&layout, &sprint_data, "[[Close-Out]]", &carried).unwrap();
+        let path = carry_over_into_backlog(&layout, &sprint_data, "[[Close-Out]]", &carried)
+            .unwrap();
+
+        assert_eq!(path, layout.backlog());
+        let content = fs::read_to_string(path).unwrap();
+        assert!(content.starts_with("# Backlog\n"));
+        assert_eq!(
+            content
+                .matches("## From Sprint 4: The Sprint Orchestrator (The Leash)\n\nFrom [[Close-Out]]\n- [ ] Task two")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_archive_sprint_folder() {
+        let temp_dir = TempDir::new().unwrap();
+        let planning_path = temp_dir.path();
+        let (layout, templates) = workspace(planning_path);
+        let sprint_data = create_test_sprint_data();
+        assert!(archive_sprint_folder(&layout, &sprint_data, "x").unwrap().is_none());
+
+        scaffold_sprint_folder(&layout, &templates, &sprint_data).unwrap();
+        let archived = archive_sprint_folder(&layout, &sprint_data, "2026-10-17-093000")
+            .unwrap()
+            .unwrap();
+
+        assert!(!layout.sprint_folder(&sprint_data).exists());
+        assert_eq!(
+            archived,
+            planning_path
+                .join("00-MANAGEMENT/sprints/aborted/sprint-4-the-sprint-orchestrator-2026-10-17-093000")
+        );
+        assert!(archived.join("Tasks.md").exists());
+    }
 }
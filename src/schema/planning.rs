@@ -1,91 +1,380 @@
+//! Planning Document Schema
+//!
+//! The set of planning documents a project maintains: file names, required and
+//! optional headers, word minimums, generation guidance, and which sections feed
+//! the CLAUDE.md template context. The built-in schema lives in
+//! `templates/planning-schema.toml`; projects extend or replace it through the
+//! `[planning]` section of nexus.toml or a schema file it references.
+//!
+//! Headers can have aliases (other wordings or translations, such as the
+//! Swedish headers of `nexus init --locale sv`). An alias satisfies a required
+//! header and fills the same `PlanningContext` field.
+
+use crate::config::NexusConfig;
+use crate::planning::{HeaderMatcher, HeaderMatching, PlanningContext};
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+/// Built-in schema, embedded in the binary
+const BUILTIN_SCHEMA_TOML: &str = include_str!("../../templates/planning-schema.toml");
+
+/// Context mapping value that takes the whole document instead of one section
+pub const WHOLE_DOCUMENT: &str = "*";
+
+fn default_min_word_count() -> usize {
+    15
+}
+
+/// One planning document
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
 pub struct DocumentSchema {
-    pub filename: &'static str,
-    #[allow(dead_code)]
-    pub display_name: &'static str,
-    pub required_headers: &'static [&'static str],
-    #[allow(dead_code)]
-    pub optional_headers: &'static [&'static str],
+    /// File name inside the planning directory (e.g. "03-Tech-Stack.md")
+    pub filename: String,
+    pub display_name: String,
+    /// Headers the gate requires
+    #[serde(default)]
+    pub required_headers: Vec<String>,
+    /// Headers that may appear; used for scaffolding and generation prompts
+    #[serde(default)]
+    pub optional_headers: Vec<String>,
+    /// Minimum word count per section
+    #[serde(default = "default_min_word_count")]
     pub min_word_count: usize,
-    pub template_guidance: &'static str,
+    /// What the document must contain, used in generation prompts
+    #[serde(default)]
+    pub guidance: String,
+    /// Markdown template used to scaffold the document, relative to the project root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// `PlanningContext` field per section header (`"*"` for the whole document)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub context: BTreeMap<String, String>,
+    /// Other wordings accepted for a header, keyed by the header as named above
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aliases: BTreeMap<String, Vec<String>>,
+}
+
+impl DocumentSchema {
+    /// Skeleton markdown for a document without a template file
+    pub fn skeleton(&self) -> String {
+        let mut content = format!("# {}\n", self.display_name);
+        if !self.guidance.is_empty() {
+            content.push_str(&format!("\n> {}\n", self.guidance));
+        }
+        for header in &self.required_headers {
+            content.push_str(&format!("\n## {header}\n\n"));
+        }
+        content
+    }
+}
+
+/// Contents of a schema file (`[[documents]]` entries)
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct PlanningSchemaFile {
+    #[serde(default)]
+    pub documents: Vec<DocumentSchema>,
+}
+
+/// The ordered set of planning documents for a project
+///
+/// The first document is the vision that Project Genesis takes as input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlanningSchema {
+    pub documents: Vec<DocumentSchema>,
+    /// How headings are compared with schema headers
+    pub header_matching: HeaderMatching,
+    /// Aliases from nexus.toml that apply to every document
+    pub aliases: BTreeMap<String, Vec<String>>,
 }
 
-pub const PLANNING_DOCUMENTS: &[DocumentSchema] = &[
-    DocumentSchema {
-        filename: "01-Problem-and-Vision.md",
-        display_name: "Problem and Vision",
-        required_headers: &[
-            "My problem (personal):",
-            "Who else has this problem?",
-            "Solution in ONE SENTENCE:",
-            "Success criteria (3 months):",
-            "Anti-vision (what this project is NOT):",
-        ],
-        optional_headers: &[
-            "What is the problem?",
-        ],
-        min_word_count: 15,
-        template_guidance: "Define the personal problem, identify who shares it, provide a one-sentence solution, set 3-month success criteria, and clarify what this project is NOT",
-    },
-    DocumentSchema {
-        filename: "02-Scope-and-Boundaries.md",
-        display_name: "Scope and Boundaries",
-        required_headers: &[
-            "MVP (Minimum Viable Product):",
-            "Version 2 (NOT NOW - just document):",
-            "Never (things I will NOT build):",
-            "Tech constraints:",
-        ],
-        optional_headers: &[],
-        min_word_count: 15,
-        template_guidance: "Define MVP features (3-5 max), document future Version 2 features, list what will NEVER be built to prevent scope creep, and specify technical constraints (budget, timeline, platform)",
-    },
-    DocumentSchema {
-        filename: "03-Tech-Stack.md",
-        display_name: "Tech Stack",
-        required_headers: &[
-            "Stack (force yourself to choose NOW):",
-            "Why these choices?",
-            "What I will NOT use:",
-            "Dependencies (important ones):",
-            "Development environment:",
-        ],
-        optional_headers: &[],
-        min_word_count: 15,
-        template_guidance: "Choose specific technologies NOW (frontend, backend, database, hosting), justify each choice in 2 sentences, list technologies to avoid, specify key dependencies (max 10), and document dev environment (IDE, OS, device)",
-    },
-    DocumentSchema {
-        filename: "04-Architecture.md",
-        display_name: "Architecture",
-        required_headers: &[
-            "Folder structure:",
-            "Data model (main entities):",
-            "Flow (user journey):",
-            "Critical technical decisions:",
-        ],
-        optional_headers: &[],
-        min_word_count: 15,
-        template_guidance: "Define project folder structure with main directories, describe data entities (2 sentences each) with their fields, map out user journey step-by-step, and document critical decisions (state management, navigation, data persistence)",
-    },
-    DocumentSchema {
-        filename: "05-MVP-Breakdown.md",
-        display_name: "MVP Breakdown",
-        required_headers: &[
-            "Sprint 1:",
-            "Sprint 2:",
-            "Definition of Done (each sprint):",
-        ],
-        optional_headers: &[
-            "Sprint 0:",
-            "Sprint 3:",
-            "Sprint 4:",
-            "Sprint 5:",
-        ],
-        min_word_count: 15,
-        template_guidance: "Break MVP into minimum 2 sprints (can include Sprint 0 for setup and additional sprints as needed), each with specific tasks and concrete exit criteria. Include a universal Definition of Done checklist (builds without errors, tested, committed to git, session log updated)",
-    },
-];
-
-#[allow(dead_code)]
-pub fn get_document_schema(filename: &str) -> Option<&'static DocumentSchema> {
-    PLANNING_DOCUMENTS.iter().find(|doc| doc.filename == filename)
+impl PlanningSchema {
+    /// The built-in five-document schema
+    pub fn builtin() -> Result<Self> {
+        let file: PlanningSchemaFile =
+            toml::from_str(BUILTIN_SCHEMA_TOML).context("Failed to parse built-in planning schema")?;
+        Ok(Self {
+            documents: file.documents,
+            header_matching: HeaderMatching::default(),
+            aliases: BTreeMap::new(),
+        })
+    }
+
+    /// Schema for a project, from the `[planning]` section of its nexus.toml
+    ///
+    /// Documents from the schema file and from inline `[[planning.documents]]`
+    /// entries are added to the built-in set (unless `include_default = false`);
+    /// a document with the same file name as an earlier one replaces it in place.
+    ///
+    /// # Returns
+    /// * `Ok(PlanningSchema)` - Validated schema
+    /// * `Err` - Unreadable schema file, invalid TOML, or an invalid document
+    pub fn for_project(config: &NexusConfig) -> Result<Self> {
+        let Some(planning) = &config.planning else {
+            return Self::builtin();
+        };
+
+        let mut schema = if planning.include_default {
+            Self::builtin()?
+        } else {
+            Self {
+                documents: Vec::new(),
+                header_matching: HeaderMatching::default(),
+                aliases: BTreeMap::new(),
+            }
+        };
+        schema.header_matching = planning.header_matching;
+        schema.aliases = planning.aliases.clone();
+
+        if let Some(schema_file) = &planning.schema {
+            let path = config.get_repo_path().join(schema_file);
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read planning schema: {}", path.display()))?;
+            let file: PlanningSchemaFile = toml::from_str(&content)
+                .with_context(|| format!("Failed to parse planning schema: {}", path.display()))?;
+            schema.merge(file.documents);
+        }
+        schema.merge(planning.documents.clone());
+
+        schema.validate().context("Invalid planning schema")?;
+        Ok(schema)
+    }
+
+    /// Adds documents, replacing any with the same file name
+    fn merge(&mut self, documents: Vec<DocumentSchema>) {
+        for document in documents {
+            match self
+                .documents
+                .iter_mut()
+                .find(|existing| existing.filename == document.filename)
+            {
+                Some(existing) => *existing = document,
+                None => self.documents.push(document),
+            }
+        }
+    }
+
+    /// Checks file names and context field names
+    fn validate(&self) -> Result<()> {
+        if self.documents.is_empty() {
+            bail!("The planning schema defines no documents");
+        }
+
+        let mut seen = HashSet::new();
+        for document in &self.documents {
+            let filename = &document.filename;
+            if !filename.ends_with(".md") || filename.contains(['/', '\\']) {
+                bail!("Planning document '{filename}' must be a .md file name without a directory");
+            }
+            if !seen.insert(filename.as_str()) {
+                bail!("Planning document '{filename}' is defined twice");
+            }
+            for field in document.context.keys() {
+                if !PlanningContext::FIELDS.contains(&field.as_str()) {
+                    bail!(
+                        "Unknown context field '{field}' in planning document '{filename}' (expected one of: {})",
+                        PlanningContext::FIELDS.join(", ")
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Looks up a document by file name
+    pub fn document(&self, filename: &str) -> Option<&DocumentSchema> {
+        self.documents.iter().find(|doc| doc.filename == filename)
+    }
+
+    /// Header matcher with the aliases of every document and of nexus.toml
+    pub fn header_matcher(&self) -> HeaderMatcher {
+        let mut aliases = self.aliases.clone();
+        for document in &self.documents {
+            for (header, alternatives) in &document.aliases {
+                aliases
+                    .entry(header.clone())
+                    .or_default()
+                    .extend(alternatives.iter().cloned());
+            }
+        }
+        HeaderMatcher::new(self.header_matching, aliases)
+    }
+}
+
+/// Creates planning documents that do not exist yet
+///
+/// Uses the document's `template` (relative to `project_root`), then
+/// `<template_dir>/<filename>`, then a skeleton built from its headers.
+///
+/// # Returns
+/// * `Ok(Vec<String>)` - File names of the documents created
+/// * `Err` - A template could not be read or a document could not be written
+pub fn scaffold_documents(
+    schema: &PlanningSchema,
+    planning_dir: &Path,
+    project_root: &Path,
+    template_dir: &Path,
+) -> Result<Vec<String>> {
+    std::fs::create_dir_all(planning_dir).with_context(|| {
+        format!("Failed to create planning directory: {}", planning_dir.display())
+    })?;
+
+    let mut created = Vec::new();
+    for document in &schema.documents {
+        let target = planning_dir.join(&document.filename);
+        if target.exists() {
+            continue;
+        }
+
+        let template = document
+            .template
+            .as_ref()
+            .map(|template| project_root.join(template))
+            .unwrap_or_else(|| template_dir.join(&document.filename));
+        let content = if template.exists() {
+            std::fs::read_to_string(&template)
+                .with_context(|| format!("Failed to read template: {}", template.display()))?
+        } else {
+            document.skeleton()
+        };
+
+        std::fs::write(&target, content)
+            .with_context(|| format!("Failed to write {}", target.display()))?;
+        created.push(document.filename.clone());
+    }
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PlanningConfig;
+    use tempfile::TempDir;
+
+    fn config_with(planning: PlanningConfig, root: &Path) -> NexusConfig {
+        let mut config = NexusConfig::new("test".to_string(), root.display().to_string());
+        config.planning = Some(planning);
+        config
+    }
+
+    fn risks() -> DocumentSchema {
+        DocumentSchema {
+            filename: "06-Risks.md".to_string(),
+            display_name: "Risks".to_string(),
+            required_headers: vec!["Top risks:".to_string(), "Mitigations:".to_string()],
+            optional_headers: Vec::new(),
+            min_word_count: 10,
+            guidance: "List the risks that could sink the MVP".to_string(),
+            template: None,
+            context: BTreeMap::new(),
+            aliases: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_builtin_schema() {
+        let schema = PlanningSchema::builtin().unwrap();
+        schema.validate().unwrap();
+
+        assert_eq!(schema.documents.len(), 5);
+        assert_eq!(schema.documents[0].filename, "01-Problem-and-Vision.md");
+        let tech = schema.document("03-Tech-Stack.md").unwrap();
+        assert!(tech.required_headers.contains(&"Dependencies (max 10 important ones):".to_string()));
+        assert_eq!(tech.context["dependencies"], "Dependencies (max 10 important ones):");
+        assert_eq!(schema.document("05-MVP-Breakdown.md").unwrap().context["mvp_breakdown"], WHOLE_DOCUMENT);
+    }
+
+    #[test]
+    fn test_project_schema_extends_builtin() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("schema.toml"),
+            r#"[[documents]]
+filename = "03-Tech-Stack.md"
+display_name = "Tech Stack"
+required_headers = ["Stack:"]
+"#,
+        )
+        .unwrap();
+
+        let config = config_with(
+            PlanningConfig {
+                schema: Some("schema.toml".to_string()),
+                include_default: true,
+                documents: vec![risks()],
+                ..PlanningConfig::default()
+            },
+            temp_dir.path(),
+        );
+        let schema = PlanningSchema::for_project(&config).unwrap();
+
+        assert_eq!(schema.documents.len(), 6);
+        assert_eq!(schema.documents[2].required_headers, ["Stack:"]);
+        assert_eq!(schema.documents[2].min_word_count, 15);
+        assert_eq!(schema.documents[5].filename, "06-Risks.md");
+
+        let config = config_with(
+            PlanningConfig {
+                schema: None,
+                include_default: false,
+                documents: vec![risks()],
+                ..PlanningConfig::default()
+            },
+            temp_dir.path(),
+        );
+        assert_eq!(PlanningSchema::for_project(&config).unwrap().documents.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_schema_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut bad_field = risks();
+        bad_field.context.insert("risk_register".to_string(), "Top risks:".to_string());
+        let mut bad_name = risks();
+        bad_name.filename = "docs/06-Risks.md".to_string();
+
+        for (document, expected) in [(bad_field, "Unknown context field"), (bad_name, "without a directory")] {
+            let config = config_with(
+                PlanningConfig {
+                    schema: None,
+                    include_default: true,
+                    documents: vec![document],
+                    ..PlanningConfig::default()
+                },
+                temp_dir.path(),
+            );
+            let error = format!("{:#}", PlanningSchema::for_project(&config).unwrap_err());
+            assert!(error.contains(expected), "{error}");
+        }
+    }
+
+    #[test]
+    fn test_scaffold_uses_template_then_skeleton() {
+        let temp_dir = TempDir::new().unwrap();
+        let template_dir = temp_dir.path().join("templates");
+        std::fs::create_dir_all(&template_dir).unwrap();
+        std::fs::write(template_dir.join("01-Problem-and-Vision.md"), "# From template\n").unwrap();
+
+        let mut schema = PlanningSchema::builtin().unwrap();
+        schema.documents.truncate(1);
+        schema.documents.push(risks());
+        let planning_dir = temp_dir.path().join("01-PLANNING");
+
+        let created = scaffold_documents(&schema, &planning_dir, temp_dir.path(), &template_dir).unwrap();
+        assert_eq!(created, ["01-Problem-and-Vision.md", "06-Risks.md"]);
+
+        let risks = std::fs::read_to_string(planning_dir.join("06-Risks.md")).unwrap();
+        assert!(risks.starts_with("# Risks\n"));
+        assert!(risks.contains("## Top risks:"));
+        assert_eq!(
+            std::fs::read_to_string(planning_dir.join("01-Problem-and-Vision.md")).unwrap(),
+            "# From template\n"
+        );
+
+        // Existing documents are left alone
+        assert!(scaffold_documents(&schema, &planning_dir, temp_dir.path(), &template_dir).unwrap().is_empty());
+    }
 }
@@ -0,0 +1,367 @@
+//! Dev Session Notes
+//!
+//! Renders a note per dev session into the active sprint's `sessions/` folder
+//! from the session template (`dev-sessions/_session-template.md`), and keeps
+//! it up to date as the session goes on: timestamped Comms lines while
+//! working, then the Debrief with duration, commits and checklist. Session
+//! state lives in the note's inline fields (`Status::`, `Started::`,
+//! `Start-Commit::`), so a note stays open until its status says otherwise.
+
+use crate::git_ops::CommitSummary;
+use anyhow::{Context, Result};
+use chrono::{NaiveDateTime, NaiveTime};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tera::Tera;
+
+/// Session template shipped with the project templates
+pub const SESSION_TEMPLATE: &str = include_str!("../templates/project/dev-sessions/_session-template.md");
+
+/// `Status::` of a session in progress
+pub const STATUS_OPEN: &str = "🔴 In-Field";
+
+/// `Status::` of an ended session
+pub const STATUS_ENDED: &str = "🟢 Extracted";
+
+/// Format of `Started::` and `Ended::`
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// Values a new session note is rendered with
+#[derive(Debug, Clone)]
+pub struct SessionStart {
+    pub started: NaiveDateTime,
+    pub project: String,
+    /// Wikilink target of the project dashboard (e.g., "00-MANAGEMENT/00-START-HERE")
+    pub project_link: String,
+    /// Wikilink target of the sprint's task list
+    pub sprint_link: String,
+    /// "Sprint 4: The Sprint Orchestrator"
+    pub sprint_title: String,
+    /// HEAD when the session started; `None` in a repository without commits
+    pub start_commit: Option<String>,
+    /// Objective for the Mission Briefing
+    pub goal: Option<String>,
+}
+
+/// What `nexus session end` records
+#[derive(Debug, Clone)]
+pub struct Debrief {
+    pub ended: NaiveDateTime,
+    /// Commits made during the session, newest first
+    pub commits: Vec<CommitSummary>,
+    /// Whether the build and tests pass, if known
+    pub passed: Option<bool>,
+    pub next_task: Option<String>,
+}
+
+/// Render a session note from a template
+///
+/// Templates copied into a vault before the `Started::` and `Start-Commit::`
+/// fields existed get them added below `Status::`.
+///
+/// # Returns
+/// * `Ok(String)` - The note
+/// * `Err` - If the template is not valid Tera
+pub fn render_session(template: &str, start: &SessionStart) -> Result<String> {
+    let mut context = tera::Context::new();
+    context.insert("date", &start.started.format("%Y-%m-%d").to_string());
+    context.insert("time", &start.started.format("%H:%M").to_string());
+    context.insert("project", &start.project);
+    context.insert("project_link", &start.project_link);
+    context.insert("sprint_link", &start.sprint_link);
+    context.insert("sprint_title", &start.sprint_title);
+    context.insert("start_commit", start.start_commit.as_deref().unwrap_or_default());
+    if let Some(goal) = &start.goal {
+        context.insert("goal", goal);
+    }
+    let mut content = Tera::one_off(template, &context, false).context("Failed to render session template")?;
+
+    if field(&content, "Project").is_some_and(|project| project.trim() == "[[ ]]") {
+        set_field(
+            &mut content,
+            "Project",
+            &format!("[[{}|{}]]", start.project_link, start.project),
+        );
+    }
+    let started = start.started.format(TIMESTAMP_FORMAT).to_string();
+    for (name, value) in [
+        ("Start-Commit", start.start_commit.as_deref().unwrap_or_default()),
+        ("Started", started.as_str()),
+    ] {
+        if !set_field(&mut content, name, value) {
+            insert_after_field(&mut content, "Status", &format!("{name}:: {value}"));
+        }
+    }
+    Ok(content)
+}
+
+/// Value of an inline field (`Name:: value`, also as a list item); `None` when absent
+pub fn field<'a>(content: &'a str, name: &str) -> Option<&'a str> {
+    content.lines().find_map(|line| field_value(line, name))
+}
+
+/// Whether a session note is still in progress
+pub fn is_open(content: &str) -> bool {
+    field(content, "Status").is_some_and(|status| status.trim() == STATUS_OPEN)
+}
+
+/// The newest session note in `sessions_dir` that is still in progress
+pub fn find_open_session(sessions_dir: &Path) -> Option<PathBuf> {
+    let mut notes: Vec<PathBuf> = fs::read_dir(sessions_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(std::result::Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    notes.sort();
+    notes
+        .into_iter()
+        .rev()
+        .find(|path| fs::read_to_string(path).is_ok_and(|content| is_open(&content)))
+}
+
+/// Path of a new session note: `YYYY-MM-DD-HHMM.md`, numbered when taken
+pub fn new_session_path(sessions_dir: &Path, started: NaiveDateTime) -> PathBuf {
+    let stem = started.format("%Y-%m-%d-%H%M").to_string();
+    let mut path = sessions_dir.join(format!("{stem}.md"));
+    let mut number = 2;
+    while path.exists() {
+        path = sessions_dir.join(format!("{stem}-{number}.md"));
+        number += 1;
+    }
+    path
+}
+
+/// Append a timestamped line to the Comms section
+///
+/// The template's empty `- ` placeholder is replaced by the first line.
+pub fn add_comms(content: &mut String, time: NaiveTime, text: &str) {
+    let line = format!("- {} {}", time.format("%H:%M"), text.trim());
+    append_to_section(content, "Comms", "## 📝 Comms (During)", &[line]);
+}
+
+/// Fill in the Debrief and mark the session ended
+///
+/// # Returns
+/// * `Some(minutes)` - Duration of the session, from `Started::`
+/// * `None` - The note has no (valid) `Started::`
+pub fn close_session(content: &mut String, debrief: &Debrief) -> Option<i64> {
+    let minutes = field(content, "Started")
+        .and_then(|started| NaiveDateTime::parse_from_str(started.trim(), TIMESTAMP_FORMAT).ok())
+        .map(|started| (debrief.ended - started).num_minutes().max(0));
+
+    set_field(content, "Status", STATUS_ENDED);
+    let ended = debrief.ended.format(TIMESTAMP_FORMAT).to_string();
+    if !set_field(content, "Ended", &ended) {
+        insert_after_field(content, "Started", &format!("Ended:: {ended}"));
+    }
+    if let Some(passed) = debrief.passed {
+        let checklist = if passed { "- [x] Build/Test Pass: ✅" } else { "- [ ] Build/Test Pass: ❌" };
+        replace_line(content, |line| line.contains("Build/Test Pass"), checklist);
+    }
+    if let Some(next_task) = &debrief.next_task {
+        set_field(content, "Next-Task", next_task);
+    }
+    if let Some(minutes) = minutes {
+        set_field(content, "Actual-time", &format!("{minutes} min"));
+    }
+
+    let mut lines = vec![format!("- Commits:: {}", debrief.commits.len())];
+    lines.extend(
+        debrief
+            .commits
+            .iter()
+            .map(|commit| format!("  - `{}` {}", commit.short_sha, commit.summary)),
+    );
+    append_to_section(content, "Debrief", "## ✅ Debrief (Post-Session)", &lines);
+    minutes
+}
+
+/// The value of `line` if it is the inline field `name`
+fn field_value<'a>(line: &'a str, name: &str) -> Option<&'a str> {
+    let item = line.trim_start().trim_start_matches("- ").trim_start();
+    let value = item.strip_prefix(name)?.strip_prefix("::")?;
+    Some(value.strip_prefix(' ').unwrap_or(value))
+}
+
+/// Set the value of an inline field; `false` when the note does not have it
+fn set_field(content: &mut String, name: &str, value: &str) -> bool {
+    replace_lines(content, |line| {
+        field_value(line, name).map(|old| format!("{}{value}", &line[..line.len() - old.len()]))
+    })
+}
+
+/// Insert a line below the inline field `name`, or below the title without it
+fn insert_after_field(content: &mut String, name: &str, new_line: &str) {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let at = lines
+        .iter()
+        .position(|line| field_value(line, name).is_some())
+        .or_else(|| lines.iter().position(|line| line.starts_with("# ")))
+        .map_or(0, |index| index + 1);
+    lines.insert(at, new_line.to_string());
+    *content = join_lines(&lines, content.ends_with('\n'));
+}
+
+/// Replace the first line matching `matches`
+fn replace_line(content: &mut String, matches: impl Fn(&str) -> bool, new_line: &str) -> bool {
+    replace_lines(content, |line| matches(line).then(|| new_line.to_string()))
+}
+
+/// Replace the first line `replace` returns a new line for
+fn replace_lines(content: &mut String, replace: impl Fn(&str) -> Option<String>) -> bool {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let Some((index, new_line)) = lines
+        .iter()
+        .enumerate()
+        .find_map(|(index, line)| replace(line).map(|new_line| (index, new_line)))
+    else {
+        return false;
+    };
+    lines[index] = new_line;
+    *content = join_lines(&lines, content.ends_with('\n'));
+    true
+}
+
+/// Append lines to the end of the `## ` section whose heading contains `keyword`
+///
+/// A section ends at the next heading or `---` rule. Empty `- ` placeholder
+/// items in it are removed. Without the section, it is added at the end as `heading`.
+fn append_to_section(content: &mut String, keyword: &str, heading: &str, new_lines: &[String]) {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let Some(start) = lines
+        .iter()
+        .position(|line| line.starts_with("## ") && line.contains(keyword))
+    else {
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push_str(&format!("\n{heading}\n{}\n", new_lines.join("\n")));
+        return;
+    };
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| line.starts_with('#') || line.trim() == "---")
+        .map_or(lines.len(), |offset| start + 1 + offset);
+    let followed = end < lines.len();
+
+    let mut section: Vec<String> = lines
+        .drain(start + 1..end)
+        .filter(|line| line.trim() != "-")
+        .collect();
+    let trailing_blank = section.iter().rev().take_while(|line| line.trim().is_empty()).count();
+    let blank = section.split_off(section.len() - trailing_blank);
+    section.extend(new_lines.iter().cloned());
+    section.extend(blank);
+    if followed && section.last().is_some_and(|line| !line.trim().is_empty()) {
+        section.push(String::new());
+    }
+    lines.splice(start + 1..start + 1, section);
+    *content = join_lines(&lines, true);
+}
+
+fn join_lines(lines: &[String], trailing_newline: bool) -> String {
+    let mut joined = lines.join("\n");
+    if trailing_newline {
+        joined.push('\n');
+    }
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn at(timestamp: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).unwrap()
+    }
+
+    fn session_start() -> SessionStart {
+        SessionStart {
+            started: at("2026-10-17 09:30"),
+            project: "Atlas".to_string(),
+            project_link: "00-MANAGEMENT/00-START-HERE".to_string(),
+            sprint_link: "00-MANAGEMENT/sprints/sprint-4-sync/Tasks".to_string(),
+            sprint_title: "Sprint 4: Sync".to_string(),
+            start_commit: Some("abc1234def".to_string()),
+            goal: Some("Retry failed uploads".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_render_session() {
+        let content = render_session(SESSION_TEMPLATE, &session_start()).unwrap();
+        assert!(content.starts_with("# Session: 2026-10-17 09:30\n\n"));
+        assert!(content.contains("Project:: [[00-MANAGEMENT/00-START-HERE|Atlas]]\n"));
+        assert!(content.contains("Sprint:: [[00-MANAGEMENT/sprints/sprint-4-sync/Tasks|Sprint 4: Sync]]\n"));
+        assert!(content.contains("Status:: 🔴 In-Field\nStarted:: 2026-10-17 09:30\nStart-Commit:: abc1234def\n"));
+        assert!(content.contains("- One-Thing:: Retry failed uploads\n"));
+        assert!(is_open(&content));
+
+        // A template from before the session commands
+        let old = "# Session: {{date}} {{time}}\n\nProject:: [[ ]]\nStatus:: 🔴 In-Field\n";
+        let content = render_session(old, &session_start()).unwrap();
+        assert_eq!(
+            content,
+            "# Session: 2026-10-17 09:30\n\nProject:: [[00-MANAGEMENT/00-START-HERE|Atlas]]\n\
+             Status:: 🔴 In-Field\nStarted:: 2026-10-17 09:30\nStart-Commit:: abc1234def\n"
+        );
+    }
+
+    #[test]
+    fn test_add_comms_replaces_placeholder() {
+        let mut content = render_session(SESSION_TEMPLATE, &session_start()).unwrap();
+        add_comms(&mut content, NaiveTime::from_hms_opt(9, 45, 0).unwrap(), "Found the bug");
+        add_comms(&mut content, NaiveTime::from_hms_opt(10, 5, 0).unwrap(), "  Fixed it ");
+        assert!(
+            content.contains("## 📝 Comms (During)\n- 09:45 Found the bug\n- 10:05 Fixed it\n\n---\n"),
+            "{content}"
+        );
+
+        let mut content = "# Session\n".to_string();
+        add_comms(&mut content, NaiveTime::from_hms_opt(9, 45, 0).unwrap(), "Hello");
+        assert_eq!(content, "# Session\n\n## 📝 Comms (During)\n- 09:45 Hello\n");
+    }
+
+    #[test]
+    fn test_close_session() {
+        let mut content = render_session(SESSION_TEMPLATE, &session_start()).unwrap();
+        let debrief = Debrief {
+            ended: at("2026-10-17 11:05"),
+            commits: vec![CommitSummary {
+                short_sha: "def5678".to_string(),
+                summary: "Retry uploads".to_string(),
+            }],
+            passed: Some(true),
+            next_task: Some("s4-3".to_string()),
+        };
+        assert_eq!(close_session(&mut content, &debrief), Some(95));
+
+        assert!(!is_open(&content));
+        assert!(content.contains("Status:: 🟢 Extracted\nStarted:: 2026-10-17 09:30\nEnded:: 2026-10-17 11:05\n"));
+        assert!(
+            content.ends_with(
+                "## ✅ Debrief (Post-Session)\n- [x] Build/Test Pass: ✅\n- Next-Task:: s4-3\n\
+                 - Actual-time:: 95 min\n- Commits:: 1\n  - `def5678` Retry uploads\n"
+            ),
+            "{content}"
+        );
+    }
+
+    #[test]
+    fn test_find_open_session() {
+        let temp_dir = TempDir::new().unwrap();
+        let sessions_dir = temp_dir.path();
+        assert_eq!(find_open_session(sessions_dir), None);
+
+        let first = new_session_path(sessions_dir, at("2026-10-17 09:30"));
+        fs::write(&first, "Status:: 🔴 In-Field\n").unwrap();
+        let second = new_session_path(sessions_dir, at("2026-10-17 09:30"));
+        assert_eq!(second, sessions_dir.join("2026-10-17-0930-2.md"));
+        fs::write(&second, "Status:: 🟢 Extracted\n").unwrap();
+
+        assert_eq!(find_open_session(sessions_dir), Some(first));
+    }
+}
@@ -130,6 +130,19 @@ impl NexusState {
             .map(|id| self.obsidian_vault_root.join(id))
     }
 
+    /// Worktree of the active project's current sprint, with the sprint identifier
+    ///
+    /// Set when the project runs with `[git] worktrees = true` and a sprint is
+    /// active; read from the project's nexus.toml.
+    pub fn get_active_sprint_worktree(&self) -> Option<(String, PathBuf)> {
+        let repo_path = self.get_active_repo_path()?;
+        let content = fs::read_to_string(repo_path.join("nexus.toml")).ok()?;
+        let config = crate::config::NexusConfig::from_toml(&content).ok()?;
+        let active = config.state?.active_sprint?;
+        let worktree = active.worktree?;
+        Some((active.current, repo_path.join(worktree)))
+    }
+
     /// Get the path to the active project's session file
     #[allow(dead_code)]
     pub fn get_project_session_path(&self) -> Option<PathBuf> {
@@ -241,4 +254,31 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_get_active_sprint_worktree() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut state = NexusState::new();
+        state.repos_root = temp_dir.path().to_path_buf();
+        state.set_active_project("app".to_string());
+        assert_eq!(state.get_active_sprint_worktree(), None);
+
+        let repo_path = temp_dir.path().join("app");
+        fs::create_dir_all(&repo_path).unwrap();
+        let mut config =
+            crate::config::NexusConfig::new("app".to_string(), repo_path.display().to_string());
+        let state_config = config.state.as_mut().unwrap();
+        state_config.start_sprint(2, chrono::NaiveDate::from_ymd_opt(2026, 10, 1).unwrap());
+        state_config.active_sprint.as_mut().unwrap().worktree =
+            Some(PathBuf::from(".nexus/worktrees/sprint-2-sync"));
+        fs::write(repo_path.join("nexus.toml"), config.to_toml().unwrap()).unwrap();
+
+        assert_eq!(
+            state.get_active_sprint_worktree(),
+            Some((
+                "sprint-2".to_string(),
+                repo_path.join(".nexus/worktrees/sprint-2-sync")
+            ))
+        );
+    }
 }
@@ -1,14 +1,160 @@
-//! Templating Module for CLAUDE.md Generation
+//! Templating Module for CLAUDE.md and Sprint Workspace Generation
 //!
-//! Uses Tera to render CLAUDE.md from extracted planning context.
+//! Uses Tera to render CLAUDE.md from extracted planning context, and the
+//! files of each sprint workspace from the sprint's data.
 
 #![allow(clippy::similar_names)] // context/content are domain-appropriate names
 
-use crate::planning::PlanningContext;
-use anyhow::{Context, Result};
-use std::path::Path;
+use crate::config::NexusConfig;
+use crate::layout::{MVP_BREAKDOWN_FILE, VaultLayout};
+use crate::links::VaultIndex;
+use crate::mvp::{SprintData, render_tasks};
+use crate::planning::{PlanningContext, parse_planning_documents};
+use crate::schema::planning::PlanningSchema;
+use anyhow::{Context, Result, bail};
+use std::path::{Component, Path};
 use tera::Tera;
 
+/// Embedded sprint workspace templates, by the file they render
+const SPRINT_TEMPLATES: &[(&str, &str)] = &[
+    ("Tasks.md", include_str!("../templates/sprint/Tasks.md.tera")),
+    (
+        "Sprint-Context.md",
+        include_str!("../templates/sprint/Sprint-Context.md.tera"),
+    ),
+];
+
+/// Templates of the files in a sprint workspace, with the project-wide values
+/// they are rendered with
+///
+/// Each file `<file>` renders from `<file>.tera`, looked up in
+/// `[templates] sprint_templates`, then the vault's
+/// `{management_dir}/templates/sprint/`, then the embedded defaults. Templates
+/// see:
+/// - `sprint` - the sprint from the MVP breakdown (number, name, title, focus,
+///   depends_on, tasks, exit_criteria)
+/// - `task_list` - the sprint's tasks as a checklist with ids and metadata
+/// - `estimate` - total estimate of the tasks, when they have one
+/// - `sprint_folder`, `planning_dir`, `management_dir`, `sprint_dir`,
+///   `mvp_breakdown_file` - vault paths
+/// - `project`, `config` - nexus.toml
+/// - `planning` - planning documents as for CLAUDE.md, when they can be read
+/// - `date` - today (YYYY-MM-DD)
+pub struct SprintTemplates {
+    tera: Tera,
+    context: tera::Context,
+    /// Files rendered into each sprint workspace
+    pub files: Vec<String>,
+    /// Empty folders created in each sprint workspace
+    pub folders: Vec<String>,
+}
+
+impl SprintTemplates {
+    /// Load the templates for the files configured in `[templates]`
+    ///
+    /// # Returns
+    /// * `Ok(SprintTemplates)` - Every configured file has a template that parses
+    /// * `Err` - A file has no template, or a template cannot be read or parsed
+    pub fn load(config: &NexusConfig) -> Result<Self> {
+        let templates = config.templates.clone().unwrap_or_default();
+        let layout = config.layout();
+        let mut dirs = Vec::new();
+        if let Some(dir) = &templates.sprint_templates {
+            dirs.push(config.get_repo_path().join(dir));
+        }
+        dirs.push(layout.sprint_templates_dir());
+
+        let files = templates
+            .sprint_files()
+            .iter()
+            .map(|file| workspace_entry(file))
+            .collect::<Result<Vec<_>>>()?;
+        let folders = templates
+            .sprint_folders()
+            .iter()
+            .map(|folder| workspace_entry(folder))
+            .collect::<Result<Vec<_>>>()?;
+        let mut tera = Tera::default();
+        for file in &files {
+            let name = format!("{file}.tera");
+            let content = match dirs.iter().map(|dir| dir.join(&name)).find(|path| path.exists()) {
+                Some(path) => std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read template from: {}", path.display()))?,
+                None => match SPRINT_TEMPLATES.iter().find(|(default, _)| default == file) {
+                    Some((_, content)) => (*content).to_string(),
+                    None => bail!(
+                        "No template for sprint file '{file}': add {name} to {}",
+                        dirs.iter()
+                            .map(|dir| dir.display().to_string())
+                            .collect::<Vec<_>>()
+                            .join(" or ")
+                    ),
+                },
+            };
+            tera.add_raw_template(file, &content)
+                .with_context(|| format!("Failed to parse sprint template {name}"))?;
+        }
+
+        let mut context = tera::Context::new();
+        context.insert("project", &config.project);
+        context.insert("config", config);
+        context.insert("date", &chrono::Local::now().format("%Y-%m-%d").to_string());
+        if let Some(planning) = planning_context(config, &layout) {
+            context.insert("planning", &planning);
+        }
+
+        Ok(Self {
+            tera,
+            context,
+            files,
+            folders,
+        })
+    }
+
+    /// Render one of the workspace files for a sprint
+    pub fn render(&self, file: &str, sprint_data: &SprintData, layout: &VaultLayout) -> Result<String> {
+        let relative = |path: &Path| layout.relative(path).to_string_lossy().replace('\\', "/");
+        let mut context = self.context.clone();
+        context.insert("sprint", sprint_data);
+        context.insert("task_list", &render_tasks(&sprint_data.tasks));
+        context.insert(
+            "estimate",
+            &sprint_data.total_estimate().map(|estimate| estimate.to_string()),
+        );
+        context.insert("sprint_folder", &relative(&layout.sprint_folder(sprint_data)));
+        context.insert("planning_dir", &relative(&layout.planning_dir));
+        context.insert("management_dir", &relative(&layout.management_dir));
+        context.insert("sprint_dir", &relative(&layout.sprint_dir));
+        context.insert("mvp_breakdown_file", MVP_BREAKDOWN_FILE);
+
+        self.tera
+            .render(file, &context)
+            .with_context(|| format!("Failed to render sprint template {file}.tera"))
+    }
+}
+
+/// A `sprint_files` or `sprint_folders` entry, checked to stay inside the sprint folder
+fn workspace_entry(entry: &str) -> Result<String> {
+    let entry = entry.replace('\\', "/").trim_matches('/').to_string();
+    let inside_folder = Path::new(&entry)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    if entry.is_empty() || !inside_folder {
+        bail!("Invalid sprint workspace entry '{entry}' in [templates]: use a path relative to the sprint folder");
+    }
+    Ok(entry)
+}
+
+/// Planning documents for sprint templates; `None` when they cannot be read
+fn planning_context(config: &NexusConfig, layout: &VaultLayout) -> Option<PlanningContext> {
+    if !layout.planning_dir.exists() {
+        return None;
+    }
+    let schema = PlanningSchema::for_project(config).ok()?;
+    let vault = VaultIndex::build(&layout.root).ok();
+    parse_planning_documents(&layout.planning_dir, &schema, vault.as_ref()).ok()
+}
+
 /// Generate CLAUDE.md from planning context using Tera template
 ///
 /// # Arguments
@@ -56,7 +202,9 @@ pub fn generate_claude_md(
     tera_context.insert("user_flow", &context.user_flow);
     tera_context.insert("technical_decisions", &context.technical_decisions);
     tera_context.insert("mvp_breakdown", &context.mvp_breakdown);
+    tera_context.insert("sprints", &context.sprints);
     tera_context.insert("generation_date", &context.generation_date);
+    tera_context.insert("frontmatter", &context.frontmatter);
 
     // Render template
     let rendered = tera
@@ -74,6 +222,7 @@ pub fn generate_claude_md(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::TemplatesConfig;
     use tempfile::TempDir;
 
     #[test]
@@ -103,6 +252,99 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mvp_breakdown_renders_from_sprints() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_root = temp_dir.path();
+
+        let mut context = PlanningContext::new("TestProject".to_string());
+        context.mvp_breakdown = "Raw breakdown text".to_string();
+        context.sprints = crate::mvp::parse_sprints(
+            "## Sprint 1: Core (days 2-4)
+_Focus: The data layer._
+**Depends on:** sprint-0
+
+- [x] Schema ~2h
+- [ ] Queries ⛔ s1-1
+  - [ ] Pagination ~30m
+**Exit criteria:** Queries return results
+",
+        );
+
+        generate_claude_md(&context, project_root, None).unwrap();
+        let content = std::fs::read_to_string(project_root.join("CLAUDE.md")).unwrap();
+        let expected = "## 📋 MVP Breakdown
+
+### Sprint 1: Core (days 2-4)
+
+_Focus: The data layer._
+
+**Depends on:** sprint-0
+
+- [x] `s1-1` Schema (~2h)
+- [ ] `s1-2` Queries — after s1-1
+  - [ ] `s1-2-1` Pagination (~30m)
+
+**Exit criteria:**
+- Queries return results
+
+## 🚫 Rules of Engagement";
+        assert!(content.contains(expected), "{content}");
+        assert!(!content.contains("Raw breakdown text"));
+
+        // Without parsed sprints the breakdown is included as written
+        context.sprints.clear();
+        generate_claude_md(&context, project_root, None).unwrap();
+        let content = std::fs::read_to_string(project_root.join("CLAUDE.md")).unwrap();
+        assert!(content.contains("## 📋 MVP Breakdown\n\nRaw breakdown text\n\n## 🚫"));
+    }
+
+    #[test]
+    fn test_sprint_template_sees_config_and_planning() {
+        let temp_dir = TempDir::new().unwrap();
+        let vault = temp_dir.path();
+        let mut config = NexusConfig::new("Atlas".to_string(), vault.display().to_string());
+        config.templates = Some(TemplatesConfig {
+            sprint_templates: Some("sprint-templates".to_string()),
+            sprint_files: Some(vec!["Brief.md".to_string()]),
+            ..TemplatesConfig::default()
+        });
+        let layout = config.layout();
+        std::fs::create_dir_all(&layout.planning_dir).unwrap();
+        std::fs::write(
+            layout.planning_dir.join("01-Problem-and-Vision.md"),
+            "# Problem\n\n## Solution in ONE SENTENCE:\nMaps for everyone.\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(vault.join("sprint-templates")).unwrap();
+        std::fs::write(
+            vault.join("sprint-templates/Brief.md.tera"),
+            "{{ project.name }}: {{ planning.problem_statement }} \
+             ({{ sprint_folder }}, {{ config.structure.planning_dir }}){% if estimate %} ~{{ estimate }}{% endif %}",
+        )
+        .unwrap();
+
+        let templates = SprintTemplates::load(&config).unwrap();
+        let sprint = crate::mvp::parse_sprints("## Sprint 2: Sync\n- [ ] Engine ~4h\n").remove(0);
+        assert_eq!(
+            templates.render("Brief.md", &sprint, &layout).unwrap(),
+            "Atlas: Maps for everyone. (00-MANAGEMENT/sprints/sprint-2-sync, 01-PLANNING) ~4h"
+        );
+
+        // A file without a template anywhere is an error
+        config.templates.as_mut().unwrap().sprint_files = Some(vec!["Missing.md".to_string()]);
+        let error = SprintTemplates::load(&config).err().unwrap();
+        assert!(format!("{error:#}").contains("No template for sprint file 'Missing.md'"));
+
+        // Workspace entries cannot leave the sprint folder
+        config.templates.as_mut().unwrap().sprint_files = Some(vec!["../../x.md".to_string()]);
+        let error = SprintTemplates::load(&config).err().unwrap();
+        assert!(format!("{error:#}").contains("Invalid sprint workspace entry '../../x.md'"));
+        config.templates.as_mut().unwrap().sprint_files = None;
+        config.templates.as_mut().unwrap().sprint_folders = Some(vec!["sessions/../..".to_string()]);
+        assert!(SprintTemplates::load(&config).is_err());
+    }
+
     #[test]
     fn test_generate_with_custom_template() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,398 @@
+//! Transclusion - Resolving Obsidian Embeds
+//!
+//! Planning sections often embed other notes (`![[Data-Model-Details]]`,
+//! `![[04-Architecture#Data model]]`, `![[Decisions#^auth]]`). Before a document
+//! is validated or its sections are extracted, every markdown embed is replaced
+//! by the content Obsidian shows in its place, recursively. Headings of embedded
+//! content are dropped so its words count towards the embedding section.
+//!
+//! A source map records where each piece of the expanded text came from, so
+//! issues found in embedded content point at the embedded file.
+
+use crate::frontmatter::{body_events, body_start};
+use crate::links::{Anchor, VaultIndex, find_links, heading_matches};
+use crate::planning::{LineIndex, ValidationIssue};
+use pulldown_cmark::{Event, HeadingLevel, Options, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Nesting limit for embeds inside embeds (cycles are detected separately)
+const MAX_DEPTH: usize = 16;
+
+/// A validation issue and the embedded file its span points into
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourcedIssue {
+    pub issue: ValidationIssue,
+    /// File the issue was found in, when it is not the validated document
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<PathBuf>,
+}
+
+impl From<ValidationIssue> for SourcedIssue {
+    fn from(issue: ValidationIssue) -> Self {
+        Self {
+            issue,
+            source: None,
+        }
+    }
+}
+
+/// A file that contributed text to an expanded document
+#[derive(Debug)]
+struct Source {
+    path: PathBuf,
+    content: String,
+}
+
+/// A run of expanded text copied verbatim from one source
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    /// Offset in the expanded text
+    start: usize,
+    len: usize,
+    /// Index into `sources`
+    source: usize,
+    /// Offset in the source content
+    offset: usize,
+}
+
+/// A document with its embeds replaced by their content
+#[derive(Debug)]
+pub struct ExpandedDocument {
+    pub text: String,
+    /// The document itself first, then every embedded file
+    sources: Vec<Source>,
+    segments: Vec<Segment>,
+}
+
+impl ExpandedDocument {
+    /// Files whose content appears in the expanded text, the document itself first
+    pub fn sources(&self) -> impl Iterator<Item = &Path> {
+        self.sources.iter().map(|source| source.path.as_path())
+    }
+
+    /// Source index and source offset of an offset in the expanded text
+    fn locate(&self, offset: usize) -> Option<(usize, Segment)> {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.start + segment.len <= offset)
+            .min(self.segments.len().checked_sub(1)?);
+        let segment = self.segments[index];
+        Some((segment.offset + offset.saturating_sub(segment.start).min(segment.len), segment))
+    }
+
+    /// Rewrites an issue's span to its source file
+    ///
+    /// Returns the issue with the embedded file it came from (`None` for the
+    /// document itself).
+    pub fn attribute(&self, mut issue: ValidationIssue) -> SourcedIssue {
+        let Some(span) = issue.span_mut() else {
+            return issue.into();
+        };
+        let Some((start, segment)) = self.locate(span.start.offset) else {
+            return issue.into();
+        };
+
+        // Keep the end in the same source; otherwise stop where the start's run ends
+        let end = match self.locate(span.end.offset.saturating_sub(1).max(span.start.offset)) {
+            Some((end, other)) if other.source == segment.source && end >= start => {
+                end + usize::from(span.end.offset > span.start.offset)
+            }
+            _ => segment.offset + segment.len,
+        };
+
+        let source = &self.sources[segment.source];
+        *span = LineIndex::new(&source.content).span(start..end);
+        SourcedIssue {
+            issue,
+            source: (segment.source != 0).then(|| source.path.clone()),
+        }
+    }
+}
+
+/// Replaces every markdown embed in a document with the embedded content
+///
+/// Embeds that do not resolve, point at non-markdown files (images), or would
+/// recurse into themselves stay as written.
+pub fn expand_embeds(path: &Path, content: &str, vault: &VaultIndex) -> ExpandedDocument {
+    let mut expander = Expander {
+        vault,
+        document: ExpandedDocument {
+            text: String::with_capacity(content.len()),
+            sources: vec![Source {
+                path: path.to_path_buf(),
+                content: content.to_string(),
+            }],
+            segments: Vec::new(),
+        },
+        stack: vec![(
+            path.canonicalize().unwrap_or_else(|_| path.to_path_buf()),
+            String::new(),
+        )],
+    };
+    expander.expand(0, 0..content.len());
+    expander.document
+}
+
+struct Expander<'a> {
+    vault: &'a VaultIndex,
+    document: ExpandedDocument,
+    /// The document and the embeds currently being expanded, as (file, anchor) keys
+    stack: Vec<(PathBuf, String)>,
+}
+
+impl Expander<'_> {
+    fn expand(&mut self, source: usize, range: Range<usize>) {
+        let path = self.document.sources[source].path.clone();
+        let links = find_links(&self.document.sources[source].content[range.clone()]);
+        let mut cursor = range.start;
+
+        for link in links.into_iter().filter(|link| link.embed) {
+            if self.stack.len() > MAX_DEPTH {
+                break;
+            }
+            let Some(target) = self.vault.resolve(&link.note, &path) else {
+                continue;
+            };
+            if target.extension().and_then(|ext| ext.to_str()) != Some("md") {
+                continue;
+            }
+            let key = (
+                target.canonicalize().unwrap_or_else(|_| target.clone()),
+                link.target.split_once('#').map(|(_, anchor)| anchor.to_string()).unwrap_or_default(),
+            );
+            if self.stack.contains(&key) {
+                continue;
+            }
+
+            let Some(target_source) = self.load(&target) else {
+                continue;
+            };
+            let Some(ranges) = select(&self.document.sources[target_source].content, link.anchor.as_ref())
+            else {
+                continue;
+            };
+
+            let link_start = range.start + link.range.start;
+            self.copy(source, cursor..link_start);
+            self.stack.push(key);
+            for part in ranges {
+                self.expand(target_source, part);
+            }
+            self.stack.pop();
+            cursor = range.start + link.range.end;
+        }
+
+        self.copy(source, cursor..range.end);
+    }
+
+    /// Index of a loaded source, reading the file on first use
+    fn load(&mut self, path: &Path) -> Option<usize> {
+        if let Some(index) = self.document.sources.iter().position(|source| source.path == path) {
+            return Some(index);
+        }
+        let content = std::fs::read_to_string(path).ok()?;
+        self.document.sources.push(Source {
+            path: path.to_path_buf(),
+            content,
+        });
+        Some(self.document.sources.len() - 1)
+    }
+
+    fn copy(&mut self, source: usize, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        self.document.segments.push(Segment {
+            start: self.document.text.len(),
+            len: range.len(),
+            source,
+            offset: range.start,
+        });
+        self.document
+            .text
+            .push_str(&self.document.sources[source].content[range]);
+    }
+}
+
+/// Byte ranges an embed shows, with heading lines left out
+///
+/// `None` when the heading or block does not exist.
+fn select(content: &str, anchor: Option<&Anchor>) -> Option<Vec<Range<usize>>> {
+    let headings = heading_ranges(content);
+    let scope = match anchor {
+        // Like Obsidian, a whole-note embed leaves out the note's properties
+        None => body_start(content)..content.len(),
+        Some(Anchor::Heading(path)) => section(content, &headings, path)?,
+        Some(Anchor::Block(block)) => return block_range(content, block).map(|range| vec![range]),
+    };
+
+    // Everything in scope except heading lines
+    let mut ranges = Vec::new();
+    let mut cursor = scope.start;
+    for (_, heading, _) in headings.iter().filter(|(_, h, _)| scope.contains(&h.start)) {
+        if heading.start > cursor {
+            ranges.push(cursor..heading.start);
+        }
+        cursor = cursor.max(heading.end);
+    }
+    if cursor < scope.end {
+        ranges.push(cursor..scope.end);
+    }
+    Some(ranges)
+}
+
+/// Headings as (level, byte range of the heading line, heading text)
+fn heading_ranges(content: &str) -> Vec<(HeadingLevel, Range<usize>, String)> {
+    let mut headings = Vec::new();
+    let mut current: Option<(HeadingLevel, Range<usize>, String)> = None;
+    for (event, range) in body_events(content, Options::empty()) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => current = Some((level, range, String::new())),
+            Event::End(TagEnd::Heading(_)) => headings.extend(current.take()),
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, _, heading)) = current.as_mut() {
+                    heading.push_str(&text);
+                }
+            }
+            _ => {}
+        }
+    }
+    headings
+}
+
+/// Body of the section named by a (possibly nested) heading anchor
+fn section(
+    content: &str,
+    headings: &[(HeadingLevel, Range<usize>, String)],
+    path: &[String],
+) -> Option<Range<usize>> {
+    let mut from = 0;
+    let mut found = None;
+    for wanted in path {
+        let position = headings[from..]
+            .iter()
+            .position(|(_, _, text)| heading_matches(text.trim(), wanted))?
+            + from;
+        found = Some(position);
+        from = position + 1;
+    }
+
+    let (level, heading, _) = &headings[found?];
+    let end = headings[from..]
+        .iter()
+        .find(|(next_level, _, _)| next_level <= level)
+        .map_or(content.len(), |(_, next, _)| next.start);
+    Some(heading.end..end)
+}
+
+/// The paragraph or list item carrying `^block`, without the marker
+fn block_range(content: &str, block: &str) -> Option<Range<usize>> {
+    let marker = format!("^{block}");
+    let mut paragraph_start = 0;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let line_start = offset;
+        offset += line.len();
+        let trimmed = line.trim_end();
+        if trimmed.is_empty() {
+            paragraph_start = offset;
+            continue;
+        }
+        if trimmed == marker || trimmed.ends_with(&format!(" {marker}")) {
+            let is_list_item = {
+                let text = trimmed.trim_start();
+                text.starts_with("- ")
+                    || text.starts_with("* ")
+                    || text.starts_with("+ ")
+                    || text.split_once(". ").is_some_and(|(n, _)| n.parse::<u32>().is_ok())
+            };
+            let start = if is_list_item { line_start } else { paragraph_start };
+            let end = line_start + trimmed[..trimmed.len() - marker.len()].trim_end().len();
+            return Some(start..end.max(start));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::planning::SourceSpan;
+    use tempfile::TempDir;
+
+    fn position(span: &SourceSpan) -> (usize, usize) {
+        (span.start.line, span.start.column)
+    }
+
+    fn vault(files: &[(&str, &str)]) -> (TempDir, VaultIndex) {
+        let temp_dir = TempDir::new().unwrap();
+        for (name, content) in files {
+            let path = temp_dir.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let index = VaultIndex::build(temp_dir.path()).unwrap();
+        (temp_dir, index)
+    }
+
+    #[test]
+    fn test_expands_notes_headings_and_blocks() {
+        let (temp_dir, vault) = vault(&[
+            ("notes/Details.md", "# Details\n\nUsers own projects.\n\n## Storage\n\nSQLite on device.\n"),
+            ("notes/Decisions.md", "Intro.\n\nWe use JWT for auth. ^auth\n\n- Offline first ^offline\n"),
+        ]);
+        let doc = temp_dir.path().join("04-Architecture.md");
+        let content = "## Data model:\n\n![[Details]]\n\n## Storage:\n\n![[Details#Storage]] ![[Decisions#^auth]] ![[Decisions#^offline]]\n";
+
+        let expanded = expand_embeds(&doc, content, &vault);
+        assert_eq!(
+            expanded.text,
+            "## Data model:\n\n\nUsers own projects.\n\n\nSQLite on device.\n\n\n## Storage:\n\n\nSQLite on device.\n We use JWT for auth. - Offline first\n"
+        );
+        assert_eq!(expanded.sources().count(), 3);
+    }
+
+    #[test]
+    fn test_cycles_and_unresolved_embeds_stay_literal() {
+        let (temp_dir, vault) = vault(&[
+            ("A.md", "A text ![[B]]\n"),
+            ("B.md", "B text ![[A]] ![[Missing]] ![[B#Nope]]\n"),
+        ]);
+        let path = temp_dir.path().join("A.md");
+        let content = std::fs::read_to_string(&path).unwrap();
+
+        let expanded = expand_embeds(&path, &content, &vault);
+        assert_eq!(expanded.text, "A text B text ![[A]] ![[Missing]] ![[B#Nope]]\n\n");
+    }
+
+    #[test]
+    fn test_issues_are_attributed_to_embedded_file() {
+        let (temp_dir, vault) = vault(&[("Details.md", "# Details\n\nStill TODO here.\n")]);
+        let doc = temp_dir.path().join("01-Brief.md");
+        let content = "## Scope:\n\n![[Details]]\n\nOwn TODO.\n";
+        let expanded = expand_embeds(&doc, content, &vault);
+
+        let issue_at = |needle: &str, from: usize| {
+            let at = expanded.text[from..].find(needle).unwrap() + from;
+            let index = LineIndex::new(&expanded.text);
+            expanded.attribute(ValidationIssue::IllegalString {
+                string: "TODO".to_string(),
+                context: String::new(),
+                span: index.span(at..at + 4),
+                section: None,
+            })
+        };
+
+        let embedded = issue_at("TODO", 0);
+        assert_eq!(embedded.source.as_deref(), Some(temp_dir.path().join("Details.md").as_path()));
+        let span = embedded.issue.span().unwrap();
+        assert_eq!(position(span), (3, 7));
+        assert_eq!(span.len(), 4);
+
+        let own = issue_at("TODO", expanded.text.find("Own").unwrap());
+        assert_eq!(own.source, None);
+        assert_eq!(position(own.issue.span().unwrap()), (5, 5));
+    }
+}
@@ -0,0 +1,240 @@
+//! Gate Waivers - Accepted Gaps in Planning Documents
+//!
+//! Loads and saves `.nexus/gate-waivers.toml`. A waiver suppresses one kind of
+//! validation issue in one file (optionally one section) until its expiry date,
+//! after which the issue blocks the gate again.
+
+use crate::planning::ValidationIssue;
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Location of the waivers file relative to the project root
+pub const WAIVERS_FILE: &str = ".nexus/gate-waivers.toml";
+
+/// A justified, time-limited exception to a gate check
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Waiver {
+    /// Rule id of the waived issue (e.g. "illegal-string" or a custom rule id)
+    pub rule: String,
+    /// File name (e.g. "03-Tech-Stack.md") or path relative to the project root
+    pub file: String,
+    /// Section header the issue must belong to; any section when omitted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    /// Text the issue message must contain (e.g. "TBD"); any issue of the rule when omitted
+    #[serde(default, rename = "match", skip_serializing_if = "Option::is_none")]
+    pub contains: Option<String>,
+    /// Why the gap is accepted
+    pub reason: String,
+    /// Who accepted it
+    pub author: String,
+    /// Last day the waiver applies (inclusive)
+    pub expires: NaiveDate,
+}
+
+impl Waiver {
+    /// Checks the waiver for empty required fields
+    pub fn validate(&self) -> Result<()> {
+        for (field, value) in [
+            ("rule", &self.rule),
+            ("file", &self.file),
+            ("reason", &self.reason),
+            ("author", &self.author),
+        ] {
+            if value.trim().is_empty() {
+                bail!("Waiver for '{}' in '{}' has an empty '{field}'", self.rule, self.file);
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the waiver has expired as of `today`
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        self.expires < today
+    }
+
+    /// Whether the waiver covers an issue found in the given file
+    ///
+    /// # Arguments
+    /// * `file_names` - Names the document is known by (file name and project-relative path)
+    /// * `issue` - The validation issue to test
+    pub fn covers(&self, file_names: &[&str], issue: &ValidationIssue) -> bool {
+        if issue.rule_id() != self.rule || !file_names.contains(&self.file.as_str()) {
+            return false;
+        }
+        if let Some(section) = &self.section
+            && !issue
+                .section()
+                .is_some_and(|found| found.trim().eq_ignore_ascii_case(section.trim()))
+        {
+            return false;
+        }
+        self.contains
+            .as_ref()
+            .is_none_or(|text| issue.message().contains(text.as_str()))
+    }
+
+    /// Short description for terminal output
+    pub fn describe(&self) -> String {
+        let mut target = format!("{} in {}", self.rule, self.file);
+        if let Some(section) = &self.section {
+            target.push_str(&format!(" › {section}"));
+        }
+        if let Some(text) = &self.contains {
+            target.push_str(&format!(" ('{text}')"));
+        }
+        target
+    }
+}
+
+/// Contents of `.nexus/gate-waivers.toml`
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct WaiverFile {
+    #[serde(default, rename = "waiver")]
+    pub waivers: Vec<Waiver>,
+}
+
+/// Loads waivers from the given file
+///
+/// # Returns
+/// * `Ok(WaiverFile)` - Parsed waivers (empty if the file does not exist)
+/// * `Err` - File unreadable, invalid TOML, or a waiver with empty fields
+pub fn load_waivers(path: &Path) -> Result<WaiverFile> {
+    if !path.exists() {
+        return Ok(WaiverFile::default());
+    }
+
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read waivers file: {}", path.display()))?;
+    let file: WaiverFile = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse waivers file: {}", path.display()))?;
+
+    for waiver in &file.waivers {
+        waiver
+            .validate()
+            .with_context(|| format!("Invalid waiver in: {}", path.display()))?;
+    }
+
+    Ok(file)
+}
+
+/// Appends a waiver to the given file, creating it if needed
+pub fn add_waiver(path: &Path, waiver: Waiver) -> Result<()> {
+    waiver.validate()?;
+
+    let mut file = load_waivers(path)?;
+    file.waivers.push(waiver);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    let content = toml::to_string_pretty(&file).context("Failed to serialize waivers")?;
+    fs::write(path, content)
+        .with_context(|| format!("Failed to write waivers file: {}", path.display()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn tbd_issue(section: &str) -> ValidationIssue {
+        let position = crate::planning::SourcePosition {
+            offset: 0,
+            line: 1,
+            column: 1,
+        };
+        ValidationIssue::IllegalString {
+            string: "TBD".to_string(),
+            context: "Database: TBD".to_string(),
+            span: crate::planning::SourceSpan {
+                start: position,
+                end: position,
+            },
+            section: Some(section.to_string()),
+        }
+    }
+
+    fn waiver() -> Waiver {
+        Waiver {
+            rule: "illegal-string".to_string(),
+            file: "03-Tech-Stack.md".to_string(),
+            section: Some("Stack (force yourself to choose NOW):".to_string()),
+            contains: Some("TBD".to_string()),
+            reason: "Vendor decision pending".to_string(),
+            author: "dana".to_string(),
+            expires: NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_waiver_matching() {
+        let waiver = waiver();
+        let names = ["03-Tech-Stack.md", "01-PLANNING/03-Tech-Stack.md"];
+
+        assert!(waiver.covers(&names, &tbd_issue("Stack (force yourself to choose NOW):")));
+        assert!(!waiver.covers(&names, &tbd_issue("What I will NOT use:")));
+        assert!(!waiver.covers(&["04-Architecture.md"], &tbd_issue("Stack (force yourself to choose NOW):")));
+        assert!(!waiver.covers(
+            &names,
+            &ValidationIssue::MissingHeader {
+                header: "Stack (force yourself to choose NOW):".to_string()
+            }
+        ));
+    }
+
+    #[test]
+    fn test_waiver_expiry_is_inclusive() {
+        let waiver = waiver();
+        assert!(!waiver.is_expired(NaiveDate::from_ymd_opt(2026, 3, 31).unwrap()));
+        assert!(waiver.is_expired(NaiveDate::from_ymd_opt(2026, 4, 1).unwrap()));
+    }
+
+    #[test]
+    fn test_add_and_load_waivers() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join(WAIVERS_FILE);
+
+        assert!(load_waivers(&path).unwrap().waivers.is_empty());
+
+        add_waiver(&path, waiver()).unwrap();
+        add_waiver(
+            &path,
+            Waiver {
+                section: None,
+                contains: None,
+                ..waiver()
+            },
+        )
+        .unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("[[waiver]]"));
+        assert!(content.contains("match = \"TBD\""));
+
+        let loaded = load_waivers(&path).unwrap();
+        assert_eq!(loaded.waivers.len(), 2);
+        assert_eq!(loaded.waivers[0], waiver());
+    }
+
+    #[test]
+    fn test_invalid_waiver_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("gate-waivers.toml");
+        fs::write(
+            &path,
+            "[[waiver]]\nrule = \"illegal-string\"\nfile = \"a.md\"\nreason = \"\"\nauthor = \"x\"\nexpires = \"2026-01-01\"\n",
+        )
+        .unwrap();
+
+        let error = format!("{:#}", load_waivers(&path).unwrap_err());
+        assert!(error.contains("empty 'reason'"), "{error}");
+    }
+}
@@ -9,6 +9,7 @@
 //! - Special handling for Architecture.md changes (triggers full re-index)
 
 use crate::brain::{Layer, NexusBrain, NexusMetadata};
+use crate::layout::VaultLayout;
 use anyhow::{Context, Result};
 use crossbeam_channel::{bounded, Receiver, Sender};
 use notify::{Event, EventKind, RecursiveMode, Watcher};
@@ -36,6 +37,8 @@ pub enum WatcherMessage {
         project_id: String,
         repo_path: PathBuf,
         obsidian_path: PathBuf,
+        /// Folders of the project's vault, for layer classification
+        layout: VaultLayout,
     },
     /// Stop watching all paths
     StopWatching,
@@ -73,12 +76,14 @@ impl SentinelWatcher {
         project_id: String,
         repo_path: PathBuf,
         obsidian_path: PathBuf,
+        layout: VaultLayout,
     ) -> Result<()> {
         self.sender
             .send(WatcherMessage::WatchProject {
                 project_id,
                 repo_path,
                 obsidian_path,
+                layout,
             })
             .context("Failed to send watch message")
     }
@@ -110,7 +115,7 @@ impl SentinelWatcher {
 #[allow(clippy::needless_pass_by_value)] // Receiver must be owned for thread
 fn run_watcher_loop(rx: Receiver<WatcherMessage>, brain_url: String) -> Result<()> {
     let mut _current_watcher: Option<notify::RecommendedWatcher> = None;
-    let mut current_project: Option<String> = None;
+    let mut current_project: Option<(String, VaultLayout)> = None;
     let watched_paths: Arc<Mutex<HashSet<PathBuf>>> = Arc::new(Mutex::new(HashSet::new()));
 
     // Channel for file system events
@@ -124,6 +129,7 @@ fn run_watcher_loop(rx: Receiver<WatcherMessage>, brain_url: String) -> Result<(
                     project_id,
                     repo_path,
                     obsidian_path,
+                    layout,
                 } => {
                     println!("🔍 Sentinel: Starting watch for project '{project_id}'");
 
@@ -157,7 +163,7 @@ fn run_watcher_loop(rx: Receiver<WatcherMessage>, brain_url: String) -> Result<(
                     }
 
                     _current_watcher = Some(watcher);
-                    current_project = Some(project_id);
+                    current_project = Some((project_id, layout));
 
                     // Clear watched paths
                     watched_paths.lock().unwrap().clear();
@@ -177,8 +183,8 @@ fn run_watcher_loop(rx: Receiver<WatcherMessage>, brain_url: String) -> Result<(
 
         // Process file system events
         if let Ok(event) = event_rx.try_recv()
-            && let Some(ref project_id) = current_project
-            && let Err(e) = handle_file_event(&event, project_id, &brain_url, &watched_paths)
+            && let Some((ref project_id, ref layout)) = current_project
+            && let Err(e) = handle_file_event(&event, project_id, layout, &brain_url, &watched_paths)
         {
             eprintln!("Error handling file event: {e}");
         }
@@ -195,6 +201,7 @@ fn run_watcher_loop(rx: Receiver<WatcherMessage>, brain_url: String) -> Result<(
 fn handle_file_event(
     event: &Event,
     project_id: &str,
+    layout: &VaultLayout,
     brain_url: &str,
     watched_paths: &Arc<Mutex<HashSet<PathBuf>>>,
 ) -> Result<()> {
@@ -251,7 +258,7 @@ fn handle_file_event(
 
         // Index the file
         println!("📝 Indexing: {}", path.display());
-        if let Err(e) = index_file(path, project_id, brain_url) {
+        if let Err(e) = index_file(path, project_id, layout, brain_url) {
             eprintln!("Failed to index {}: {}", path.display(), e);
         }
     }
@@ -270,22 +277,20 @@ fn get_machine_id() -> String {
 /// Classify a file into a layer based on its path
 ///
 /// Classification rules:
-/// - Files in `01-PLANNING/` → `ProjectArchitecture`
-/// - Files in `00-MANAGEMENT/sprints/` → `SprintMemory`
+/// - Files in the planning directory (`01-PLANNING/`) → `ProjectArchitecture`
+/// - Files in the sprint directory (`00-MANAGEMENT/sprints/`) → `SprintMemory`
 /// - Files in `src/`, `tests/`, etc. → `SourceCode`
 /// - Files in a global standards directory → `GlobalStandard`
-fn classify_file_layer(file_path: &Path) -> Layer {
+fn classify_file_layer(file_path: &Path, layout: &VaultLayout) -> Layer {
     let path_str = file_path.to_string_lossy();
 
     // Check for Planning directory (Architecture)
-    if path_str.contains("/01-PLANNING/") || path_str.contains("\\01-PLANNING\\") {
+    if layout.is_planning(file_path) {
         return Layer::ProjectArchitecture;
     }
 
-    // Check for Management/Sprints directory (Sprint Memory)
-    if path_str.contains("/00-MANAGEMENT/sprints/")
-        || path_str.contains("\\00-MANAGEMENT\\sprints\\")
-    {
+    // Check for Sprints directory (Sprint Memory)
+    if layout.is_sprint(file_path) {
         return Layer::SprintMemory;
     }
 
@@ -310,7 +315,7 @@ fn classify_file_layer(file_path: &Path) -> Layer {
 }
 
 /// Index a file to the Brain with proper metadata classification
-fn index_file(file_path: &Path, project_id: &str, brain_url: &str) -> Result<()> {
+fn index_file(file_path: &Path, project_id: &str, layout: &VaultLayout, brain_url: &str) -> Result<()> {
     // Read file content
     let content = fs::read_to_string(file_path)
         .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
@@ -322,7 +327,7 @@ fn index_file(file_path: &Path, project_id: &str, brain_url: &str) -> Result<()>
         .map(std::string::ToString::to_string);
 
     // Classify file into layer
-    let layer = classify_file_layer(file_path);
+    let layer = classify_file_layer(file_path, layout);
 
     // Get machine ID
     let machine_id = get_machine_id();
@@ -453,4 +458,22 @@ mod tests {
         let id3 = generate_point_id(&path, 1);
         assert_ne!(id1, id3);
     }
+
+    #[test]
+    fn test_classify_file_layer_follows_structure() {
+        let structure = crate::config::StructureConfig {
+            planning_dir: "docs/plan".to_string(),
+            management_dir: "docs/mgmt".to_string(),
+            sprint_dir: "docs/mgmt/iterations".to_string(),
+        };
+        let layout = VaultLayout::new(Path::new("/vault"), &structure);
+
+        let layer = |path: &str| classify_file_layer(Path::new(path), &layout);
+        assert!(matches!(layer("/vault/docs/plan/01-Problem.md"), Layer::ProjectArchitecture));
+        assert!(matches!(
+            layer("/vault/docs/mgmt/iterations/sprint-1-core/Tasks.md"),
+            Layer::SprintMemory
+        ));
+        assert!(matches!(layer("/vault/01-PLANNING/01-Problem.md"), Layer::SourceCode));
+    }
 }
@@ -0,0 +1,172 @@
+//! Integration tests for the export command
+
+use assert_cmd::cargo::cargo_bin_cmd;
+use predicates::prelude::*;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Helper to create a project with an MVP breakdown and recorded sprint dates
+fn create_project(project_path: &Path) {
+    let project_path_str = project_path.to_str().unwrap();
+    let config_content = format!(
+        r#"[project]
+name = "Demo App"
+version = "0.1.0"
+obsidian_path = "{project_path_str}"
+
+[structure]
+planning_dir = "01-PLANNING"
+management_dir = "00-MANAGEMENT"
+sprint_dir = "00-MANAGEMENT/sprints"
+
+[gate]
+heuristics_file = "Gate-Heuristics.json"
+strict_mode = true
+
+[state]
+is_unlocked = true
+approved_sprints = [1]
+
+[[state.sprint_history]]
+number = 1
+started = "2026-09-01"
+approved = "2026-09-04"
+
+[state.active_sprint]
+current = "sprint-2"
+status = "in_progress"
+started = "2026-09-07"
+"#
+    );
+    fs::write(project_path.join("nexus.toml"), config_content).unwrap();
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    fs::write(
+        planning_dir.join("05-MVP-Breakdown.md"),
+        r"# MVP Breakdown
+
+## Sprint 1: Storage
+- [x] Schema ~2h 🆔 schema
+
+## Sprint 2: Sync
+- [ ] Sync engine ~4h ⛔ schema 📅 2026-09-10
+- [ ] Status UI ~2h #frontend
+",
+    )
+    .unwrap();
+
+    // The sprint workspace has progressed further than the breakdown
+    let sprint_dir = project_path.join("00-MANAGEMENT/sprints/sprint-2-sync");
+    fs::create_dir_all(&sprint_dir).unwrap();
+    fs::write(
+        sprint_dir.join("Tasks.md"),
+        "# Sprint 2 Tasks\n\n- [x] Sync engine 🆔 s2-1 ~4h\n- [ ] Status UI 🆔 s2-2 ~2h\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_export_roadmap_json_includes_task_completion() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+    create_project(project_path);
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("export")
+        .arg("roadmap")
+        .arg(project_path)
+        .arg("--format")
+        .arg("json");
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let sprints = report["sprints"].as_array().unwrap();
+
+    assert_eq!(sprints[0]["status"], "approved");
+    assert_eq!(sprints[0]["start"], "2026-09-01");
+    assert_eq!(sprints[0]["end"], "2026-09-04");
+    assert_eq!(sprints[0]["planned"], false);
+
+    assert_eq!(sprints[1]["status"], "in_progress");
+    assert_eq!(sprints[1]["start"], "2026-09-07");
+    assert_eq!(sprints[1]["completion_source"], "tasks_md");
+    assert_eq!(sprints[1]["tasks_done"], 1);
+    assert_eq!(sprints[1]["tasks_total"], 2);
+    assert_eq!(sprints[1]["tasks"][0]["done"], true);
+}
+
+#[test]
+fn test_export_roadmap_csv_and_ics() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+    create_project(project_path);
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("export")
+        .arg("roadmap")
+        .arg(project_path)
+        .arg("--format")
+        .arg("csv");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("sprint,sprint_title,sprint_status"))
+        .stdout(predicate::str::contains("2,Sync,in_progress,2026-09-07,"))
+        .stdout(predicate::str::contains(",s2-1,,Sync engine,true,4h,2026-09-10,,,schema\n"));
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("export")
+        .arg("roadmap")
+        .arg(project_path)
+        .arg("--format")
+        .arg("ics");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("UID:demo-app-sprint-1@nexus\r\n"))
+        .stdout(predicate::str::contains(
+            "DTSTART;VALUE=DATE:20260901\r\nDTEND;VALUE=DATE:20260905\r\n",
+        ))
+        .stdout(predicate::str::contains("SUMMARY:Sync engine (s2-1)\r\n"));
+}
+
+#[test]
+fn test_export_roadmap_mermaid_to_vault_note() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+    create_project(project_path);
+    let note = project_path.join("00-MANAGEMENT/Roadmap.md");
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("export")
+        .arg("roadmap")
+        .arg(project_path)
+        .arg("--output")
+        .arg(&note);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Roadmap exported"));
+
+    let mermaid = fs::read_to_string(&note).unwrap();
+    assert!(mermaid.starts_with("```mermaid\ngantt\n"));
+    assert!(mermaid.contains("Storage :crit, done, sprint1, 2026-09-01, 4d\n"));
+    assert!(mermaid.contains("Sync engine :milestone, done, 2026-09-10, 0d\n"));
+}
+
+#[test]
+fn test_export_roadmap_fails_on_invalid_dependencies() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+    create_project(project_path);
+    fs::write(
+        project_path.join("01-PLANNING/05-MVP-Breakdown.md"),
+        "## Sprint 1: Storage\n- [ ] Schema ⛔ auth\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("export").arg("roadmap").arg(project_path);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("[unknown-dependency]"));
+}
@@ -231,7 +231,7 @@ fn test_gate_shows_context_for_issues() {
     cmd.assert()
         .failure()
         .stdout(predicate::str::contains("TODO"))
-        .stdout(predicate::str::contains("line ~"));
+        .stdout(predicate::str::contains("01-Project-Brief.md:2:1"));
 }
 
 #[test]
@@ -250,6 +250,716 @@ fn test_gate_fails_with_missing_config() {
         .stderr(predicate::str::contains("Failed to read config"));
 }
 
+#[test]
+fn test_gate_json_report() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    // Setup project structure
+    create_nexus_config(project_path, project_path);
+    create_heuristics(project_path);
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_invalid_planning_doc(&planning_dir.join("01-Project-Brief.md"));
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    // Run gate command with JSON output
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path).arg("--format").arg("json");
+
+    let output = cmd.assert().failure().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(report["verdict"], "failed");
+    assert_eq!(report["exit_code"], 1);
+
+    let brief = report["documents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["name"] == "01-Project-Brief.md")
+        .expect("Planning document should be reported");
+    assert_eq!(brief["file"], "01-PLANNING/01-Project-Brief.md");
+    assert_eq!(brief["passed"], false);
+
+    let issues = brief["issues"].as_array().unwrap();
+    let illegal = issues
+        .iter()
+        .find(|i| i["kind"] == "illegal_string")
+        .expect("TODO should be reported");
+    assert_eq!(illegal["string"], "TODO");
+    assert_eq!(illegal["section"], "Problem");
+    assert!(illegal["line"].is_u64());
+    assert!(issues.iter().any(|i| i["kind"] == "missing_header"));
+}
+
+#[test]
+fn test_gate_sarif_report_passes() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    // Setup project structure
+    create_nexus_config(project_path, project_path);
+    create_heuristics(project_path);
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_valid_planning_doc(&planning_dir.join("01-Project-Brief.md"));
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    // Run gate command with SARIF output
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path).arg("--format").arg("sarif");
+
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let sarif: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(sarif["runs"][0]["tool"]["driver"]["name"], "nexus-gate");
+    assert!(sarif["runs"][0]["results"].as_array().unwrap().is_empty());
+    assert_eq!(sarif["runs"][0]["properties"]["verdict"], "passed");
+}
+
+/// Helper to write versioned (v2) heuristics with custom rules to the stable path
+fn create_rule_heuristics(project_path: &Path, rules: &str) {
+    let heuristics_content = format!(
+        r#"{{
+  "version": 2,
+  "min_section_length": 50,
+  "required_headers": ["Problem", "Vision"],
+  "illegal_strings": ["TODO"],
+  "management_files": {{
+    "dashboard": "00-START-HERE.md",
+    "require_all_checked": true
+  }},
+  "rules": {rules}
+}}
+"#
+    );
+    fs::create_dir_all(project_path.join(".nexus")).unwrap();
+    fs::write(
+        project_path.join(".nexus/gate-heuristics.json"),
+        heuristics_content,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_gate_custom_rule_violation() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_rule_heuristics(
+        project_path,
+        r#"[
+    { "id": "vision-measurable", "file": "01-*.md", "section": "Vision",
+      "pattern": "\\d+%", "message": "Vision needs a measurable target" },
+    { "id": "scope-only-elsewhere", "file": "02-*.md", "forbid": "clearly" }
+  ]"#,
+    );
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_valid_planning_doc(&planning_dir.join("01-Project-Brief.md"));
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path).arg("--format").arg("json");
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    let issues = report["documents"][1]["issues"].as_array().unwrap();
+    assert_eq!(issues.len(), 1, "only the rule scoped to this file applies");
+    assert_eq!(issues[0]["kind"], "rule_violation");
+    assert_eq!(issues[0]["rule"], "vision-measurable");
+    assert_eq!(issues[0]["message"], "Vision needs a measurable target");
+    assert_eq!(issues[0]["section"], "Vision");
+    assert_eq!(issues[0]["line"], 4);
+}
+
+#[test]
+fn test_gate_frontmatter_status_rule() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_rule_heuristics(
+        project_path,
+        r#"[{ "id": "final-before-unlock", "field_values": { "status": "final" } }]"#,
+    );
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    let brief_path = planning_dir.join("01-Project-Brief.md");
+    create_valid_planning_doc(&brief_path);
+    let body = fs::read_to_string(&brief_path).unwrap();
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    let gate_json = || {
+        let mut cmd = cargo_bin_cmd!("nexus");
+        cmd.arg("gate").arg(project_path).arg("--format").arg("json");
+        cmd.assert()
+    };
+
+    // Frontmatter text is not checked for placeholders, but the status must be final
+    fs::write(&brief_path, format!("---\nstatus: draft\nnotes: TODO\n---\n{body}")).unwrap();
+    let output = gate_json().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let issues = report["documents"][1]["issues"].as_array().unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0]["rule"], "final-before-unlock");
+    assert_eq!(
+        issues[0]["message"],
+        "Frontmatter field 'status' is 'draft' (must be 'final')"
+    );
+    assert_eq!(issues[0]["line"], 2);
+
+    fs::write(&brief_path, format!("---\nstatus: final\nnotes: TODO\n---\n{body}")).unwrap();
+    gate_json().success();
+}
+
+#[test]
+fn test_gate_invalid_rule_is_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_rule_heuristics(project_path, r#"[{ "id": "broken", "pattern": "(" }]"#);
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_valid_planning_doc(&planning_dir.join("01-Project-Brief.md"));
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path);
+
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("broken"));
+}
+
+#[test]
+fn test_gate_warnings_block_only_in_strict_mode() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_rule_heuristics(
+        project_path,
+        r#"[{ "id": "vision-measurable", "section": "Vision", "pattern": "\\d+%", "severity": "warning" }]"#,
+    );
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_valid_planning_doc(&planning_dir.join("01-Project-Brief.md"));
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    // Strict mode (the default): the warning is promoted and closes the gate
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path);
+    cmd.assert()
+        .code(1)
+        .stdout(predicate::str::contains("GATE CLOSED"));
+
+    // Non-strict mode: the gate opens and lists the warning
+    let config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    fs::write(
+        project_path.join("nexus.toml"),
+        config.replace("strict_mode = true", "strict_mode = false"),
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("[warning]"))
+        .stdout(predicate::str::contains("1 warning(s)"))
+        .stdout(predicate::str::contains("MISSION READY"));
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path).arg("--format").arg("json");
+    let output = cmd.assert().success().get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(report["summary"]["warnings"], 1);
+    assert_eq!(report["documents"][1]["issues"][0]["severity"], "warning");
+}
+
+#[test]
+fn test_gate_waiver_suppresses_issue_until_expiry() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_heuristics(project_path);
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    let doc_path = planning_dir.join("01-Project-Brief.md");
+    create_valid_planning_doc(&doc_path);
+    let mut content = fs::read_to_string(&doc_path).unwrap();
+    content.push_str("\n# Vendor\nTBD\n");
+    fs::write(&doc_path, content).unwrap();
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path);
+    cmd.assert().code(1);
+
+    // Record a waiver for the TBD in the Vendor section
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate")
+        .arg("waive")
+        .arg(project_path)
+        .args(["--rule", "illegal-string"])
+        .args(["--file", "01-Project-Brief.md"])
+        .args(["--section", "Vendor"])
+        .args(["--match", "TBD"])
+        .args(["--reason", "Vendor decision pending procurement"])
+        .args(["--author", "tester"])
+        .args(["--expires", "2099-12-31"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Waiver recorded"));
+
+    let waivers = fs::read_to_string(project_path.join(".nexus/gate-waivers.toml")).unwrap();
+    assert!(waivers.contains("reason = \"Vendor decision pending procurement\""));
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("[waived]"))
+        .stdout(predicate::str::contains("WAIVERS"))
+        .stdout(predicate::str::contains("1 issue(s) waived"));
+
+    // Once expired, the waiver no longer applies and the gate closes again
+    fs::write(
+        project_path.join(".nexus/gate-waivers.toml"),
+        waivers.replace("2099-12-31", "2000-01-01"),
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path).arg("--format").arg("json");
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(report["waivers"][0]["status"], "expired");
+    assert_eq!(report["summary"]["waived"], 0);
+}
+
+#[test]
+fn test_gate_cross_document_conflict() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_heuristics(project_path);
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    fs::write(
+        planning_dir.join("03-Tech-Stack.md"),
+        r"# Tech Stack
+
+## Stack (force yourself to choose NOW):
+- API: GraphQL served by axum
+
+## What I will NOT use:
+- GraphQL (REST is simpler for this scope)
+",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path).arg("--format").arg("json");
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+
+    let conflict = report["documents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|doc| doc["group"] == "cross_document")
+        .expect("cross-document group is reported");
+    assert_eq!(conflict["passed"], false);
+
+    let issue = &conflict["issues"][0];
+    assert_eq!(issue["kind"], "cross_document_conflict");
+    assert_eq!(issue["rule"], "cross-document-conflict");
+    assert_eq!(issue["line"], 4);
+    assert!(issue["message"].as_str().unwrap().contains("'GraphQL'"));
+}
+
+#[test]
+fn test_gate_layered_heuristics_and_explain_config() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path().join("repo");
+    let home = temp_dir.path().join("home");
+    fs::create_dir_all(&project_path).unwrap();
+
+    let profile_dir = home.join(".config/nexus/heuristics");
+    fs::create_dir_all(&profile_dir).unwrap();
+    fs::write(
+        profile_dir.join("team.json"),
+        r#"{ "min_section_length": 50, "required_headers": [], "illegal_strings": ["TODO"] }"#,
+    )
+    .unwrap();
+
+    create_nexus_config(&project_path, &project_path);
+    fs::create_dir_all(project_path.join(".nexus")).unwrap();
+    fs::write(
+        project_path.join(".nexus/gate-heuristics.json"),
+        r#"{ "extends": "team", "illegal_strings": { "add": ["WIP"] } }"#,
+    )
+    .unwrap();
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_valid_planning_doc(&planning_dir.join("01-Project-Brief.md"));
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    // The inherited list still applies alongside the project's addition
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.env("HOME", &home).arg("gate").arg(&project_path);
+    cmd.assert().success();
+
+    fs::write(
+        planning_dir.join("02-Notes.md"),
+        "# Notes\n- Status: WIP\n",
+    )
+    .unwrap();
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.env("HOME", &home).arg("gate").arg(&project_path);
+    cmd.assert()
+        .code(1)
+        .stdout(predicate::str::contains("Illegal string 'WIP'"));
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.env("HOME", &home)
+        .arg("gate")
+        .arg(&project_path)
+        .arg("--explain-config");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::is_match(r"min_section_length = 50\s+← team").unwrap())
+        .stdout(predicate::str::is_match(r"- TODO\s+← team").unwrap())
+        .stdout(predicate::str::is_match(r"- WIP\s+← project").unwrap());
+}
+
+#[test]
+fn test_gate_validates_project_defined_documents() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    let mut config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    config.push_str(
+        r#"
+[[planning.documents]]
+filename = "06-Risks.md"
+display_name = "Risks"
+required_headers = ["Top risks:", "Mitigations:"]
+"#,
+    );
+    fs::write(project_path.join("nexus.toml"), config).unwrap();
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    fs::write(
+        planning_dir.join("06-Risks.md"),
+        "# Risks\n\n## Top risks:\nThe payment provider could change its pricing before launch and eat the margin.\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path).arg("--format").arg("json");
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let documents = report["documents"].as_array().unwrap();
+
+    // The built-in documents are still required alongside the project's own
+    let vision = documents
+        .iter()
+        .find(|doc| doc["name"] == "01-Problem-and-Vision.md")
+        .unwrap();
+    assert!(vision["summary"].as_str().unwrap().contains("File not found"));
+
+    let risks = documents.iter().find(|doc| doc["name"] == "06-Risks.md").unwrap();
+    assert_eq!(risks["passed"], false);
+    let missing = risks["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|issue| issue["kind"] == "missing_header")
+        .unwrap();
+    assert!(missing["message"].as_str().unwrap().contains("Mitigations:"));
+}
+
+#[test]
+fn test_gate_reuses_cached_results_until_content_changes() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_heuristics(project_path);
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    let brief_path = planning_dir.join("01-Project-Brief.md");
+    create_invalid_planning_doc(&brief_path);
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    let gate = |extra: &[&str]| {
+        let mut cmd = cargo_bin_cmd!("nexus");
+        cmd.arg("gate").arg(project_path).args(extra);
+        cmd.assert()
+    };
+
+    // First run validates the document and records it in the cache
+    gate(&[]).failure().code(1);
+    let cache_path = project_path.join(".nexus/gate-cache.json");
+    let mut cache: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&cache_path).unwrap()).unwrap();
+    let entry = &mut cache["documents"]["01-Project-Brief.md"];
+    assert!(!entry["issues"].as_array().unwrap().is_empty());
+
+    // An unchanged document is not validated again: the (tampered) cached result wins
+    entry["issues"] = serde_json::json!([]);
+    fs::write(&cache_path, serde_json::to_string(&cache).unwrap()).unwrap();
+    gate(&[]).success();
+
+    // --no-cache forces a full run and rebuilds the cache
+    gate(&["--no-cache"]).failure().code(1);
+    gate(&[]).failure().code(1);
+
+    // Editing the document invalidates its entry
+    create_valid_planning_doc(&brief_path);
+    gate(&[]).success();
+}
+
+#[test]
+fn test_gate_watch_revalidates_until_mission_ready() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_heuristics(project_path);
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    let brief_path = planning_dir.join("01-Project-Brief.md");
+    create_invalid_planning_doc(&brief_path);
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    let mut child = std::process::Command::new(env!("CARGO_BIN_EXE_nexus"))
+        .arg("gate")
+        .arg(project_path)
+        .arg("--watch")
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // The first run writes the cache; the watcher is active by then
+    let cache_path = project_path.join(".nexus/gate-cache.json");
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(30);
+    while !cache_path.exists() && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+    create_valid_planning_doc(&brief_path);
+
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        if std::time::Instant::now() > deadline {
+            child.kill().unwrap();
+            panic!("gate --watch did not finish after the document was fixed");
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    };
+    let output = child.wait_with_output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(status.success(), "{stdout}");
+    assert!(stdout.contains("GATE WATCH"));
+    assert!(stdout.contains("issue(s) remaining"));
+    assert!(stdout.contains("01-Project-Brief.md"));
+    assert!(stdout.contains("MISSION READY"));
+}
+
+#[test]
+fn test_gate_reports_broken_wikilinks() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_heuristics(project_path);
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    let brief_path = planning_dir.join("01-Project-Brief.md");
+    create_valid_planning_doc(&brief_path);
+    let mut brief = fs::read_to_string(&brief_path).unwrap();
+    brief.push_str("\nSee [[01-Project-Brief#Vision]] and [[Data-Model#Entities]].\n");
+    fs::write(&brief_path, brief).unwrap();
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    let gate_json = || {
+        let mut cmd = cargo_bin_cmd!("nexus");
+        cmd.arg("gate").arg(project_path).arg("--format").arg("json");
+        cmd.assert()
+    };
+
+    let output = gate_json().failure().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let issues = report["documents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["name"] == "01-Project-Brief.md")
+        .unwrap()["issues"]
+        .as_array()
+        .unwrap()
+        .clone();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0]["kind"], "broken_link");
+    assert_eq!(issues[0]["target"], "Data-Model#Entities");
+    assert_eq!(issues[0]["section"], "Architecture");
+    assert!(issues[0]["line"].is_u64());
+
+    // Links resolve anywhere in the vault, but the heading must exist too
+    let notes_dir = project_path.join("notes");
+    fs::create_dir_all(&notes_dir).unwrap();
+    fs::write(notes_dir.join("Data-Model.md"), "# Data Model\n").unwrap();
+    gate_json().failure().code(1);
+    fs::write(notes_dir.join("Data-Model.md"), "# Data Model\n\n## Entities\n").unwrap();
+    gate_json().success();
+}
+
+#[test]
+fn test_gate_validates_embedded_content() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_heuristics(project_path);
+
+    // The Architecture section is written in a separate note and embedded
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    let brief_path = planning_dir.join("01-Project-Brief.md");
+    create_valid_planning_doc(&brief_path);
+    let brief = fs::read_to_string(&brief_path).unwrap();
+    let (brief, _) = brief.split_once("# Architecture\n").unwrap();
+    fs::write(
+        &brief_path,
+        format!("{brief}# Architecture\n![[Architecture-Notes#Overview]]\n"),
+    )
+    .unwrap();
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::create_dir_all(&management_dir).unwrap();
+    create_valid_dashboard(&management_dir.join("00-START-HERE.md"));
+
+    let notes_dir = project_path.join("notes");
+    fs::create_dir_all(&notes_dir).unwrap();
+    let overview = "The system is a single binary that reads markdown files from the vault, \
+        validates them against the planning schema, and renders reports for terminals, editors \
+        and continuous integration. Every module owns one concern and communicates through plain \
+        data structures, which keeps the architecture easy to test and explain to new contributors.";
+    let note_path = notes_dir.join("Architecture-Notes.md");
+    fs::write(
+        &note_path,
+        format!("# Architecture Notes\n\n## Overview\n{overview}\n\n## Open questions\nTODO\n"),
+    )
+    .unwrap();
+
+    let gate_json = || {
+        let mut cmd = cargo_bin_cmd!("nexus");
+        cmd.arg("gate").arg(project_path).arg("--format").arg("json");
+        cmd.assert()
+    };
+
+    // Only the embedded section counts, and its words satisfy the minimum
+    gate_json().success();
+
+    // Placeholders in embedded content are reported in the embedded file
+    fs::write(
+        &note_path,
+        format!("# Architecture Notes\n\n## Overview\n{overview}\nTODO: confirm caching\n"),
+    )
+    .unwrap();
+    let output = gate_json().failure().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let issues = report["documents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["name"] == "01-Project-Brief.md")
+        .unwrap()["issues"]
+        .as_array()
+        .unwrap()
+        .clone();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0]["kind"], "illegal_string");
+    assert_eq!(issues[0]["file"], "notes/Architecture-Notes.md");
+    assert_eq!(issues[0]["embedded_in"], "01-PLANNING/01-Project-Brief.md");
+    assert_eq!(issues[0]["line"], 5);
+    assert_eq!(issues[0]["column"], 1);
+}
+
+#[test]
+fn test_gate_json_missing_config_exit_code() {
+    let temp_dir = TempDir::new().unwrap();
+
+    // Don't create config file
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate")
+        .arg(temp_dir.path())
+        .arg("--format")
+        .arg("json");
+
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("Failed to read config"));
+}
+
 #[test]
 fn test_gate_adhoc_mode_fails_with_empty_planning() {
     let temp_dir = TempDir::new().unwrap();
@@ -407,3 +1117,61 @@ adhoc_dashboard = "00-ADHOC-TASK.md"
         .stdout(predicate::str::contains("MISSION READY"))
         .stdout(predicate::str::contains("Gate is open"));
 }
+
+#[test]
+fn test_gate_rejects_invalid_sprint_dependencies() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path);
+    create_heuristics(project_path);
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    fs::write(
+        planning_dir.join("05-MVP-Breakdown.md"),
+        r"# MVP Breakdown
+
+## Sprint 1: Core
+**Depends on:** sprint-2
+- [ ] Schema 🆔 schema
+
+## Sprint 2: Sync
+- [ ] Engine ⛔ schema
+- [ ] Offline mode ⛔ cache
+",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("gate").arg(project_path).arg("--format").arg("json");
+
+    let output = cmd.assert().code(1).get_output().stdout.clone();
+    let report: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    let issues = report["documents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["name"] == "05-MVP-Breakdown.md")
+        .unwrap()["issues"]
+        .as_array()
+        .unwrap()
+        .clone();
+
+    let unknown = issues
+        .iter()
+        .find(|issue| issue["rule"] == "unknown-dependency")
+        .expect("dangling reference is reported");
+    assert_eq!(unknown["kind"], "invalid_dependency");
+    assert_eq!(unknown["line"], 9);
+    assert_eq!(unknown["section"], "Sprint 2: Sync");
+
+    let cycle = issues
+        .iter()
+        .find(|issue| issue["rule"] == "dependency-cycle")
+        .expect("cycle is reported");
+    assert_eq!(
+        cycle["message"],
+        "Dependency cycle: sprint-2 → s2-1 → schema → sprint-2"
+    );
+}
@@ -347,3 +347,43 @@ fn test_init_invalid_mode_fails() {
         .failure()
         .stderr(predicate::str::contains("Invalid mode"));
 }
+
+#[test]
+fn test_init_locale_copies_translated_templates() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let project_path = temp_dir.path().join("svenskt_projekt");
+
+    let mut cmd = nexus_cmd();
+    cmd.arg("init")
+        .arg(&project_path)
+        .arg("--locale")
+        .arg("sv")
+        .assert()
+        .success();
+
+    // Planning documents are translated; the other templates are copied as usual
+    let vision = fs::read_to_string(project_path.join("01-Problem-and-Vision.md"))
+        .expect("Failed to read vision");
+    assert!(vision.contains("## Lösning i EN MENING:"));
+    let breakdown = fs::read_to_string(project_path.join("05-MVP-Breakdown.md"))
+        .expect("Failed to read breakdown");
+    assert!(breakdown.contains("## Sprint 1:"));
+    assert!(project_path.join("00-START-HERE.md").exists());
+}
+
+#[test]
+fn test_init_unknown_locale_fails() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let project_path = temp_dir.path().join("unknown_locale_test");
+
+    let mut cmd = nexus_cmd();
+    cmd.arg("init")
+        .arg(&project_path)
+        .arg("--locale")
+        .arg("xx")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown locale 'xx'. Available: en, sv"));
+
+    assert!(!project_path.exists(), "Nothing should be created for an unknown locale");
+}
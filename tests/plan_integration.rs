@@ -1,7 +1,7 @@
 //! Integration tests for the `nexus plan` command
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
 /// Helper: Create a test project structure with nexus.toml
@@ -54,7 +54,7 @@ enabled = false
 }
 
 /// Helper: Create a vision document
-fn create_vision_document(vault_path: &PathBuf) {
+fn create_vision_document(vault_path: &Path) {
     let vision_content = r#"# My problem (personal):
 I need to manage my projects better and ensure I stay focused on the MVP.
 
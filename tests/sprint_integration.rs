@@ -268,6 +268,33 @@ fn test_sprint_command_succeeds_if_previous_approved() {
         .stdout(predicate::str::contains("SPRINT READY"));
 }
 
+#[test]
+fn test_sprint_command_pins_referenced_task_ids() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-3", "approved")));
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    let mvp_path = planning_dir.join("05-MVP-Breakdown.md");
+    let mvp = fs::read_to_string(&mvp_path).unwrap().replace(
+        "- [ ] Branching Logic: Use the git2 crate",
+        "- [ ] Branching Logic: Use the git2 crate ⛔ s4-1",
+    );
+    fs::write(&mvp_path, mvp).unwrap();
+    init_test_git_repo(&project_path.to_path_buf());
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg(project_path).arg("4");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Pinned task ids in 01-PLANNING/05-MVP-Breakdown.md: s4-1"));
+
+    let mvp = fs::read_to_string(&mvp_path).unwrap();
+    assert!(mvp.contains("- [ ] MVP Parser: Extract specific sprint tasks 🆔 s4-1\n"), "{mvp}");
+}
+
 #[test]
 fn test_sprint_command_fails_with_dirty_working_directory() {
     let temp_dir = TempDir::new().unwrap();
@@ -324,3 +351,823 @@ fn test_sprint_command_fails_with_invalid_sprint_number() {
         .failure()
         .stderr(predicate::str::contains("Sprint 99 not found"));
 }
+
+/// Helper to create an MVP breakdown whose sprints depend on each other
+fn create_dependent_mvp_breakdown(planning_dir: &Path) {
+    let mvp_content = r"# MVP broken into sprints
+
+## Sprint 1: Storage
+- [x] Schema ~2h 🆔 schema
+- [x] Migrations ~1h
+
+## Sprint 2: Sync
+- [ ] Sync engine ~4h ⛔ schema
+
+## Sprint 3: Sharing
+**Depends on:** sprint-2
+- [ ] Share links ~30m
+";
+
+    fs::write(planning_dir.join("05-MVP-Breakdown.md"), mvp_content).unwrap();
+}
+
+#[test]
+fn test_sprint_command_fails_if_prerequisites_not_approved() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    // Sprint 1 is approved, but Sprint 3 also needs Sprint 2
+    create_nexus_config(project_path, project_path, Some(("sprint-1", "approved")));
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_dependent_mvp_breakdown(&planning_dir);
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg(project_path).arg("3");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("Prerequisites not approved"))
+        .stdout(predicate::str::contains("Sprint 2: Sync"))
+        .stdout(predicate::str::contains("Sprint 1: Storage").not())
+        .stderr(predicate::str::contains(
+            "Cannot start Sprint 3 until its prerequisites are approved: Sprint 2",
+        ));
+}
+
+#[test]
+fn test_roadmap_command_prints_graph_and_critical_path() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-2", "in_progress")));
+    let config_path = project_path.join("nexus.toml");
+    let config = fs::read_to_string(&config_path)
+        .unwrap()
+        .replace("is_unlocked = true", "is_unlocked = true\napproved_sprints = [1]");
+    fs::write(&config_path, config).unwrap();
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_dependent_mvp_breakdown(&planning_dir);
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("roadmap").arg(project_path);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Sprint 1: Storage ~3h [approved]"))
+        .stdout(predicate::str::contains("Sprint 2: Sync ~4h [in progress]"))
+        .stdout(predicate::str::contains("Sprint 3: Sharing ~30m [blocked by Sprint 2]"))
+        .stdout(predicate::str::contains("Suggested order: 1 → 2 → 3"))
+        .stdout(predicate::str::contains("Critical path: 3 task(s), ~6.5h"));
+}
+
+#[test]
+fn test_roadmap_command_fails_on_dependency_cycle() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, None);
+
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    fs::write(
+        planning_dir.join("05-MVP-Breakdown.md"),
+        "## Sprint 1: Storage\n**Depends on:** sprint-2\n- [ ] Schema\n\n## Sprint 2: Sync\n**Depends on:** sprint-1\n- [ ] Engine\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("roadmap").arg(project_path);
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("dependency-cycle"))
+        .stdout(predicate::str::contains("sprint-1 → sprint-2 → sprint-1"));
+}
+
+/// Helper to write the sprint 4 workspace's Tasks.md
+fn create_sprint_tasks(project_path: &Path, tasks: &str) -> PathBuf {
+    let sprint_dir = project_path.join("00-MANAGEMENT/sprints/sprint-4-the-sprint-orchestrator");
+    fs::create_dir_all(sprint_dir.join("approvals")).unwrap();
+    fs::write(sprint_dir.join("Tasks.md"), tasks).unwrap();
+    sprint_dir
+}
+
+#[test]
+fn test_sprint_approve_writes_record_and_marks_approved() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-4", "in_progress")));
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    let mut mvp = fs::read_to_string(planning_dir.join("05-MVP-Breakdown.md")).unwrap();
+    mvp.push_str("\n## Definition of Done (each sprint):\n- [ ] Tests pass\n- [ ] Docs updated\n");
+    fs::write(planning_dir.join("05-MVP-Breakdown.md"), mvp).unwrap();
+    let sprint_dir = create_sprint_tasks(
+        project_path,
+        "# Sprint 4 Tasks\n\n- [x] MVP Parser 🆔 s4-1\n- [x] Branching Logic 🆔 s4-2\n",
+    );
+    init_test_git_repo(&project_path.to_path_buf());
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint")
+        .arg("approve")
+        .arg(project_path)
+        .arg("--approver")
+        .arg("Dana Lee")
+        .arg("--yes");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("SPRINT APPROVED"));
+
+    let records: Vec<_> = fs::read_dir(sprint_dir.join("approvals"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(records.len(), 1);
+    let record = fs::read_to_string(&records[0]).unwrap();
+    let head = git2::Repository::open(project_path)
+        .unwrap()
+        .head()
+        .unwrap()
+        .peel_to_commit()
+        .unwrap()
+        .id()
+        .to_string();
+    assert!(record.contains("approved_by: \"Dana Lee\""));
+    assert!(record.contains(&format!("commit: {head}")));
+    assert!(record.contains("2 task(s) done"));
+    assert!(record.contains("- [x] Tests pass\n- [x] Docs updated\n"));
+
+    let config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    assert!(config.contains("status = \"approved\""));
+    assert!(config.contains("approved = \""));
+}
+
+#[test]
+fn test_sprint_approve_records_worktree_commit() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-3", "approved")));
+    let mut config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    config.push_str("\n[git]\nworktrees = true\n");
+    fs::write(project_path.join("nexus.toml"), config).unwrap();
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    init_test_git_repo(&project_path.to_path_buf());
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg(project_path).arg("4");
+    cmd.assert().success();
+
+    // Sprint work is committed in the worktree only
+    let worktree = project_path.join(".nexus/worktrees/sprint-4-the-sprint-orchestrator");
+    let repo = git2::Repository::open(&worktree).unwrap();
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    fs::write(worktree.join("feature.txt"), "sprint work\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("feature.txt")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = git2::Signature::now("Test User", "test@example.com").unwrap();
+    let sprint_commit = repo
+        .commit(Some("HEAD"), &signature, &signature, "Sprint work", &tree, &[&head])
+        .unwrap();
+
+    let sprint_dir = project_path.join("00-MANAGEMENT/sprints/sprint-4-the-sprint-orchestrator");
+    let tasks = fs::read_to_string(sprint_dir.join("Tasks.md")).unwrap();
+    fs::write(sprint_dir.join("Tasks.md"), tasks.replace("- [ ]", "- [x]")).unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint")
+        .arg("approve")
+        .arg(project_path)
+        .arg("--approver")
+        .arg("Dana Lee")
+        .arg("--yes");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("SPRINT APPROVED"));
+
+    let records: Vec<_> = fs::read_dir(sprint_dir.join("approvals"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(records.len(), 1);
+    let record = fs::read_to_string(&records[0]).unwrap();
+    assert!(record.contains(&format!("commit: {sprint_commit}")), "{record}");
+    assert!(record.contains("feature/sprint-4-the-sprint-orchestrator"), "{record}");
+}
+
+#[test]
+fn test_sprint_approve_fails_with_unchecked_tasks() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-4", "in_progress")));
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    let sprint_dir = create_sprint_tasks(
+        project_path,
+        "# Sprint 4 Tasks\n\n- [x] MVP Parser 🆔 s4-1\n- [ ] Branching Logic 🆔 s4-2\n",
+    );
+    init_test_git_repo(&project_path.to_path_buf());
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint")
+        .arg("approve")
+        .arg(project_path)
+        .arg("--approver")
+        .arg("Dana Lee")
+        .arg("--yes");
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("APPROVAL BLOCKED"))
+        .stdout(predicate::str::contains("Branching Logic"));
+
+    assert_eq!(fs::read_dir(sprint_dir.join("approvals")).unwrap().count(), 0);
+    let config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    assert!(config.contains("status = \"in_progress\""));
+}
+
+#[test]
+fn test_sprint_close_carries_unfinished_tasks_into_next_sprint() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-3", "in_progress")));
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    let sprint_dir = project_path.join("00-MANAGEMENT/sprints/sprint-3-the-unlock");
+    fs::create_dir_all(&sprint_dir).unwrap();
+    fs::write(
+        sprint_dir.join("Tasks.md"),
+        "# Sprint 3 Tasks: The Unlock\n\n## Task List\n- [x] Integrate Tera templating 🆔 s3-1\n- [ ] Generate CLAUDE.md 🆔 s3-2\n\n## Notes\n\n## Blockers\n- Template engine upgrade pending\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg("close").arg(project_path);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("SPRINT CLOSED"))
+        .stdout(predicate::str::contains("carried over to Sprint 4"));
+
+    let next_tasks = fs::read_to_string(
+        project_path.join("00-MANAGEMENT/sprints/sprint-4-the-sprint-orchestrator/Tasks.md"),
+    )
+    .unwrap();
+    assert!(next_tasks.contains("## Carried Over"));
+    assert!(next_tasks.contains("Close-Out|Sprint 3: The Unlock]]"));
+    assert!(next_tasks.contains(
+        "- [ ] Generate CLAUDE.md 🆔 s3-2 #carried-over [carried-from:: sprint-3]"
+    ));
+    assert!(next_tasks.contains("MVP Parser"), "next sprint workspace is scaffolded");
+
+    let report = fs::read_to_string(sprint_dir.join("Close-Out.md")).unwrap();
+    assert!(report.contains("tasks_carried_over: 1"));
+    assert!(report.contains("- [x] Integrate Tera templating 🆔 s3-1"));
+    assert!(report.contains("- Template engine upgrade pending"));
+
+    let config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    assert!(config.contains("status = \"closed\""));
+}
+
+#[test]
+fn test_sprint_close_to_backlog() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-4", "in_progress")));
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    create_sprint_tasks(
+        project_path,
+        "# Sprint 4 Tasks\n\n- [x] MVP Parser 🆔 s4-1\n- [ ] Branching Logic 🆔 s4-2\n",
+    );
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg("close").arg(project_path).arg("--backlog");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("carried over to the backlog"));
+
+    let backlog = fs::read_to_string(project_path.join("00-MANAGEMENT/Backlog.md")).unwrap();
+    assert!(backlog.contains("## From Sprint 4: The Sprint Orchestrator"));
+    assert!(backlog.contains("- [ ] Branching Logic 🆔 s4-2 #carried-over [carried-from:: sprint-4]"));
+
+    // A closed sprint can be closed only once
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg("close").arg(project_path);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("already closed"));
+}
+
+#[test]
+fn test_sprint_finish_requires_approval() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-4", "in_progress")));
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    init_test_git_repo(&project_path.to_path_buf());
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg("finish").arg(project_path);
+
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("FINISH BLOCKED"))
+        .stderr(predicate::str::contains("until it is approved"));
+}
+
+#[test]
+fn test_sprint_finish_merges_and_tags_branch() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-4", "approved")));
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    init_test_git_repo(&project_path.to_path_buf());
+
+    // Work on the sprint branch
+    let repo = git2::Repository::open(project_path).unwrap();
+    let base = repo.head().unwrap().shorthand().unwrap().to_string();
+    let initial = repo.head().unwrap().peel_to_commit().unwrap();
+    repo.branch("feature/sprint-4-the-sprint-orchestrator", &initial, false)
+        .unwrap();
+    repo.set_head("refs/heads/feature/sprint-4-the-sprint-orchestrator")
+        .unwrap();
+    fs::write(project_path.join("feature.txt"), "sprint work\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("feature.txt")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = git2::Signature::now("Test User", "test@example.com").unwrap();
+    repo.commit(Some("HEAD"), &signature, &signature, "Sprint work", &tree, &[&initial])
+        .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint")
+        .arg("finish")
+        .arg(project_path)
+        .arg("--base")
+        .arg(&base)
+        .arg("--delete-branch");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("SPRINT FINISHED"))
+        .stdout(predicate::str::contains("Tagged sprint-4"));
+
+    let repo = git2::Repository::open(project_path).unwrap();
+    assert_eq!(repo.head().unwrap().shorthand(), Some(base.as_str()));
+    let merge = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(merge.parent_count(), 2);
+    assert!(
+        repo.find_branch("feature/sprint-4-the-sprint-orchestrator", git2::BranchType::Local)
+            .is_err()
+    );
+    let tag = repo
+        .find_reference("refs/tags/sprint-4")
+        .unwrap()
+        .peel_to_tag()
+        .unwrap();
+    assert!(tag.message().unwrap().starts_with("Sprint 4: The Sprint Orchestrator\n\n0 of 3 task(s) done"));
+}
+
+#[test]
+fn test_sprint_command_creates_worktree() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-3", "approved")));
+    let mut config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    config.push_str("\n[git]\nworktrees = true\n");
+    fs::write(project_path.join("nexus.toml"), config).unwrap();
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    fs::write(project_path.join("README.md"), "# Test Project\n").unwrap();
+    init_test_git_repo(&project_path.to_path_buf());
+
+    // A dirty main working tree does not block worktree mode
+    fs::write(project_path.join("README.md"), "# Hotfix in progress\n").unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg(project_path).arg("4");
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Worktree created"))
+        .stdout(predicate::str::contains("SPRINT READY"));
+
+    let worktree = project_path.join(".nexus/worktrees/sprint-4-the-sprint-orchestrator");
+    let repo = git2::Repository::open(&worktree).unwrap();
+    assert_eq!(
+        repo.head().unwrap().shorthand(),
+        Some("feature/sprint-4-the-sprint-orchestrator")
+    );
+    let main = git2::Repository::open(project_path).unwrap();
+    assert_ne!(
+        main.head().unwrap().shorthand(),
+        Some("feature/sprint-4-the-sprint-orchestrator")
+    );
+
+    let config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    assert!(config.contains("worktree = \".nexus/worktrees/sprint-4-the-sprint-orchestrator\""));
+}
+
+/// Project with Sprint 3 approved and Sprint 4 started on its branch
+fn start_sprint_four(project_path: &Path) -> String {
+    create_nexus_config(project_path, project_path, Some(("sprint-3", "approved")));
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    fs::write(project_path.join("README.md"), "# Test Project\n").unwrap();
+    init_test_git_repo(&project_path.to_path_buf());
+    let base = git2::Repository::open(project_path)
+        .unwrap()
+        .head()
+        .unwrap()
+        .shorthand()
+        .unwrap()
+        .to_string();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg(project_path).arg("4");
+    cmd.assert().success();
+    base
+}
+
+#[test]
+fn test_sprint_start_approve_finish() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+    let base = start_sprint_four(project_path);
+
+    // Sprint work is committed; nexus.toml stays as nexus sprint left it
+    let repo = git2::Repository::open(project_path).unwrap();
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    fs::write(project_path.join("feature.txt"), "sprint work\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("feature.txt")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = git2::Signature::now("Test User", "test@example.com").unwrap();
+    repo.commit(Some("HEAD"), &signature, &signature, "Sprint work", &tree, &[&head])
+        .unwrap();
+
+    let tasks_path = project_path.join("00-MANAGEMENT/sprints/sprint-4-the-sprint-orchestrator/Tasks.md");
+    let tasks = fs::read_to_string(&tasks_path).unwrap();
+    fs::write(&tasks_path, tasks.replace("- [ ]", "- [x]")).unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint")
+        .arg("approve")
+        .arg(project_path)
+        .arg("--approver")
+        .arg("Dana Lee")
+        .arg("--yes");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("SPRINT APPROVED"));
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg("finish").arg(project_path).arg("--base").arg(&base);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Tagged sprint-4"))
+        .stdout(predicate::str::contains("SPRINT FINISHED"));
+
+    let repo = git2::Repository::open(project_path).unwrap();
+    assert_eq!(repo.head().unwrap().shorthand(), Some(base.as_str()));
+    assert!(project_path.join("feature.txt").exists());
+    let tagged = repo
+        .find_reference("refs/tags/sprint-4")
+        .unwrap()
+        .peel_to_commit()
+        .unwrap();
+    assert_eq!(tagged.id(), repo.head().unwrap().peel_to_commit().unwrap().id());
+
+    // The approved state survives the checkout of the base branch
+    let config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    assert!(config.contains("current = \"sprint-4\""), "{config}");
+    assert!(config.contains("status = \"approved\""), "{config}");
+}
+
+#[test]
+fn test_sprint_abort_restores_previous_state() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+    let base = start_sprint_four(project_path);
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg("abort").arg(project_path);
+
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains(format!("Switched back to {base}")))
+        .stdout(predicate::str::contains("Deleted feature/sprint-4-the-sprint-orchestrator"))
+        .stdout(predicate::str::contains("Active sprint restored: sprint-3 (approved)"))
+        .stdout(predicate::str::contains("SPRINT ABORTED"));
+
+    let repo = git2::Repository::open(project_path).unwrap();
+    assert_eq!(repo.head().unwrap().shorthand(), Some(base.as_str()));
+    assert!(
+        repo.find_branch("feature/sprint-4-the-sprint-orchestrator", git2::BranchType::Local)
+            .is_err()
+    );
+
+    let sprints_dir = project_path.join("00-MANAGEMENT/sprints");
+    assert!(!sprints_dir.join("sprint-4-the-sprint-orchestrator").exists());
+    let archived: Vec<_> = fs::read_dir(sprints_dir.join("aborted"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(archived.len(), 1);
+    assert!(archived[0].starts_with("sprint-4-the-sprint-orchestrator-"));
+
+    let config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    assert!(config.contains("current = \"sprint-3\""), "{config}");
+    assert!(config.contains("status = \"approved\""), "{config}");
+    assert!(!config.contains("sprint-4"), "{config}");
+    assert!(!config.contains("approved_sprints = [3]"), "{config}");
+
+    // Sprint 4 can be started again
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg(project_path).arg("4");
+    cmd.assert().success();
+}
+
+#[test]
+fn test_sprint_abort_refuses_branch_with_commits_unless_forced() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+    start_sprint_four(project_path);
+
+    let repo = git2::Repository::open(project_path).unwrap();
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    fs::write(project_path.join("feature.txt"), "sprint work\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("feature.txt")).unwrap();
+    index.add_path(Path::new("nexus.toml")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = git2::Signature::now("Test User", "test@example.com").unwrap();
+    repo.commit(Some("HEAD"), &signature, &signature, "Sprint work", &tree, &[&head])
+        .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg("abort").arg(project_path);
+    cmd.assert()
+        .failure()
+        .stdout(predicate::str::contains("ABORT BLOCKED"))
+        .stderr(predicate::str::contains("1 commit(s) of its own"));
+    assert!(project_path.join("feature.txt").exists());
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint")
+        .arg("abort")
+        .arg(project_path)
+        .arg("--force")
+        .arg("--remove");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 commit(s) discarded"))
+        .stdout(predicate::str::contains("Removed 00-MANAGEMENT/sprints/sprint-4-the-sprint-orchestrator/"));
+
+    assert!(!project_path.join("feature.txt").exists());
+    assert!(!project_path.join("00-MANAGEMENT/sprints/aborted").exists());
+    assert!(!project_path.join("00-MANAGEMENT/sprints/sprint-4-the-sprint-orchestrator").exists());
+}
+
+#[test]
+fn test_sprint_command_honors_custom_structure() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-3", "approved")));
+    let config = fs::read_to_string(project_path.join("nexus.toml"))
+        .unwrap()
+        .replace(r#"planning_dir = "01-PLANNING""#, r#"planning_dir = "docs/plan""#)
+        .replace(r#"sprint_dir = "00-MANAGEMENT/sprints""#, r#"sprint_dir = "docs/sprints""#);
+    fs::write(project_path.join("nexus.toml"), config).unwrap();
+
+    let planning_dir = project_path.join("docs/plan");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    init_test_git_repo(&project_path.to_path_buf());
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg(project_path).arg("4");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("docs/sprints/sprint-4-the-sprint-orchestrator"));
+
+    let sprint_folder = project_path.join("docs/sprints/sprint-4-the-sprint-orchestrator");
+    assert!(sprint_folder.join("Tasks.md").exists());
+    assert!(!project_path.join("00-MANAGEMENT/sprints").exists());
+    let context = fs::read_to_string(sprint_folder.join("Sprint-Context.md")).unwrap();
+    assert!(context.contains("docs/plan/05-MVP-Breakdown.md"));
+}
+
+#[test]
+fn test_sprint_command_renders_vault_templates() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+
+    create_nexus_config(project_path, project_path, Some(("sprint-3", "approved")));
+    let config = fs::read_to_string(project_path.join("nexus.toml")).unwrap().replace(
+        r#"claude_template = "templates/CLAUDE.md.example""#,
+        "claude_template = \"templates/CLAUDE.md.example\"\n\
+         sprint_files = [\"Tasks.md\", \"Sprint-Context.md\"]\n\
+         sprint_folders = [\"approvals\", \"reviews\"]",
+    );
+    fs::write(project_path.join("nexus.toml"), config).unwrap();
+    let planning_dir = project_path.join("01-PLANNING");
+    fs::create_dir_all(&planning_dir).unwrap();
+    create_mvp_breakdown(&planning_dir);
+    let templates_dir = project_path.join("00-MANAGEMENT/templates/sprint");
+    fs::create_dir_all(&templates_dir).unwrap();
+    fs::write(
+        templates_dir.join("Sprint-Context.md.tera"),
+        "# {{ sprint.title }}\n\n## Forbidden\n- Anything not in [[Coding-Standards]]\n",
+    )
+    .unwrap();
+    init_test_git_repo(&project_path.to_path_buf());
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("sprint").arg(project_path).arg("4");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("✓ reviews/"));
+
+    let sprint_folder = project_path.join("00-MANAGEMENT/sprints/sprint-4-the-sprint-orchestrator");
+    assert_eq!(
+        fs::read_to_string(sprint_folder.join("Sprint-Context.md")).unwrap(),
+        "# The Sprint Orchestrator\n\n## Forbidden\n- Anything not in [[Coding-Standards]]\n"
+    );
+    assert!(fs::read_to_string(sprint_folder.join("Tasks.md")).unwrap().contains("## Task List"));
+    assert!(sprint_folder.join("reviews").is_dir());
+    assert!(!sprint_folder.join("sessions").exists());
+}
+
+#[test]
+fn test_migrate_layout_moves_folders_and_rewrites_links() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+    start_sprint_four(project_path);
+
+    let management_dir = project_path.join("00-MANAGEMENT");
+    fs::write(
+        management_dir.join("Backlog.md"),
+        "See [[01-PLANNING/05-MVP-Breakdown#Sprint 4|the plan]] and [[05-MVP-Breakdown]].\n",
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("migrate-layout")
+        .arg(project_path)
+        .arg("--planning-dir")
+        .arg("docs/plan")
+        .arg("--management-dir")
+        .arg("docs/mgmt")
+        .arg("--dry-run");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("01-PLANNING/ → docs/plan/"))
+        .stdout(predicate::str::contains("Dry run"));
+    assert!(management_dir.join("Backlog.md").exists());
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("migrate-layout")
+        .arg(project_path)
+        .arg("--planning-dir")
+        .arg("docs/plan")
+        .arg("--management-dir")
+        .arg("docs/mgmt");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("LAYOUT MIGRATED"));
+
+    assert!(!project_path.join("01-PLANNING").exists());
+    assert!(!management_dir.exists());
+    assert!(project_path.join("docs/plan/05-MVP-Breakdown.md").exists());
+    assert!(
+        project_path
+            .join("docs/mgmt/sprints/sprint-4-the-sprint-orchestrator/Tasks.md")
+            .exists()
+    );
+    assert_eq!(
+        fs::read_to_string(project_path.join("docs/mgmt/Backlog.md")).unwrap(),
+        "See [[docs/plan/05-MVP-Breakdown#Sprint 4|the plan]] and [[05-MVP-Breakdown]].\n"
+    );
+    let config = fs::read_to_string(project_path.join("nexus.toml")).unwrap();
+    assert!(config.contains(r#"planning_dir = "docs/plan""#));
+    assert!(config.contains(r#"sprint_dir = "docs/mgmt/sprints""#));
+
+    // Commands read the MVP breakdown from its new place
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("roadmap").arg(project_path);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("The Sprint Orchestrator"));
+}
+
+#[test]
+fn test_session_start_note_end_logs_sprint_session() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_path = temp_dir.path();
+    start_sprint_four(project_path);
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("session").arg("start").arg(project_path).arg("--goal").arg("Wire the CLI");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Linked from 00-MANAGEMENT/sprints/sprint-4-the-sprint-orchestrator/Tasks.md"));
+
+    // Only one session at a time
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("session").arg("start").arg(project_path);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("is still in progress"));
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("session").arg("note").arg(project_path).arg("Parser").arg("done");
+    cmd.assert().success();
+
+    let repo = git2::Repository::open(project_path).unwrap();
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    fs::write(project_path.join("feature.txt"), "sprint work\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("feature.txt")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = git2::Signature::now("Test User", "test@example.com").unwrap();
+    repo.commit(Some("HEAD"), &signature, &signature, "Add the parser", &tree, &[&head])
+        .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("session")
+        .arg("end")
+        .arg(project_path)
+        .arg("--passed")
+        .arg("--next")
+        .arg("Error handling");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("1 commit(s)"))
+        .stdout(predicate::str::contains("Add the parser"))
+        .stdout(predicate::str::contains("SESSION ENDED"));
+
+    let sprint_folder = project_path.join("00-MANAGEMENT/sprints/sprint-4-the-sprint-orchestrator");
+    let sessions: Vec<_> = fs::read_dir(sprint_folder.join("sessions"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    assert_eq!(sessions.len(), 1);
+    let session = fs::read_to_string(&sessions[0]).unwrap();
+    assert!(session.contains("Status:: 🟢 Extracted"), "{session}");
+    assert!(session.contains("One-Thing:: Wire the CLI"), "{session}");
+    assert!(session.contains("Parser done"), "{session}");
+    assert!(session.contains("Add the parser"), "{session}");
+    assert!(session.contains("Next-Task:: Error handling"), "{session}");
+    assert!(session.contains("Actual-time::"), "{session}");
+
+    let tasks = fs::read_to_string(sprint_folder.join("Tasks.md")).unwrap();
+    let name = sessions[0].file_stem().unwrap().to_string_lossy().into_owned();
+    assert!(tasks.contains("## Sessions"), "{tasks}");
+    assert!(
+        tasks.contains(&format!("sprint-4-the-sprint-orchestrator/sessions/{name}|Session ")),
+        "{tasks}"
+    );
+
+    // Nothing left to end
+    let mut cmd = cargo_bin_cmd!("nexus");
+    cmd.arg("session").arg("end").arg(project_path);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("No session in progress"));
+}